@@ -15,15 +15,33 @@
  */
 
 use crate::ast::{Quantifier, Substring};
-use crate::char::{Grapheme, GraphemeCluster};
+use crate::char::{case_variants, is_cased_char, Grapheme, GraphemeCluster};
 use crate::fsm::DFA;
-use crate::regexp::RegExpConfig;
+use crate::regexp::{GenerationError, RegExpConfig, Wildcard};
+use crate::unicode_tables::{DECIMAL_NUMBER, SCRIPTS, WHITE_SPACE, WORD};
 use itertools::EitherOrBoth::Both;
 use itertools::Itertools;
 use ndarray::{Array1, Array2};
 use petgraph::prelude::EdgeRef;
-use std::collections::BTreeSet;
+use std::cmp::Reverse;
+use std::collections::{BTreeMap, BTreeSet};
+use std::mem::size_of;
 
+const CHAR_COUNT: u128 = 0x110000 - 0x800;
+
+const NEGATED_SHORTHANDS: [&str; 3] = ["\\D", "\\S", "\\W"];
+
+const SHORTHAND_SUBSETS: [(&str, &str); 5] = [
+    ("\\D", "\\s"),
+    ("\\D", "\\W"),
+    ("\\S", "\\d"),
+    ("\\S", "\\w"),
+    ("\\W", "\\s"),
+];
+
+const MAXIMUM_SAMPLES: usize = 10_000;
+
+#[allow(clippy::large_enum_variant)]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub enum Expression {
     Alternation(Vec<Expression>, RegExpConfig),
@@ -31,13 +49,20 @@ pub enum Expression {
     Concatenation(Box<Expression>, Box<Expression>, RegExpConfig),
     Literal(GraphemeCluster, RegExpConfig),
     Repetition(Box<Expression>, Quantifier, RegExpConfig),
+    SubroutineCall(String, RegExpConfig),
 }
 
 impl Expression {
-    pub(crate) fn from(dfa: DFA, config: &RegExpConfig) -> Self {
+    pub(crate) fn from(dfa: &DFA, config: &RegExpConfig) -> Result<Self, GenerationError> {
         let states = dfa.states_in_depth_first_order();
         let state_count = dfa.state_count();
 
+        config.check_memory_budget(
+            state_count
+                .saturating_mul(state_count + 1)
+                .saturating_mul(size_of::<Option<Expression>>()),
+        )?;
+
         let mut a = Array2::<Option<Expression>>::default((state_count, state_count));
         let mut b = Array1::<Option<Expression>>::default(state_count);
 
@@ -96,19 +121,187 @@ impl Expression {
         }
 
         if !b.is_empty() && b[0].is_some() {
-            b[0].as_ref().unwrap().clone()
+            Ok(b[0].as_ref().unwrap().clone())
+        } else {
+            Ok(Expression::new_literal(
+                GraphemeCluster::from("", config),
+                config,
+            ))
+        }
+    }
+
+    pub(crate) fn from_literals(clusters: &[GraphemeCluster], config: &RegExpConfig) -> Self {
+        let mut options = clusters
+            .iter()
+            .map(|cluster| Expression::new_literal(cluster.clone(), config))
+            .collect_vec();
+
+        if options.len() == 1 {
+            options.remove(0)
         } else {
-            Expression::new_literal(GraphemeCluster::from("", config), config)
+            options.sort_by_key(|it| Reverse(it.len()));
+            Expression::Alternation(options, config.clone())
         }
     }
 
-    fn new_alternation(expr1: Expression, expr2: Expression, config: &RegExpConfig) -> Self {
+    pub(crate) fn from_prefixes(clusters: &[GraphemeCluster], config: &RegExpConfig) -> Self {
+        let sequences = clusters
+            .iter()
+            .map(|cluster| cluster.graphemes().as_slice())
+            .collect_vec();
+
+        match Self::from_prefix_tree(&sequences, config) {
+            Some(expr) => Self::new_repetition(expr, Quantifier::QuestionMark, config),
+            None => Self::new_literal(GraphemeCluster::from("", config), config),
+        }
+    }
+
+    fn from_prefix_tree(sequences: &[&[Grapheme]], config: &RegExpConfig) -> Option<Self> {
+        let mut children: BTreeMap<&Grapheme, Vec<&[Grapheme]>> = BTreeMap::new();
+        for sequence in sequences.iter().filter(|it| !it.is_empty()) {
+            children
+                .entry(&sequence[0])
+                .or_default()
+                .push(&sequence[1..]);
+        }
+
+        children
+            .into_iter()
+            .map(|(grapheme, rests)| {
+                let head =
+                    Self::new_literal(GraphemeCluster::new(grapheme.clone(), config), config);
+                match Self::from_prefix_tree(&rests, config) {
+                    Some(tail) => Self::new_concatenation(
+                        head,
+                        Self::new_repetition(tail, Quantifier::QuestionMark, config),
+                        config,
+                    ),
+                    None => head,
+                }
+            })
+            .fold(None, |union, option| {
+                Self::union(&union, &Some(option), config)
+            })
+    }
+
+    pub(crate) fn from_with_substring_branches(
+        clusters: &[GraphemeCluster],
+        test_cases: &[String],
+        config: &RegExpConfig,
+    ) -> Result<Self, GenerationError> {
+        let is_substring = |test_case: &String| {
+            !test_case.is_empty()
+                && test_cases
+                    .iter()
+                    .any(|other| other != test_case && other.contains(test_case.as_str()))
+        };
+        let (substring_clusters, other_clusters): (Vec<_>, Vec<_>) = test_cases
+            .iter()
+            .zip(clusters.iter().cloned())
+            .partition(|(test_case, _)| is_substring(test_case));
+
+        if substring_clusters.is_empty() {
+            return Self::from(&DFA::from(clusters.to_vec(), config)?, config);
+        }
+
+        let is_empty_string_contained = test_cases.iter().any(|it| it.is_empty());
+        // Clusters beyond the test cases stem from mutations such as transpositions
+        let other_clusters = other_clusters
+            .into_iter()
+            .filter(|(test_case, _)| !test_case.is_empty())
+            .map(|(_, cluster)| cluster)
+            .chain(clusters.iter().skip(test_cases.len()).cloned())
+            .collect_vec();
+        let substring_clusters = substring_clusters
+            .into_iter()
+            .map(|(_, cluster)| cluster)
+            .collect_vec();
+        let alternation = Self::new_alternation(
+            Self::from(&DFA::from(other_clusters, config)?, config)?,
+            Self::from_literals(&substring_clusters, config),
+            config,
+        );
+
+        if is_empty_string_contained {
+            Ok(Self::new_repetition(
+                alternation,
+                Quantifier::QuestionMark,
+                config,
+            ))
+        } else {
+            Ok(alternation)
+        }
+    }
+
+    pub(crate) fn from_csv_columns(
+        columns: Vec<(Expression, bool)>,
+        config: &RegExpConfig,
+    ) -> Self {
+        let separator = Some(Expression::new_literal(
+            GraphemeCluster::from(",", config),
+            config,
+        ));
+        let quote = Some(Expression::new_literal(
+            GraphemeCluster::from("\"", config),
+            config,
+        ));
+        let mut row: Option<Expression> = None;
+
+        for (column, is_quoted) in columns {
+            let mut column = Some(column);
+            if is_quoted {
+                column =
+                    Self::concatenate(&Self::concatenate(&quote, &column, config), &quote, config);
+            }
+            row = match row {
+                Some(_) => Self::concatenate(
+                    &Self::concatenate(&row, &separator, config),
+                    &column,
+                    config,
+                ),
+                None => column,
+            };
+        }
+
+        row.unwrap()
+    }
+
+    pub(crate) fn from_alternatives(alternatives: Vec<Expression>, config: &RegExpConfig) -> Self {
         let mut options: Vec<Expression> = vec![];
-        Self::flatten_alternations(&mut options, vec![expr1, expr2]);
+        Self::flatten_alternations(&mut options, alternatives);
         options.sort_by(|a, b| b.len().cmp(&a.len()));
         Expression::Alternation(options, config.clone())
     }
 
+    pub(crate) fn from_semver(
+        has_pre_release: bool,
+        has_build_metadata: bool,
+        config: &RegExpConfig,
+    ) -> Self {
+        let mut semver = Some(Expression::new_literal(
+            GraphemeCluster::from_semver_core(config),
+            config,
+        ));
+        for (prefix, is_present) in [('-', has_pre_release), ('+', has_build_metadata)].iter() {
+            if *is_present {
+                let identifiers = Some(Expression::new_repetition(
+                    Expression::new_literal(
+                        GraphemeCluster::from_semver_identifiers(*prefix, config),
+                        config,
+                    ),
+                    Quantifier::QuestionMark,
+                    config,
+                ));
+                semver = Self::concatenate(&semver, &identifiers, config);
+            }
+        }
+        semver.unwrap()
+    }
+
+    fn new_alternation(expr1: Expression, expr2: Expression, config: &RegExpConfig) -> Self {
+        Self::from_alternatives(vec![expr1, expr2], config)
+    }
+
     fn new_character_class(
         first_char_set: BTreeSet<char>,
         second_char_set: BTreeSet<char>,
@@ -140,14 +333,30 @@ impl Expression {
     pub(crate) fn is_single_codepoint(&self) -> bool {
         match self {
             Expression::CharacterClass(_, _) => true,
+            // Surrogate pairs consist of two escape sequences which cannot form a class member
             Expression::Literal(cluster, config) => {
-                cluster.char_count(config.is_non_ascii_char_escaped) == 1
+                cluster.char_count() == 1
                     && cluster.graphemes().first().unwrap().maximum() == 1
+                    && !(config.is_surrogate_pair_conversion_enabled()
+                        && cluster.graphemes()[0]
+                            .value()
+                            .chars()
+                            .any(|c| c > '\u{ffff}'))
             }
             _ => false,
         }
     }
 
+    fn is_wildcard(&self) -> bool {
+        match self {
+            Expression::Literal(cluster, config) => cluster
+                .graphemes()
+                .iter()
+                .any(|grapheme| config.is_wildcard(&grapheme.value())),
+            _ => false,
+        }
+    }
+
     fn len(&self) -> usize {
         match self {
             Expression::Alternation(options, _) => options.first().unwrap().len(),
@@ -155,6 +364,7 @@ impl Expression {
             Expression::Concatenation(expr1, expr2, _) => expr1.len() + expr2.len(),
             Expression::Literal(cluster, _) => cluster.size(),
             Expression::Repetition(expr, _, _) => expr.len(),
+            Expression::SubroutineCall(_, _) => 1,
         }
     }
 
@@ -162,7 +372,7 @@ impl Expression {
         match self {
             Expression::Alternation(_, _) | Expression::CharacterClass(_, _) => 1,
             Expression::Concatenation(_, _, _) | Expression::Literal(_, _) => 2,
-            Expression::Repetition(_, _, _) => 3,
+            Expression::Repetition(_, _, _) | Expression::SubroutineCall(_, _) => 3,
         }
     }
 
@@ -207,6 +417,838 @@ impl Expression {
         }
     }
 
+    pub(crate) fn convert_to_any_grapheme(
+        &mut self,
+        threshold: usize,
+        config: &RegExpConfig,
+    ) -> bool {
+        match self {
+            Expression::Alternation(options, _) => {
+                if options.iter().all(|it| it.is_single_grapheme())
+                    && options.iter().map(|it| it.grapheme_count()).sum::<usize>() >= threshold
+                {
+                    *self = Expression::new_any_grapheme(config);
+                    return true;
+                }
+                let mut is_converted = false;
+                for option in options.iter_mut() {
+                    is_converted |= option.convert_to_any_grapheme(threshold, config);
+                }
+                is_converted
+            }
+            Expression::CharacterClass(char_set, _) => {
+                if char_set.len() < threshold {
+                    return false;
+                }
+                *self = Expression::new_any_grapheme(config);
+                true
+            }
+            Expression::Concatenation(expr1, expr2, _) => {
+                expr1.convert_to_any_grapheme(threshold, config)
+                    | expr2.convert_to_any_grapheme(threshold, config)
+            }
+            Expression::Literal(_, _) => false,
+            Expression::Repetition(expr, _, _) => expr.convert_to_any_grapheme(threshold, config),
+            Expression::SubroutineCall(_, _) => false,
+        }
+    }
+
+    pub(crate) fn factor_common_suffixes(&mut self, config: &RegExpConfig) {
+        self.factor_common_suffixes_at_depth(0, config);
+    }
+
+    fn factor_common_suffixes_at_depth(&mut self, depth: u32, config: &RegExpConfig) {
+        match self {
+            Expression::Alternation(options, _) => {
+                for option in options.iter_mut() {
+                    option.factor_common_suffixes_at_depth(depth, config);
+                }
+
+                if let Some(maximum_depth) = config.maximum_factor_depth {
+                    if depth >= maximum_depth {
+                        return;
+                    }
+                }
+
+                let mut groups: Vec<(Option<Grapheme>, Vec<Expression>)> = vec![];
+                for option in options.iter() {
+                    let last_grapheme = option
+                        .value(Some(&Substring::Suffix))
+                        .and_then(|graphemes| graphemes.last().cloned());
+                    match groups
+                        .iter_mut()
+                        .find(|(grapheme, _)| last_grapheme.is_some() && grapheme == &last_grapheme)
+                    {
+                        Some((_, group)) => group.push(option.clone()),
+                        None => groups.push((last_grapheme, vec![option.clone()])),
+                    }
+                }
+
+                if groups.len() == options.len() {
+                    return;
+                }
+
+                let factored_expr = groups
+                    .into_iter()
+                    .map(|(_, group)| Self::factor_common_suffix(group, depth, config))
+                    .fold1(|a, b| Expression::new_alternation(a, b, config))
+                    .unwrap();
+
+                if factored_expr.to_string().len() < self.to_string().len() {
+                    *self = factored_expr;
+                }
+            }
+            Expression::Concatenation(expr1, expr2, _) => {
+                expr1.factor_common_suffixes_at_depth(depth, config);
+                expr2.factor_common_suffixes_at_depth(depth, config);
+            }
+            Expression::Repetition(expr, _, _) => {
+                expr.factor_common_suffixes_at_depth(depth, config);
+            }
+            Expression::CharacterClass(_, _)
+            | Expression::Literal(_, _)
+            | Expression::SubroutineCall(_, _) => (),
+        }
+    }
+
+    fn factor_common_suffix(mut group: Vec<Expression>, depth: u32, config: &RegExpConfig) -> Self {
+        if group.len() == 1 {
+            return group.remove(0);
+        }
+
+        let suffixes = group
+            .iter()
+            .map(|it| it.value(Some(&Substring::Suffix)).unwrap())
+            .collect_vec();
+        let shortest_suffix = suffixes.iter().min_by_key(|it| it.len()).unwrap();
+        let common_suffix_length = (1..=shortest_suffix.len())
+            .take_while(|&length| {
+                suffixes
+                    .iter()
+                    .map(|it| &it[it.len() - length..])
+                    .all_equal()
+            })
+            .last()
+            .unwrap();
+        let common_suffix =
+            shortest_suffix[shortest_suffix.len() - common_suffix_length..].to_vec();
+
+        let mut is_optional = false;
+        let mut remainders = vec![];
+
+        for mut option in group {
+            option.remove_substring(&Substring::Suffix, common_suffix_length);
+            if let Expression::Concatenation(expr1, expr2, _) = &option {
+                if expr2.is_empty() {
+                    option = *expr1.clone();
+                }
+            }
+            if option.is_empty() {
+                is_optional = true;
+            } else {
+                remainders.push(option);
+            }
+        }
+
+        let suffix = Expression::new_literal(
+            GraphemeCluster::from_graphemes(common_suffix, config),
+            config,
+        );
+        let remainder = match remainders
+            .into_iter()
+            .fold1(|a, b| Expression::new_alternation(a, b, config))
+        {
+            Some(mut remainder) => {
+                remainder.factor_common_suffixes_at_depth(depth + 1, config);
+                remainder
+            }
+            None => return suffix,
+        };
+        let remainder = if is_optional {
+            Expression::new_repetition(remainder, Quantifier::QuestionMark, config)
+        } else {
+            remainder
+        };
+
+        Expression::new_concatenation(remainder, suffix, config)
+    }
+
+    pub(crate) fn convert_to_word_class(
+        &mut self,
+        threshold: usize,
+        config: &RegExpConfig,
+    ) -> bool {
+        match self {
+            Expression::Alternation(options, _) => {
+                let mut is_converted = false;
+                for option in options.iter_mut() {
+                    is_converted |= option.convert_to_word_class(threshold, config);
+                }
+                is_converted
+            }
+            Expression::CharacterClass(char_set, _) => {
+                let is_ascii_only = config.is_class_notation_ascii_only();
+                if char_set.len() < threshold
+                    || !char_set
+                        .iter()
+                        .all(|&c| (!is_ascii_only || c.is_ascii()) && is_in_table(c, WORD))
+                {
+                    return false;
+                }
+                *self = Expression::new_literal(
+                    GraphemeCluster::new(Grapheme::from("\\w", config), config),
+                    config,
+                );
+                true
+            }
+            Expression::Concatenation(expr1, expr2, _) => {
+                expr1.convert_to_word_class(threshold, config)
+                    | expr2.convert_to_word_class(threshold, config)
+            }
+            Expression::Literal(_, _) => false,
+            Expression::Repetition(expr, _, _) => expr.convert_to_word_class(threshold, config),
+            Expression::SubroutineCall(_, _) => false,
+        }
+    }
+
+    pub(crate) fn make_optional(&mut self, config: &RegExpConfig) {
+        match self {
+            Expression::Repetition(_, Quantifier::KleeneStar, _)
+            | Expression::Repetition(_, Quantifier::QuestionMark, _) => (),
+            _ => {
+                *self = Expression::new_repetition(self.clone(), Quantifier::QuestionMark, config);
+            }
+        }
+    }
+
+    pub(crate) fn merge_into_negated_shorthands(&mut self) {
+        match self {
+            Expression::Alternation(options, _) => {
+                for option in options.iter_mut() {
+                    option.merge_into_negated_shorthands();
+                }
+                let negated_shorthands = options
+                    .iter()
+                    .filter_map(|it| it.single_grapheme_value())
+                    .filter(|it| NEGATED_SHORTHANDS.contains(&it.as_str()))
+                    .collect_vec();
+                options.retain(|option| {
+                    !negated_shorthands
+                        .iter()
+                        .any(|shorthand| option.is_subsumed_by(shorthand))
+                });
+                if options.len() == 1 {
+                    *self = options.remove(0);
+                }
+            }
+            Expression::Concatenation(expr1, expr2, _) => {
+                expr1.merge_into_negated_shorthands();
+                expr2.merge_into_negated_shorthands();
+            }
+            Expression::Repetition(expr, _, _) => expr.merge_into_negated_shorthands(),
+            Expression::CharacterClass(_, _)
+            | Expression::Literal(_, _)
+            | Expression::SubroutineCall(_, _) => (),
+        }
+    }
+
+    fn single_grapheme_value(&self) -> Option<String> {
+        match self {
+            Expression::Literal(cluster, _) if self.is_single_grapheme() => {
+                Some(cluster.graphemes().first().unwrap().value())
+            }
+            _ => None,
+        }
+    }
+
+    fn is_subsumed_by(&self, negated_shorthand: &str) -> bool {
+        match self {
+            Expression::CharacterClass(char_set, _) => char_set
+                .iter()
+                .all(|&c| is_in_shorthand(negated_shorthand, c)),
+            Expression::Literal(cluster, _) => match self.single_grapheme_value() {
+                Some(value) if value != negated_shorthand => {
+                    SHORTHAND_SUBSETS.contains(&(negated_shorthand, value.as_str()))
+                        || is_single_char_in(&cluster.graphemes()[0], |c| {
+                            is_in_shorthand(negated_shorthand, c)
+                        })
+                }
+                _ => false,
+            },
+            _ => false,
+        }
+    }
+
+    fn new_any_grapheme(config: &RegExpConfig) -> Self {
+        Expression::new_literal(
+            GraphemeCluster::new(Grapheme::from("\\X", config), config),
+            config,
+        )
+    }
+
+    fn is_single_grapheme(&self) -> bool {
+        match self {
+            Expression::CharacterClass(_, _) => true,
+            Expression::Literal(cluster, _) => {
+                cluster.size() == 1 && cluster.graphemes().first().unwrap().maximum() == 1
+            }
+            _ => false,
+        }
+    }
+
+    fn grapheme_count(&self) -> usize {
+        match self {
+            Expression::CharacterClass(char_set, _) => char_set.len(),
+            _ => 1,
+        }
+    }
+
+    pub(crate) fn limit_branches(&mut self, max_branches: usize, config: &RegExpConfig) -> bool {
+        match self {
+            Expression::Alternation(options, _) => {
+                let mut is_merged = false;
+                for option in options.iter_mut() {
+                    is_merged |= option.limit_branches(max_branches, config);
+                }
+                if options.len() <= max_branches {
+                    return is_merged;
+                }
+                let merged_option_strs = options
+                    .iter()
+                    .map(|it| it.to_string())
+                    .sorted()
+                    .skip(max_branches - 1)
+                    .collect::<BTreeSet<_>>();
+                let (merged_options, mut kept_options): (Vec<_>, Vec<_>) = options
+                    .drain(..)
+                    .partition(|it| merged_option_strs.contains(&it.to_string()));
+                let catch_all = Expression::new_catch_all(&merged_options, config);
+
+                if kept_options.is_empty() {
+                    *self = catch_all;
+                } else {
+                    kept_options.push(catch_all);
+                    *options = kept_options;
+                }
+                true
+            }
+            Expression::Concatenation(expr1, expr2, _) => {
+                expr1.limit_branches(max_branches, config)
+                    | expr2.limit_branches(max_branches, config)
+            }
+            Expression::Repetition(expr, _, _) => expr.limit_branches(max_branches, config),
+            Expression::CharacterClass(_, _)
+            | Expression::Literal(_, _)
+            | Expression::SubroutineCall(_, _) => false,
+        }
+    }
+
+    fn new_catch_all(options: &[Expression], config: &RegExpConfig) -> Self {
+        let mut chars = BTreeSet::new();
+        let mut shorthands = BTreeSet::new();
+        let mut min_length = usize::MAX;
+        let mut max_length = Some(0);
+
+        for option in options {
+            option.collect_symbols(&mut chars, &mut shorthands, config);
+            let (min, max) = option.length_bounds();
+            min_length = min_length.min(min);
+            max_length = match (max_length, max) {
+                (Some(first), Some(second)) => Some(first.max(second)),
+                _ => None,
+            };
+        }
+
+        let is_in_class = |c: char, table: &[(char, char)]| {
+            (!config.is_class_notation_ascii_only() || c.is_ascii()) && is_in_table(c, table)
+        };
+        let is_subset_of = |allowed_shorthands: &[&str], table: &[(char, char)]| {
+            shorthands
+                .iter()
+                .all(|it| allowed_shorthands.contains(&it.as_str()))
+                && chars.iter().all(|&c| is_in_class(c, table))
+        };
+        let is_non_space = shorthands
+            .iter()
+            .all(|it| ["\\d", "\\w", "\\S"].contains(&it.as_str()))
+            && chars.iter().all(|&c| !is_in_class(c, WHITE_SPACE));
+
+        let char_class = if is_subset_of(&["\\d"], DECIMAL_NUMBER) {
+            "\\d"
+        } else if is_subset_of(&["\\d", "\\w"], WORD) {
+            "\\w"
+        } else if is_non_space {
+            "\\S"
+        } else {
+            "\\p{Any}"
+        };
+
+        let new_literal = |min: usize, max: usize| {
+            Expression::new_literal(
+                GraphemeCluster::new(
+                    Grapheme::new(vec![char_class.to_string()], min as u32, max as u32, config),
+                    config,
+                ),
+                config,
+            )
+        };
+
+        match max_length {
+            Some(max) => new_literal(min_length, max),
+            None => {
+                let repeated_part =
+                    Expression::new_repetition(new_literal(1, 1), Quantifier::KleeneStar, config);
+                if min_length == 0 {
+                    repeated_part
+                } else {
+                    Expression::new_concatenation(
+                        new_literal(min_length, min_length),
+                        repeated_part,
+                        config,
+                    )
+                }
+            }
+        }
+    }
+
+    fn collect_symbols(
+        &self,
+        chars: &mut BTreeSet<char>,
+        shorthands: &mut BTreeSet<String>,
+        config: &RegExpConfig,
+    ) {
+        match self {
+            Expression::Alternation(options, _) => {
+                for option in options {
+                    option.collect_symbols(chars, shorthands, config);
+                }
+            }
+            Expression::CharacterClass(char_set, _) => chars.extend(char_set),
+            Expression::Concatenation(expr1, expr2, _) => {
+                expr1.collect_symbols(chars, shorthands, config);
+                expr2.collect_symbols(chars, shorthands, config);
+            }
+            Expression::Literal(cluster, _) => {
+                for symbol in cluster.graphemes().iter().flat_map(|it| it.chars()) {
+                    if symbol.starts_with('\\') && symbol.chars().count() > 1
+                        || config.is_wildcard(symbol)
+                    {
+                        shorthands.insert(symbol.clone());
+                    } else {
+                        chars.extend(symbol.chars());
+                    }
+                }
+            }
+            Expression::Repetition(expr, _, _) => {
+                expr.collect_symbols(chars, shorthands, config);
+            }
+            Expression::SubroutineCall(_, _) => (),
+        }
+    }
+
+    fn length_bounds(&self) -> (usize, Option<usize>) {
+        match self {
+            Expression::Alternation(options, _) => {
+                let bounds = options.iter().map(|it| it.length_bounds()).collect_vec();
+                let min = bounds.iter().map(|(min, _)| *min).min().unwrap_or(0);
+                let max = bounds
+                    .iter()
+                    .try_fold(0, |acc, (_, max)| max.map(|it| acc.max(it)));
+                (min, max)
+            }
+            Expression::CharacterClass(_, _) => (1, Some(1)),
+            Expression::Concatenation(expr1, expr2, _) => {
+                let (min1, max1) = expr1.length_bounds();
+                let (min2, max2) = expr2.length_bounds();
+                (
+                    min1 + min2,
+                    max1.and_then(|first| max2.map(|second| first + second)),
+                )
+            }
+            Expression::Literal(cluster, config) => {
+                cluster
+                    .graphemes()
+                    .iter()
+                    .fold((0, Some(0)), |(min, max), grapheme| {
+                        let symbol_count = grapheme
+                            .chars()
+                            .iter()
+                            .map(|it| count_code_points(it, config))
+                            .sum::<usize>();
+                        (
+                            min + symbol_count * grapheme.minimum() as usize,
+                            max.map(|it| it + symbol_count * grapheme.maximum() as usize),
+                        )
+                    })
+            }
+            Expression::Repetition(expr, quantifier, _) => match quantifier {
+                Quantifier::KleeneStar => (0, None),
+                Quantifier::QuestionMark => (0, expr.length_bounds().1),
+            },
+            Expression::SubroutineCall(_, _) => (0, None),
+        }
+    }
+
+    pub(crate) fn has_repetition_range(&self) -> bool {
+        match self {
+            Expression::Alternation(options, _) => {
+                options.iter().any(|it| it.has_repetition_range())
+            }
+            Expression::CharacterClass(_, _) => false,
+            Expression::Concatenation(expr1, expr2, _) => {
+                expr1.has_repetition_range() || expr2.has_repetition_range()
+            }
+            Expression::Literal(cluster, _) => cluster
+                .graphemes()
+                .iter()
+                .any(|it| it.minimum() < it.maximum()),
+            Expression::Repetition(expr, _, _) => expr.has_repetition_range(),
+            Expression::SubroutineCall(_, _) => false,
+        }
+    }
+
+    pub(crate) fn set_config(&mut self, new_config: &RegExpConfig) {
+        match self {
+            Expression::Alternation(options, config) => {
+                options.iter_mut().for_each(|it| it.set_config(new_config));
+                *config = new_config.clone();
+            }
+            Expression::CharacterClass(_, config) => *config = new_config.clone(),
+            Expression::Concatenation(expr1, expr2, config) => {
+                expr1.set_config(new_config);
+                expr2.set_config(new_config);
+                *config = new_config.clone();
+            }
+            Expression::Literal(cluster, config) => {
+                cluster.set_config(new_config);
+                *config = new_config.clone();
+            }
+            Expression::Repetition(expr, _, config) => {
+                expr.set_config(new_config);
+                *config = new_config.clone();
+            }
+            Expression::SubroutineCall(_, config) => *config = new_config.clone(),
+        }
+    }
+
+    pub(crate) fn has_unbounded_quantifier(&self) -> bool {
+        match self {
+            Expression::Alternation(options, _) => {
+                options.iter().any(|it| it.has_unbounded_quantifier())
+            }
+            Expression::CharacterClass(_, _) => false,
+            Expression::Concatenation(expr1, expr2, _) => {
+                expr1.has_unbounded_quantifier() || expr2.has_unbounded_quantifier()
+            }
+            Expression::Literal(cluster, _) => cluster
+                .graphemes()
+                .iter()
+                .any(|it| it.has_unbounded_quantifier()),
+            Expression::Repetition(_, Quantifier::KleeneStar, _) => true,
+            Expression::Repetition(expr, Quantifier::QuestionMark, _) => {
+                expr.has_unbounded_quantifier()
+            }
+            Expression::SubroutineCall(_, _) => false,
+        }
+    }
+
+    pub(crate) fn has_cased_char(&self) -> bool {
+        match self {
+            Expression::Alternation(options, _) => options.iter().any(|it| it.has_cased_char()),
+            Expression::CharacterClass(char_set, _) => char_set.iter().any(|&c| is_cased_char(c)),
+            Expression::Concatenation(expr1, expr2, _) => {
+                expr1.has_cased_char() || expr2.has_cased_char()
+            }
+            Expression::Literal(cluster, _) => {
+                cluster.graphemes().iter().any(|it| it.has_cased_char())
+            }
+            Expression::Repetition(expr, _, _) => expr.has_cased_char(),
+            Expression::SubroutineCall(_, _) => false,
+        }
+    }
+
+    pub(crate) fn has_backreference(&self) -> bool {
+        match self {
+            Expression::Alternation(options, _) => options.iter().any(|it| it.has_backreference()),
+            Expression::CharacterClass(_, _) => false,
+            Expression::Concatenation(expr1, expr2, _) => {
+                expr1.has_backreference() || expr2.has_backreference()
+            }
+            Expression::Literal(cluster, _) => {
+                cluster.graphemes().iter().any(|it| it.has_backreference())
+            }
+            Expression::Repetition(expr, _, _) => expr.has_backreference(),
+            Expression::SubroutineCall(_, _) => false,
+        }
+    }
+
+    pub(crate) fn has_variable_quantifier(&self) -> bool {
+        match self {
+            Expression::Alternation(options, _) => {
+                options.iter().any(|it| it.has_variable_quantifier())
+            }
+            Expression::CharacterClass(_, _) => false,
+            Expression::Concatenation(expr1, expr2, _) => {
+                expr1.has_variable_quantifier() || expr2.has_variable_quantifier()
+            }
+            Expression::Literal(cluster, _) => cluster
+                .graphemes()
+                .iter()
+                .any(|it| it.has_variable_quantifier()),
+            Expression::Repetition(_, _, _) => true,
+            Expression::SubroutineCall(_, _) => false,
+        }
+    }
+
+    pub(crate) fn collect_alternations<'a>(&'a self, alternations: &mut Vec<&'a Expression>) {
+        match self {
+            Expression::Alternation(options, _) => {
+                alternations.push(self);
+                for option in options {
+                    option.collect_alternations(alternations);
+                }
+            }
+            Expression::CharacterClass(_, _)
+            | Expression::Literal(_, _)
+            | Expression::SubroutineCall(_, _) => {}
+            Expression::Concatenation(expr1, expr2, _) => {
+                expr1.collect_alternations(alternations);
+                expr2.collect_alternations(alternations);
+            }
+            Expression::Repetition(expr, _, _) => expr.collect_alternations(alternations),
+        }
+    }
+
+    // The expression itself is never replaced, only the subexpressions below it
+    pub(crate) fn replace_subexpressions(
+        &mut self,
+        target: &Expression,
+        replacement: &Expression,
+    ) -> usize {
+        let children: Vec<&mut Expression> = match self {
+            Expression::Alternation(options, _) => options.iter_mut().collect_vec(),
+            Expression::Concatenation(expr1, expr2, _) => vec![expr1.as_mut(), expr2.as_mut()],
+            Expression::Repetition(expr, _, _) => vec![expr.as_mut()],
+            Expression::CharacterClass(_, _)
+            | Expression::Literal(_, _)
+            | Expression::SubroutineCall(_, _) => vec![],
+        };
+        children
+            .into_iter()
+            .map(|child| {
+                if child == target {
+                    *child = replacement.clone();
+                    1
+                } else {
+                    child.replace_subexpressions(target, replacement)
+                }
+            })
+            .sum()
+    }
+
+    pub(crate) fn has_any_char_wildcard(&self) -> bool {
+        match self {
+            Expression::Alternation(options, _) => {
+                options.iter().any(|it| it.has_any_char_wildcard())
+            }
+            Expression::CharacterClass(_, _) => false,
+            Expression::Concatenation(expr1, expr2, _) => {
+                expr1.has_any_char_wildcard() || expr2.has_any_char_wildcard()
+            }
+            Expression::Literal(cluster, config) => cluster
+                .graphemes()
+                .iter()
+                .any(|it| is_any_char_wildcard(it, config)),
+            Expression::Repetition(expr, _, _) => expr.has_any_char_wildcard(),
+            Expression::SubroutineCall(_, _) => false,
+        }
+    }
+
+    pub(crate) fn language_size(&self) -> Option<u128> {
+        match self {
+            Expression::Alternation(options, _) => options.iter().try_fold(0u128, |acc, it| {
+                Some(acc.saturating_add(it.language_size()?))
+            }),
+            Expression::CharacterClass(char_set, config) => Some(
+                char_set
+                    .iter()
+                    .map(|&c| count_case_variants(c, config))
+                    .sum(),
+            ),
+            Expression::Concatenation(expr1, expr2, _) => Some(
+                expr1
+                    .language_size()?
+                    .saturating_mul(expr2.language_size()?),
+            ),
+            Expression::Literal(cluster, config) => {
+                cluster.graphemes().iter().try_fold(1u128, |acc, it| {
+                    Some(acc.saturating_mul(count_grapheme_strings(it, config)?))
+                })
+            }
+            Expression::Repetition(expr, quantifier, _) => match quantifier {
+                Quantifier::KleeneStar => None,
+                Quantifier::QuestionMark => Some(expr.language_size()?.saturating_add(1)),
+            },
+            Expression::SubroutineCall(_, _) => None,
+        }
+    }
+
+    pub(crate) fn required_literals(&self) -> Vec<String> {
+        let literals = self.collect_required_literals();
+        literals
+            .iter()
+            .unique()
+            .filter(|&literal| {
+                !literals
+                    .iter()
+                    .any(|it| it.len() > literal.len() && it.contains(literal.as_str()))
+            })
+            .cloned()
+            .collect_vec()
+    }
+
+    fn collect_required_literals(&self) -> Vec<String> {
+        match self {
+            Expression::Alternation(options, _) => {
+                let mut common_literals = options.first().unwrap().collect_required_literals();
+                for option in options.iter().skip(1) {
+                    let literals = option.collect_required_literals();
+                    common_literals = common_literals
+                        .iter()
+                        .cartesian_product(literals.iter())
+                        .map(|(first, second)| find_longest_common_substring(first, second))
+                        .filter(|it| !it.is_empty())
+                        .collect_vec();
+                }
+                common_literals
+            }
+            Expression::CharacterClass(_, _)
+            | Expression::Repetition(_, _, _)
+            | Expression::SubroutineCall(_, _) => vec![],
+            Expression::Concatenation(expr1, expr2, _) => {
+                let mut literals = expr1.collect_required_literals();
+                literals.extend(expr2.collect_required_literals());
+                literals
+            }
+            Expression::Literal(cluster, _) => {
+                let mut literals = vec![];
+                let mut current_literal = String::new();
+
+                for grapheme in cluster.graphemes() {
+                    if grapheme.is_char_class() {
+                        if !current_literal.is_empty() {
+                            literals.push(current_literal);
+                            current_literal = String::new();
+                        }
+                        continue;
+                    }
+
+                    current_literal.push_str(&grapheme.value().repeat(grapheme.minimum() as usize));
+
+                    if grapheme.minimum() < grapheme.maximum() {
+                        literals.push(current_literal);
+                        current_literal = String::new();
+                    }
+                }
+
+                if !current_literal.is_empty() {
+                    literals.push(current_literal);
+                }
+                literals
+            }
+        }
+    }
+
+    pub(crate) fn matches(&self, graphemes: &[Grapheme]) -> bool {
+        self.match_ends(graphemes, 0).contains(&graphemes.len())
+    }
+
+    pub(crate) fn sample_strings(&self, alphabet: &BTreeSet<char>) -> Option<Vec<String>> {
+        let samples =
+            match self {
+                Expression::Alternation(options, _) => {
+                    let mut samples = vec![];
+                    for option in options {
+                        samples.extend(option.sample_strings(alphabet)?);
+                    }
+                    samples
+                }
+                Expression::CharacterClass(char_set, _) => {
+                    char_set.iter().map(|c| c.to_string()).collect_vec()
+                }
+                Expression::Concatenation(expr1, expr2, _) => concatenate_samples(
+                    &expr1.sample_strings(alphabet)?,
+                    &expr2.sample_strings(alphabet)?,
+                )?,
+                Expression::Literal(cluster, _) => cluster.graphemes().iter().try_fold(
+                    vec![String::new()],
+                    |samples, grapheme| {
+                        concatenate_samples(&samples, &sample_grapheme_strings(grapheme, alphabet)?)
+                    },
+                )?,
+                Expression::Repetition(expr, quantifier, _) => {
+                    let expr_samples = expr.sample_strings(alphabet)?;
+                    let mut samples = vec![String::new()];
+                    samples.extend(expr_samples.iter().cloned());
+                    if quantifier == &Quantifier::KleeneStar {
+                        samples.extend(concatenate_samples(&expr_samples, &expr_samples)?);
+                    }
+                    samples
+                }
+                Expression::SubroutineCall(_, _) => return None,
+            };
+
+        if samples.len() > MAXIMUM_SAMPLES {
+            None
+        } else {
+            Some(samples)
+        }
+    }
+
+    fn match_ends(&self, graphemes: &[Grapheme], start: usize) -> BTreeSet<usize> {
+        match self {
+            Expression::Alternation(options, _) => options
+                .iter()
+                .flat_map(|it| it.match_ends(graphemes, start))
+                .collect(),
+            Expression::CharacterClass(char_set, _) => match graphemes.get(start) {
+                Some(grapheme) if is_single_char_in(grapheme, |c| char_set.contains(&c)) => {
+                    btreeset![start + 1]
+                }
+                _ => BTreeSet::new(),
+            },
+            Expression::Concatenation(expr1, expr2, _) => expr1
+                .match_ends(graphemes, start)
+                .into_iter()
+                .flat_map(|end| expr2.match_ends(graphemes, end))
+                .collect(),
+            Expression::Literal(cluster, _) => {
+                cluster
+                    .graphemes()
+                    .iter()
+                    .fold(btreeset![start], |ends, pattern_grapheme| {
+                        ends.into_iter()
+                            .flat_map(|end| match_grapheme_ends(pattern_grapheme, graphemes, end))
+                            .collect()
+                    })
+            }
+            Expression::Repetition(expr, quantifier, _) => {
+                let mut ends = btreeset![start];
+                let mut unvisited_ends = vec![start];
+
+                while let Some(end) = unvisited_ends.pop() {
+                    for next_end in expr.match_ends(graphemes, end) {
+                        if ends.insert(next_end) && quantifier == &Quantifier::KleeneStar {
+                            unvisited_ends.push(next_end);
+                        }
+                    }
+                }
+                ends
+            }
+            Expression::SubroutineCall(_, _) => BTreeSet::new(),
+        }
+    }
+
     fn repeat_zero_or_more_times(
         expr: &Option<Expression>,
         config: &RegExpConfig,
@@ -317,6 +1359,11 @@ impl Expression {
                     None
                 };
 
+                if result.is_none() {
+                    result = Self::merge_optional_affix(&expr1, &expr2, config)
+                        .or_else(|| Self::merge_optional_affix(&expr2, &expr1, config));
+                }
+
                 if result.is_none() {
                     if let Expression::Repetition(expr, quantifier, _) = &expr1 {
                         if quantifier == &Quantifier::QuestionMark {
@@ -345,7 +1392,12 @@ impl Expression {
                     }
                 }
 
-                if result.is_none() && expr1.is_single_codepoint() && expr2.is_single_codepoint() {
+                if result.is_none()
+                    && expr1.is_single_codepoint()
+                    && expr2.is_single_codepoint()
+                    && !expr1.is_wildcard()
+                    && !expr2.is_wildcard()
+                {
                     let first_char_set = Self::extract_character_set(expr1.clone());
                     let second_char_set = Self::extract_character_set(expr2.clone());
                     result = Some(Expression::new_character_class(
@@ -398,6 +1450,93 @@ impl Expression {
         }
     }
 
+    fn merge_optional_affix(
+        longer: &Expression,
+        shorter: &Expression,
+        config: &RegExpConfig,
+    ) -> Option<Expression> {
+        if let Some((first, rest)) = longer.split_off_first_grapheme(config) {
+            if first.is_single_grapheme() && rest == *shorter {
+                let optional_first =
+                    Expression::new_repetition(first, Quantifier::QuestionMark, config);
+                return Self::concatenate(&Some(optional_first), &Some(rest), config);
+            }
+        }
+        if let Some((rest, last)) = longer.split_off_last_grapheme(config) {
+            if last.is_single_grapheme() && rest == *shorter {
+                let optional_last =
+                    Expression::new_repetition(last, Quantifier::QuestionMark, config);
+                return Self::concatenate(&Some(rest), &Some(optional_last), config);
+            }
+        }
+        None
+    }
+
+    fn split_off_first_grapheme(&self, config: &RegExpConfig) -> Option<(Expression, Expression)> {
+        match self {
+            Expression::Literal(cluster, _) if cluster.size() > 1 => {
+                let graphemes = cluster.graphemes();
+                Some((
+                    Expression::new_literal(
+                        GraphemeCluster::from_graphemes(graphemes[..1].to_vec(), config),
+                        config,
+                    ),
+                    Expression::new_literal(
+                        GraphemeCluster::from_graphemes(graphemes[1..].to_vec(), config),
+                        config,
+                    ),
+                ))
+            }
+            Expression::Concatenation(expr1, expr2, _) => {
+                let (first, rest) = if expr1.is_single_grapheme() {
+                    (*expr1.clone(), None)
+                } else {
+                    let (first, rest) = expr1.split_off_first_grapheme(config)?;
+                    (first, Some(rest))
+                };
+                let rest = match rest {
+                    Some(rest) => Self::concatenate(&Some(rest), &Some(*expr2.clone()), config)?,
+                    None => *expr2.clone(),
+                };
+                Some((first, rest))
+            }
+            _ => None,
+        }
+    }
+
+    fn split_off_last_grapheme(&self, config: &RegExpConfig) -> Option<(Expression, Expression)> {
+        match self {
+            Expression::Literal(cluster, _) if cluster.size() > 1 => {
+                let graphemes = cluster.graphemes();
+                let last_index = graphemes.len() - 1;
+                Some((
+                    Expression::new_literal(
+                        GraphemeCluster::from_graphemes(graphemes[..last_index].to_vec(), config),
+                        config,
+                    ),
+                    Expression::new_literal(
+                        GraphemeCluster::from_graphemes(graphemes[last_index..].to_vec(), config),
+                        config,
+                    ),
+                ))
+            }
+            Expression::Concatenation(expr1, expr2, _) => {
+                let (rest, last) = if expr2.is_single_grapheme() {
+                    (None, *expr2.clone())
+                } else {
+                    let (rest, last) = expr2.split_off_last_grapheme(config)?;
+                    (Some(rest), last)
+                };
+                let rest = match rest {
+                    Some(rest) => Self::concatenate(&Some(*expr1.clone()), &Some(rest), config)?,
+                    None => *expr1.clone(),
+                };
+                Some((rest, last))
+            }
+            _ => None,
+        }
+    }
+
     fn flatten_alternations(
         flattened_options: &mut Vec<Expression>,
         current_options: Vec<Expression>,
@@ -481,6 +1620,235 @@ impl Expression {
     }
 }
 
+fn match_grapheme_ends(pattern: &Grapheme, graphemes: &[Grapheme], start: usize) -> Vec<usize> {
+    if let Some(grapheme) = graphemes.get(start) {
+        if grapheme.chars() == pattern.chars()
+            && pattern.minimum() <= grapheme.minimum()
+            && grapheme.maximum() <= pattern.maximum()
+        {
+            return vec![start + 1];
+        }
+    }
+
+    let shorthand = match pattern.chars().as_slice() {
+        [shorthand] if pattern.is_char_class() || shorthand == "." => shorthand,
+        _ => return vec![],
+    };
+    let mut ends = vec![];
+    let mut end = start;
+
+    while end - start < pattern.maximum() as usize {
+        match graphemes.get(end) {
+            Some(grapheme) if is_single_char_in(grapheme, |c| is_in_shorthand(shorthand, c)) => {
+                end += 1;
+                if end - start >= pattern.minimum() as usize {
+                    ends.push(end);
+                }
+            }
+            _ => break,
+        }
+    }
+    ends
+}
+
+fn sample_grapheme_strings(grapheme: &Grapheme, alphabet: &BTreeSet<char>) -> Option<Vec<String>> {
+    let base_samples = if grapheme.has_repetitions() {
+        grapheme
+            .repetitions
+            .iter()
+            .try_fold(vec![String::new()], |samples, it| {
+                concatenate_samples(&samples, &sample_grapheme_strings(it, alphabet)?)
+            })?
+    } else {
+        match grapheme.chars().as_slice() {
+            [shorthand] if grapheme.is_char_class() || shorthand == "." => alphabet
+                .iter()
+                .filter(|&&c| is_in_shorthand(shorthand, c))
+                .map(|c| c.to_string())
+                .collect_vec(),
+            _ => vec![grapheme.value()],
+        }
+    };
+
+    let mut samples = if grapheme.minimum() == 0 {
+        vec![String::new()]
+    } else {
+        vec![]
+    };
+    let mut repeated_samples = vec![String::new()];
+    for count in 1..=grapheme.maximum() {
+        repeated_samples = concatenate_samples(&repeated_samples, &base_samples)?;
+        if count >= grapheme.minimum() {
+            samples.extend(repeated_samples.iter().cloned());
+        }
+        if samples.len() > MAXIMUM_SAMPLES {
+            return None;
+        }
+    }
+    Some(samples)
+}
+
+fn concatenate_samples(prefixes: &[String], suffixes: &[String]) -> Option<Vec<String>> {
+    if prefixes.len().saturating_mul(suffixes.len()) > MAXIMUM_SAMPLES {
+        return None;
+    }
+    Some(
+        prefixes
+            .iter()
+            .cartesian_product(suffixes.iter())
+            .map(|(prefix, suffix)| format!("{}{}", prefix, suffix))
+            .collect_vec(),
+    )
+}
+
+fn is_any_char_wildcard(grapheme: &Grapheme, config: &RegExpConfig) -> bool {
+    if grapheme.has_repetitions() {
+        grapheme
+            .repetitions
+            .iter()
+            .any(|it| is_any_char_wildcard(it, config))
+    } else {
+        grapheme.value() == Wildcard::AnyChar.value() && config.is_wildcard(&grapheme.value())
+    }
+}
+
+// Shorthands, wildcards and escaped literals match a single code point,
+// whereas a literal grapheme may consist of several ones, such as `\r\n`
+fn count_code_points(value: &str, config: &RegExpConfig) -> usize {
+    if value.starts_with('\\') && value.chars().count() > 1 || config.is_wildcard(value) {
+        1
+    } else {
+        value.chars().count()
+    }
+}
+
+fn is_single_char_in<F: Fn(char) -> bool>(grapheme: &Grapheme, predicate: F) -> bool {
+    let value = grapheme.value();
+    let mut chars = value.chars();
+    match (chars.next(), chars.next()) {
+        (Some(c), None) => grapheme.maximum() == 1 && predicate(c),
+        _ => false,
+    }
+}
+
+fn is_in_shorthand(shorthand: &str, c: char) -> bool {
+    match shorthand {
+        "\\d" => is_in_table(c, DECIMAL_NUMBER),
+        "\\D" => !is_in_table(c, DECIMAL_NUMBER),
+        "\\s" => is_in_table(c, WHITE_SPACE),
+        "\\S" => !is_in_table(c, WHITE_SPACE),
+        "\\w" => is_in_table(c, WORD),
+        "\\W" => !is_in_table(c, WORD),
+        "." => c != '\n',
+        "\\X" | "\\p{Any}" => true,
+        _ => match find_script_table(shorthand) {
+            Some(table) => is_in_table(c, table),
+            None => false,
+        },
+    }
+}
+
+fn count_grapheme_strings(grapheme: &Grapheme, config: &RegExpConfig) -> Option<u128> {
+    let (min, max) = (grapheme.minimum(), grapheme.maximum());
+    if min < max && config.is_repetition_upper_bound_relaxed {
+        return None;
+    }
+
+    let base = if grapheme.has_repetitions() {
+        grapheme.repetitions.iter().try_fold(1u128, |acc, it| {
+            Some(acc.saturating_mul(count_grapheme_strings(it, config)?))
+        })?
+    } else {
+        grapheme.chars().iter().try_fold(1u128, |acc, it| {
+            Some(acc.saturating_mul(count_chars_matched_by(it, config)?))
+        })?
+    };
+
+    Some((min..=max).fold(0u128, |acc, exponent| {
+        acc.saturating_add(base.saturating_pow(exponent))
+    }))
+}
+
+fn count_chars_matched_by(value: &str, config: &RegExpConfig) -> Option<u128> {
+    match value {
+        "\\d" => Some(count_table_chars(DECIMAL_NUMBER)),
+        "\\D" => Some(CHAR_COUNT - count_table_chars(DECIMAL_NUMBER)),
+        "\\s" => Some(count_table_chars(WHITE_SPACE)),
+        "\\S" => Some(CHAR_COUNT - count_table_chars(WHITE_SPACE)),
+        "\\w" => Some(count_table_chars(WORD)),
+        "\\W" => Some(CHAR_COUNT - count_table_chars(WORD)),
+        "." if config.is_wildcard(value) => Some(CHAR_COUNT - 1),
+        "\\p{Any}" => Some(CHAR_COUNT),
+        "\\X" => None,
+        _ if value.starts_with("\\p{") => find_script_table(value).map(count_table_chars),
+        _ => {
+            // Escaped literals, such as a literal dot next to the wildcard `.`,
+            // match nothing but the escaped character itself.
+            let literal = value.strip_prefix('\\').filter(|it| !it.is_empty());
+            Some(
+                literal
+                    .unwrap_or(value)
+                    .chars()
+                    .map(|c| count_case_variants(c, config))
+                    .product(),
+            )
+        }
+    }
+}
+
+fn count_case_variants(c: char, config: &RegExpConfig) -> u128 {
+    if config.is_case_expanded_to_classes {
+        case_variants(c, config).len() as u128
+    } else if config.is_case_insensitive_matching() && is_cased_char(c) {
+        2
+    } else {
+        1
+    }
+}
+
+fn count_table_chars(table: &[(char, char)]) -> u128 {
+    table
+        .iter()
+        .map(|&(start, end)| (end as u32 - start as u32 + 1) as u128)
+        .sum()
+}
+
+fn find_script_table(shorthand: &str) -> Option<&'static [(char, char)]> {
+    let name = shorthand.strip_prefix("\\p{")?.strip_suffix('}')?;
+    SCRIPTS
+        .iter()
+        .find(|&&(script, _)| script == name)
+        .map(|&(_, table)| table)
+}
+
+fn is_in_table(c: char, table: &[(char, char)]) -> bool {
+    table.iter().any(|&(start, end)| start <= c && c <= end)
+}
+
+fn find_longest_common_substring(first: &str, second: &str) -> String {
+    let first_chars = first.chars().collect_vec();
+    let second_chars = second.chars().collect_vec();
+    let mut lengths = Array2::<usize>::zeros((first_chars.len() + 1, second_chars.len() + 1));
+    let mut longest_length = 0;
+    let mut end_idx = 0;
+
+    for i in 1..=first_chars.len() {
+        for j in 1..=second_chars.len() {
+            if first_chars[i - 1] == second_chars[j - 1] {
+                lengths[(i, j)] = lengths[(i - 1, j - 1)] + 1;
+                if lengths[(i, j)] > longest_length {
+                    longest_length = lengths[(i, j)];
+                    end_idx = i;
+                }
+            }
+        }
+    }
+
+    first_chars[end_idx - longest_length..end_idx]
+        .iter()
+        .collect()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
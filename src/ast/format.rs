@@ -15,12 +15,17 @@
  */
 
 use crate::ast::{Expression, Quantifier};
-use crate::char::{ColorizableString, GraphemeCluster};
+use crate::char::{
+    case_variants, escape_non_ascii_char, escape_verbose_char, ColorizableString, Grapheme,
+    GraphemeCluster,
+};
 use crate::regexp::RegExpConfig;
+use crate::unicode_tables::WORD;
 use itertools::Itertools;
+use std::cmp::Ordering;
 use std::collections::BTreeSet;
 use std::fmt::{Display, Formatter, Result};
-use unic_char_range::CharRange;
+use unic_ucd_category::GeneralCategory;
 
 impl Display for Expression {
     fn fmt(&self, f: &mut Formatter<'_>) -> Result {
@@ -38,14 +43,15 @@ impl Display for Expression {
             Expression::Repetition(expr, quantifier, config) => {
                 format_repetition(f, &self, expr, quantifier, config)
             }
+            Expression::SubroutineCall(name, config) => write!(
+                f,
+                "{}",
+                ColorizableString::SubroutineCall(name.clone()).to_colorized_string(config)
+            ),
         }
     }
 }
 
-fn get_codepoint_position(c: char) -> usize {
-    CharRange::all().iter().position(|it| it == c).unwrap()
-}
-
 fn format_alternation(
     f: &mut Formatter<'_>,
     expr: &Expression,
@@ -62,22 +68,22 @@ fn format_alternation(
         ColorizableString::Pipe,
     ]
     .iter()
-    .map(|it| it.to_colorized_string(config.is_output_colorized))
+    .map(|it| it.to_colorized_string(config))
     .collect_tuple()
     .unwrap();
 
-    let alternation_str = options
-        .iter()
-        .map(|option| {
-            if option.precedence() < expr.precedence() && !option.is_single_codepoint() {
-                format!("{}{}{}", left_parenthesis, option, right_parenthesis)
-            } else {
-                format!("{}", option)
-            }
-        })
-        .join(&pipe.to_string());
+    for (i, option) in options.iter().enumerate() {
+        if i > 0 {
+            write!(f, "{}", pipe)?;
+        }
+        if option.precedence() < expr.precedence() && !option.is_single_codepoint() {
+            write!(f, "{}{}{}", left_parenthesis, option, right_parenthesis)?;
+        } else {
+            write!(f, "{}", option)?;
+        }
+    }
 
-    write!(f, "{}", alternation_str)
+    Ok(())
 }
 
 fn format_character_class(
@@ -85,83 +91,178 @@ fn format_character_class(
     char_set: &BTreeSet<char>,
     config: &RegExpConfig,
 ) -> Result {
-    let chars_to_escape = ['[', ']', '\\', '-', '^'];
-    let escaped_char_set = char_set
-        .iter()
-        .map(|c| {
-            if chars_to_escape.contains(&c) {
-                format!("{}{}", "\\", c)
-            } else if c == &'\n' {
-                "\\n".to_string()
-            } else if c == &'\r' {
-                "\\r".to_string()
-            } else if c == &'\t' {
-                "\\t".to_string()
-            } else {
-                c.to_string()
-            }
-        })
-        .collect_vec();
-    let char_positions = char_set
-        .iter()
-        .map(|&it| get_codepoint_position(it))
-        .collect_vec();
-
-    let mut subsets = vec![];
-    let mut subset = vec![];
-
-    for ((first_c, first_pos), (second_c, second_pos)) in
-        escaped_char_set.iter().zip(char_positions).tuple_windows()
-    {
-        if subset.is_empty() {
-            subset.push(first_c);
-        }
-        if second_pos == first_pos + 1 {
-            subset.push(second_c);
-        } else {
-            subsets.push(subset);
-            subset = vec![];
-            subset.push(second_c);
-        }
-    }
-
-    subsets.push(subset);
-
-    let mut char_class_strs = vec![];
     let (hyphen, left_bracket, right_bracket) = vec![
         ColorizableString::Hyphen,
         ColorizableString::LeftBracket,
         ColorizableString::RightBracket,
     ]
     .iter()
-    .map(|it| it.to_colorized_string(config.is_output_colorized))
+    .map(|it| it.to_colorized_string(config))
     .collect_tuple()
     .unwrap();
 
-    for subset in subsets.iter() {
-        if subset.len() <= 2 {
-            for c in subset.iter() {
-                char_class_strs.push((*c).to_string());
-            }
+    let char_set = if config.is_case_expanded_to_classes {
+        char_set
+            .iter()
+            .flat_map(|&c| case_variants(c, config))
+            .collect()
+    } else {
+        char_set.clone()
+    };
+
+    let (shorthand, char_set) =
+        if config.is_length_minimized && config.flavor.is_shorthand_class_ascii_only() {
+            split_off_shorthand(&char_set, !config.is_unicode_word_class_enabled())
         } else {
-            char_class_strs.push(format!(
-                "{}{}{}",
-                subset.first().unwrap(),
-                hyphen,
-                subset.last().unwrap()
-            ));
+            (None, char_set)
+        };
+
+    if let Some(shorthand) = &shorthand {
+        if char_set.is_empty() {
+            return write!(f, "{}", shorthand.to_colorized_string(config));
         }
     }
 
+    if config.flavor.is_posix() {
+        return write!(
+            f,
+            "{}{}{}",
+            left_bracket,
+            format_posix_bracket_expression(&char_set, &hyphen.to_string()),
+            right_bracket
+        );
+    }
+
+    let shorthand_str = shorthand.map_or_else(String::new, |it| it.to_string());
+    let char_class_str = merge_into_ranges(&char_set)
+        .iter()
+        .map(|&(first, last)| {
+            let first_str = escape_char_class_member(first, config);
+            match last as u32 - first as u32 {
+                0 => first_str,
+                1 => format!("{}{}", first_str, escape_char_class_member(last, config)),
+                _ => format!(
+                    "{}{}{}",
+                    first_str,
+                    hyphen,
+                    escape_char_class_member(last, config)
+                ),
+            }
+        })
+        .join("");
+
     write!(
         f,
-        "{}{}{}",
+        "{}{}{}{}",
         left_bracket,
-        char_class_strs.join(""),
+        shorthand_str,
+        escape_nul_chars(&char_class_str, config),
         right_bracket
     )
 }
 
+/// Replaces the largest ASCII shorthand class which is fully contained in the given
+/// set of characters, returning it together with the characters left over.
+fn split_off_shorthand(
+    char_set: &BTreeSet<char>,
+    is_word_class_allowed: bool,
+) -> (Option<ColorizableString>, BTreeSet<char>) {
+    let digits = ('0'..='9').collect::<BTreeSet<_>>();
+    let word_chars = ('A'..='Z')
+        .chain('a'..='z')
+        .chain(std::iter::once('_'))
+        .chain(digits.iter().copied())
+        .collect::<BTreeSet<_>>();
+
+    if is_word_class_allowed && char_set.is_superset(&word_chars) {
+        let remaining = char_set.difference(&word_chars).copied().collect();
+        (Some(ColorizableString::WordCharClass), remaining)
+    } else if char_set.is_superset(&digits) {
+        let remaining = char_set.difference(&digits).copied().collect();
+        (Some(ColorizableString::DigitCharClass), remaining)
+    } else {
+        (None, char_set.clone())
+    }
+}
+
+fn format_posix_bracket_expression(char_set: &BTreeSet<char>, hyphen: &str) -> String {
+    let special_chars = [']', '^', '-'];
+    let other_chars = char_set
+        .iter()
+        .filter(|c| !special_chars.contains(c))
+        .copied()
+        .collect();
+    let mut members = merge_into_ranges(&other_chars)
+        .iter()
+        .map(|&(first, last)| match last as u32 - first as u32 {
+            0 => first.to_string(),
+            1 => format!("{}{}", first, last),
+            _ => format!("{}{}{}", first, hyphen, last),
+        })
+        .collect_vec();
+
+    // Backslashes are literal within POSIX bracket expressions, so special characters
+    // are placed where they lose their meaning: `]` first, `^` anywhere else, `-` last.
+    if char_set.contains(&']') {
+        members.insert(0, "]".to_string());
+    }
+    if char_set.contains(&'^') {
+        if members.is_empty() && char_set.contains(&'-') {
+            return "-^".to_string();
+        }
+        members.push("^".to_string());
+    }
+    if char_set.contains(&'-') {
+        members.push("-".to_string());
+    }
+
+    members.join("")
+}
+
+fn merge_into_ranges(char_set: &BTreeSet<char>) -> Vec<(char, char)> {
+    let mut ranges: Vec<(char, char)> = vec![];
+
+    for &c in char_set.iter() {
+        match ranges.last_mut() {
+            Some((_, last)) if c as u32 == *last as u32 + 1 => *last = c,
+            _ => ranges.push((c, c)),
+        }
+    }
+
+    ranges
+}
+
+fn escape_char_class_member(c: char, config: &RegExpConfig) -> String {
+    match c {
+        '[' | ']' | '\\' | '-' | '^' if !config.is_char_unescaped(c) => {
+            format!("\\{}", c)
+        }
+        _ if config.escaped_chars.contains(&c) && !config.flavor.is_posix() => {
+            format!("\\{}", c)
+        }
+        '\n' => "\\n".to_string(),
+        '\r' => "\\r".to_string(),
+        '\t' => "\\t".to_string(),
+        _ if config.is_control_char_escaped && is_escapable_control_char(c) => {
+            format!("\\x{:02x}", c as u32)
+        }
+        _ if config.is_non_ascii_char_escaped && !c.is_ascii() => escape_non_ascii_char(c, config),
+        _ if is_zero_width(c) => config.flavor.code_point_escape(c),
+        _ if config.is_verbose_mode_enabled => escape_verbose_char(c, config.flavor),
+        _ => c.to_string(),
+    }
+}
+
+fn is_escapable_control_char(c: char) -> bool {
+    // NUL is left to the flavor-specific escaping of `escape_nul_chars`
+    c != '\0' && (c < '\u{20}' || ('\u{80}'..='\u{9f}').contains(&c))
+}
+
+fn is_zero_width(c: char) -> bool {
+    let category = GeneralCategory::of(c);
+    category.is_mark() || category == GeneralCategory::Format
+}
+
 fn format_concatenation(
     f: &mut Formatter<'_>,
     expr: &Expression,
@@ -169,36 +270,28 @@ fn format_concatenation(
     expr2: &Expression,
     config: &RegExpConfig,
 ) -> Result {
-    let expr_strs = vec![expr1, expr2]
-        .iter()
-        .map(|&it| {
-            if it.precedence() < expr.precedence() && !it.is_single_codepoint() {
-                let (left_parenthesis, right_parenthesis) = vec![
-                    if config.is_capturing_group_enabled() {
-                        ColorizableString::CapturingLeftParenthesis
-                    } else {
-                        ColorizableString::NonCapturingLeftParenthesis
-                    },
-                    ColorizableString::RightParenthesis,
-                ]
-                .iter()
-                .map(|it| it.to_colorized_string(config.is_output_colorized))
-                .collect_tuple()
-                .unwrap();
-
-                format!("{}{}{}", left_parenthesis, it, right_parenthesis)
-            } else {
-                format!("{}", it)
-            }
-        })
-        .collect_vec();
+    for it in [expr1, expr2].iter() {
+        if it.precedence() < expr.precedence() && !it.is_single_codepoint() {
+            let (left_parenthesis, right_parenthesis) = [
+                if config.is_capturing_group_enabled() {
+                    ColorizableString::CapturingLeftParenthesis
+                } else {
+                    ColorizableString::NonCapturingLeftParenthesis
+                },
+                ColorizableString::RightParenthesis,
+            ]
+            .iter()
+            .map(|it| it.to_colorized_string(config))
+            .collect_tuple()
+            .unwrap();
 
-    write!(
-        f,
-        "{}{}",
-        expr_strs.first().unwrap(),
-        expr_strs.last().unwrap()
-    )
+            write!(f, "{}{}{}", left_parenthesis, it, right_parenthesis)?;
+        } else {
+            write!(f, "{}", it)?;
+        }
+    }
+
+    Ok(())
 }
 
 fn format_literal(
@@ -206,11 +299,13 @@ fn format_literal(
     cluster: &GraphemeCluster,
     config: &RegExpConfig,
 ) -> Result {
-    let literal_str = cluster
-        .graphemes()
+    let graphemes = cluster.graphemes();
+    let word_boundary = ColorizableString::WordBoundary.to_colorized_string(config);
+    let literal_str = graphemes
         .iter()
         .cloned()
-        .map(|mut grapheme| {
+        .enumerate()
+        .map(|(i, mut grapheme)| {
             if grapheme.has_repetitions() {
                 grapheme
                     .repetitions_mut()
@@ -218,20 +313,120 @@ fn format_literal(
                     .for_each(|repeated_grapheme| {
                         repeated_grapheme.escape_regexp_symbols(
                             config.is_non_ascii_char_escaped,
-                            config.is_astral_code_point_converted_to_surrogate,
+                            config.is_surrogate_pair_conversion_enabled(),
                         );
                     });
             } else {
                 grapheme.escape_regexp_symbols(
                     config.is_non_ascii_char_escaped,
-                    config.is_astral_code_point_converted_to_surrogate,
+                    config.is_surrogate_pair_conversion_enabled(),
                 );
             }
-            grapheme.to_string()
+            if config.is_internal_word_boundary_used
+                && i > 0
+                && is_word_boundary_between(&graphemes[i - 1], &graphemes[i], config)
+            {
+                format!("{}{}", word_boundary, grapheme)
+            } else {
+                grapheme.to_string()
+            }
         })
         .join("");
 
-    write!(f, "{}", literal_str)
+    write!(f, "{}", escape_nul_chars(&literal_str, config))
+}
+
+fn is_word_boundary_between(first: &Grapheme, second: &Grapheme, config: &RegExpConfig) -> bool {
+    match (
+        is_word_char_at_edge(first, false, config),
+        is_word_char_at_edge(second, true, config),
+    ) {
+        (Some(is_first_word_char), Some(is_second_word_char)) => {
+            is_first_word_char != is_second_word_char
+        }
+        _ => false,
+    }
+}
+
+fn is_word_char_at_edge(
+    grapheme: &Grapheme,
+    is_leading: bool,
+    config: &RegExpConfig,
+) -> Option<bool> {
+    if grapheme.minimum() == 0 {
+        return None;
+    }
+    if grapheme.has_repetitions() {
+        let repetitions = &grapheme.repetitions;
+        let repetition = if is_leading {
+            repetitions.first()
+        } else {
+            repetitions.last()
+        };
+        return is_word_char_at_edge(repetition?, is_leading, config);
+    }
+    let chars = grapheme.chars();
+    let s = if is_leading {
+        chars.first()
+    } else {
+        chars.last()
+    }?;
+
+    match s.as_str() {
+        "\\d" | "\\w" => Some(true),
+        "\\s" | "\\W" => Some(false),
+        // Other classes, such as `\p{Greek}` or bracket expressions, may match both
+        _ if config.is_wildcard(s) || contains_char_class(s) => None,
+        // The engine looks at the code points directly adjacent to the boundary
+        _ if is_leading => s.chars().next().map(is_word_char),
+        _ => s.chars().last().map(is_word_char),
+    }
+}
+
+fn contains_char_class(value: &str) -> bool {
+    if value.starts_with('[') && value.chars().count() > 1 {
+        return true;
+    }
+    let mut chars = value.chars().peekable();
+    while let Some(c) = chars.next() {
+        // Backslashes of the input are never followed by ascii letters within a grapheme
+        if c == '\\' && chars.peek().is_some_and(|it| it.is_ascii_alphabetic()) {
+            return true;
+        }
+    }
+    false
+}
+
+fn is_word_char(c: char) -> bool {
+    WORD.binary_search_by(|&(start, end)| {
+        if c < start {
+            Ordering::Greater
+        } else if c > end {
+            Ordering::Less
+        } else {
+            Ordering::Equal
+        }
+    })
+    .is_ok()
+}
+
+fn escape_nul_chars(s: &str, config: &RegExpConfig) -> String {
+    let mut escaped_str = String::new();
+    let mut chars = s.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        if c != '\0' {
+            escaped_str.push(c);
+            continue;
+        }
+        let is_followed_by_non_octal_digit = match chars.peek() {
+            Some(next) => !('0'..='7').contains(next),
+            None => false,
+        };
+        escaped_str.push_str(config.flavor.nul_escape(is_followed_by_non_octal_digit));
+    }
+
+    escaped_str
 }
 
 fn format_repetition(
@@ -254,17 +449,28 @@ fn format_repetition(
         ColorizableString::RightParenthesis,
     ]
     .iter()
-    .map(|it| it.to_colorized_string(config.is_output_colorized))
+    .map(|it| it.to_colorized_string(config))
     .collect_tuple()
     .unwrap();
 
-    if expr1.precedence() < expr.precedence() && !expr1.is_single_codepoint() {
-        write!(
-            f,
+    let repetition = if expr1.precedence() < expr.precedence() && !expr1.is_single_codepoint() {
+        format!(
             "{}{}{}{}",
             left_parenthesis, expr1, right_parenthesis, colored_quantifier
         )
     } else {
-        write!(f, "{}{}", expr1, colored_quantifier)
+        format!("{}{}", expr1, colored_quantifier)
+    };
+
+    if config.is_atomic_grouping_enabled() && expr1.has_variable_quantifier() {
+        let atomic_left_parenthesis =
+            ColorizableString::AtomicLeftParenthesis.to_colorized_string(config);
+        write!(
+            f,
+            "{}{}{}",
+            atomic_left_parenthesis, repetition, right_parenthesis
+        )
+    } else {
+        write!(f, "{}", repetition)
     }
 }
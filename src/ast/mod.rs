@@ -16,6 +16,7 @@
 
 mod expression;
 mod format;
+mod parse;
 mod quantifier;
 mod substring;
 
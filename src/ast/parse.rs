@@ -0,0 +1,437 @@
+/*
+ * Copyright © 2019-2020 Peter M. Stahl pemistahl@gmail.com
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ * http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either expressed or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use crate::ast::{Expression, Quantifier};
+use crate::char::{Grapheme, GraphemeCluster};
+use crate::regexp::{ParseError, RegExpConfig};
+use itertools::Itertools;
+use std::collections::BTreeSet;
+
+const MAXIMUM_TEST_CASES: usize = 100_000;
+
+enum Atom {
+    Char(char),
+    Shorthand(String),
+    Expression(Box<Expression>),
+}
+
+impl Expression {
+    /// Parses the subset of regular expression syntax which grex itself generates
+    /// for the flavors Rust and PCRE: literals, character classes without negation,
+    /// shorthand classes, groups, alternations, quantifiers and surrounding anchors.
+    pub(crate) fn parse(pattern: &str, config: &RegExpConfig) -> Result<Self, ParseError> {
+        let mut parser = Parser {
+            chars: pattern.chars().collect_vec(),
+            position: 0,
+            config,
+        };
+        parser.skip_start_anchor();
+        let expr = parser.parse_alternation()?;
+
+        if parser.position < parser.chars.len() {
+            Err(ParseError::InvalidSyntax(parser.position))
+        } else {
+            Ok(expr)
+        }
+    }
+
+    pub(crate) fn to_test_cases(&self) -> Result<Vec<String>, ParseError> {
+        match self {
+            Expression::Alternation(options, _) => {
+                let mut test_cases = vec![];
+                for option in options {
+                    test_cases.extend(option.to_test_cases()?);
+                    if test_cases.len() > MAXIMUM_TEST_CASES {
+                        return Err(ParseError::LanguageTooLarge);
+                    }
+                }
+                Ok(test_cases)
+            }
+            Expression::CharacterClass(char_set, _) => {
+                Ok(char_set.iter().map(|c| c.to_string()).collect_vec())
+            }
+            Expression::Concatenation(expr1, expr2, _) => {
+                let prefixes = expr1.to_test_cases()?;
+                let suffixes = expr2.to_test_cases()?;
+                if prefixes.len().saturating_mul(suffixes.len()) > MAXIMUM_TEST_CASES {
+                    return Err(ParseError::LanguageTooLarge);
+                }
+                Ok(prefixes
+                    .iter()
+                    .cartesian_product(suffixes.iter())
+                    .map(|(prefix, suffix)| format!("{}{}", prefix, suffix))
+                    .collect_vec())
+            }
+            Expression::Literal(cluster, config) => {
+                let mut test_case = String::new();
+                for grapheme in cluster.graphemes() {
+                    let value = grapheme.value();
+                    if grapheme.is_char_class() || config.is_wildcard(&value) {
+                        return Err(ParseError::UnsupportedSyntax(value));
+                    }
+                    test_case.push_str(&value);
+                }
+                Ok(vec![test_case])
+            }
+            Expression::Repetition(expr, quantifier, _) => match quantifier {
+                Quantifier::KleeneStar => Err(ParseError::UnboundedLanguage),
+                Quantifier::QuestionMark => {
+                    let mut test_cases = vec![String::new()];
+                    test_cases.extend(expr.to_test_cases()?);
+                    Ok(test_cases)
+                }
+            },
+            Expression::SubroutineCall(name, _) => {
+                Err(ParseError::UnsupportedSyntax(format!("(?&{})", name)))
+            }
+        }
+    }
+}
+
+struct Parser<'a> {
+    chars: Vec<char>,
+    position: usize,
+    config: &'a RegExpConfig,
+}
+
+impl<'a> Parser<'a> {
+    fn skip_start_anchor(&mut self) {
+        let _ = self.consume("^") || self.consume("\\G") || self.consume("\\b");
+    }
+
+    fn is_at_end_anchor(&self) -> bool {
+        let rest = &self.chars[self.position..];
+        rest == ['$'] || rest == ['\\', 'b']
+    }
+
+    fn consume(&mut self, s: &str) -> bool {
+        let chars = s.chars().collect_vec();
+        if self.chars[self.position..].starts_with(&chars) {
+            self.position += chars.len();
+            true
+        } else {
+            false
+        }
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.chars.get(self.position).copied()
+    }
+
+    fn next(&mut self) -> Result<char, ParseError> {
+        let c = self
+            .peek()
+            .ok_or(ParseError::InvalidSyntax(self.position))?;
+        self.position += 1;
+        Ok(c)
+    }
+
+    fn expect(&mut self, c: char) -> Result<(), ParseError> {
+        if self.peek() == Some(c) {
+            self.position += 1;
+            Ok(())
+        } else {
+            Err(ParseError::InvalidSyntax(self.position))
+        }
+    }
+
+    fn parse_alternation(&mut self) -> Result<Expression, ParseError> {
+        let mut options = vec![self.parse_concatenation()?];
+        while self.consume("|") {
+            options.push(self.parse_concatenation()?);
+        }
+
+        if options.len() == 1 {
+            Ok(options.remove(0))
+        } else {
+            Ok(Expression::Alternation(options, self.config.clone()))
+        }
+    }
+
+    fn parse_concatenation(&mut self) -> Result<Expression, ParseError> {
+        let mut items = vec![];
+        let mut literal = String::new();
+
+        while let Some(c) = self.peek() {
+            if c == '|' || c == ')' {
+                break;
+            }
+            if self.is_at_end_anchor() {
+                self.position = self.chars.len();
+                break;
+            }
+            let atom = self.parse_atom()?;
+            match (atom, self.is_at_quantifier()) {
+                (Atom::Char(c), false) => literal.push(c),
+                (atom, _) => {
+                    if !literal.is_empty() {
+                        items.push(self.new_literal(&literal));
+                        literal.clear();
+                    }
+                    let expr = self.to_expression(atom);
+                    items.push(self.parse_quantifier(expr)?);
+                }
+            }
+        }
+        if !literal.is_empty() || items.is_empty() {
+            items.push(self.new_literal(&literal));
+        }
+
+        Ok(self.concatenate(items))
+    }
+
+    fn parse_atom(&mut self) -> Result<Atom, ParseError> {
+        let position = self.position;
+        match self.next()? {
+            '(' => {
+                if !self.consume("?:") && self.peek() == Some('?') {
+                    let construct = self.chars[position..].iter().take(3).collect();
+                    return Err(ParseError::UnsupportedSyntax(construct));
+                }
+                let expr = self.parse_alternation()?;
+                self.expect(')')?;
+                Ok(Atom::Expression(Box::from(expr)))
+            }
+            '[' => Ok(Atom::Expression(Box::from(self.parse_character_class()?))),
+            '\\' => self.parse_escape(),
+            '.' if self.config.is_wildcard(".") => Ok(Atom::Shorthand(".".to_string())),
+            c @ '.' | c @ '^' | c @ '$' => Err(ParseError::UnsupportedSyntax(c.to_string())),
+            '?' | '*' | '+' | '{' | ']' | '}' => Err(ParseError::InvalidSyntax(position)),
+            c => Ok(Atom::Char(c)),
+        }
+    }
+
+    fn parse_escape(&mut self) -> Result<Atom, ParseError> {
+        let c = self.next()?;
+        let atom = match c {
+            'd' | 'D' | 's' | 'S' | 'w' | 'W' | 'X' => Atom::Shorthand(format!("\\{}", c)),
+            'p' => {
+                self.expect('{')?;
+                let name = self.take_until('}')?;
+                Atom::Shorthand(format!("\\p{{{}}}", name))
+            }
+            'n' => Atom::Char('\n'),
+            'r' => Atom::Char('\r'),
+            't' => Atom::Char('\t'),
+            'f' => Atom::Char('\u{c}'),
+            'v' => Atom::Char('\u{b}'),
+            '0' => Atom::Char('\0'),
+            'x' => Atom::Char(self.parse_code_point(2)?),
+            'u' => Atom::Char(self.parse_code_point(4)?),
+            c if c.is_alphanumeric() => {
+                return Err(ParseError::UnsupportedSyntax(format!("\\{}", c)))
+            }
+            c => Atom::Char(c),
+        };
+        Ok(atom)
+    }
+
+    fn parse_code_point(&mut self, digit_count: usize) -> Result<char, ParseError> {
+        let position = self.position;
+        let code = self.parse_hex_number(digit_count)?;
+
+        if (0xD800..0xDC00).contains(&code) && self.consume("\\u") {
+            let low_surrogate = self.parse_hex_number(4)?;
+            if (0xDC00..0xE000).contains(&low_surrogate) {
+                let code = 0x10000 + ((code - 0xD800) << 10) + (low_surrogate - 0xDC00);
+                return std::char::from_u32(code).ok_or(ParseError::InvalidSyntax(position));
+            }
+        }
+        std::char::from_u32(code).ok_or(ParseError::InvalidSyntax(position))
+    }
+
+    fn parse_hex_number(&mut self, digit_count: usize) -> Result<u32, ParseError> {
+        let position = self.position;
+        let digits = if self.consume("{") {
+            self.take_until('}')?
+        } else {
+            (0..digit_count)
+                .map(|_| self.next())
+                .collect::<Result<String, _>>()?
+        };
+        u32::from_str_radix(&digits, 16).map_err(|_| ParseError::InvalidSyntax(position))
+    }
+
+    fn take_until(&mut self, end: char) -> Result<String, ParseError> {
+        let mut s = String::new();
+        loop {
+            match self.next()? {
+                c if c == end => return Ok(s),
+                c => s.push(c),
+            }
+        }
+    }
+
+    fn parse_character_class(&mut self) -> Result<Expression, ParseError> {
+        if self.peek() == Some('^') {
+            return Err(ParseError::UnsupportedSyntax("[^".to_string()));
+        }
+        let mut char_set = BTreeSet::new();
+        let mut options = vec![];
+        let mut is_first_member = true;
+
+        loop {
+            let position = self.position;
+            let member = match self.next()? {
+                ']' if !is_first_member => break,
+                '\\' => self.parse_escape()?,
+                '[' if self.peek() == Some(':') => {
+                    return Err(ParseError::UnsupportedSyntax("[:".to_string()))
+                }
+                c => Atom::Char(c),
+            };
+            is_first_member = false;
+
+            match member {
+                Atom::Char(first) if self.peek() == Some('-') => {
+                    self.position += 1;
+                    if self.peek() == Some(']') {
+                        char_set.insert(first);
+                        char_set.insert('-');
+                        continue;
+                    }
+                    let last = match self.next()? {
+                        '\\' => match self.parse_escape()? {
+                            Atom::Char(last) if first <= last => last,
+                            _ => return Err(ParseError::InvalidSyntax(position)),
+                        },
+                        last if first <= last => last,
+                        _ => return Err(ParseError::InvalidSyntax(position)),
+                    };
+                    char_set.extend(first..=last);
+                }
+                Atom::Char(c) => {
+                    char_set.insert(c);
+                }
+                atom => options.push(self.to_expression(atom)),
+            }
+        }
+
+        if !char_set.is_empty() {
+            options.insert(0, Expression::CharacterClass(char_set, self.config.clone()));
+        }
+
+        if options.len() == 1 {
+            Ok(options.remove(0))
+        } else {
+            Ok(Expression::Alternation(options, self.config.clone()))
+        }
+    }
+
+    fn is_at_quantifier(&self) -> bool {
+        match self.peek() {
+            Some('?') | Some('*') | Some('+') => true,
+            Some('{') => self.chars[self.position + 1..]
+                .iter()
+                .take_while(|&&c| c != '}')
+                .all(|&c| c.is_ascii_digit() || c == ','),
+            _ => false,
+        }
+    }
+
+    fn parse_quantifier(&mut self, expr: Expression) -> Result<Expression, ParseError> {
+        let position = self.position;
+        let (min, max) = match self.peek() {
+            Some('?') => {
+                self.position += 1;
+                (0, Some(1))
+            }
+            Some('*') => {
+                self.position += 1;
+                (0, None)
+            }
+            Some('+') => {
+                self.position += 1;
+                (1, None)
+            }
+            Some('{') => {
+                self.position += 1;
+                let bounds = self.take_until('}')?;
+                let parse_bound = |bound: &str| {
+                    bound
+                        .parse::<u32>()
+                        .map_err(|_| ParseError::InvalidSyntax(position))
+                };
+                match bounds.split(',').collect_vec().as_slice() {
+                    [count] => (parse_bound(count)?, Some(parse_bound(count)?)),
+                    [min, ""] => (parse_bound(min)?, None),
+                    [min, max] => (parse_bound(min)?, Some(parse_bound(max)?)),
+                    _ => return Err(ParseError::InvalidSyntax(position)),
+                }
+            }
+            _ => return Ok(expr),
+        };
+        if let Some(c @ '?') | Some(c @ '+') = self.peek() {
+            return Err(ParseError::UnsupportedSyntax(format!(
+                "{}{}",
+                self.chars[position..self.position]
+                    .iter()
+                    .collect::<String>(),
+                c
+            )));
+        }
+        if let Some(max) = max {
+            if max < min {
+                return Err(ParseError::InvalidSyntax(position));
+            }
+        }
+
+        let mut items = vec![expr.clone(); min as usize];
+        match max {
+            Some(max) => items.extend((min..max).map(|_| {
+                Expression::Repetition(
+                    Box::from(expr.clone()),
+                    Quantifier::QuestionMark,
+                    self.config.clone(),
+                )
+            })),
+            None => items.push(Expression::Repetition(
+                Box::from(expr),
+                Quantifier::KleeneStar,
+                self.config.clone(),
+            )),
+        }
+        if items.is_empty() {
+            items.push(self.new_literal(""));
+        }
+
+        Ok(self.concatenate(items))
+    }
+
+    fn to_expression(&self, atom: Atom) -> Expression {
+        match atom {
+            Atom::Char(c) => self.new_literal(&c.to_string()),
+            Atom::Shorthand(shorthand) => Expression::Literal(
+                GraphemeCluster::new(Grapheme::from(&shorthand, self.config), self.config),
+                self.config.clone(),
+            ),
+            Atom::Expression(expr) => *expr,
+        }
+    }
+
+    fn concatenate(&self, items: Vec<Expression>) -> Expression {
+        items
+            .into_iter()
+            .fold1(|expr1, expr2| {
+                Expression::Concatenation(Box::from(expr1), Box::from(expr2), self.config.clone())
+            })
+            .unwrap()
+    }
+
+    fn new_literal(&self, s: &str) -> Expression {
+        Expression::Literal(GraphemeCluster::from(s, self.config), self.config.clone())
+    }
+}
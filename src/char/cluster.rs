@@ -14,17 +14,24 @@
  * limitations under the License.
  */
 
-use crate::char::Grapheme;
-use crate::regexp::RegExpConfig;
-use crate::unicode_tables::{DECIMAL_NUMBER, WHITE_SPACE, WORD};
+use crate::char::{Grapheme, SEMVER_IDENTIFIER};
+use crate::regexp::{scan_pattern_fragment, Flavor, Mutation, RegExpConfig, Wildcard};
+use crate::unicode_tables::{DECIMAL_NUMBER, SCRIPTS, WHITE_SPACE, WORD};
 use itertools::Itertools;
 use std::cmp::Ordering;
-use std::collections::HashMap;
+use std::collections::{BTreeSet, HashMap, HashSet};
 use std::ops::Range;
 use unic_char_range::CharRange;
 use unic_ucd_category::GeneralCategory;
 use unicode_segmentation::UnicodeSegmentation;
 
+const ZERO_WIDTH_JOINER: char = '\u{200d}';
+pub(crate) const DIGIT_PLACEHOLDER: char = '#';
+const OPTIONAL_WHITESPACE: &str = "\\s*";
+
+/// This struct represents a single test case as a sequence of graphemes.
+/// It is passed to the transformation set with
+/// [`RegExpBuilder.with_cluster_transformation`](./struct.RegExpBuilder.html#method.with_cluster_transformation).
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct GraphemeCluster {
     graphemes: Vec<Grapheme>,
@@ -33,22 +40,56 @@ pub struct GraphemeCluster {
 
 impl GraphemeCluster {
     pub(crate) fn from(s: &str, config: &RegExpConfig) -> Self {
+        let graphemes = UnicodeSegmentation::graphemes(s, true)
+            .flat_map(|it| {
+                let starts_with_backslash = it.chars().count() == 2 && it.starts_with('\\');
+                let contains_combining_mark = it.chars().any(|c| GeneralCategory::of(c).is_mark());
+
+                if starts_with_backslash || contains_combining_mark {
+                    it.chars()
+                        .map(|c| Grapheme::from(&c.to_string(), config))
+                        .collect_vec()
+                } else if config.is_zwj_sequence_split && it.contains(ZERO_WIDTH_JOINER) {
+                    split_zwj_sequence(it)
+                        .iter()
+                        .map(|it| Grapheme::from(it, config))
+                        .collect_vec()
+                } else {
+                    vec![Grapheme::from(it, config)]
+                }
+            })
+            .collect_vec();
+        let graphemes = if config.separators.is_empty() {
+            graphemes
+        } else {
+            surround_separators(graphemes, config)
+        };
+
         Self {
-            graphemes: UnicodeSegmentation::graphemes(s, true)
-                .flat_map(|it| {
-                    let starts_with_backslash = it.chars().count() == 2 && it.starts_with('\\');
-                    let contains_combining_mark =
-                        it.chars().any(|c| GeneralCategory::of(c).is_mark());
-
-                    if starts_with_backslash || contains_combining_mark {
-                        it.chars()
-                            .map(|c| Grapheme::from(&c.to_string(), config))
-                            .collect_vec()
-                    } else {
-                        vec![Grapheme::from(it, config)]
-                    }
-                })
-                .collect_vec(),
+            graphemes: match config.placeholder {
+                Some((placeholder, wildcard)) => {
+                    convert_placeholders(graphemes, placeholder, wildcard, config)
+                }
+                None => graphemes,
+            },
+            config: config.clone(),
+        }
+    }
+
+    pub(crate) fn from_pattern_fragment(fragment: &str, config: &RegExpConfig) -> Self {
+        let has_top_level_alternation =
+            scan_pattern_fragment(fragment, config.flavor).unwrap_or(false);
+        let value = if has_top_level_alternation {
+            match config.flavor {
+                Flavor::PosixBasic => format!("\\({}\\)", fragment),
+                Flavor::PosixExtended => format!("({})", fragment),
+                Flavor::Rust | Flavor::Pcre => format!("(?:{})", fragment),
+            }
+        } else {
+            fragment.to_string()
+        };
+        Self {
+            graphemes: vec![Grapheme::from(&value, config)],
             config: config.clone(),
         }
     }
@@ -60,6 +101,65 @@ impl GraphemeCluster {
         }
     }
 
+    pub(crate) fn from_ipv4_octet_bounds(
+        octet_bounds: &[(u32, u32)],
+        config: &RegExpConfig,
+    ) -> Self {
+        let octets = octet_bounds
+            .iter()
+            .map(|&(min, max)| Grapheme::new(vec!["\\d".to_string()], min, max, config));
+        Self {
+            graphemes: Itertools::intersperse(octets, Grapheme::from(".", config)).collect_vec(),
+            config: config.clone(),
+        }
+    }
+
+    pub(crate) fn from_digit_layout(layout: &str, config: &RegExpConfig) -> Self {
+        let mut graphemes = vec![];
+        for (is_digit, group) in &layout.chars().group_by(|&c| c == DIGIT_PLACEHOLDER) {
+            if is_digit {
+                let count = group.count() as u32;
+                graphemes.push(Grapheme::new(vec!["\\d".to_string()], count, count, config));
+            } else {
+                graphemes.extend(group.map(|c| Grapheme::from(&c.to_string(), config)));
+            }
+        }
+        Self {
+            graphemes,
+            config: config.clone(),
+        }
+    }
+
+    pub(crate) fn from_hex_digits(
+        hex_class: &str,
+        min: u32,
+        max: u32,
+        config: &RegExpConfig,
+    ) -> Self {
+        Self {
+            graphemes: vec![Grapheme::new(vec![hex_class.to_string()], min, max, config)],
+            config: config.clone(),
+        }
+    }
+
+    pub(crate) fn from_semver_core(config: &RegExpConfig) -> Self {
+        let numbers = (0..3).map(|_| Grapheme::from("\\d+", config));
+        Self {
+            graphemes: Itertools::intersperse(numbers, Grapheme::from(".", config)).collect_vec(),
+            config: config.clone(),
+        }
+    }
+
+    pub(crate) fn from_semver_identifiers(prefix: char, config: &RegExpConfig) -> Self {
+        Self {
+            graphemes: vec![
+                Grapheme::from(&prefix.to_string(), config),
+                Grapheme::from(SEMVER_IDENTIFIER, config),
+            ],
+            config: config.clone(),
+        }
+    }
+
     pub(crate) fn new(grapheme: Grapheme, config: &RegExpConfig) -> Self {
         Self {
             graphemes: vec![grapheme],
@@ -67,25 +167,56 @@ impl GraphemeCluster {
         }
     }
 
-    pub(crate) fn convert_to_char_classes(&mut self) {
+    pub(crate) fn transpositions(&self, limit: u32) -> Vec<GraphemeCluster> {
+        let mut variants = vec![];
+        collect_transpositions(&mut self.graphemes.clone(), 0, limit, &mut variants);
+        variants
+            .into_iter()
+            .map(|graphemes| Self {
+                graphemes,
+                config: self.config.clone(),
+            })
+            .collect_vec()
+    }
+
+    pub(crate) fn convert_to_char_classes(&mut self, position_limit: usize) -> BTreeSet<String> {
         let is_digit_converted = self.config.is_digit_converted();
         let is_non_digit_converted = self.config.is_non_digit_converted();
         let is_space_converted = self.config.is_space_converted();
         let is_non_space_converted = self.config.is_non_space_converted();
         let is_word_converted = self.config.is_word_converted();
         let is_non_word_converted = self.config.is_non_word_converted();
+        let is_ascii_only = self.config.is_class_notation_ascii_only();
 
         let valid_numeric_chars = convert_chars_to_range(DECIMAL_NUMBER);
         let valid_alphanumeric_chars = convert_chars_to_range(WORD);
         let valid_space_chars = convert_chars_to_range(WHITE_SPACE);
 
-        for grapheme in self.graphemes.iter_mut() {
+        let config = &self.config;
+        let mut shorthands = BTreeSet::new();
+
+        let position_limit = position_limit.min(self.graphemes.len());
+        let graphemes = &mut self.graphemes[..position_limit];
+
+        if config.is_script_converted() {
+            shorthands.extend(convert_to_script_classes(graphemes, config));
+        }
+
+        for grapheme in graphemes.iter_mut() {
             grapheme.chars = grapheme
                 .chars
                 .iter()
                 .map(|it| {
+                    if it.starts_with('\\') && it.chars().count() > 1 || config.is_wildcard(it) {
+                        return it.clone();
+                    }
                     it.chars()
                         .map(|c| {
+                            // Beyond ascii, classes in this notation do not match
+                            // the same characters as their Unicode counterparts
+                            if is_ascii_only && !c.is_ascii() {
+                                return c.to_string();
+                            }
                             let is_digit =
                                 valid_numeric_chars.iter().any(|range| range.contains(c));
                             let is_word = valid_alphanumeric_chars
@@ -93,26 +224,58 @@ impl GraphemeCluster {
                                 .any(|range| range.contains(c));
                             let is_space = valid_space_chars.iter().any(|range| range.contains(c));
 
-                            if is_digit_converted && is_digit {
-                                "\\d".to_string()
+                            let shorthand = if is_digit_converted && is_digit {
+                                "\\d"
                             } else if is_word_converted && is_word {
-                                "\\w".to_string()
+                                "\\w"
                             } else if is_space_converted && is_space {
-                                "\\s".to_string()
+                                "\\s"
                             } else if is_non_digit_converted && !is_digit {
-                                "\\D".to_string()
+                                "\\D"
                             } else if is_non_word_converted && !is_word {
-                                "\\W".to_string()
+                                "\\W"
                             } else if is_non_space_converted && !is_space {
-                                "\\S".to_string()
+                                "\\S"
                             } else {
-                                c.to_string()
-                            }
+                                return c.to_string();
+                            };
+                            shorthands.insert(shorthand.to_string());
+                            shorthand.to_string()
                         })
                         .join("")
                 })
                 .collect_vec();
         }
+
+        shorthands
+    }
+
+    pub(crate) fn apply_mutations(&mut self) {
+        let valid_numeric_chars = convert_chars_to_range(DECIMAL_NUMBER);
+
+        for mutation in self.config.mutations.iter() {
+            match mutation {
+                Mutation::DigitForDigit => {
+                    for grapheme in self.graphemes.iter_mut() {
+                        let value = grapheme.value();
+                        let mut chars = value.chars();
+                        let is_digit = match (chars.next(), chars.next()) {
+                            (Some(c), None) => {
+                                valid_numeric_chars.iter().any(|range| range.contains(c))
+                            }
+                            _ => false,
+                        };
+                        if is_digit {
+                            *grapheme = Grapheme::from("\\d", &self.config);
+                        }
+                    }
+                }
+                // Letters are already lowercased for case-insensitive matching
+                Mutation::CaseFlip => {}
+                // Transpositions result in additional clusters instead
+                Mutation::Transposition(_) => {}
+            }
+        }
     }
 
     pub(crate) fn convert_repetitions(&mut self) {
@@ -137,6 +300,31 @@ impl GraphemeCluster {
         }
     }
 
+    /// Returns the unescaped values of the graphemes in this cluster, such as `a` or `\\d`.
+    /// The value of a repeated sequence of graphemes is the concatenation of its parts.
+    pub fn values(&self) -> Vec<String> {
+        self.graphemes.iter().map(grapheme_value).collect_vec()
+    }
+
+    /// Returns a copy of this cluster in which each unescaped grapheme value,
+    /// including those within repeated sequences, has been replaced with the result
+    /// of the given function. Escaping takes place afterwards, so the returned values
+    /// are always matched literally unless they are shorthand classes such as `\\d`.
+    pub fn map_values<F: Fn(&str) -> String>(&self, f: F) -> Self {
+        let mut cluster = self.clone();
+        for grapheme in cluster.graphemes.iter_mut() {
+            grapheme.map_chars(&f);
+        }
+        cluster
+    }
+
+    pub(crate) fn set_config(&mut self, config: &RegExpConfig) {
+        self.config = config.clone();
+        for grapheme in self.graphemes.iter_mut() {
+            grapheme.set_config(config);
+        }
+    }
+
     pub(crate) fn graphemes(&self) -> &Vec<Grapheme> {
         &self.graphemes
     }
@@ -149,11 +337,8 @@ impl GraphemeCluster {
         self.graphemes.len()
     }
 
-    pub(crate) fn char_count(&self, is_non_ascii_char_escaped: bool) -> usize {
-        self.graphemes
-            .iter()
-            .map(|it| it.char_count(is_non_ascii_char_escaped))
-            .sum()
+    pub(crate) fn char_count(&self) -> usize {
+        self.graphemes.iter().map(|it| it.char_count()).sum()
     }
 
     pub(crate) fn is_empty(&self) -> bool {
@@ -161,71 +346,430 @@ impl GraphemeCluster {
     }
 }
 
+fn grapheme_value(grapheme: &Grapheme) -> String {
+    if grapheme.has_repetitions() {
+        grapheme.repetitions.iter().map(grapheme_value).join("")
+    } else {
+        grapheme.value()
+    }
+}
+
+fn surround_separators(graphemes: Vec<Grapheme>, config: &RegExpConfig) -> Vec<Grapheme> {
+    let mut surrounded_graphemes: Vec<Grapheme> = vec![];
+
+    for grapheme in graphemes {
+        let is_separator = matches!(
+            grapheme.value().chars().exactly_one(),
+            Ok(c) if config.separators.contains(&c)
+        );
+
+        if is_separator {
+            // Adjacent separators share the optional whitespace between them
+            let is_preceded_by_whitespace = matches!(
+                surrounded_graphemes.last(),
+                Some(it) if it.value() == OPTIONAL_WHITESPACE
+            );
+            if !is_preceded_by_whitespace {
+                surrounded_graphemes.push(Grapheme::from(OPTIONAL_WHITESPACE, config));
+            }
+            surrounded_graphemes.push(grapheme);
+            surrounded_graphemes.push(Grapheme::from(OPTIONAL_WHITESPACE, config));
+        } else {
+            surrounded_graphemes.push(grapheme);
+        }
+    }
+
+    surrounded_graphemes
+}
+
+fn split_zwj_sequence(s: &str) -> Vec<String> {
+    let mut components = vec![];
+    let mut component = String::new();
+
+    for c in s.chars() {
+        if c == ZERO_WIDTH_JOINER {
+            if !component.is_empty() {
+                components.push(component);
+                component = String::new();
+            }
+            components.push(c.to_string());
+        } else {
+            component.push(c);
+        }
+    }
+
+    if !component.is_empty() {
+        components.push(component);
+    }
+    components
+}
+
+fn collect_transpositions(
+    graphemes: &mut Vec<Grapheme>,
+    start: usize,
+    limit: u32,
+    variants: &mut Vec<Vec<Grapheme>>,
+) {
+    if limit == 0 {
+        return;
+    }
+    for i in start..graphemes.len().saturating_sub(1) {
+        if graphemes[i] != graphemes[i + 1] {
+            graphemes.swap(i, i + 1);
+            variants.push(graphemes.clone());
+            collect_transpositions(graphemes, i + 2, limit - 1, variants);
+            graphemes.swap(i, i + 1);
+        }
+    }
+}
+
+fn convert_placeholders(
+    graphemes: Vec<Grapheme>,
+    placeholder: char,
+    wildcard: Wildcard,
+    config: &RegExpConfig,
+) -> Vec<Grapheme> {
+    let placeholder = if config.is_case_insensitive_matching() {
+        placeholder.to_lowercase().to_string()
+    } else {
+        placeholder.to_string()
+    };
+    let to_literal = |grapheme: Grapheme| {
+        if grapheme.value() == wildcard.value() {
+            Grapheme::from(&format!("\\{}", grapheme.value()), config)
+        } else {
+            grapheme
+        }
+    };
+    let mut converted_graphemes = vec![];
+    let mut graphemes = graphemes.into_iter().peekable();
+
+    while let Some(grapheme) = graphemes.next() {
+        let value = grapheme.value();
+        let is_placeholder_escaped =
+            value == "\\" && graphemes.peek().map(|next| next.value()) == Some(placeholder.clone());
+
+        if is_placeholder_escaped {
+            converted_graphemes.push(to_literal(graphemes.next().unwrap()));
+        } else if value == placeholder {
+            converted_graphemes.push(Grapheme::from(wildcard.value(), config));
+        } else {
+            converted_graphemes.push(to_literal(grapheme));
+        }
+    }
+
+    converted_graphemes
+}
+
+fn convert_to_script_classes(graphemes: &mut [Grapheme], config: &RegExpConfig) -> Vec<String> {
+    let scripts = graphemes
+        .iter()
+        .map(|it| {
+            if it.is_char_class() || config.is_wildcard(&it.value()) {
+                ScriptKind::Common
+            } else {
+                find_script(&it.value())
+            }
+        })
+        .collect_vec();
+    let mut shorthands = vec![];
+    let mut start = 0;
+
+    while start < scripts.len() {
+        let end = scripts[start..]
+            .iter()
+            .position(|it| *it == ScriptKind::Common)
+            .map_or(scripts.len(), |it| start + it);
+        let run_scripts = scripts[start..end]
+            .iter()
+            .filter(|it| **it != ScriptKind::Inherited)
+            .unique()
+            .collect_vec();
+
+        if let [ScriptKind::Single(name)] = run_scripts.as_slice() {
+            let shorthand = format!("\\p{{{}}}", name);
+            let mut is_converted = false;
+            for (grapheme, script) in graphemes[start..end].iter_mut().zip(&scripts[start..end]) {
+                // A class matches a single code point only, so graphemes consisting
+                // of several ones, such as letters with combining marks, are kept
+                if *script != ScriptKind::Inherited && grapheme.value().chars().count() == 1 {
+                    grapheme.chars = vec![shorthand.clone()];
+                    is_converted = true;
+                }
+            }
+            if is_converted {
+                shorthands.push(shorthand);
+            }
+        }
+        start = end + 1;
+    }
+
+    shorthands
+}
+
+#[derive(Debug, Eq, Hash, PartialEq)]
+enum ScriptKind {
+    Common,
+    Inherited,
+    Single(&'static str),
+    Mixed,
+}
+
+fn find_script(value: &str) -> ScriptKind {
+    let scripts = value
+        .chars()
+        .map(|c| {
+            SCRIPTS
+                .iter()
+                .find(|(_, table)| table.iter().any(|&(start, end)| start <= c && c <= end))
+                .map_or("Unknown", |&(name, _)| name)
+        })
+        .filter(|&name| name != "Inherited")
+        .unique()
+        .collect_vec();
+
+    match scripts.as_slice() {
+        [] => ScriptKind::Inherited,
+        ["Common"] | ["Unknown"] => ScriptKind::Common,
+        [name] => ScriptKind::Single(name),
+        _ => ScriptKind::Mixed,
+    }
+}
+
+const HASH_MODULUS: u64 = (1 << 61) - 1;
+const HASH_BASES: [u64; 2] = [131_071, 524_287];
+
+/// Polynomial hashes of all prefixes of a grapheme sequence, so that any two
+/// substrings can be compared in constant time. Two independent bases are
+/// used to make collisions of distinct substrings practically impossible.
+struct RollingHash {
+    ids: Vec<u64>,
+    prefix_hashes: Vec<[u64; 2]>,
+    powers: Vec<[u64; 2]>,
+}
+
+impl RollingHash {
+    fn new(values: &[String]) -> Self {
+        let mut id_map = HashMap::new();
+        let mut ids = vec![];
+        let mut prefix_hashes = vec![[0; 2]];
+        let mut powers = vec![[1; 2]];
+
+        for value in values {
+            let next_id = id_map.len() as u64 + 1;
+            let id = *id_map.entry(value.as_str()).or_insert(next_id);
+            let last_hash = prefix_hashes[prefix_hashes.len() - 1];
+            let last_power = powers[powers.len() - 1];
+            let mut hash = [0; 2];
+            let mut power = [0; 2];
+            for k in 0..2 {
+                hash[k] = (mul_mod(last_hash[k], HASH_BASES[k]) + id) % HASH_MODULUS;
+                power[k] = mul_mod(last_power[k], HASH_BASES[k]);
+            }
+            ids.push(id);
+            prefix_hashes.push(hash);
+            powers.push(power);
+        }
+
+        Self {
+            ids,
+            prefix_hashes,
+            powers,
+        }
+    }
+
+    fn len(&self) -> usize {
+        self.ids.len()
+    }
+
+    fn substring(&self, start: usize, length: usize) -> [u64; 2] {
+        let mut hash = [0; 2];
+        for (k, substring_hash) in hash.iter_mut().enumerate() {
+            let shifted = mul_mod(self.prefix_hashes[start][k], self.powers[length][k]);
+            *substring_hash =
+                (self.prefix_hashes[start + length][k] + HASH_MODULUS - shifted) % HASH_MODULUS;
+        }
+        hash
+    }
+
+    /// Returns the length of the longest common prefix of the suffixes
+    /// starting at `first` and `second`.
+    fn common_prefix_length(&self, first: usize, second: usize) -> usize {
+        longest_matching_length(self.len() - first.max(second), |length| {
+            self.substring(first, length) == self.substring(second, length)
+        })
+    }
+
+    /// Returns the length of the longest common suffix of the prefixes
+    /// ending before `first` and `second`.
+    fn common_suffix_length(&self, first: usize, second: usize) -> usize {
+        longest_matching_length(first.min(second), |length| {
+            self.substring(first - length, length) == self.substring(second - length, length)
+        })
+    }
+
+    /// Compares the suffixes starting at `first` and `second`, treating
+    /// a proper prefix as the smaller suffix regardless of the order of graphemes.
+    fn compare_suffixes(&self, first: usize, second: usize, is_reversed: bool) -> Ordering {
+        let length = self.common_prefix_length(first, second);
+        match (self.ids.get(first + length), self.ids.get(second + length)) {
+            (Some(first_id), Some(second_id)) if is_reversed => second_id.cmp(first_id),
+            (Some(first_id), Some(second_id)) => first_id.cmp(second_id),
+            (first_id, second_id) => first_id.is_some().cmp(&second_id.is_some()),
+        }
+    }
+
+    /// Finds all maximal runs, i.e. maximal substrings which consist of at least
+    /// two repetitions of their shortest period, as triples of start, end and period.
+    ///
+    /// Every run has a Lyndon root which is the longest Lyndon word starting
+    /// at its position with respect to one of the two orders of graphemes,
+    /// so it suffices to try to extend these Lyndon words to runs.
+    fn runs(&self) -> BTreeSet<(usize, usize, usize)> {
+        let mut runs = BTreeSet::new();
+
+        for &is_reversed in [false, true].iter() {
+            let mut stack: Vec<usize> = vec![];
+
+            for start in (0..self.len()).rev() {
+                while let Some(&next_start) = stack.last() {
+                    if self.compare_suffixes(start, next_start, is_reversed) == Ordering::Less {
+                        stack.pop();
+                    } else {
+                        break;
+                    }
+                }
+
+                let end = stack.last().copied().unwrap_or_else(|| self.len());
+                let period = end - start;
+                let extension_to_left = self.common_suffix_length(start, end);
+                let extension_to_right = if end < self.len() {
+                    self.common_prefix_length(start, end)
+                } else {
+                    0
+                };
+
+                if extension_to_left + extension_to_right >= period {
+                    runs.insert((start - extension_to_left, end + extension_to_right, period));
+                }
+
+                stack.push(start);
+            }
+        }
+        runs
+    }
+}
+
+fn longest_matching_length<F: Fn(usize) -> bool>(max_length: usize, is_matching: F) -> usize {
+    let mut lower_bound = 0;
+    let mut upper_bound = max_length + 1;
+
+    while upper_bound - lower_bound > 1 {
+        let length = (lower_bound + upper_bound) / 2;
+        if is_matching(length) {
+            lower_bound = length;
+        } else {
+            upper_bound = length;
+        }
+    }
+    lower_bound
+}
+
+fn mul_mod(a: u64, b: u64) -> u64 {
+    ((a as u128 * b as u128) % HASH_MODULUS as u128) as u64
+}
+
 fn convert_repetitions(
     graphemes: &[Grapheme],
     repetitions: &mut Vec<Grapheme>,
     config: &RegExpConfig,
 ) {
-    let repeated_substrings = collect_repeated_substrings(graphemes);
+    let values = graphemes.iter().map(|it| it.value()).collect_vec();
+    let repeated_substrings = collect_repeated_substrings(&values);
     let ranges_of_repetitions = create_ranges_of_repetitions(repeated_substrings);
     let coalesced_repetitions = coalesce_repetitions(ranges_of_repetitions);
-    replace_graphemes_with_repetitions(coalesced_repetitions, graphemes, repetitions, config)
+    replace_graphemes_with_repetitions(
+        coalesced_repetitions,
+        graphemes,
+        &values,
+        repetitions,
+        config,
+    )
 }
 
-fn collect_repeated_substrings(graphemes: &[Grapheme]) -> HashMap<Vec<String>, Vec<usize>> {
-    let mut map = HashMap::new();
+/// Collects the start indices of all substrings which are immediately followed by
+/// themselves at least once, grouped by substring and ordered by descending length.
+///
+/// A substring which is a repetition of a shorter one also occurs in between its
+/// adjacent occurrences, so these never form a repetition and are skipped. All other
+/// substrings are the periods of maximal runs which are found in `O(n log n)` time,
+/// so very long inputs do not cause a quadratic number of substring comparisons.
+fn collect_repeated_substrings(values: &[String]) -> Vec<(usize, Vec<usize>)> {
+    let rolling_hash = RollingHash::new(values);
+    let mut starts_by_length = HashMap::<usize, Vec<usize>>::new();
+    let mut repeated_substrings = vec![];
+
+    for (start, end, period) in rolling_hash.runs() {
+        starts_by_length
+            .entry(period)
+            .or_default()
+            .extend(start..=end - 2 * period);
+    }
 
-    for i in 0..graphemes.len() {
-        let suffix = &graphemes[i..];
-        for j in 1..=graphemes.len() / 2 {
-            if suffix.len() >= j {
-                let prefix = suffix[..j].iter().map(|it| it.value()).collect_vec();
-                let indices = map.entry(prefix).or_insert_with(Vec::new);
-                indices.push(i);
+    for (length, starts) in starts_by_length.into_iter().sorted().rev() {
+        let hashes = starts
+            .into_iter()
+            .map(|start| rolling_hash.substring(start, length))
+            .collect::<HashSet<_>>();
+
+        let mut indices_by_hash = HashMap::<[u64; 2], Vec<usize>>::new();
+        for start in 0..=values.len() - length {
+            let hash = rolling_hash.substring(start, length);
+            if hashes.contains(&hash) {
+                indices_by_hash.entry(hash).or_default().push(start);
             }
         }
+
+        for (_, indices) in indices_by_hash
+            .into_iter()
+            .sorted_by_key(|(_, indices)| indices[0])
+        {
+            repeated_substrings.push((length, indices));
+        }
     }
-    map
+    repeated_substrings
 }
 
 fn create_ranges_of_repetitions(
-    repeated_substrings: HashMap<Vec<String>, Vec<usize>>,
-) -> Vec<(Range<usize>, Vec<String>)> {
-    let mut repetitions = Vec::<(Range<usize>, Vec<String>)>::new();
+    repeated_substrings: Vec<(usize, Vec<usize>)>,
+) -> Vec<(Range<usize>, usize)> {
+    let mut repetitions = Vec::<(Range<usize>, usize)>::new();
 
-    for (prefix_length, group) in &repeated_substrings
-        .iter()
-        .filter(|&(_, indices)| indices.len() > 1)
-        .sorted_by_key(|&(prefix, _)| prefix.len())
-        .rev()
-        .group_by(|&(prefix, _)| prefix.len())
-    {
-        for (prefix, indices) in group.sorted_by_key(|&(_, indices)| indices[0]) {
-            let all_even = indices
-                .iter()
-                .all(|it| it % prefix_length == 0 || it % 2 == 0);
-            let all_odd = indices
+    for (prefix_length, indices) in repeated_substrings {
+        let all_even = indices
+            .iter()
+            .all(|it| it % prefix_length == 0 || it % 2 == 0);
+        let all_odd = indices
+            .iter()
+            .all(|it| it % prefix_length == 1 || it % 2 == 1);
+
+        if all_even || all_odd {
+            let ranges = indices
                 .iter()
-                .all(|it| it % prefix_length == 1 || it % 2 == 1);
-
-            if all_even || all_odd {
-                let ranges = indices
-                    .iter()
-                    .cloned()
-                    .map(|it| it..it + prefix_length)
-                    .coalesce(|x, y| {
-                        if x.end == y.start {
-                            Ok(x.start..y.end)
-                        } else {
-                            Err((x, y))
-                        }
-                    })
-                    .filter(|it| (it.end - it.start) > prefix_length)
-                    .collect_vec();
+                .cloned()
+                .map(|it| it..it + prefix_length)
+                .coalesce(|x, y| {
+                    if x.end == y.start {
+                        Ok(x.start..y.end)
+                    } else {
+                        Err((x, y))
+                    }
+                })
+                .filter(|it| (it.end - it.start) > prefix_length)
+                .collect_vec();
 
-                for range in ranges {
-                    repetitions.push((range, prefix.clone()));
-                }
+            for range in ranges {
+                repetitions.push((range, prefix_length));
             }
         }
     }
@@ -233,8 +777,8 @@ fn create_ranges_of_repetitions(
 }
 
 fn coalesce_repetitions(
-    ranges_of_repetitions: Vec<(Range<usize>, Vec<String>)>,
-) -> Vec<(Range<usize>, Vec<String>)> {
+    ranges_of_repetitions: Vec<(Range<usize>, usize)>,
+) -> Vec<(Range<usize>, usize)> {
     ranges_of_repetitions
         .iter()
         .sorted_by(|&(first_range, _), &(second_range, _)| {
@@ -256,13 +800,14 @@ fn coalesce_repetitions(
                 Err((first_tup, second_tup))
             }
         })
-        .map(|(range, substr)| (range.clone(), substr.clone()))
+        .map(|(range, substr_length)| (range.clone(), *substr_length))
         .collect_vec()
 }
 
 fn replace_graphemes_with_repetitions(
-    coalesced_repetitions: Vec<(Range<usize>, Vec<String>)>,
+    coalesced_repetitions: Vec<(Range<usize>, usize)>,
     graphemes: &[Grapheme],
+    values: &[String],
     repetitions: &mut Vec<Grapheme>,
     config: &RegExpConfig,
 ) {
@@ -274,15 +819,21 @@ fn replace_graphemes_with_repetitions(
         repetitions.push(grapheme.clone());
     }
 
-    for (range, substr) in coalesced_repetitions.iter() {
+    for (range, substr_length) in coalesced_repetitions.iter() {
         if range.end > repetitions.len() {
             break;
         }
 
+        let substr = &values[range.start..range.start + substr_length];
+
         let count = ((range.end - range.start) / substr.len()) as u32;
 
         if count <= config.minimum_repetitions
             || substr.len() < config.minimum_substring_length as usize
+            || substr.iter().any(|it| {
+                it.chars()
+                    .any(|c| config.repetition_excluded_chars.contains(&c))
+            })
         {
             continue;
         }
@@ -297,9 +848,15 @@ fn replace_graphemes_with_repetitions(
             break;
         }
 
+        if let Some(threshold) = config.quantifier_threshold {
+            if !is_quantifier_shorter(substr, count, threshold, config) {
+                continue;
+            }
+        }
+
         repetitions.splice(
             range.clone(),
-            [Grapheme::new(substr.clone(), count, count, config)]
+            [Grapheme::new(substr.to_vec(), count, count, config)]
                 .iter()
                 .cloned(),
         );
@@ -318,6 +875,29 @@ fn replace_graphemes_with_repetitions(
     }
 }
 
+fn is_quantifier_shorter(
+    substr: &[String],
+    count: u32,
+    threshold: u32,
+    config: &RegExpConfig,
+) -> bool {
+    let config = RegExpConfig {
+        is_output_colorized: false,
+        ..config.clone()
+    };
+    let rendered_length = |mut grapheme: Grapheme| {
+        grapheme.escape_regexp_symbols(
+            config.is_non_ascii_char_escaped,
+            config.is_surrogate_pair_conversion_enabled(),
+        );
+        grapheme.to_string().chars().count()
+    };
+    let quantified_length = rendered_length(Grapheme::new(substr.to_vec(), count, count, &config));
+    let literal_length = rendered_length(Grapheme::new(substr.to_vec(), 1, 1, &config));
+
+    quantified_length + threshold as usize <= literal_length * count as usize
+}
+
 fn convert_chars_to_range(chars: &[(char, char)]) -> Vec<CharRange> {
     chars
         .iter()
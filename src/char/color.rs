@@ -14,21 +14,34 @@
  * limitations under the License.
  */
 
+use crate::regexp::{ClassSyntax, Flavor, RegExpConfig};
 use colored::{ColoredString, Colorize};
 use std::fmt::{Display, Formatter, Result};
 
+// These classes match the same characters as the Unicode-aware shorthands \w and \W
+const UNICODE_WORD_CLASS: &str = "[\\p{L}\\p{M}\\p{Nd}\\p{Pc}\\x{200C}\\x{200D}]";
+const UNICODE_NON_WORD_CLASS: &str = "[^\\p{L}\\p{M}\\p{Nd}\\p{Pc}\\x{200C}\\x{200D}]";
+
 pub enum ColorizableString {
     Asterisk,
+    AtomicLeftParenthesis,
     CapturingLeftParenthesis,
     Caret,
+    CatchAll,
     Comma,
+    ContinuationAnchor,
+    DefineLeftParenthesis,
     DigitCharClass,
     DollarSign,
+    DotAllFlag,
     EmptyString,
     Hyphen,
     IgnoreCaseFlag,
     LeftBrace,
     LeftBracket,
+    Lookahead(String),
+    Lookbehind(String),
+    NamedLeftParenthesis(String),
     NonCapturingLeftParenthesis,
     NonDigitCharClass,
     NonSpaceCharClass,
@@ -40,7 +53,11 @@ pub enum ColorizableString {
     RightBrace,
     RightBracket,
     RightParenthesis,
+    ScopedIgnoreCaseLeftParenthesis,
     SpaceCharClass,
+    SubroutineCall(String),
+    VerboseFlag,
+    WordBoundary,
     WordCharClass,
 }
 
@@ -57,23 +74,36 @@ impl ColorizableString {
         }
     }
 
-    pub fn to_colorized_string(&self, is_output_colorized: bool) -> ColoredString {
-        let string_repr = self.to_string();
+    pub fn to_colorized_string(&self, config: &RegExpConfig) -> ColoredString {
+        let string_repr = self.to_flavored_string(config);
         let repr = string_repr.as_str();
 
-        if !is_output_colorized {
+        if !config.is_output_colorized {
             return repr.clear();
         }
 
         match self {
-            ColorizableString::IgnoreCaseFlag => repr.bright_yellow().on_black(),
+            ColorizableString::IgnoreCaseFlag
+            | ColorizableString::DotAllFlag
+            | ColorizableString::VerboseFlag => repr.bright_yellow().on_black(),
             ColorizableString::Pipe => repr.red().bold(),
             ColorizableString::Asterisk | ColorizableString::QuestionMark => repr.purple().bold(),
-            ColorizableString::Caret | ColorizableString::DollarSign => repr.yellow().bold(),
+            ColorizableString::Caret
+            | ColorizableString::CatchAll
+            | ColorizableString::ContinuationAnchor
+            | ColorizableString::DollarSign
+            | ColorizableString::Lookahead(_)
+            | ColorizableString::Lookbehind(_)
+            | ColorizableString::WordBoundary => repr.yellow().bold(),
             ColorizableString::EmptyString | ColorizableString::Other(_) => repr.clear(),
 
             ColorizableString::NonCapturingLeftParenthesis
+            | ColorizableString::AtomicLeftParenthesis
             | ColorizableString::CapturingLeftParenthesis
+            | ColorizableString::NamedLeftParenthesis(_)
+            | ColorizableString::DefineLeftParenthesis
+            | ColorizableString::ScopedIgnoreCaseLeftParenthesis
+            | ColorizableString::SubroutineCall(_)
             | ColorizableString::RightParenthesis => repr.green().bold(),
 
             ColorizableString::Number(_)
@@ -95,6 +125,123 @@ impl ColorizableString {
     }
 }
 
+impl ColorizableString {
+    fn to_flavored_string(&self, config: &RegExpConfig) -> String {
+        let is_length_minimized = config.is_length_minimized;
+        let class_syntax = config.class_syntax();
+        match (self, config.flavor) {
+            (ColorizableString::WordCharClass, _) if config.is_unicode_word_class_enabled() => {
+                UNICODE_WORD_CLASS.to_string()
+            }
+            (ColorizableString::NonWordCharClass, _) if config.is_unicode_word_class_enabled() => {
+                UNICODE_NON_WORD_CLASS.to_string()
+            }
+            // Graphemes of several characters may contain several shorthands
+            (ColorizableString::Other(value), _) if config.is_unicode_word_class_enabled() => {
+                translate_word_shorthands(value)
+            }
+            (_, Flavor::Rust) | (_, Flavor::Pcre) if class_syntax == ClassSyntax::Perl => {
+                self.to_string()
+            }
+            (_, Flavor::Rust) | (_, Flavor::Pcre) => {
+                translate_shorthands(&self.to_string(), class_syntax, false, is_length_minimized)
+            }
+            (ColorizableString::CapturingLeftParenthesis, Flavor::PosixBasic)
+            | (ColorizableString::NonCapturingLeftParenthesis, Flavor::PosixBasic)
+            | (ColorizableString::NamedLeftParenthesis(_), Flavor::PosixBasic) => "\\(".to_string(),
+            (ColorizableString::NonCapturingLeftParenthesis, Flavor::PosixExtended)
+            | (ColorizableString::NamedLeftParenthesis(_), Flavor::PosixExtended) => {
+                "(".to_string()
+            }
+            (ColorizableString::RightParenthesis, Flavor::PosixBasic) => "\\)".to_string(),
+            (ColorizableString::LeftBrace, Flavor::PosixBasic) => "\\{".to_string(),
+            (ColorizableString::RightBrace, Flavor::PosixBasic) => "\\}".to_string(),
+            (ColorizableString::Pipe, Flavor::PosixBasic) => "\\|".to_string(),
+            (ColorizableString::QuestionMark, Flavor::PosixBasic) => "\\{0,1\\}".to_string(),
+            (ColorizableString::IgnoreCaseFlag, _)
+            | (ColorizableString::DotAllFlag, _)
+            | (ColorizableString::VerboseFlag, _) => "".to_string(),
+            (ColorizableString::Other(value), _) => {
+                translate_shorthands(value, class_syntax, true, is_length_minimized)
+            }
+            _ => translate_shorthands(&self.to_string(), class_syntax, true, is_length_minimized),
+        }
+    }
+}
+
+fn translate_shorthands(
+    value: &str,
+    class_syntax: ClassSyntax,
+    is_posix: bool,
+    is_length_minimized: bool,
+) -> String {
+    let is_literal = class_syntax == ClassSyntax::Literal;
+    let mut translated_value = String::new();
+    let mut chars = value.chars();
+
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            translated_value.push(c);
+            continue;
+        }
+        match chars.next() {
+            // The class [:digit:] always consists of exactly the ASCII digits
+            Some('d') if is_literal || is_length_minimized => translated_value.push_str("[0-9]"),
+            Some('D') if is_literal || is_length_minimized => translated_value.push_str("[^0-9]"),
+            Some('d') => translated_value.push_str("[[:digit:]]"),
+            Some('D') => translated_value.push_str("[^[:digit:]]"),
+            // Bracket expressions of the POSIX flavors cannot contain escape sequences
+            Some('s') if is_literal && !is_posix => {
+                translated_value.push_str("[\\t\\n\\x0B\\f\\r\\x20]")
+            }
+            Some('S') if is_literal && !is_posix => {
+                translated_value.push_str("[^\\t\\n\\x0B\\f\\r\\x20]")
+            }
+            Some('s') => translated_value.push_str("[[:space:]]"),
+            Some('S') => translated_value.push_str("[^[:space:]]"),
+            Some('w') if is_literal => translated_value.push_str("[0-9A-Z_a-z]"),
+            Some('W') if is_literal => translated_value.push_str("[^0-9A-Z_a-z]"),
+            Some('w') => translated_value.push_str("[[:alnum:]_]"),
+            Some('W') => translated_value.push_str("[^[:alnum:]_]"),
+            // Unicode properties do not exist in the POSIX flavors
+            Some('p') if is_posix && chars.as_str().starts_with("{Any}") => {
+                chars.nth(4);
+                translated_value.push('.');
+            }
+            Some(next_char) => {
+                translated_value.push(c);
+                translated_value.push(next_char);
+            }
+            None => translated_value.push(c),
+        }
+    }
+
+    translated_value
+}
+
+fn translate_word_shorthands(value: &str) -> String {
+    let mut translated_value = String::new();
+    let mut chars = value.chars();
+
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            translated_value.push(c);
+            continue;
+        }
+        match chars.next() {
+            Some('w') => translated_value.push_str(UNICODE_WORD_CLASS),
+            Some('W') => translated_value.push_str(UNICODE_NON_WORD_CLASS),
+            Some(next_char) => {
+                translated_value.push(c);
+                translated_value.push(next_char);
+            }
+            None => translated_value.push(c),
+        }
+    }
+
+    translated_value
+}
+
 impl Display for ColorizableString {
     fn fmt(&self, f: &mut Formatter<'_>) -> Result {
         write!(
@@ -102,16 +249,24 @@ impl Display for ColorizableString {
             "{}",
             match self {
                 ColorizableString::Asterisk => "*".to_string(),
+                ColorizableString::AtomicLeftParenthesis => "(?>".to_string(),
                 ColorizableString::CapturingLeftParenthesis => "(".to_string(),
                 ColorizableString::Caret => "^".to_string(),
+                ColorizableString::CatchAll => ".*".to_string(),
                 ColorizableString::Comma => ",".to_string(),
+                ColorizableString::ContinuationAnchor => "\\G".to_string(),
+                ColorizableString::DefineLeftParenthesis => "(?(DEFINE)".to_string(),
                 ColorizableString::DigitCharClass => "\\d".to_string(),
                 ColorizableString::DollarSign => "$".to_string(),
+                ColorizableString::DotAllFlag => "(?s)".to_string(),
                 ColorizableString::Hyphen => "-".to_string(),
                 ColorizableString::IgnoreCaseFlag => "(?i)".to_string(),
                 ColorizableString::LeftBrace => "{".to_string(),
                 ColorizableString::LeftBracket => "[".to_string(),
                 ColorizableString::EmptyString => "".to_string(),
+                ColorizableString::Lookahead(assertion) => format!("(?={})", assertion),
+                ColorizableString::Lookbehind(assertion) => format!("(?<={})", assertion),
+                ColorizableString::NamedLeftParenthesis(name) => format!("(?P<{}>", name),
                 ColorizableString::NonCapturingLeftParenthesis => "(?:".to_string(),
                 ColorizableString::NonDigitCharClass => "\\D".to_string(),
                 ColorizableString::NonSpaceCharClass => "\\S".to_string(),
@@ -123,7 +278,11 @@ impl Display for ColorizableString {
                 ColorizableString::RightBrace => "}".to_string(),
                 ColorizableString::RightBracket => "]".to_string(),
                 ColorizableString::RightParenthesis => ")".to_string(),
+                ColorizableString::ScopedIgnoreCaseLeftParenthesis => "(?i:".to_string(),
                 ColorizableString::SpaceCharClass => "\\s".to_string(),
+                ColorizableString::SubroutineCall(name) => format!("(?&{})", name),
+                ColorizableString::VerboseFlag => "(?x)".to_string(),
+                ColorizableString::WordBoundary => "\\b".to_string(),
                 ColorizableString::WordCharClass => "\\w".to_string(),
             }
         )
@@ -15,14 +15,18 @@
  */
 
 use crate::char::ColorizableString;
-use crate::regexp::RegExpConfig;
+use crate::regexp::{Flavor, RegExpConfig};
+use crate::unicode_tables::CASE_FOLDING_SIMPLE;
+#[cfg(feature = "unicode-names")]
+use crate::unicode_tables::NAMES;
 use colored::ColoredString;
 use itertools::Itertools;
 use std::fmt::{Display, Formatter, Result};
 
-const CHARS_TO_ESCAPE: [&str; 14] = [
-    "(", ")", "[", "]", "{", "}", "+", "*", "-", ".", "?", "|", "^", "$",
-];
+// The identifiers of pre-release versions and build metadata of semantic versions
+// are emitted as a character class which must not be escaped
+pub(crate) const SEMVER_IDENTIFIER: &str = "[\\w.]+";
+pub(crate) const HEX_DIGIT_CLASSES: [&str; 3] = ["[0-9A-Fa-f]", "[0-9a-f]", "[0-9A-F]"];
 
 #[derive(Clone, Debug, Hash, Ord, PartialOrd, Eq, PartialEq)]
 pub struct Grapheme {
@@ -58,12 +62,63 @@ impl Grapheme {
         self.chars.join("")
     }
 
+    pub(crate) fn set_config(&mut self, config: &RegExpConfig) {
+        self.config = config.clone();
+        for repetition in self.repetitions.iter_mut() {
+            repetition.set_config(config);
+        }
+    }
+
+    pub(crate) fn map_chars(&mut self, f: &dyn Fn(&str) -> String) {
+        self.chars = self.chars.iter().map(|it| f(it)).collect_vec();
+        for repetition in self.repetitions.iter_mut() {
+            repetition.map_chars(f);
+        }
+    }
+
     pub(crate) fn chars(&self) -> &Vec<String> {
         &self.chars
     }
 
-    pub(crate) fn chars_mut(&mut self) -> &mut Vec<String> {
-        &mut self.chars
+    pub(crate) fn is_char_class(&self) -> bool {
+        self.chars
+            .iter()
+            .any(|it| it.starts_with('\\') && it.chars().count() > 1)
+    }
+
+    pub(crate) fn has_unbounded_quantifier(&self) -> bool {
+        // Quantifiers only occur in preformatted values such as `\s*`,
+        // a single quantifier character is a literal one
+        self.chars
+            .iter()
+            .any(|it| it.chars().count() > 1 && (it.ends_with('*') || it.ends_with('+')))
+            || (self.config.is_repetition_upper_bound_relaxed && self.min < self.max)
+            || self
+                .repetitions
+                .iter()
+                .any(|it| it.has_unbounded_quantifier())
+    }
+
+    pub(crate) fn has_cased_char(&self) -> bool {
+        self.chars
+            .iter()
+            .filter(|it| !(it.starts_with('\\') && it.chars().count() > 1))
+            .flat_map(|it| it.chars())
+            .any(is_cased_char)
+            || self.repetitions.iter().any(|it| it.has_cased_char())
+    }
+
+    pub(crate) fn has_backreference(&self) -> bool {
+        self.chars.iter().any(|it| is_backreference(it))
+            || self.repetitions.iter().any(|it| it.has_backreference())
+    }
+
+    pub(crate) fn has_variable_quantifier(&self) -> bool {
+        self.min < self.max
+            || self
+                .repetitions
+                .iter()
+                .any(|it| it.has_variable_quantifier())
     }
 
     pub(crate) fn has_repetitions(&self) -> bool {
@@ -82,17 +137,8 @@ impl Grapheme {
         self.max
     }
 
-    pub(crate) fn char_count(&self, is_non_ascii_char_escaped: bool) -> usize {
-        if is_non_ascii_char_escaped {
-            self.chars
-                .iter()
-                .map(|it| it.chars().map(|c| self.escape(c, false)).join(""))
-                .join("")
-                .chars()
-                .count()
-        } else {
-            self.chars.iter().map(|it| it.chars().count()).sum()
-        }
+    pub(crate) fn char_count(&self) -> usize {
+        self.chars.iter().map(|it| it.chars().count()).sum()
     }
 
     pub(crate) fn escape_non_ascii_chars(&mut self, use_surrogate_pairs: bool) {
@@ -112,24 +158,42 @@ impl Grapheme {
         is_non_ascii_char_escaped: bool,
         is_astral_code_point_converted_to_surrogate: bool,
     ) {
-        let characters = self.chars_mut();
+        let config = &self.config;
+        let characters = &mut self.chars;
 
         #[allow(clippy::needless_range_loop)]
         for i in 0..characters.len() {
             let mut character = characters[i].clone();
 
-            for char_to_escape in CHARS_TO_ESCAPE.iter() {
-                character =
-                    character.replace(char_to_escape, &format!("{}{}", "\\", char_to_escape));
+            if character.starts_with('\\') && character.chars().count() > 1
+                || character == SEMVER_IDENTIFIER
+                || HEX_DIGIT_CLASSES.contains(&character.as_str())
+                || config.is_wildcard(&character)
+            {
+                continue;
+            }
+
+            if config.is_case_expanded_to_classes {
+                if let Ok(c) = character.chars().exactly_one() {
+                    let variants = case_variants(c, config);
+                    if variants.len() > 1 {
+                        characters[i] = format!("[{}]", variants.iter().join(""));
+                        continue;
+                    }
+                }
             }
 
-            character = character
-                .replace("\n", "\\n")
-                .replace("\r", "\\r")
-                .replace("\t", "\\t");
+            character = escape_chars(&character, config);
 
-            if character == "\\" {
-                character = "\\\\".to_string();
+            if !config.flavor.is_posix() {
+                character = character
+                    .replace("\n", "\\n")
+                    .replace("\r", "\\r")
+                    .replace("\t", "\\t");
+            }
+
+            if config.is_verbose_mode_enabled {
+                character = escape_verbose_chars(&character, config.flavor);
             }
 
             characters[i] = character;
@@ -146,22 +210,138 @@ impl Grapheme {
         } else if use_surrogate_pairs && ('\u{10000}'..'\u{10ffff}').contains(&c) {
             self.convert_to_surrogate_pair(c)
         } else {
-            c.escape_unicode().to_string()
+            escape_non_ascii_char(c, &self.config)
         }
     }
 
     fn convert_to_surrogate_pair(&self, c: char) -> String {
         c.encode_utf16(&mut [0; 2])
             .iter()
-            .map(|it| format!("\\u{{{:x}}}", it))
+            .map(|&it| self.config.flavor.code_unit_escape(it as u32))
             .join("")
     }
 }
 
+// Within a single grapheme, a backslash of the input can only be followed by
+// extending code points. So a backslash followed by an ASCII letter starts a
+// shorthand class which has been inserted by the conversion of the grapheme.
+fn escape_chars(value: &str, config: &RegExpConfig) -> String {
+    let mut chars = value.chars().peekable();
+    let mut escaped_value = String::new();
+
+    while let Some(c) = chars.next() {
+        if c == '\\' && chars.peek().is_some_and(|it| it.is_ascii_alphabetic()) {
+            escaped_value.push(c);
+            escaped_value.push(chars.next().unwrap());
+        } else if config.is_char_escaped(c) {
+            escaped_value.push('\\');
+            escaped_value.push(c);
+        } else {
+            escaped_value.push(c);
+        }
+    }
+
+    escaped_value
+}
+
+pub(crate) fn escape_non_ascii_char(c: char, config: &RegExpConfig) -> String {
+    #[cfg(feature = "unicode-names")]
+    {
+        if config.is_unicode_name_enabled() {
+            if let Some(name) = find_unicode_name(c) {
+                return format!("\\N{{{}}}", name);
+            }
+        }
+    }
+
+    config.flavor.code_point_escape(c)
+}
+
+#[cfg(feature = "unicode-names")]
+fn find_unicode_name(c: char) -> Option<&'static str> {
+    NAMES
+        .binary_search_by_key(&c, |&(named_char, _)| named_char)
+        .ok()
+        .map(|idx| NAMES[idx].1)
+}
+
+pub(crate) fn is_cased_char(c: char) -> bool {
+    c.to_uppercase().ne(c.to_lowercase())
+}
+
+pub(crate) fn case_variants(c: char, config: &RegExpConfig) -> Vec<char> {
+    let mut variants = vec![c];
+    if config.is_case_folding_full {
+        if let Ok(idx) = CASE_FOLDING_SIMPLE.binary_search_by_key(&c, |&(key, _)| key) {
+            variants.extend_from_slice(CASE_FOLDING_SIMPLE[idx].1);
+        }
+    }
+    // Case mappings to several characters such as ß -> SS cannot be part of a class
+    for mapping in &[
+        c.to_lowercase().collect_vec(),
+        c.to_uppercase().collect_vec(),
+    ] {
+        if let [variant] = mapping.as_slice() {
+            variants.push(*variant);
+        }
+    }
+    variants.sort_unstable();
+    variants.dedup();
+    variants
+}
+
+fn is_char_class(value: &str) -> bool {
+    value.chars().count() > 2 && value.starts_with('[') && value.ends_with(']')
+}
+
+fn is_backreference(value: &str) -> bool {
+    let mut chars = value.chars();
+    while let Some(c) = chars.next() {
+        if c == '\\' {
+            match chars.next() {
+                Some('1'..='9') | Some('k') | Some('g') => return true,
+                _ => {}
+            }
+        }
+    }
+    false
+}
+
+// Characters which have been escaped already must not be escaped once more,
+// otherwise an escaped `#` would turn into an escaped backslash followed by a comment
+fn escape_verbose_chars(value: &str, flavor: Flavor) -> String {
+    let mut escaped_value = String::new();
+    let mut chars = value.chars();
+
+    while let Some(c) = chars.next() {
+        if c == '\\' {
+            escaped_value.push(c);
+            if let Some(next_char) = chars.next() {
+                escaped_value.push(next_char);
+            }
+        } else {
+            escaped_value.push_str(&escape_verbose_char(c, flavor));
+        }
+    }
+
+    escaped_value
+}
+
+pub fn escape_verbose_char(c: char, flavor: Flavor) -> String {
+    if c == ' ' || c == '#' {
+        format!("\\{}", c)
+    } else if c.is_whitespace() {
+        flavor.code_point_escape(c)
+    } else {
+        c.to_string()
+    }
+}
+
 impl Display for Grapheme {
     fn fmt(&self, f: &mut Formatter<'_>) -> Result {
-        let is_single_char = self.char_count(false) == 1
-            || (self.chars.len() == 1 && self.chars[0].matches('\\').count() == 1);
+        let is_single_char = self.char_count() == 1
+            || (self.chars.len() == 1 && self.chars[0].matches('\\').count() == 1)
+            || (self.chars.len() == 1 && is_char_class(&self.chars[0]));
         let is_range = self.min < self.max;
         let is_repetition = self.min > 1;
         let value = if self.repetitions.is_empty() {
@@ -192,7 +372,11 @@ impl Display for Grapheme {
                 },
                 ColorizableString::RightParenthesis,
                 ColorizableString::Number(self.min),
-                ColorizableString::Number(self.max),
+                if self.config.is_repetition_upper_bound_relaxed {
+                    ColorizableString::EmptyString
+                } else {
+                    ColorizableString::Number(self.max)
+                },
             ],
             &self.config,
         );
@@ -211,6 +395,23 @@ impl Display for Grapheme {
                 "{}{}{}{}{}{}",
                 colored_value, left_brace, min, comma, max, right_brace
             )
+        } else if is_range && !is_single_char && self.is_atomic() {
+            let atomic_left_parenthesis =
+                ColorizableString::AtomicLeftParenthesis.to_colorized_string(&self.config);
+            write!(
+                f,
+                "{}{}{}{}{}{}{}{}{}{}",
+                atomic_left_parenthesis,
+                left_parenthesis,
+                colored_value,
+                right_parenthesis,
+                left_brace,
+                min,
+                comma,
+                max,
+                right_brace,
+                right_parenthesis
+            )
         } else if is_range && !is_single_char {
             write!(
                 f,
@@ -230,6 +431,16 @@ impl Display for Grapheme {
     }
 }
 
+impl Grapheme {
+    fn is_atomic(&self) -> bool {
+        self.config.is_atomic_grouping_enabled()
+            && self
+                .repetitions
+                .iter()
+                .any(|it| it.has_variable_quantifier())
+    }
+}
+
 fn to_colorized_string(
     strings: Vec<ColorizableString>,
     config: &RegExpConfig,
@@ -245,7 +456,7 @@ fn to_colorized_string(
 ) {
     let v = strings
         .iter()
-        .map(|it| it.to_colorized_string(config.is_output_colorized))
+        .map(|it| it.to_colorized_string(config))
         .collect_vec();
 
     (
@@ -19,5 +19,9 @@ mod color;
 mod grapheme;
 
 pub use cluster::GraphemeCluster;
+pub(crate) use cluster::DIGIT_PLACEHOLDER;
 pub use color::ColorizableString;
-pub use grapheme::Grapheme;
+pub(crate) use grapheme::{
+    case_variants, escape_non_ascii_char, is_cased_char, HEX_DIGIT_CLASSES, SEMVER_IDENTIFIER,
+};
+pub use grapheme::{escape_verbose_char, Grapheme};
@@ -15,20 +15,22 @@
  */
 
 use crate::char::{Grapheme, GraphemeCluster};
-use crate::regexp::RegExpConfig;
+use crate::regexp::{AutomatonTable, GenerationError, RegExpConfig};
 use itertools::Itertools;
 use petgraph::dot::{Config, Dot};
 use petgraph::graph::NodeIndex;
 use petgraph::stable_graph::{Edges, StableGraph};
-use petgraph::visit::Dfs;
+use petgraph::visit::{Dfs, EdgeRef};
 use petgraph::{Directed, Direction};
 use std::cmp::{max, min};
-use std::collections::{BTreeSet, HashMap, HashSet};
+use std::collections::{BTreeSet, HashMap, HashSet, VecDeque};
+use std::mem::size_of;
 
 type State = NodeIndex<u32>;
 type StateLabel = String;
 type EdgeLabel = Grapheme;
 
+#[derive(Clone)]
 pub struct DFA {
     alphabet: BTreeSet<Grapheme>,
     graph: StableGraph<StateLabel, EdgeLabel>,
@@ -38,13 +40,17 @@ pub struct DFA {
 }
 
 impl DFA {
-    pub(crate) fn from(grapheme_clusters: Vec<GraphemeCluster>, config: &RegExpConfig) -> Self {
+    pub(crate) fn from(
+        grapheme_clusters: Vec<GraphemeCluster>,
+        config: &RegExpConfig,
+    ) -> Result<Self, GenerationError> {
         let mut dfa = Self::new(config);
         for cluster in grapheme_clusters {
             dfa.insert(cluster);
+            config.check_memory_budget(dfa.estimated_memory())?;
         }
         dfa.minimize();
-        dfa
+        Ok(dfa)
     }
 
     pub(crate) fn state_count(&self) -> usize {
@@ -64,10 +70,193 @@ impl DFA {
         self.graph.edges_directed(state, Direction::Outgoing)
     }
 
+    pub(crate) fn initial_edge_labels(&self) -> Vec<&Grapheme> {
+        self.outgoing_edges(self.initial_state)
+            .map(|edge| edge.weight())
+            .collect_vec()
+    }
+
     pub(crate) fn is_final_state(&self, state: State) -> bool {
         self.final_state_indices.contains(&state.index())
     }
 
+    pub(crate) fn to_table(&self) -> AutomatonTable {
+        let mut state_numbers = HashMap::new();
+        let mut queue = VecDeque::new();
+        let mut transitions = vec![];
+
+        state_numbers.insert(self.initial_state, 0);
+        queue.push_back(self.initial_state);
+
+        while let Some(state) = queue.pop_front() {
+            let source = state_numbers[&state];
+            let edges = self
+                .outgoing_edges(state)
+                .map(|edge| (edge.weight().to_string(), edge.target()))
+                .sorted_by(|(a, _), (b, _)| a.cmp(b))
+                .collect_vec();
+            for (label, target) in edges {
+                let next_number = state_numbers.len();
+                let target_number = *state_numbers.entry(target).or_insert_with(|| {
+                    queue.push_back(target);
+                    next_number
+                });
+                transitions.push((source, label, target_number));
+            }
+        }
+
+        let alphabet = transitions
+            .iter()
+            .map(|(_, label, _)| label.clone())
+            .collect::<BTreeSet<_>>()
+            .into_iter()
+            .collect_vec();
+        let accept_states = state_numbers
+            .iter()
+            .filter(|(state, _)| self.is_final_state(**state))
+            .map(|(_, &number)| number)
+            .sorted()
+            .collect_vec();
+
+        AutomatonTable {
+            state_count: state_numbers.len(),
+            alphabet,
+            transitions,
+            accept_states,
+        }
+    }
+
+    pub(crate) fn complement(&self) -> Option<Self> {
+        let edge_labels = self
+            .graph
+            .edge_indices()
+            .map(|edge| self.graph.edge_weight(edge).unwrap().clone())
+            .collect::<BTreeSet<Grapheme>>();
+
+        if edge_labels.is_empty() {
+            return None;
+        }
+
+        let mut graph = self.graph.clone();
+        let dead_state = graph.add_node("".to_string());
+
+        for state in graph.node_indices().collect_vec() {
+            let outgoing_labels = graph
+                .edges_directed(state, Direction::Outgoing)
+                .map(|edge| edge.weight().clone())
+                .collect::<HashSet<Grapheme>>();
+
+            for label in edge_labels.iter() {
+                if !outgoing_labels.contains(label) {
+                    graph.add_edge(state, dead_state, label.clone());
+                }
+            }
+        }
+
+        let final_state_indices = graph
+            .node_indices()
+            .map(|state| state.index())
+            .filter(|idx| !self.final_state_indices.contains(idx))
+            .collect();
+
+        Some(Self {
+            alphabet: self.alphabet.clone(),
+            graph,
+            initial_state: self.initial_state,
+            final_state_indices,
+            config: self.config.clone(),
+        })
+    }
+
+    pub(crate) fn intersection(&self, other: &Self) -> Option<Self> {
+        let mut dfa = Self::new(&self.config);
+        let mut state_pairs = HashMap::new();
+        let mut unvisited_state_pairs = vec![(self.initial_state, other.initial_state)];
+        state_pairs.insert((self.initial_state, other.initial_state), dfa.initial_state);
+
+        while let Some((first_state, second_state)) = unvisited_state_pairs.pop() {
+            let state = state_pairs[&(first_state, second_state)];
+            if self.is_final_state(first_state) && other.is_final_state(second_state) {
+                dfa.final_state_indices.insert(state.index());
+            }
+
+            for first_edge in self.outgoing_edges(first_state) {
+                for second_edge in other.outgoing_edges(second_state) {
+                    if !is_same_label(first_edge.weight(), second_edge.weight()) {
+                        continue;
+                    }
+                    let state_pair = (first_edge.target(), second_edge.target());
+                    let next_state = match state_pairs.get(&state_pair) {
+                        Some(&next_state) => next_state,
+                        None => {
+                            let next_state = dfa.graph.add_node("".to_string());
+                            state_pairs.insert(state_pair, next_state);
+                            unvisited_state_pairs.push(state_pair);
+                            next_state
+                        }
+                    };
+                    dfa.alphabet.insert(first_edge.weight().clone());
+                    dfa.graph
+                        .add_edge(state, next_state, first_edge.weight().clone());
+                }
+            }
+        }
+
+        dfa.remove_dead_states();
+        if dfa.final_state_indices.is_empty() {
+            return None;
+        }
+        dfa.minimize();
+        Some(dfa)
+    }
+
+    pub(crate) fn is_equivalent(&self, other: &Self) -> bool {
+        let initial_state_pair = (Some(self.initial_state), Some(other.initial_state));
+        let mut visited_state_pairs = HashSet::new();
+        let mut unvisited_state_pairs = vec![initial_state_pair];
+        visited_state_pairs.insert(initial_state_pair);
+
+        while let Some((first_state, second_state)) = unvisited_state_pairs.pop() {
+            let is_first_final = matches!(first_state, Some(it) if self.is_final_state(it));
+            let is_second_final = matches!(second_state, Some(it) if other.is_final_state(it));
+            if is_first_final != is_second_final {
+                return false;
+            }
+
+            let first_edges = first_state
+                .map(|it| self.collect_edges(it))
+                .unwrap_or_default();
+            let second_edges = second_state
+                .map(|it| other.collect_edges(it))
+                .unwrap_or_default();
+            let labels = first_edges
+                .iter()
+                .chain(second_edges.iter())
+                .map(|(label, _)| label);
+
+            for label in labels {
+                let find_target = |edges: &[(&Grapheme, State)]| {
+                    edges
+                        .iter()
+                        .find(|(edge_label, _)| is_same_label(edge_label, label))
+                        .map(|&(_, target)| target)
+                };
+                let state_pair = (find_target(&first_edges), find_target(&second_edges));
+                if visited_state_pairs.insert(state_pair) {
+                    unvisited_state_pairs.push(state_pair);
+                }
+            }
+        }
+
+        true
+    }
+
+    fn collect_edges(&self, state: State) -> Vec<(&Grapheme, State)> {
+        self.outgoing_edges(state)
+            .map(|edge| (edge.weight(), edge.target()))
+            .collect_vec()
+    }
+
     #[allow(dead_code)]
     fn println(&self, comment: &str) {
         println!(
@@ -78,7 +267,7 @@ impl DFA {
         println!("{:?}", self.final_state_indices);
     }
 
-    fn new(config: &RegExpConfig) -> Self {
+    pub(crate) fn new(config: &RegExpConfig) -> Self {
         let mut graph = StableGraph::new();
         let initial_state = graph.add_node("".to_string());
         Self {
@@ -90,7 +279,36 @@ impl DFA {
         }
     }
 
-    fn insert(&mut self, cluster: GraphemeCluster) {
+    fn remove_dead_states(&mut self) {
+        let mut live_states = self
+            .graph
+            .node_indices()
+            .filter(|state| self.is_final_state(*state))
+            .collect::<HashSet<State>>();
+        let mut unvisited_states = live_states.iter().copied().collect_vec();
+
+        while let Some(state) = unvisited_states.pop() {
+            for parent_state in self.graph.neighbors_directed(state, Direction::Incoming) {
+                if live_states.insert(parent_state) {
+                    unvisited_states.push(parent_state);
+                }
+            }
+        }
+
+        for state in self.graph.node_indices().collect_vec() {
+            if !live_states.contains(&state) && state != self.initial_state {
+                self.graph.remove_node(state);
+            }
+        }
+    }
+
+    fn estimated_memory(&self) -> usize {
+        self.graph.node_count() * size_of::<StateLabel>()
+            + self.graph.edge_count() * size_of::<EdgeLabel>()
+            + self.alphabet.len() * size_of::<Grapheme>()
+    }
+
+    pub(crate) fn insert(&mut self, cluster: GraphemeCluster) {
         let mut current_state = self.initial_state;
 
         for grapheme in cluster.graphemes() {
@@ -116,7 +334,9 @@ impl DFA {
                 continue;
             }
 
-            if current_grapheme.maximum() == grapheme.maximum() - 1 {
+            if current_grapheme.maximum() == grapheme.maximum() - 1
+                && !self.config.is_repetition_strict
+            {
                 let min = min(current_grapheme.minimum(), grapheme.minimum());
                 let max = max(current_grapheme.maximum(), grapheme.maximum());
                 let new_grapheme = Grapheme::new(grapheme.chars().clone(), min, max, &self.config);
@@ -138,7 +358,7 @@ impl DFA {
     }
 
     #[allow(clippy::many_single_char_names)]
-    fn minimize(&mut self) {
+    pub(crate) fn minimize(&mut self) {
         let mut p = self.get_initial_partition();
         let mut w = p.iter().cloned().collect_vec();
 
@@ -209,16 +429,14 @@ impl DFA {
         let mut x = HashSet::new();
 
         for &state in a {
-            let direct_parent_states = self.graph.neighbors_directed(state, Direction::Incoming);
-            for parent_state in direct_parent_states {
-                let edge = self.graph.find_edge(parent_state, state).unwrap();
-                let grapheme = self.graph.edge_weight(edge).unwrap();
+            // A parent state may be connected by several edges, e.g. after an intersection
+            for edge in self.graph.edges_directed(state, Direction::Incoming) {
+                let grapheme = edge.weight();
                 if grapheme.value() == label.value()
                     && (grapheme.maximum() == label.maximum()
                         || grapheme.minimum() == label.minimum())
                 {
-                    x.insert(parent_state);
-                    break;
+                    x.insert(edge.source());
                 }
             }
         }
@@ -238,6 +456,9 @@ impl DFA {
                 if self.initial_state == *old_state {
                     new_initial_state = Some(new_state);
                 }
+                if self.final_state_indices.contains(&old_state.index()) {
+                    final_state_indices.insert(new_state.index());
+                }
                 state_mappings.insert(*old_state, new_state);
             }
         }
@@ -246,20 +467,11 @@ impl DFA {
             let old_source_state = *equivalence_class.iter().next().unwrap();
             let new_source_state = state_mappings.get(&old_source_state).unwrap();
 
-            for old_target_state in self.graph.neighbors(old_source_state) {
-                let edge = self
-                    .graph
-                    .find_edge(old_source_state, old_target_state)
-                    .unwrap();
-
-                let grapheme = self.graph.edge_weight(edge).unwrap().clone();
-                let new_target_state = state_mappings.get(&old_target_state).unwrap();
+            for edge in self.graph.edges(old_source_state) {
+                let grapheme = edge.weight().clone();
+                let new_target_state = state_mappings.get(&edge.target()).unwrap();
 
-                graph.add_edge(*new_source_state, *new_target_state, grapheme.clone());
-
-                if self.final_state_indices.contains(&old_target_state.index()) {
-                    final_state_indices.insert(new_target_state.index());
-                }
+                graph.add_edge(*new_source_state, *new_target_state, grapheme);
             }
         }
         self.initial_state = new_initial_state.unwrap();
@@ -268,6 +480,18 @@ impl DFA {
     }
 }
 
+fn is_same_label(first: &Grapheme, second: &Grapheme) -> bool {
+    first.chars() == second.chars()
+        && first.minimum() == second.minimum()
+        && first.maximum() == second.maximum()
+        && first.repetitions.len() == second.repetitions.len()
+        && first
+            .repetitions
+            .iter()
+            .zip(second.repetitions.iter())
+            .all(|(a, b)| is_same_label(a, b))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -288,7 +512,8 @@ mod tests {
         let dfa = DFA::from(
             vec![GraphemeCluster::from("abcd", &RegExpConfig::new())],
             &config,
-        );
+        )
+        .unwrap();
 
         let intermediate_state = State::new(3);
         assert_eq!(dfa.is_final_state(intermediate_state), false);
@@ -306,7 +531,8 @@ mod tests {
                 GraphemeCluster::from("abxd", &RegExpConfig::new()),
             ],
             &config,
-        );
+        )
+        .unwrap();
         let state = State::new(2);
         let mut edges = dfa.outgoing_edges(state);
 
@@ -337,7 +563,8 @@ mod tests {
                 GraphemeCluster::from("axyz", &RegExpConfig::new()),
             ],
             &config,
-        );
+        )
+        .unwrap();
         let states = dfa.states_in_depth_first_order();
         assert_eq!(states.len(), 7);
 
@@ -398,6 +625,21 @@ mod tests {
         assert!(edges.next().is_none());
     }
 
+    #[test]
+    fn test_minimization_keeps_final_initial_state() {
+        let config = RegExpConfig::new();
+        let dfa = DFA::from(
+            vec![
+                GraphemeCluster::from("", &RegExpConfig::new()),
+                GraphemeCluster::from("a", &RegExpConfig::new()),
+            ],
+            &config,
+        )
+        .unwrap();
+        assert_eq!(dfa.graph.node_count(), 2);
+        assert!(dfa.is_final_state(dfa.initial_state));
+    }
+
     #[test]
     fn test_minimization_algorithm() {
         let config = RegExpConfig::new();
@@ -418,6 +660,62 @@ mod tests {
         assert_eq!(dfa.graph.edge_count(), 5);
     }
 
+    #[test]
+    fn test_complement() {
+        let config = RegExpConfig::new();
+        let dfa = DFA::from(
+            vec![GraphemeCluster::from("ab", &RegExpConfig::new())],
+            &config,
+        )
+        .unwrap();
+        let complement = dfa.complement().unwrap();
+        assert_eq!(complement.graph.node_count(), 4);
+        assert_eq!(complement.graph.edge_count(), 8);
+
+        for state in complement.graph.node_indices() {
+            assert_ne!(dfa.is_final_state(state), complement.is_final_state(state));
+        }
+    }
+
+    #[test]
+    fn test_complement_of_empty_alphabet() {
+        let config = RegExpConfig::new();
+        let dfa = DFA::from(
+            vec![GraphemeCluster::from("", &RegExpConfig::new())],
+            &config,
+        )
+        .unwrap();
+        assert!(dfa.complement().is_none());
+    }
+
+    #[test]
+    fn test_is_equivalent() {
+        let config = RegExpConfig::new();
+        let first_dfa = DFA::from(
+            vec![
+                GraphemeCluster::from("abc", &config),
+                GraphemeCluster::from("abd", &config),
+            ],
+            &config,
+        )
+        .unwrap();
+        let second_dfa = DFA::from(
+            vec![
+                GraphemeCluster::from("abd", &config),
+                GraphemeCluster::from("abc", &config),
+                GraphemeCluster::from("abc", &config),
+            ],
+            &config,
+        )
+        .unwrap();
+        let third_dfa = DFA::from(vec![GraphemeCluster::from("abc", &config)], &config).unwrap();
+
+        assert!(first_dfa.is_equivalent(&second_dfa));
+        assert!(second_dfa.is_equivalent(&first_dfa));
+        assert!(!first_dfa.is_equivalent(&third_dfa));
+        assert!(!third_dfa.is_equivalent(&first_dfa));
+    }
+
     #[test]
     fn test_dfa_constructor() {
         let config = RegExpConfig::new();
@@ -427,7 +725,8 @@ mod tests {
                 GraphemeCluster::from("abxd", &RegExpConfig::new()),
             ],
             &config,
-        );
+        )
+        .unwrap();
         assert_eq!(dfa.graph.node_count(), 5);
         assert_eq!(dfa.graph.edge_count(), 5);
     }
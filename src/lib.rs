@@ -222,5 +222,30 @@ mod fsm;
 mod regexp;
 mod unicode_tables;
 
+// The WebAssembly library is not built by default, but on demand with
+// `cargo rustc --lib --release --features wasm --target wasm32-unknown-unknown --crate-type cdylib`
+#[cfg(feature = "wasm")]
+mod wasm;
+
+pub use crate::char::GraphemeCluster;
+pub use regexp::AutomatonTable;
+pub use regexp::ClassSyntax;
 pub use regexp::Feature;
+pub use regexp::Flavor;
+pub use regexp::GenerationError;
+pub use regexp::GenerationTimings;
+pub use regexp::GenerationWarning;
+pub use regexp::InputFileError;
+pub use regexp::LineEnding;
+pub use regexp::MatchStats;
+pub use regexp::Mutation;
+pub use regexp::ParseError;
+pub use regexp::ProfileError;
+pub use regexp::ProfileFormat;
+pub use regexp::RegExp;
 pub use regexp::RegExpBuilder;
+pub use regexp::RegExpConfig;
+pub use regexp::Shell;
+pub use regexp::SnippetLanguage;
+pub use regexp::StreamingRegExpBuilder;
+pub use regexp::Wildcard;
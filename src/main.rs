@@ -123,13 +123,24 @@ struct CLI {
     )]
     is_non_word_converted: bool,
 
+    #[structopt(
+        name = "scripts",
+        long,
+        help = "Converts runs of letters of a single Unicode script to \\p{Script}",
+        long_help = "Converts runs of letters of a single Unicode script to \\p{Script}, e.g. \\p{Greek}.\n\n\
+                     Takes precedence over all other conversions to character classes.\n\
+                     Runs mixing several scripts are left as they are.",
+        display_order = 7
+    )]
+    is_script_converted: bool,
+
     #[structopt(
         name = "repetitions",
         short,
         long,
         help = "Detects repeated non-overlapping substrings and\n\
                 converts them to {min,max} quantifier notation",
-        display_order = 7
+        display_order = 8
     )]
     is_repetition_converted: bool,
 
@@ -138,7 +149,7 @@ struct CLI {
         short,
         long,
         help = "Replaces all non-ASCII characters with unicode escape sequences",
-        display_order = 8
+        display_order = 9
     )]
     is_non_ascii_char_escaped: bool,
 
@@ -147,7 +158,7 @@ struct CLI {
         long,
         requires = "escape",
         help = "Converts astral code points to surrogate pairs if --escape is set",
-        display_order = 9
+        display_order = 10
     )]
     is_astral_code_point_converted_to_surrogate: bool,
 
@@ -156,7 +167,7 @@ struct CLI {
         short,
         long,
         help = "Performs case-insensitive matching, letters match both upper and lower case",
-        display_order = 10
+        display_order = 11
     )]
     is_case_ignored: bool,
 
@@ -165,7 +176,7 @@ struct CLI {
         short = "g",
         long,
         help = "Replaces non-capturing groups by capturing ones",
-        display_order = 11
+        display_order = 12
     )]
     is_group_captured: bool,
 
@@ -174,7 +185,7 @@ struct CLI {
         short,
         long,
         help = "Provides syntax highlighting for the resulting regular expression",
-        display_order = 12
+        display_order = 13
     )]
     is_output_colorized: bool,
 
@@ -270,6 +281,10 @@ fn handle_input(cli: &CLI, input: Result<Vec<String>, Error>) {
                 conversion_features.push(Feature::NonWord);
             }
 
+            if cli.is_script_converted {
+                conversion_features.push(Feature::Script);
+            }
+
             if cli.is_repetition_converted {
                 conversion_features.push(Feature::Repetition);
             }
@@ -0,0 +1,129 @@
+/*
+ * Copyright © 2019-2020 Peter M. Stahl pemistahl@gmail.com
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ * http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either expressed or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use itertools::Itertools;
+use std::fmt::{Display, Formatter, Result};
+
+/// This struct describes the minimal deterministic finite automaton which a regular expression
+/// has been derived from. It is returned by method
+/// [`RegExp.automaton_table`](./struct.RegExp.html#method.automaton_table).
+///
+/// The states are numbered in breadth-first order, starting with the start state `0`
+/// and following the transitions in the order of their symbols. Each symbol of the alphabet
+/// is a grapheme as it appears in the regular expression before escaping, such as `a`,
+/// `\d` or `a{3}` if repetitions have been converted.
+///
+/// The table can be printed for inspection. Each row lists the target states of a single
+/// state, with the start state marked by `>` and the accepting states marked by `*`.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AutomatonTable {
+    pub(crate) state_count: usize,
+    pub(crate) alphabet: Vec<String>,
+    pub(crate) transitions: Vec<(usize, String, usize)>,
+    pub(crate) accept_states: Vec<usize>,
+}
+
+impl AutomatonTable {
+    /// Returns the states of the automaton.
+    pub fn states(&self) -> Vec<usize> {
+        (0..self.state_count).collect_vec()
+    }
+
+    /// Returns the sorted symbols which occur in any of the transitions.
+    pub fn alphabet(&self) -> &[String] {
+        &self.alphabet
+    }
+
+    /// Returns all transitions as triples of source state, symbol and target state,
+    /// sorted by source state and symbol.
+    pub fn transitions(&self) -> &[(usize, String, usize)] {
+        &self.transitions
+    }
+
+    /// Returns the target state of the transition leaving `state` with `symbol`,
+    /// or `None` if there is no such transition.
+    pub fn transition(&self, state: usize, symbol: &str) -> Option<usize> {
+        self.transitions
+            .iter()
+            .find(|(source, label, _)| *source == state && label == symbol)
+            .map(|&(_, _, target)| target)
+    }
+
+    /// Returns the start state of the automaton which is always `0`.
+    pub fn start_state(&self) -> usize {
+        0
+    }
+
+    /// Returns the sorted accepting states of the automaton.
+    pub fn accept_states(&self) -> &[usize] {
+        &self.accept_states
+    }
+}
+
+impl Display for AutomatonTable {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        let rows = self
+            .states()
+            .iter()
+            .map(|&state| {
+                let marker = match (state == self.start_state(), self.is_accept_state(state)) {
+                    (true, true) => ">*",
+                    (true, false) => ">",
+                    (false, true) => "*",
+                    (false, false) => "",
+                };
+                let mut row = vec![format!("{}{}", marker, state)];
+                row.extend(self.alphabet.iter().map(|symbol| {
+                    self.transition(state, symbol)
+                        .map_or_else(|| "-".to_string(), |target| target.to_string())
+                }));
+                row
+            })
+            .collect_vec();
+        let header = std::iter::once(String::new())
+            .chain(self.alphabet.iter().cloned())
+            .collect_vec();
+        let widths = (0..header.len())
+            .map(|i| {
+                std::iter::once(&header)
+                    .chain(rows.iter())
+                    .map(|row| row[i].chars().count())
+                    .max()
+                    .unwrap()
+            })
+            .collect_vec();
+
+        let lines = std::iter::once(&header)
+            .chain(rows.iter())
+            .map(|row| {
+                row.iter()
+                    .zip(widths.iter())
+                    .map(|(cell, &width)| format!("{:<width$}", cell, width = width))
+                    .join(" | ")
+                    .trim_end()
+                    .to_string()
+            })
+            .join("\n");
+
+        write!(f, "{}", lines)
+    }
+}
+
+impl AutomatonTable {
+    fn is_accept_state(&self, state: usize) -> bool {
+        self.accept_states.binary_search(&state).is_ok()
+    }
+}
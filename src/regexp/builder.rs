@@ -14,15 +14,25 @@
  * limitations under the License.
  */
 
+use crate::ast::Expression;
+use crate::char::GraphemeCluster;
 use crate::regexp::feature::Feature;
-use crate::regexp::{RegExp, RegExpConfig};
+use crate::regexp::flavor::Flavor;
+use crate::regexp::wildcard::Wildcard;
+use crate::regexp::{
+    scan_pattern_fragment, ClassSyntax, ClusterTransformation, GenerationError, GenerationTimings,
+    GenerationWarning, InputFileError, LineEnding, Mutation, ParseError, RegExp, RegExpConfig,
+    StreamingRegExpBuilder,
+};
 use itertools::Itertools;
 use std::io::ErrorKind;
 use std::path::PathBuf;
+use std::sync::Arc;
 
 /// This struct builds regular expressions from user-provided test cases.
 pub struct RegExpBuilder {
     test_cases: Vec<String>,
+    categorized_test_cases: Vec<(String, String)>,
     config: RegExpConfig,
 }
 
@@ -38,6 +48,7 @@ impl RegExpBuilder {
         }
         Self {
             test_cases: test_cases.iter().cloned().map(|it| it.into()).collect_vec(),
+            categorized_test_cases: vec![],
             config: RegExpConfig::new(),
         }
     }
@@ -59,6 +70,7 @@ impl RegExpBuilder {
         match std::fs::read_to_string(file_path.into()) {
             Ok(file_content) => Self {
                 test_cases: file_content.lines().map(|it| it.to_string()).collect_vec(),
+                categorized_test_cases: vec![],
                 config: RegExpConfig::new(),
             },
             Err(error) => match error.kind() {
@@ -74,6 +86,100 @@ impl RegExpBuilder {
         }
     }
 
+    /// Specifies a text file containing test cases to build the regular expression from.
+    ///
+    /// In contrast to [`from_file`](./struct.RegExpBuilder.html#method.from_file),
+    /// this method does not panic but returns an [`InputFileError`](./enum.InputFileError.html)
+    /// if the file cannot be read or decoded.
+    ///
+    /// The file's encoding is detected by its byte order mark. UTF-16 in little-endian
+    /// and big-endian byte order is supported if the file starts with the respective
+    /// byte order mark. Otherwise, the file is decoded as UTF-8, ignoring a UTF-8 byte
+    /// order mark if present.
+    ///
+    /// Each test case needs to be on a separate line.
+    /// Lines may be ended with either a newline (`\n`) or
+    /// a carriage return with a line feed (`\r\n`).
+    /// The final line ending is optional.
+    pub fn from_path<T: Into<PathBuf>>(file_path: T) -> Result<Self, InputFileError> {
+        let file_content = decode_file_content(std::fs::read(file_path.into())?)?;
+        Ok(Self {
+            test_cases: file_content.lines().map(|it| it.to_string()).collect_vec(),
+            categorized_test_cases: vec![],
+            config: RegExpConfig::new(),
+        })
+    }
+
+    /// Specifies an existing regular expression whose matched strings are used as test cases,
+    /// so that it can be minimized or rebuilt with different settings.
+    ///
+    /// The supported syntax is the subset of the flavors
+    /// [`Flavor::Rust`](./enum.Flavor.html#variant.Rust) and
+    /// [`Flavor::Pcre`](./enum.Flavor.html#variant.Pcre) which is required to express
+    /// finite sets of strings: literals, escape sequences, character classes with ranges,
+    /// capturing and non-capturing groups, alternations and the quantifiers `?`,
+    /// `{n}` and `{m,n}`. The anchors `^`, `\G` and `\b` at the start and `$` and `\b`
+    /// at the end are ignored.
+    ///
+    /// A [`ParseError`](./enum.ParseError.html) is returned if the regular expression
+    /// is malformed, contains any other syntax, such as shorthand character classes or
+    /// flags, or matches more strings than can be enumerated.
+    pub fn from_regexp(pattern: &str) -> Result<Self, ParseError> {
+        let config = RegExpConfig::new();
+        Ok(Self {
+            test_cases: Expression::parse(pattern, &config)?.to_test_cases()?,
+            categorized_test_cases: vec![],
+            config,
+        })
+    }
+
+    /// Specifies test cases tagged with categories to build the regular expression from.
+    /// Each element of `categorized_test_cases` is a pair of a category and a test case.
+    ///
+    /// The resulting regular expression consists of one named capture group per category,
+    /// such as `(?P<animal>cat|dog)`, which matches the test cases of this category.
+    /// The groups appear in the order in which their categories are first encountered,
+    /// so a match reveals which category a string belongs to. If a test case belongs to
+    /// several categories, it is captured by the group which comes first.
+    ///
+    /// The other methods of the resulting [`RegExp`](./struct.RegExp.html), such as
+    /// [`inner_pattern`](./struct.RegExp.html#method.inner_pattern), refer to all test cases
+    /// regardless of their categories.
+    ///
+    /// ⚠ Named capture groups are not supported by the POSIX flavors, so plain capturing
+    /// groups are generated for them instead.
+    ///
+    /// ⚠ Panics if:
+    /// - `categorized_test_cases` is empty
+    /// - a category is not a valid group name consisting of ASCII letters, digits
+    ///   and underscores only, starting with a letter or underscore
+    pub fn from_categorized<C: Clone + Into<String>, T: Clone + Into<String>>(
+        categorized_test_cases: &[(C, T)],
+    ) -> Self {
+        if categorized_test_cases.is_empty() {
+            panic!("No test cases have been provided for regular expression generation");
+        }
+        let categorized_test_cases = categorized_test_cases
+            .iter()
+            .cloned()
+            .map(|(category, test_case)| (category.into(), test_case.into()))
+            .collect_vec();
+        if !categorized_test_cases
+            .iter()
+            .all(|(category, _)| is_valid_group_name(category))
+        {
+            panic!("Categories must be valid capture group names");
+        }
+        Self {
+            test_cases: categorized_test_cases
+                .iter()
+                .map(|(_, test_case)| test_case.clone())
+                .collect_vec(),
+            categorized_test_cases,
+            config: RegExpConfig::new(),
+        }
+    }
+
     /// Tells `RegExpBuilder` which conversions should be performed during
     /// regular expression generation. The available conversion features
     /// are listed in the [`Feature`](./enum.Feature.html#variants) enum.
@@ -119,15 +225,1011 @@ impl RegExpBuilder {
         self
     }
 
+    /// Specifies the minimum quantity of characters by which the `{n}` quantifier notation
+    /// must be shorter than the literal repetition of a substring in order to be used if
+    /// [`Feature::Repetition`](./enum.Feature.html#variant.Repetition)
+    /// is set as one of the features in method
+    /// [`with_conversion_of`](./struct.RegExpBuilder.html#method.with_conversion_of).
+    /// Otherwise, the repetition is kept in its literal form. For example, with a threshold
+    /// of 1, `aa` is kept because `a{2}` is longer, whereas `aaaaa` is converted to `a{5}`.
+    ///
+    /// If the threshold is not explicitly set with this method, all repetitions are converted
+    /// regardless of their length.
+    ///
+    /// ⚠ Panics if `characters` is zero.
+    pub fn with_quantifier_threshold(&mut self, characters: u32) -> &mut Self {
+        if characters == 0 {
+            panic!("Quantifier threshold must not be zero");
+        }
+        self.config.quantifier_threshold = Some(characters);
+        self
+    }
+
+    /// Specifies characters which are never converted to `{min,max}` quantifier notation if
+    /// [`Feature::Repetition`](./enum.Feature.html#variant.Repetition)
+    /// is set as one of the features in method
+    /// [`with_conversion_of`](./struct.RegExpBuilder.html#method.with_conversion_of).
+    ///
+    /// Repeated substrings containing any of these characters are kept literally.
+    /// This is useful for separators such as the dots in `1...2`.
+    pub fn with_repetition_exclusion_of(&mut self, chars: &[char]) -> &mut Self {
+        self.config.repetition_excluded_chars = chars.iter().copied().collect();
+        self
+    }
+
+    /// Tells `RegExpBuilder` that word characters are meant to be Unicode-aware, so that
+    /// letters and digits of any script are matched by the shorthand `\w`.
+    ///
+    /// With [`Flavor::Pcre`](./enum.Flavor.html#variant.Pcre), `\w` only matches ASCII characters,
+    /// so it is written as `[\p{L}\p{M}\p{Nd}\p{Pc}\x{200C}\x{200D}]` and `\W` as its negation
+    /// `[^\p{L}\p{M}\p{Nd}\p{Pc}\x{200C}\x{200D}]` instead. These classes match the same
+    /// characters that are converted to `\w` and `\W`, including combining marks.
+    /// With [`Flavor::Rust`](./enum.Flavor.html#variant.Rust), `\w` is Unicode-aware anyway,
+    /// so it is left unchanged. Without this setting, plain `\w` is written for all flavors.
+    ///
+    /// ⚠ This setting does not take effect with the POSIX flavors which do not support
+    /// Unicode properties, and requires [`ClassSyntax::Perl`](./enum.ClassSyntax.html#variant.Perl)
+    /// as notation of shorthand character classes.
+    pub fn with_unicode_word_chars(&mut self) -> &mut Self {
+        self.config.is_word_char_unicode_aware = true;
+        self
+    }
+
+    /// if they contain nested quantifiers which vary in their quantity. Once such a group
+    /// has matched, the regex engine does not backtrack into it anymore, which prevents
+    /// catastrophic backtracking on strings that almost match, such as for `(?:ab|(?:ab){2,})?`.
+    /// Expressions without nested variable quantifiers are left unchanged.
+    ///
+    /// ⚠ This setting only takes effect with [`Flavor::Pcre`](./enum.Flavor.html#variant.Pcre)
+    /// because the other flavors do not support atomic groups. The regex engine of the
+    /// [*regex*](https://crates.io/crates/regex) crate guarantees linear matching anyway.
+    ///
+    /// ⚠ An atomic group always matches as much as possible, so strings which require
+    /// the group to give back characters to the rest of the expression are not matched anymore.
+    pub fn with_atomic_grouping(&mut self) -> &mut Self {
+        self.config.is_atomic_grouping_used = true;
+        self
+    }
+
+    /// Tells `RegExpBuilder` to omit the upper bound of repetition ranges if
+    /// [`Feature::Repetition`](./enum.Feature.html#variant.Repetition)
+    /// is set as one of the features in method
+    /// [`with_conversion_of`](./struct.RegExpBuilder.html#method.with_conversion_of).
+    ///
+    /// A range such as `{2,4}` is then converted to the open-ended form `{2,}`.
+    /// Exact repetitions such as `{3}` are not affected.
+    ///
+    /// ⚠ The resulting regular expression matches more repetitions than have been observed
+    /// in the test cases. This trades precision for brevity.
+    pub fn with_open_ended_repetitions(&mut self) -> &mut Self {
+        self.config.is_repetition_upper_bound_relaxed = true;
+        self
+    }
+
+    /// Tells `RegExpBuilder` to keep the repetitions of different test cases apart if
+    /// [`Feature::Repetition`](./enum.Feature.html#variant.Repetition)
+    /// is set as one of the features in method
+    /// [`with_conversion_of`](./struct.RegExpBuilder.html#method.with_conversion_of).
+    ///
+    /// By default, repetitions of the same substring with consecutive quantities are merged
+    /// into a range, so the test cases `aaa`, `aaaa` and `aaaaa` result in `a{3,5}`.
+    /// With this setting, each quantity gets its own branch with an exact repetition,
+    /// resulting in `a{3}|a{4}|a{5}`. This avoids to match quantities which have not been
+    /// observed in the test cases, at the expense of a longer regular expression.
+    pub fn with_strict_repetitions(&mut self) -> &mut Self {
+        self.config.is_repetition_strict = true;
+        self
+    }
+
+    /// Tells `RegExpBuilder` to factor out common suffixes of alternation branches.
+    /// For example, the alternation `testing|running|jumping` is shortened to
+    /// `(?:jump|runn|test)ing`. Branches which consist of the common suffix only
+    /// are expressed with an optional group.
+    ///
+    /// Branches are only factored if this makes the alternation shorter.
+    pub fn with_suffix_factoring(&mut self) -> &mut Self {
+        self.config.is_suffix_factored = true;
+        self
+    }
+
+    /// Specifies how many levels of suffix factoring may be nested. Factoring a common suffix
+    /// out of some branches yields an alternation of the remaining branches which is factored
+    /// again on the next level. Beyond the given depth, these alternations are left as they are.
+    ///
+    /// ⚠ This setting only takes effect if suffix factoring is enabled with method
+    /// [`with_suffix_factoring`](./struct.RegExpBuilder.html#method.with_suffix_factoring).
+    ///
+    /// ⚠ Panics if `depth` is zero.
+    pub fn with_maximum_factor_depth(&mut self, depth: u32) -> &mut Self {
+        if depth == 0 {
+            panic!("Maximum factor depth must not be zero");
+        }
+        self.config.maximum_factor_depth = Some(depth);
+        self
+    }
+
+    /// Tells `RegExpBuilder` to generate a plain alternation of the test cases
+    /// without any generalization.
+    ///
+    /// The test cases are only escaped and deduplicated. All conversion features except
+    /// [`Feature::CapturingGroup`](./enum.Feature.html#variant.CapturingGroup) and all other
+    /// settings which generalize the regular expression, such as placeholders or the
+    /// limitation of branches, are ignored. Neither character classes nor repetitions are
+    /// created and common prefixes and suffixes of the test cases are not factored out.
+    pub fn with_literal_only(&mut self) -> &mut Self {
+        self.config.is_literal_only = true;
+        self
+    }
+
+    /// Tells `RegExpBuilder` to treat the test cases as regular expression fragments
+    /// instead of literal strings. The fragments are combined verbatim as branches
+    /// of an alternation, so `\d+` and `[a-z]{2}` result in `^(?:\d+|[a-z]{2})$`.
+    /// A fragment containing an alternation itself is enclosed in a group.
+    ///
+    /// The fragments are neither split into graphemes nor escaped. Like with
+    /// [`with_literal_only`](./struct.RegExpBuilder.html#method.with_literal_only),
+    /// all settings which generalize the regular expression are ignored,
+    /// as is the verbose mode.
+    ///
+    /// ⚠ The syntax of the fragments is only checked for balanced groups and bracket
+    /// expressions, complete escape sequences and correctly placed quantifiers.
+    /// [`try_build_regexp`](./struct.RegExpBuilder.html#method.try_build_regexp) returns
+    /// a [`GenerationError::InvalidPatternFragment`](./enum.GenerationError.html#variant.InvalidPatternFragment)
+    /// for the first test case failing this check.
+    pub fn with_test_cases_as_patterns(&mut self) -> &mut Self {
+        self.config.is_input_treated_as_pattern = true;
+        self
+    }
+
+    /// Tells `RegExpBuilder` to keep test cases which are substrings of other test cases
+    /// as separate branches of the resulting alternation.
+    ///
+    /// By default, test cases such as `ab` and `abc` are merged into `abc?`.
+    /// With this setting, `abc|ab` is generated instead. Branches are ordered by length,
+    /// starting with the longest one. The empty string is never kept as a separate branch,
+    /// the alternation is made optional instead.
+    pub fn with_substring_branches(&mut self) -> &mut Self {
+        self.config.is_substring_branch_kept = true;
+        self
+    }
+
+    /// Tells `RegExpBuilder` to generate a regular expression which matches every prefix
+    /// of every test case, including the empty string.
+    ///
+    /// This is useful for autocompletion where partial input has to be validated.
+    /// For the test case `abc`, the regular expression `^(?:a(?:bc?)?)?$` is generated
+    /// which matches the empty string, `a`, `ab` and `abc`. The test cases are arranged
+    /// as a tree of nested optional groups, so the conversion of repetitions and all settings
+    /// which merge or restructure branches, such as suffix factoring, are ignored.
+    /// Conversions to character classes are still applied.
+    pub fn with_prefix_matching(&mut self) -> &mut Self {
+        self.config.is_prefix_matched = true;
+        self
+    }
+
+    /// Tells `RegExpBuilder` to treat test cases which differ only in case as duplicates.
+    /// Of each group of such test cases, only the first one is kept with its original casing.
+    ///
+    /// This is useful to remove accidental case duplicates while still generating
+    /// a case-sensitive regular expression. If
+    /// [`Feature::CaseInsensitivity`](./enum.Feature.html#variant.CaseInsensitivity)
+    /// is set, this setting has no effect because all test cases are converted
+    /// to lower case anyway.
+    pub fn with_case_insensitive_deduplication(&mut self) -> &mut Self {
+        self.config.is_deduplication_case_insensitive = true;
+        self
+    }
+
+    /// Tells `RegExpBuilder` to keep the last occurrence of each group of duplicate test cases
+    /// instead of the first one, in the order in which the test cases have been passed.
+    /// For example, if the test cases `Abc` and `abc` are deduplicated with method
+    /// [`with_case_insensitive_deduplication`](./struct.RegExpBuilder.html#method.with_case_insensitive_deduplication),
+    /// the regular expression `^abc$` is generated instead of `^Abc$`.
+    ///
+    /// Exact duplicates are indistinguishable, so this setting only has an effect
+    /// in conjunction with case-insensitive deduplication.
+    pub fn with_last_duplicate_kept(&mut self) -> &mut Self {
+        self.config.is_last_duplicate_kept = true;
+        self
+    }
+
+    /// Tells `RegExpBuilder` to interpret duplicate test cases as frequencies
+    /// and to order the branches of the top-level alternation by them.
+    /// The branch matching the most frequent test cases comes first.
+    /// For example, the test cases `abc`, `xyz` and `xyz` result in `^(?:xyz|abc)$`
+    /// instead of `^(?:abc|xyz)$`.
+    ///
+    /// Duplicate test cases are still removed, so the branches themselves are the same.
+    /// Branches of equal frequency keep their default order.
+    pub fn with_frequency_ordered_branches(&mut self) -> &mut Self {
+        self.config.is_branch_order_weighted = true;
+        self
+    }
+
+    /// Tells `RegExpBuilder` which variations of the test cases should be matched as well.
+    /// The available mutations are listed in the [`Mutation`](./enum.Mutation.html#variants)
+    /// enum. They deliberately expand the language of the resulting regular expression,
+    /// which is useful for generating patterns of test data. With
+    /// [`Mutation::DigitForDigit`](./enum.Mutation.html#variant.DigitForDigit),
+    /// the test case `a1` results in `^a\d$` which also matches `a2`.
+    ///
+    /// ⚠ Panics if `mutations` is empty or if
+    /// [`Mutation::Transposition`](./enum.Mutation.html#variant.Transposition)
+    /// is given a quantity of zero.
+    pub fn with_mutations(&mut self, mutations: &[Mutation]) -> &mut Self {
+        if mutations.is_empty() {
+            panic!("No mutations have been provided for regular expression generation");
+        }
+        if mutations.contains(&Mutation::Transposition(0)) {
+            panic!("Quantity of transpositions must not be zero");
+        }
+        self.config.mutations = mutations.to_vec();
+        self
+    }
+
+    /// Tells `RegExpBuilder` to normalize the line endings within multi-line test cases
+    /// before generating the regular expression. Both `\n` and `\r\n` are converted
+    /// to the given [`LineEnding`](./enum.LineEnding.html), so that test cases
+    /// with mixed line endings do not result in stray carriage returns.
+    ///
+    /// By default, test cases are taken as they are without any normalization.
+    pub fn with_line_ending_normalization(&mut self, line_ending: LineEnding) -> &mut Self {
+        self.config.line_ending = Some(line_ending);
+        self
+    }
+
+    /// Tells `RegExpBuilder` to make the entire regular expression optional,
+    /// so that it matches either one of the test cases or the empty string.
+    /// For example, the test cases `abc` and `abd` result in `^(?:ab[cd])?$`
+    /// instead of `^ab[cd]$`.
+    ///
+    /// This is useful for matching optional fields. In contrast to passing the empty string
+    /// as an additional test case, the regular expression is built from the given test cases
+    /// alone and only enclosed in an optional group afterwards.
+    pub fn with_optional_pattern(&mut self) -> &mut Self {
+        self.config.is_whole_pattern_optional = true;
+        self
+    }
+
+    /// Tells `RegExpBuilder` to match lists of between `min` and `max` tokens,
+    /// each of which is one of the test cases, joined by the given separator.
+    /// For example, the test cases `a` and `b` with the separator `,` and
+    /// the bounds `2` and `4` result in `^[ab](?:,[ab]){1,3}$`.
+    ///
+    /// The separator is escaped just like the test cases.
+    ///
+    /// ⚠ This setting is ignored in verbose mode and for categorized test cases.
+    ///
+    /// ⚠ Panics if `min` is zero or greater than `max`.
+    pub fn with_repeated_tokens(&mut self, separator: &str, min: u32, max: u32) -> &mut Self {
+        if min == 0 {
+            panic!("Minimum quantity of tokens must not be zero");
+        }
+        if min > max {
+            panic!("Minimum quantity of tokens must not exceed maximum quantity");
+        }
+        self.config.token_repetition = Some((separator.to_string(), min, max));
+        self
+    }
+
+    /// Tells `RegExpBuilder` to match all test cases joined by the given separator
+    /// in any order, each of them exactly once. For example, the test cases `a` and `b`
+    /// with the separator `,` result in a regular expression matching `a,b` and `b,a`.
+    ///
+    /// If lookarounds are supported by the chosen flavor and the separator is not empty,
+    /// every test case is required by a lookahead assertion, followed by as many test cases
+    /// as there are in total. For example, the test cases above result in
+    /// `^(?=(?:.*,)?a(?:,|$))(?=(?:.*,)?b(?:,|$))[ab],[ab]$` for
+    /// [`Flavor::Pcre`](./enum.Flavor.html#variant.Pcre). Otherwise, the regular expression
+    /// is generated from all permutations of the test cases, such as `^(?:a,b|b,a)$`.
+    ///
+    /// As the quantity of permutations grows factorially, at most 5 distinct test cases
+    /// are permuted. If there are more of them, the regular expression is generated as usual.
+    ///
+    /// ⚠ This setting replaces the setting of method
+    /// [`with_repeated_tokens`](./struct.RegExpBuilder.html#method.with_repeated_tokens).
+    /// It is ignored for categorized test cases.
+    pub fn with_permuted_tokens(&mut self, separator: &str) -> &mut Self {
+        self.config.permutation_separator = Some(separator.to_string());
+        self
+    }
+
+    /// Tells `RegExpBuilder` to generate a regular expression without any unbounded
+    /// quantifiers such as `*`, `+` or `{n,}`. This is useful for targets which can only
+    /// handle a finite language, such as hardware or other limited matchers.
+    ///
+    /// Settings which would introduce unbounded quantifiers, such as method
+    /// [`with_open_ended_repetitions`](./struct.RegExpBuilder.html#method.with_open_ended_repetitions),
+    /// are ignored, so repetitions are bounded by the quantity observed in the test cases.
+    /// If the regular expression cannot be generated without an unbounded quantifier,
+    /// e.g. because of method
+    /// [`with_optional_whitespace_around`](./struct.RegExpBuilder.html#method.with_optional_whitespace_around),
+    /// [`GenerationError::UnboundedQuantifierRequired`](./enum.GenerationError.html#variant.UnboundedQuantifierRequired)
+    /// is returned.
+    pub fn with_bounded_quantifiers_only(&mut self) -> &mut Self {
+        self.config.is_bounded_only = true;
+        self
+    }
+
+    /// Tells `RegExpBuilder` to treat the test cases as IPv4 addresses in dotted-decimal notation.
+    /// Each of the four octets is converted to `\d` quantified by the minimum and maximum
+    /// quantity of digits observed at the respective octet, joined by escaped dots.
+    /// For example, the test cases `192.168.0.1` and `10.0.0.255` result in
+    /// `^\d{2,3}\.\d{1,3}\.\d\.\d{1,3}$`.
+    ///
+    /// If any test case is not a valid IPv4 address, the regular expression is generated as usual.
+    ///
+    /// ⚠ The resulting regular expression matches any digits at the octet positions,
+    /// including values above 255.
+    pub fn with_structured_ipv4(&mut self) -> &mut Self {
+        self.config.is_ipv4_structured = true;
+        self
+    }
+
+    /// Tells `RegExpBuilder` to treat the test cases as ISO 8601 dates or times.
+    /// If all of them are either dates such as `2020-01-31`, times such as `23:59:00`
+    /// or combined date times such as `2020-01-31T23:59:00`, each number is converted
+    /// to `\d` quantified by its quantity of digits. For example, the test cases
+    /// `2020-01-31` and `1999-12-01` result in `^\d{4}\-\d{2}\-\d{2}$`.
+    ///
+    /// If any test case is not a valid date or time of the same format as the others,
+    /// the regular expression is generated as usual.
+    ///
+    /// ⚠ The resulting regular expression matches any digits at the number positions,
+    /// including invalid months or hours such as `13` or `24`.
+    pub fn with_structured_date_time(&mut self) -> &mut Self {
+        self.config.is_date_time_structured = true;
+        self
+    }
+
+    /// Tells `RegExpBuilder` to detect test cases consisting of hexadecimal digits only.
+    /// If all of them are hexadecimal strings and at least one of them contains a letter
+    /// from `a` to `f`, they are converted to a hexadecimal character class quantified by
+    /// the minimum and maximum length of the test cases. For example, the test cases
+    /// `deadbeef` and `0badf00d` result in `^[0-9a-f]{8}$`.
+    ///
+    /// The character class only contains the letter cases found in the test cases,
+    /// so upper case test cases result in `[0-9A-F]` and mixed case ones in `[0-9A-Fa-f]`.
+    /// If case-insensitive matching is enabled, `[0-9a-f]` is used.
+    ///
+    /// If any test case is not a hexadecimal string, the regular expression is generated as usual.
+    pub fn with_hex_detection(&mut self) -> &mut Self {
+        self.config.is_hex_detected = true;
+        self
+    }
+
+    /// Tells `RegExpBuilder` to treat the test cases as rows of comma-separated values.
+    /// The values of each column are generalized independently from the other columns,
+    /// and the resulting column patterns are joined by commas. For example, the test cases
+    /// `1,apple` and `2,banana` result in `^\d,(?:banana|apple)$` if
+    /// [`Feature::Digit`](./enum.Feature.html#variant.Digit) is converted.
+    ///
+    /// Values may be enclosed in double quotes, in which case they may contain commas.
+    /// Within quoted values, double quotes are escaped by doubling them. If all values of
+    /// a column are quoted, the quotes are kept literally around the column pattern.
+    ///
+    /// If the test cases do not have the same quantity of at least two columns, or if
+    /// any of them contains an unbalanced double quote, the regular expression
+    /// is generated as usual.
+    pub fn with_structured_csv(&mut self) -> &mut Self {
+        self.config.is_csv_structured = true;
+        self
+    }
+
+    /// Tells `RegExpBuilder` to group the test cases by their skeleton before generalizing them.
+    /// The skeleton of a test case replaces each run of digits with `D` and each run of letters
+    /// with `L`, keeping all other characters as they are. The test cases of each group are
+    /// generalized independently from the other groups, and the resulting sub-patterns are
+    /// joined by an alternation. For example, the test cases `ERROR 404` and `WARN 500` share
+    /// the skeleton `L D`, whereas `2024-01-15 started` has the skeleton `D-D-D L`.
+    ///
+    /// This is useful to cluster log lines whose shapes differ substantially.
+    /// If all test cases share the same skeleton, the regular expression is generated as usual.
+    pub fn with_skeleton_grouping(&mut self) -> &mut Self {
+        self.config.is_skeleton_grouped = true;
+        self
+    }
+
+    /// Tells `RegExpBuilder` to treat the test cases as semantic versions.
+    /// If all of them consist of three dot-separated numbers, optionally followed by
+    /// a pre-release version introduced by `-` and build metadata introduced by `+`,
+    /// a structured regular expression is generated. For example, the test cases
+    /// `1.2.3` and `1.2.3-beta.1` result in `^\d+\.\d+\.\d+(?:\-[\w.]+)?$`.
+    /// The pre-release and build metadata groups are only added if any test case contains them.
+    ///
+    /// If any test case is not a semantic version, the regular expression is generated as usual.
+    ///
+    /// ⚠ This setting is not supported by the POSIX flavors and ignored for them.
+    pub fn with_structured_semver(&mut self) -> &mut Self {
+        self.config.is_semver_structured = true;
+        self
+    }
+
+    /// Tells `RegExpBuilder` to split emoji sequences joined by the zero width joiner
+    /// `U+200D` into their components instead of treating them as a single grapheme.
+    ///
+    /// By default, sequences such as the family emoji 👨‍👩‍👧 are atomic, so two of them
+    /// sharing some components still result in an alternation of the whole sequences.
+    /// With this setting enabled, each component and each joiner is a separate grapheme,
+    /// allowing common components to be factored out. This keeps the output independent of
+    /// how a Unicode version segments such sequences.
+    pub fn with_split_zwj_sequences(&mut self) -> &mut Self {
+        self.config.is_zwj_sequence_split = true;
+        self
+    }
+
+    /// Specifies the regular expression flavor the resulting regular expression is meant for.
+    /// The available flavors are listed in the [`Flavor`](./enum.Flavor.html#variants) enum.
+    ///
+    /// If the flavor is not explicitly set with this method,
+    /// [`Flavor::Rust`](./enum.Flavor.html#variant.Rust) will be used.
+    pub fn with_flavor(&mut self, flavor: Flavor) -> &mut Self {
+        self.config.flavor = flavor;
+        self
+    }
+
+    /// Tells `RegExpBuilder` to start the resulting regular expression with the anchor `\G`
+    /// instead of `^`. The anchor `\G` matches at the position where the previous match ended,
+    /// or at the start of the string for the first match.
+    ///
+    /// This is only meaningful in iterative matching contexts, such as tokenizers which
+    /// repeatedly apply the regular expression to the same string. In any other context,
+    /// `\G` behaves exactly like `^`.
+    ///
+    /// ⚠ This setting only takes effect if
+    /// [`Flavor::Pcre`](./enum.Flavor.html#variant.Pcre) is set with method
+    /// [`with_flavor`](./struct.RegExpBuilder.html#method.with_flavor).
+    pub fn with_continuation_anchor(&mut self) -> &mut Self {
+        self.config.is_continuation_anchor_used = true;
+        self
+    }
+
+    /// Tells `RegExpBuilder` to omit the anchor `^` at the start of the resulting
+    /// regular expression, so that the test cases are also matched at the end of other strings.
+    pub fn without_start_anchor(&mut self) -> &mut Self {
+        self.config.is_start_anchor_disabled = true;
+        self
+    }
+
+    /// Tells `RegExpBuilder` to omit the anchor `$` at the end of the resulting
+    /// regular expression, so that the test cases are also matched at the start of other strings.
+    pub fn without_end_anchor(&mut self) -> &mut Self {
+        self.config.is_end_anchor_disabled = true;
+        self
+    }
+
+    /// Tells `RegExpBuilder` to omit both anchors `^` and `$` of the resulting
+    /// regular expression, so that the test cases are matched anywhere within other strings.
+    pub fn without_anchors(&mut self) -> &mut Self {
+        self.config.is_start_anchor_disabled = true;
+        self.config.is_end_anchor_disabled = true;
+        self
+    }
+
+    /// Tells `RegExpBuilder` to start the resulting regular expression with the
+    /// word boundary `\b` instead of the anchor `^`.
+    ///
+    /// Combined with [`without_end_anchor`](./struct.RegExpBuilder.html#method.without_end_anchor),
+    /// the test cases are matched at the start of words which may continue afterwards,
+    /// such as identifiers being typed. The word boundary takes the place of the anchor,
+    /// so both cannot be present on the same side. Word boundaries are only meaningful
+    /// next to word characters.
+    pub fn with_word_boundary_at_start(&mut self) -> &mut Self {
+        self.config.is_start_word_boundary_used = true;
+        self
+    }
+
+    /// Tells `RegExpBuilder` to end the resulting regular expression with the
+    /// word boundary `\b` instead of the anchor `$`.
+    ///
+    /// The word boundary takes the place of the anchor, so both cannot be present
+    /// on the same side. Word boundaries are only meaningful next to word characters.
+    pub fn with_word_boundary_at_end(&mut self) -> &mut Self {
+        self.config.is_end_word_boundary_used = true;
+        self
+    }
+
+    /// Tells `RegExpBuilder` to end the resulting regular expression with the
+    /// catch-all `.*` instead of the anchor `$`.
+    ///
+    /// This is useful for matching log lines of which only the start is known,
+    /// such as timestamps or log levels: `^(?:ERROR|WARN).*` matches every line starting
+    /// with one of the test cases, followed by arbitrary text. Unlike
+    /// [`without_end_anchor`](./struct.RegExpBuilder.html#method.without_end_anchor),
+    /// the whole line is consumed, so it is contained in the match.
+    ///
+    /// By default, the catch-all stops at the first line break. If it is supposed to match
+    /// line breaks as well, use method
+    /// [`with_dot_matching_newlines`](./struct.RegExpBuilder.html#method.with_dot_matching_newlines).
+    ///
+    /// The catch-all takes the place of the end anchor, so it replaces any word boundary
+    /// or lookahead assertion at the end of the regular expression.
+    pub fn with_trailing_catch_all(&mut self) -> &mut Self {
+        self.config.is_trailing_catch_all_used = true;
+        self
+    }
+
+    /// Tells `RegExpBuilder` to define alternations which occur more than once in the
+    /// resulting regular expression as named subpatterns within a `(?(DEFINE)...)` group
+    /// at its start. Each occurrence is replaced by a subroutine call such as `(?&sub1)`.
+    ///
+    /// ⚠ This setting only takes effect if
+    /// [`Flavor::Pcre`](./enum.Flavor.html#variant.Pcre) is set with method
+    /// [`with_flavor`](./struct.RegExpBuilder.html#method.with_flavor).
+    /// It is ignored in verbose mode.
+    ///
+    /// ⚠ If capturing groups are enabled, the named subpatterns shift the numbers
+    /// of all subsequent capturing groups.
+    pub fn with_subpattern_definitions(&mut self) -> &mut Self {
+        self.config.is_subpattern_defined = true;
+        self
+    }
+
+    /// Tells `RegExpBuilder` to start the resulting regular expression with the
+    /// lookbehind assertion `(?<=assertion)` instead of the anchor `^`.
+    ///
+    /// For example, the assertion `\d` restricts matches to positions which are
+    /// preceded by a digit. The assertion takes the place of the anchor and of a
+    /// word boundary set with [`with_word_boundary_at_start`](./struct.RegExpBuilder.html#method.with_word_boundary_at_start).
+    ///
+    /// ⚠ This setting only takes effect if
+    /// [`Flavor::Pcre`](./enum.Flavor.html#variant.Pcre) is set with method
+    /// [`with_flavor`](./struct.RegExpBuilder.html#method.with_flavor).
+    ///
+    /// ⚠ Panics if `assertion` is not a valid regular expression fragment.
+    pub fn with_lookbehind(&mut self, assertion: &str) -> &mut Self {
+        if assertion.is_empty() || scan_pattern_fragment(assertion, Flavor::Pcre).is_none() {
+            panic!("Lookbehind assertion must be a valid regular expression fragment");
+        }
+        self.config.lookbehind = Some(assertion.to_string());
+        self
+    }
+
+    /// Tells `RegExpBuilder` to end the resulting regular expression with the
+    /// lookahead assertion `(?=assertion)` instead of the anchor `$`.
+    ///
+    /// The assertion takes the place of the anchor and of a word boundary set with
+    /// [`with_word_boundary_at_end`](./struct.RegExpBuilder.html#method.with_word_boundary_at_end).
+    ///
+    /// ⚠ This setting only takes effect if
+    /// [`Flavor::Pcre`](./enum.Flavor.html#variant.Pcre) is set with method
+    /// [`with_flavor`](./struct.RegExpBuilder.html#method.with_flavor).
+    ///
+    /// ⚠ Panics if `assertion` is not a valid regular expression fragment.
+    pub fn with_lookahead(&mut self, assertion: &str) -> &mut Self {
+        if assertion.is_empty() || scan_pattern_fragment(assertion, Flavor::Pcre).is_none() {
+            panic!("Lookahead assertion must be a valid regular expression fragment");
+        }
+        self.config.lookahead = Some(assertion.to_string());
+        self
+    }
+
+    /// Tells `RegExpBuilder` to insert the word boundary `\b` between adjacent characters
+    /// of the test cases if one of them is a word character and the other one is not.
+    /// For example, the test case `foo-bar` results in `^foo\b\-\bbar$`.
+    ///
+    /// The assertions do not change which strings are matched as a whole, but they document
+    /// the structure of the test cases and keep it intact if the regular expression is
+    /// modified or embedded into a larger one. Shorthand character classes such as `\d`
+    /// and `\s` are taken into account as well, whereas wildcards never count as boundaries.
+    ///
+    /// ⚠ Boundaries are only inserted within literal sequences of characters.
+    /// Transitions into or out of alternations and repetition groups are left unchanged.
+    pub fn with_internal_word_boundaries(&mut self) -> &mut Self {
+        self.config.is_internal_word_boundary_used = true;
+        self
+    }
+
+    /// Tells `RegExpBuilder` to choose the shortest of several equivalent representations
+    /// for character classes and their shorthands, depending on the chosen flavor.
+    ///
+    /// With [`Flavor::Pcre`](./enum.Flavor.html#variant.Pcre), the shorthand classes
+    /// only match ASCII characters, so `[0-9]` becomes `\d` and `[0-9A-Z_a-z]` becomes `\w`.
+    /// With the POSIX flavors, `[[:digit:]]` becomes `[0-9]`. With [`Flavor::Rust`](./enum.Flavor.html#variant.Rust),
+    /// the shorthand classes are Unicode-aware and never equivalent to their ASCII ranges,
+    /// so nothing is replaced.
+    pub fn with_minimized_length(&mut self) -> &mut Self {
+        self.config.is_length_minimized = true;
+        self
+    }
+
+    /// Specifies the notations of shorthand character classes such as `\d`, `\s` and `\w`
+    /// in order of preference. The available notations are listed in the
+    /// [`ClassSyntax`](./enum.ClassSyntax.html#variants) enum. The first notation which is
+    /// supported by the chosen flavor is used, so `[Perl, Posix]` results in `\d` for
+    /// [`Flavor::Rust`](./enum.Flavor.html#variant.Rust) but in `[[:digit:]]` for
+    /// [`Flavor::PosixExtended`](./enum.Flavor.html#variant.PosixExtended).
+    ///
+    /// If none of the notations is supported or this method is not called,
+    /// [`ClassSyntax::Perl`](./enum.ClassSyntax.html#variant.Perl) is used for the
+    /// flavors supporting it and [`ClassSyntax::Posix`](./enum.ClassSyntax.html#variant.Posix)
+    /// is used for the POSIX flavors.
+    ///
+    /// ⚠ With [`Flavor::Rust`](./enum.Flavor.html#variant.Rust), the Perl shorthands are
+    /// Unicode-aware whereas the POSIX and literal notations only match ASCII characters.
+    ///
+    /// ⚠ Panics if `class_syntaxes` is empty.
+    pub fn with_class_syntax_preference(&mut self, class_syntaxes: &[ClassSyntax]) -> &mut Self {
+        if class_syntaxes.is_empty() {
+            panic!("No character class syntaxes have been provided");
+        }
+        self.config.class_syntax_preference = class_syntaxes.to_vec();
+        self
+    }
+
+    /// Specifies the minimum quantity of distinct graphemes at a single position
+    /// from which on the position is converted to `\X`, matching any grapheme cluster.
+    /// This replaces huge character classes and alternations of single graphemes
+    /// with a much shorter expression.
+    ///
+    /// ⚠ This setting only takes effect if
+    /// [`Flavor::Pcre`](./enum.Flavor.html#variant.Pcre) is set with method
+    /// [`with_flavor`](./struct.RegExpBuilder.html#method.with_flavor).
+    /// The resulting regular expression matches any grapheme at the converted positions.
+    ///
+    /// ⚠ Panics if `quantity` is zero.
+    pub fn with_any_grapheme_threshold(&mut self, quantity: u32) -> &mut Self {
+        if quantity == 0 {
+            panic!("Quantity of distinct graphemes must not be zero");
+        }
+        self.config.any_grapheme_threshold = Some(quantity);
+        self
+    }
+
+    /// Specifies the minimum quantity of distinct word characters in a character class
+    /// from which on the character class is converted to the shorthand `\w`.
+    /// This replaces long explicit character classes such as `[A-Za-z]` with a much
+    /// shorter expression.
+    ///
+    /// Only character classes consisting entirely of Unicode word characters are converted.
+    ///
+    /// ⚠ The resulting regular expression matches any word character at the converted positions.
+    /// This trades precision for brevity.
+    ///
+    /// ⚠ Panics if `quantity` is zero.
+    pub fn with_word_class_threshold(&mut self, quantity: u32) -> &mut Self {
+        if quantity == 0 {
+            panic!("Quantity of distinct word characters must not be zero");
+        }
+        self.config.word_class_threshold = Some(quantity);
+        self
+    }
+
+    /// Tells `RegExpBuilder` to convert characters to character classes only at those
+    /// positions which are reached by at least the given percentage of test cases.
+    /// Positions beyond the end of shorter test cases are kept literal, so that the
+    /// variable-length tails of longer test cases remain as explicit branches.
+    /// For example, the test cases `a1` and `a12345` result in `^a\d(?:2345)?$` with
+    /// a percentage of `100` instead of `^a(?:\d|\d{5})$`.
+    ///
+    /// This setting only has an effect if any character class has been enabled with
+    /// [`with_conversion_of`](./struct.RegExpBuilder.html#method.with_conversion_of).
+    /// It is not carried over by
+    /// [`to_streaming`](./struct.RegExpBuilder.html#method.to_streaming) because
+    /// the test cases are not known in advance there.
+    ///
+    /// ⚠ Panics if `percentage` is zero or greater than `100`.
+    pub fn with_class_conversion_at_common_positions(&mut self, percentage: u32) -> &mut Self {
+        if percentage == 0 || percentage > 100 {
+            panic!("Percentage of test cases must be between 1 and 100");
+        }
+        self.config.class_conversion_coverage = Some(percentage);
+        self
+    }
+
+    /// Specifies a placeholder character which is converted to the given
+    /// [`Wildcard`](./enum.Wildcard.html) wherever it appears in the test cases.
+    /// This is useful for template-like test cases such as `a?c` where `?` stands
+    /// for an arbitrary character, resulting in `a.c`.
+    ///
+    /// If the placeholder is meant literally, it has to be preceded by a backslash
+    /// in the test cases. It is then escaped in the resulting regular expression.
+    ///
+    /// ⚠ Panics if `placeholder` is a backslash.
+    pub fn with_placeholder(&mut self, placeholder: char, wildcard: Wildcard) -> &mut Self {
+        if placeholder == '\\' {
+            panic!("A backslash cannot be used as placeholder");
+        }
+        self.config.placeholder = Some((placeholder, wildcard));
+        self
+    }
+
+    /// Tells `RegExpBuilder` to let the wildcard `.` match line breaks as well.
+    /// The wildcard is generated for placeholders converted to
+    /// [`Wildcard::AnyChar`](./enum.Wildcard.html#variant.AnyChar) by method
+    /// [`with_placeholder`](./struct.RegExpBuilder.html#method.with_placeholder)
+    /// and for the catch-all added by method
+    /// [`with_trailing_catch_all`](./struct.RegExpBuilder.html#method.with_trailing_catch_all).
+    ///
+    /// If the resulting regular expression contains this wildcard, it is preceded by
+    /// the flag `(?s)`. The POSIX flavors do not need the flag because their wildcard
+    /// matches line breaks anyway, so it is omitted for them.
+    pub fn with_dot_matching_newlines(&mut self) -> &mut Self {
+        self.config.is_dot_matching_newlines = true;
+        self
+    }
+
+    /// Specifies the maximum quantity of branches that any alternation in the resulting
+    /// regular expression may consist of. If an alternation exceeds this quantity, its
+    /// lexicographically last branches are merged into a single catch-all branch.
+    ///
+    /// The catch-all branch consists of the narrowest shorthand character class out of
+    /// `\d`, `\w`, `\S` and `\p{Any}` which covers all characters of the merged branches,
+    /// quantified by the minimum and maximum length of the merged branches.
+    ///
+    /// ⚠ The catch-all branch matches many more strings than the branches it replaces.
+    /// This trades precision for brevity, so the resulting regular expression is
+    /// no longer guaranteed to match the test cases only.
+    ///
+    /// ⚠ Panics if `quantity` is zero.
+    pub fn with_maximum_branches(&mut self, quantity: u32) -> &mut Self {
+        if quantity == 0 {
+            panic!("Quantity of maximum branches must not be zero");
+        }
+        self.config.maximum_branches = Some(quantity);
+        self
+    }
+
     /// Tells `RegExpBuilder` to convert non-ASCII characters to unicode escape sequences.
     /// The parameter `use_surrogate_pairs` specifies whether to convert astral code planes
     /// (range `U+010000` to `U+10FFFF`) to surrogate pairs.
+    ///
+    /// The escape sequences are written as `\u{1f600}` by default and as `\x{1f600}`
+    /// with [`Flavor::Pcre`](./enum.Flavor.html#variant.Pcre). Escaped characters are merged
+    /// into character classes and ranges such as `[\u{1f600}-\u{1f603}]` as well,
+    /// except for astral code points converted to surrogate pairs which cannot be part of
+    /// a character class.
+    ///
+    /// ⚠ Surrogate pairs are not supported by [`Flavor::Pcre`](./enum.Flavor.html#variant.Pcre)
+    /// which only accepts valid code points, so `use_surrogate_pairs` is ignored in this case.
     pub fn with_escaping_of_non_ascii_chars(&mut self, use_surrogate_pairs: bool) -> &mut Self {
         self.config.is_non_ascii_char_escaped = true;
         self.config.is_astral_code_point_converted_to_surrogate = use_surrogate_pairs;
         self
     }
 
+    /// Tells `RegExpBuilder` to convert non-ASCII characters to named unicode escape sequences
+    /// such as `\N{GREEK SMALL LETTER ALPHA}` instead of code point escape sequences.
+    /// This makes the resulting regular expression easier to read.
+    ///
+    /// Names are available for the Latin, IPA, Greek and Cyrillic blocks, the combining
+    /// diacritical marks as well as for common punctuation, currency symbols, letterlike symbols,
+    /// arrows and mathematical operators. All other characters keep their code point
+    /// escape sequences.
+    ///
+    /// This setting requires the escaping of non-ASCII characters with method
+    /// [`with_escaping_of_non_ascii_chars`](./struct.RegExpBuilder.html#method.with_escaping_of_non_ascii_chars)
+    /// and is only available with the crate feature `unicode-names`.
+    ///
+    /// ⚠ This setting only takes effect with [`Flavor::Pcre`](./enum.Flavor.html#variant.Pcre).
+    /// Named escape sequences are understood by Perl, whereas some PCRE-based engines only
+    /// accept the form `\N{U+03B1}`.
+    #[cfg(feature = "unicode-names")]
+    pub fn with_unicode_names(&mut self) -> &mut Self {
+        self.config.is_unicode_name_used = true;
+        self
+    }
+
+    /// Tells `RegExpBuilder` to convert control characters within character classes
+    /// to hexadecimal escape sequences such as `\x1b`. This applies to the C0 control
+    /// characters below `U+0020` and to the C1 control characters from `U+0080` to `U+009F`.
+    ///
+    /// Line feeds, carriage returns and tabs keep their shorter escape sequences
+    /// `\n`, `\r` and `\t`. The NUL character is escaped as required by the chosen flavor.
+    ///
+    /// ⚠ This setting is ignored by the POSIX flavors because backslashes are literal
+    /// within POSIX bracket expressions.
+    pub fn with_escaping_of_control_chars(&mut self) -> &mut Self {
+        self.config.is_control_char_escaped = true;
+        self
+    }
+
+    /// Tells `RegExpBuilder` to allow optional whitespace around the given separator characters.
+    /// Whitespace next to a separator is removed from the test cases, and each separator
+    /// is surrounded by `\s*` in the resulting regular expression instead. For example,
+    /// the test cases `a , b` and `a,b` with the separator `,` result in `^a\s*,\s*b$`
+    /// which matches both of them as well as any other spacing.
+    ///
+    /// ⚠ The method panics if any of the characters is whitespace.
+    pub fn with_optional_whitespace_around(&mut self, separators: &[char]) -> &mut Self {
+        if separators.iter().any(|c| c.is_whitespace()) {
+            panic!("Separators must not be whitespace characters");
+        }
+        self.config.separators = separators.iter().copied().collect();
+        self
+    }
+
+    /// Tells `RegExpBuilder` to escape the given characters in addition to the
+    /// metacharacters of the chosen flavor. This is useful if the resulting regular expression
+    /// is embedded into a context in which these characters have a special meaning,
+    /// such as `/` in regex literals or `'` in single-quoted shell strings.
+    ///
+    /// If a character is passed to this method and to method
+    /// [`without_escaping_of`](./struct.RegExpBuilder.html#method.without_escaping_of)
+    /// as well, it is escaped.
+    ///
+    /// ⚠ Within character classes, the characters are not escaped by the POSIX flavors
+    /// because backslashes are literal within POSIX bracket expressions. Not every regular
+    /// expression engine accepts escape sequences of characters which are not metacharacters.
+    ///
+    /// ⚠ The method panics if any of the characters is not an ASCII punctuation character
+    /// because escaping letters, digits or whitespace changes their meaning.
+    pub fn with_escaping_of(&mut self, chars: &[char]) -> &mut Self {
+        if chars.iter().any(|c| !c.is_ascii_punctuation()) {
+            panic!("Characters to be escaped must be ASCII punctuation characters");
+        }
+        self.config.escaped_chars = chars.iter().copied().collect();
+        self
+    }
+
+    /// Tells `RegExpBuilder` not to escape the given characters,
+    /// even if they are escaped by default.
+    ///
+    /// ⚠ If any of them is a metacharacter of the chosen flavor, it keeps its special meaning
+    /// in the resulting regular expression, so the test cases are no longer matched literally.
+    /// In this case, a [`GenerationWarning`](./enum.GenerationWarning.html) is returned by method
+    /// [`build_with_warnings`](./struct.RegExpBuilder.html#method.build_with_warnings)
+    /// for each of these metacharacters.
+    ///
+    /// ⚠ The backslash is always escaped, so that a literal backslash in the test cases
+    /// is written as `\\` in every flavor. Passing it to this method has no effect.
+    pub fn without_escaping_of(&mut self, chars: &[char]) -> &mut Self {
+        self.config.unescaped_chars = chars.iter().copied().collect();
+        self
+    }
+
+    /// Specifies a function which transforms each grapheme cluster, that is each test case
+    /// broken up into its graphemes, before the regular expression is assembled from them.
+    /// This allows for custom generalizations such as collapsing vendor-specific tokens.
+    ///
+    /// The function is applied after the conversion of character classes and repetitions,
+    /// once per cluster and in the sorted order of the test cases.
+    ///
+    /// ⚠ The transformed clusters decide about the resulting regular expression on their own,
+    /// so it might not match the original test cases anymore.
+    pub fn with_cluster_transformation<F>(&mut self, transformation: F) -> &mut Self
+    where
+        F: Fn(&GraphemeCluster) -> GraphemeCluster + Send + Sync + 'static,
+    {
+        self.config.cluster_transformation = Some(ClusterTransformation(Arc::new(transformation)));
+        self
+    }
+
+    /// Replaces all settings of this builder with the given ones, such as those read from
+    /// a profile with [`RegExpConfig::from_profile`](./struct.RegExpConfig.html#method.from_profile).
+    /// Settings given before are discarded, settings given afterwards are applied on top.
+    pub fn with_config(&mut self, config: &RegExpConfig) -> &mut Self {
+        self.config = config.clone();
+        self
+    }
+
+    /// Tells `RegExpBuilder` to reject test cases containing the replacement character U+FFFD.
+    ///
+    /// Rust strings cannot hold lone surrogates or other invalid data, so inputs from external
+    /// sources which have been decoded lossily contain U+FFFD in their place. By default, this
+    /// character is treated like any other character and matched literally. If this setting
+    /// is enabled, method [`try_build_regexp`](./struct.RegExpBuilder.html#method.try_build_regexp)
+    /// returns [`GenerationError::ReplacementCharacterFound`](./enum.GenerationError.html#variant.ReplacementCharacterFound)
+    /// for the first such test case instead. The other build methods panic in this case.
+    pub fn with_rejection_of_replacement_chars(&mut self) -> &mut Self {
+        self.config.is_replacement_char_rejected = true;
+        self
+    }
+
+    /// Tells `RegExpBuilder` to limit the memory of the intermediate data structures
+    /// during generation to the given quantity of bytes. This protects against test cases
+    /// from untrusted sources which would otherwise exhaust the available memory.
+    ///
+    /// The memory is estimated while the finite automaton is constructed and before
+    /// it is converted to an abstract syntax tree, which is the most memory-intensive step.
+    /// If the budget is exceeded, generation is aborted and method
+    /// [`try_build_regexp`](./struct.RegExpBuilder.html#method.try_build_regexp)
+    /// returns [`GenerationError::MemoryBudgetExceeded`](./enum.GenerationError.html#variant.MemoryBudgetExceeded).
+    /// The other build methods panic in this case.
+    ///
+    /// ⚠ The estimate only covers the dominant data structures, so the actual memory
+    /// consumption may be somewhat higher. Methods which derive new regular expressions
+    /// from an existing one, such as [`RegExp.intersect`](./struct.RegExp.html#method.intersect),
+    /// are not limited by the budget.
+    ///
+    /// ⚠ The method panics if `bytes` is zero.
+    pub fn with_memory_budget(&mut self, bytes: usize) -> &mut Self {
+        if bytes == 0 {
+            panic!("Memory budget must not be zero");
+        }
+        self.config.memory_budget = Some(bytes);
+        self
+    }
+
+    /// Tells `RegExpBuilder` to output the resulting regular expression in verbose mode.
+    /// The flag `(?x)` is prepended and each branch of the top-level alternation
+    /// is written on a separate line. Whitespace and `#` in the test cases are escaped
+    /// because they are ignored or start a comment in verbose mode, respectively.
+    pub fn with_verbose_mode(&mut self) -> &mut Self {
+        self.config.is_verbose_mode_enabled = true;
+        self
+    }
+
+    /// Tells `RegExpBuilder` to enclose the resulting regular expression in the scoped
+    /// group `(?i:...)` instead of prepending the flag `(?i)` if case-insensitive matching
+    /// is enabled. The anchors are kept outside of the group. For example, the test cases
+    /// `abc` and `ABD` result in `^(?i:ab[cd])$` instead of `(?i)^ab[cd]$`.
+    ///
+    /// This is useful for engines which do not support flags at the start of a pattern
+    /// and for embedding the regular expression into larger ones.
+    /// If capturing groups are enabled, the scoped group encloses the capturing group
+    /// of the top-level alternation, so that the group numbers remain the same.
+    pub fn with_scoped_ignore_case_flag(&mut self) -> &mut Self {
+        self.config.is_ignore_case_flag_scoped = true;
+        self
+    }
+
+    /// Tells `RegExpBuilder` to enclose the resulting regular expression in a group
+    /// between the anchors even if it does not consist of an alternation of several branches.
+    /// For example, the test case `abc` results in `^(?:abc)$` instead of `^abc$`.
+    ///
+    /// This makes the structure of the output predictable, so that further expressions
+    /// can be concatenated with it or quantifiers applied to it without inspecting it first.
+    /// If capturing groups are enabled, the group is a capturing one.
+    pub fn with_outer_group(&mut self) -> &mut Self {
+        self.config.is_outer_group_always_used = true;
+        self
+    }
+
+    /// Tells `RegExpBuilder` to match letters case-insensitively by expanding each of them
+    /// into a character class of its case variants instead of prepending the flag `(?i)`.
+    /// For example, the test cases `abc` and `ABD` result in `^[Aa][Bb][CDcd]$`.
+    ///
+    /// Unlike the flag, this works with all flavors including the POSIX ones, and the
+    /// case-insensitivity is confined to the letters of the test cases, so the regular
+    /// expression can be embedded into larger ones without affecting them.
+    /// Letters whose case variant consists of several characters, such as `ß`, are kept as is.
+    pub fn with_case_insensitive_classes(&mut self) -> &mut Self {
+        self.config.is_case_expanded_to_classes = true;
+        self
+    }
+
+    /// Tells `RegExpBuilder` to include all characters which are equivalent under
+    /// Unicode simple case folding in the classes created by method
+    /// [`with_case_insensitive_classes`](./struct.RegExpBuilder.html#method.with_case_insensitive_classes).
+    /// Besides the upper and lower case variant of a letter, this covers characters
+    /// such as the Kelvin sign (U+212A) for `k` and the long s `ſ` (U+017F) for `s`,
+    /// so the letter `s` results in `[Ssſ]`.
+    ///
+    /// ⚠ This setting only takes effect if case-insensitive classes are enabled with method
+    /// [`with_case_insensitive_classes`](./struct.RegExpBuilder.html#method.with_case_insensitive_classes).
+    pub fn with_unicode_case_folding(&mut self) -> &mut Self {
+        self.config.is_case_folding_full = true;
+        self
+    }
+
+    /// Tells `RegExpBuilder` to append a comment `# matches: ...` to each branch
+    /// of the top-level alternation, listing the test cases matched by the branch.
+    ///
+    /// After minimization of the automaton, a branch cannot always be traced back to
+    /// the test cases it originated from. The listed test cases are therefore determined
+    /// by matching them against each branch, which is approximate for branches that
+    /// have been generalized, e.g. by
+    /// [`with_maximum_branches`](./struct.RegExpBuilder.html#method.with_maximum_branches).
+    /// Branches without any test case found are not commented.
+    ///
+    /// ⚠ This setting only takes effect if verbose mode is enabled with method
+    /// [`with_verbose_mode`](./struct.RegExpBuilder.html#method.with_verbose_mode).
+    pub fn with_branch_comments(&mut self) -> &mut Self {
+        self.config.is_branch_commented = true;
+        self
+    }
+
+    /// Tells `RegExpBuilder` to keep a copy of the original test cases in the resulting
+    /// [`RegExp`](./struct.RegExp.html), so that they can be inspected afterwards with method
+    /// [`RegExp.inputs`](./struct.RegExp.html#method.inputs).
+    ///
+    /// The test cases are not retained by default to save the memory of the copy.
+    pub fn with_input_retention(&mut self) -> &mut Self {
+        self.config.is_input_retained = true;
+        self
+    }
+
     /// Tells `RegExpBuilder` to provide syntax highlighting for the resulting regular expression.
     ///
     /// ⚠ This method may only be used if the resulting regular expression is meant to
@@ -139,9 +1241,124 @@ impl RegExpBuilder {
     }
 
     /// Builds the actual regular expression using the previously given settings.
-    /// Every generated regular expression is surrounded by the anchors `^` and `$`
-    /// so that substrings not being part of the test cases are not matched accidentally.
+    /// Unless disabled, every generated regular expression is surrounded by the anchors
+    /// `^` and `$` so that substrings not being part of the test cases are not matched
+    /// accidentally.
     pub fn build(&mut self) -> String {
-        RegExp::from(&mut self.test_cases, &self.config).to_string()
+        self.build_regexp().to_string()
+    }
+
+    /// Converts this builder into a [`StreamingRegExpBuilder`](./struct.StreamingRegExpBuilder.html)
+    /// which accepts further test cases over time and emits updated pattern snapshots.
+    /// All settings and test cases given so far are carried over.
+    ///
+    /// ⚠ Categorized test cases are not carried over.
+    pub fn to_streaming(&self) -> StreamingRegExpBuilder {
+        StreamingRegExpBuilder::from(&self.test_cases, &self.config)
+    }
+
+    /// Builds the actual regular expression using the previously given settings
+    /// and returns it as a [`RegExp`](./struct.RegExp.html) which can be inspected further.
+    /// Its string representation is identical to the one returned by
+    /// [`build`](./struct.RegExpBuilder.html#method.build).
+    ///
+    /// ⚠ The method panics if a memory budget has been set with
+    /// [`with_memory_budget`](./struct.RegExpBuilder.html#method.with_memory_budget)
+    /// and generation exceeds it, if replacement characters are rejected with
+    /// [`with_rejection_of_replacement_chars`](./struct.RegExpBuilder.html#method.with_rejection_of_replacement_chars)
+    /// and a test case contains one, if bounded quantifiers are enforced with
+    /// [`with_bounded_quantifiers_only`](./struct.RegExpBuilder.html#method.with_bounded_quantifiers_only)
+    /// and an unbounded one is required, or if test cases are treated as patterns with
+    /// [`with_test_cases_as_patterns`](./struct.RegExpBuilder.html#method.with_test_cases_as_patterns)
+    /// and one of them is invalid. Use
+    /// [`try_build_regexp`](./struct.RegExpBuilder.html#method.try_build_regexp) instead
+    /// to handle this case gracefully.
+    pub fn build_regexp(&mut self) -> RegExp {
+        self.try_build_regexp()
+            .unwrap_or_else(|error| panic!("Regular expression generation failed: {}", error))
+    }
+
+    /// Builds the actual regular expression using the previously given settings
+    /// and returns it as a [`RegExp`](./struct.RegExp.html), or a
+    /// [`GenerationError`](./enum.GenerationError.html) if a memory budget has been set with
+    /// [`with_memory_budget`](./struct.RegExpBuilder.html#method.with_memory_budget)
+    /// and generation would exceed it, if replacement characters are rejected with
+    /// [`with_rejection_of_replacement_chars`](./struct.RegExpBuilder.html#method.with_rejection_of_replacement_chars)
+    /// and a test case contains one, if bounded quantifiers are enforced with
+    /// [`with_bounded_quantifiers_only`](./struct.RegExpBuilder.html#method.with_bounded_quantifiers_only)
+    /// and an unbounded one is required, or if test cases are treated as patterns with
+    /// [`with_test_cases_as_patterns`](./struct.RegExpBuilder.html#method.with_test_cases_as_patterns)
+    /// and one of them is invalid.
+    pub fn try_build_regexp(&mut self) -> Result<RegExp, GenerationError> {
+        if !self.categorized_test_cases.is_empty() {
+            return RegExp::from_categorized(&self.categorized_test_cases, &self.config);
+        }
+        RegExp::from(&self.test_cases, &self.config)
+    }
+
+    /// Builds the actual regular expression using the previously given settings
+    /// and returns it as a [`RegExp`](./struct.RegExp.html) together with a list of
+    /// [`GenerationWarning`](./enum.GenerationWarning.html)s.
+    ///
+    /// The warnings describe notable decisions taken during generation, such as
+    /// the generalization of characters to shorthand character classes or settings
+    /// which have had no effect. If there are none, the returned list is empty.
+    pub fn build_with_warnings(&mut self) -> (RegExp, Vec<GenerationWarning>) {
+        let result = if !self.categorized_test_cases.is_empty() {
+            RegExp::from_categorized_with_warnings(&self.categorized_test_cases, &self.config)
+        } else {
+            RegExp::from_with_warnings(&self.test_cases, &self.config)
+        };
+        result.unwrap_or_else(|error| panic!("Regular expression generation failed: {}", error))
+    }
+
+    /// Builds the actual regular expression using the previously given settings
+    /// and returns it as a [`RegExp`](./struct.RegExp.html) together with
+    /// [`GenerationTimings`](./struct.GenerationTimings.html) which report
+    /// how long each stage of the generation took.
+    ///
+    /// This helps to find out which stage dominates the generation time for
+    /// particular test cases and settings.
+    pub fn build_with_timings(&mut self) -> (RegExp, GenerationTimings) {
+        let result = if !self.categorized_test_cases.is_empty() {
+            RegExp::from_categorized_timed(&self.categorized_test_cases, &self.config)
+        } else {
+            RegExp::from_timed(&self.test_cases, &self.config)
+        };
+        result.unwrap_or_else(|error| panic!("Regular expression generation failed: {}", error))
+    }
+}
+
+fn decode_file_content(bytes: Vec<u8>) -> Result<String, InputFileError> {
+    match bytes.as_slice() {
+        [0xEF, 0xBB, 0xBF, rest @ ..] => decode_utf8(rest.to_vec()),
+        [0xFF, 0xFE, rest @ ..] => decode_utf16(rest, u16::from_le_bytes),
+        [0xFE, 0xFF, rest @ ..] => decode_utf16(rest, u16::from_be_bytes),
+        _ => decode_utf8(bytes),
+    }
+}
+
+fn decode_utf8(bytes: Vec<u8>) -> Result<String, InputFileError> {
+    String::from_utf8(bytes).map_err(|_| InputFileError::InvalidUtf8)
+}
+
+fn decode_utf16(bytes: &[u8], to_code_unit: fn([u8; 2]) -> u16) -> Result<String, InputFileError> {
+    let chunks = bytes.chunks_exact(2);
+    if !chunks.remainder().is_empty() {
+        return Err(InputFileError::InvalidUtf16);
+    }
+    let code_units = chunks
+        .map(|chunk| to_code_unit([chunk[0], chunk[1]]))
+        .collect_vec();
+    String::from_utf16(&code_units).map_err(|_| InputFileError::InvalidUtf16)
+}
+
+fn is_valid_group_name(name: &str) -> bool {
+    let mut chars = name.chars();
+    match chars.next() {
+        Some(first) if first.is_ascii_alphabetic() || first == '_' => {
+            chars.all(|c| c.is_ascii_alphanumeric() || c == '_')
+        }
+        _ => false,
     }
 }
@@ -0,0 +1,37 @@
+/*
+ * Copyright © 2019-2020 Peter M. Stahl pemistahl@gmail.com
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ * http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either expressed or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+/// This enum specifies the notations of shorthand character classes which can be passed
+/// in order of preference to method
+/// [`RegExpBuilder.with_class_syntax_preference`](./struct.RegExpBuilder.html#method.with_class_syntax_preference).
+#[derive(Clone, Copy, Debug, Hash, Ord, PartialOrd, Eq, PartialEq)]
+pub enum ClassSyntax {
+    /// Writes character classes as Perl shorthands such as `\d`, `\s` and `\w`.
+    /// This notation is not supported by the POSIX flavors.
+    Perl,
+
+    /// Writes character classes as POSIX bracket expressions such as `[[:digit:]]`,
+    /// `[[:space:]]` and `[[:alnum:]_]`. This notation is supported by all flavors.
+    /// As these classes only cover ASCII characters, other characters are not converted.
+    Posix,
+
+    /// Writes character classes as explicit ranges such as `[0-9]`, `[\t\n\x0B\f\r\x20]`
+    /// and `[0-9A-Z_a-z]`. This notation is supported by all flavors. As bracket expressions
+    /// of the POSIX flavors cannot contain escape sequences, whitespace is written as
+    /// `[[:space:]]` for them. As these classes only cover ASCII characters, other characters
+    /// are not converted.
+    Literal,
+}
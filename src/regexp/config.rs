@@ -14,16 +14,91 @@
  * limitations under the License.
  */
 
-use crate::regexp::Feature;
+use crate::char::GraphemeCluster;
+use crate::regexp::{
+    read_profile, ClassSyntax, Feature, Flavor, GenerationError, LineEnding, Mutation,
+    ProfileError, Wildcard,
+};
+use std::cmp::Ordering;
+use std::collections::BTreeSet;
+use std::fmt::{Debug, Formatter};
+use std::hash::{Hash, Hasher};
+use std::sync::Arc;
 
+/// This struct holds the settings of a [`RegExpBuilder`](./struct.RegExpBuilder.html).
+/// It can be restored from a profile written by
+/// [`RegExp.to_profile`](./struct.RegExp.html#method.to_profile) and passed to
+/// [`RegExpBuilder.with_config`](./struct.RegExpBuilder.html#method.with_config).
 #[derive(Clone, Debug, Hash, Ord, PartialOrd, Eq, PartialEq)]
 pub struct RegExpConfig {
     pub(crate) conversion_features: Vec<Feature>,
     pub(crate) minimum_repetitions: u32,
     pub(crate) minimum_substring_length: u32,
+    pub(crate) quantifier_threshold: Option<u32>,
+    pub(crate) repetition_excluded_chars: BTreeSet<char>,
+    pub(crate) escaped_chars: BTreeSet<char>,
+    pub(crate) unescaped_chars: BTreeSet<char>,
+    pub(crate) separators: BTreeSet<char>,
     pub(crate) is_non_ascii_char_escaped: bool,
     pub(crate) is_astral_code_point_converted_to_surrogate: bool,
+    pub(crate) is_control_char_escaped: bool,
+    pub(crate) is_unicode_name_used: bool,
     pub(crate) is_output_colorized: bool,
+    pub(crate) is_repetition_upper_bound_relaxed: bool,
+    pub(crate) is_repetition_strict: bool,
+    pub(crate) is_atomic_grouping_used: bool,
+    pub(crate) is_word_char_unicode_aware: bool,
+    pub(crate) is_case_expanded_to_classes: bool,
+    pub(crate) is_case_folding_full: bool,
+    pub(crate) is_deduplication_case_insensitive: bool,
+    pub(crate) is_last_duplicate_kept: bool,
+    pub(crate) is_branch_order_weighted: bool,
+    pub(crate) is_bounded_only: bool,
+    pub(crate) class_syntax_preference: Vec<ClassSyntax>,
+    pub(crate) mutations: Vec<Mutation>,
+    pub(crate) line_ending: Option<LineEnding>,
+    pub(crate) is_whole_pattern_optional: bool,
+    pub(crate) token_repetition: Option<(String, u32, u32)>,
+    pub(crate) permutation_separator: Option<String>,
+    pub(crate) is_continuation_anchor_used: bool,
+    pub(crate) is_start_anchor_disabled: bool,
+    pub(crate) is_end_anchor_disabled: bool,
+    pub(crate) is_start_word_boundary_used: bool,
+    pub(crate) is_end_word_boundary_used: bool,
+    pub(crate) is_trailing_catch_all_used: bool,
+    pub(crate) lookbehind: Option<String>,
+    pub(crate) lookahead: Option<String>,
+    pub(crate) is_internal_word_boundary_used: bool,
+    pub(crate) is_ipv4_structured: bool,
+    pub(crate) is_date_time_structured: bool,
+    pub(crate) is_hex_detected: bool,
+    pub(crate) is_csv_structured: bool,
+    pub(crate) is_skeleton_grouped: bool,
+    pub(crate) is_semver_structured: bool,
+    pub(crate) is_verbose_mode_enabled: bool,
+    pub(crate) is_ignore_case_flag_scoped: bool,
+    pub(crate) is_outer_group_always_used: bool,
+    pub(crate) is_subpattern_defined: bool,
+    pub(crate) is_length_minimized: bool,
+    pub(crate) is_dot_matching_newlines: bool,
+    pub(crate) is_branch_commented: bool,
+    pub(crate) is_zwj_sequence_split: bool,
+    pub(crate) is_suffix_factored: bool,
+    pub(crate) is_literal_only: bool,
+    pub(crate) is_input_treated_as_pattern: bool,
+    pub(crate) is_substring_branch_kept: bool,
+    pub(crate) is_prefix_matched: bool,
+    pub(crate) is_input_retained: bool,
+    pub(crate) is_replacement_char_rejected: bool,
+    pub(crate) flavor: Flavor,
+    pub(crate) any_grapheme_threshold: Option<u32>,
+    pub(crate) word_class_threshold: Option<u32>,
+    pub(crate) class_conversion_coverage: Option<u32>,
+    pub(crate) placeholder: Option<(char, Wildcard)>,
+    pub(crate) maximum_branches: Option<u32>,
+    pub(crate) maximum_factor_depth: Option<u32>,
+    pub(crate) memory_budget: Option<usize>,
+    pub(crate) cluster_transformation: Option<ClusterTransformation>,
 }
 
 impl RegExpConfig {
@@ -32,9 +107,217 @@ impl RegExpConfig {
             conversion_features: vec![],
             minimum_repetitions: 1,
             minimum_substring_length: 1,
+            quantifier_threshold: None,
+            repetition_excluded_chars: BTreeSet::new(),
+            escaped_chars: BTreeSet::new(),
+            unescaped_chars: BTreeSet::new(),
+            separators: BTreeSet::new(),
             is_non_ascii_char_escaped: false,
             is_astral_code_point_converted_to_surrogate: false,
+            is_control_char_escaped: false,
+            is_unicode_name_used: false,
             is_output_colorized: false,
+            is_repetition_upper_bound_relaxed: false,
+            is_repetition_strict: false,
+            is_atomic_grouping_used: false,
+            is_word_char_unicode_aware: false,
+            is_case_expanded_to_classes: false,
+            is_case_folding_full: false,
+            is_deduplication_case_insensitive: false,
+            is_last_duplicate_kept: false,
+            is_branch_order_weighted: false,
+            is_bounded_only: false,
+            class_syntax_preference: vec![],
+            mutations: vec![],
+            line_ending: None,
+            is_whole_pattern_optional: false,
+            token_repetition: None,
+            permutation_separator: None,
+            is_continuation_anchor_used: false,
+            is_start_anchor_disabled: false,
+            is_end_anchor_disabled: false,
+            is_start_word_boundary_used: false,
+            is_end_word_boundary_used: false,
+            is_trailing_catch_all_used: false,
+            lookbehind: None,
+            lookahead: None,
+            is_internal_word_boundary_used: false,
+            is_ipv4_structured: false,
+            is_date_time_structured: false,
+            is_hex_detected: false,
+            is_csv_structured: false,
+            is_skeleton_grouped: false,
+            is_semver_structured: false,
+            is_verbose_mode_enabled: false,
+            is_ignore_case_flag_scoped: false,
+            is_outer_group_always_used: false,
+            is_subpattern_defined: false,
+            is_length_minimized: false,
+            is_dot_matching_newlines: false,
+            is_branch_commented: false,
+            is_zwj_sequence_split: false,
+            is_suffix_factored: false,
+            is_literal_only: false,
+            is_input_treated_as_pattern: false,
+            is_substring_branch_kept: false,
+            is_prefix_matched: false,
+            is_input_retained: false,
+            is_replacement_char_rejected: false,
+            flavor: Flavor::Rust,
+            any_grapheme_threshold: None,
+            word_class_threshold: None,
+            class_conversion_coverage: None,
+            placeholder: None,
+            maximum_branches: None,
+            maximum_factor_depth: None,
+            memory_budget: None,
+            cluster_transformation: None,
+        }
+    }
+
+    /// Reads the settings from the given profile which has been written by
+    /// [`RegExp.to_profile`](./struct.RegExp.html#method.to_profile) in any of the
+    /// supported formats. Only the section `config` is evaluated, settings missing
+    /// from it keep their default values.
+    ///
+    /// ⚠ Cluster transformations set with
+    /// [`RegExpBuilder.with_cluster_transformation`](./struct.RegExpBuilder.html#method.with_cluster_transformation)
+    /// are functions which cannot be written to a profile, so they are never restored.
+    pub fn from_profile(profile: &str) -> Result<Self, ProfileError> {
+        read_profile(profile)
+    }
+
+    pub(crate) fn without_generalization(&self) -> Self {
+        Self {
+            conversion_features: self
+                .conversion_features
+                .iter()
+                .filter(|&it| *it == Feature::CapturingGroup)
+                .cloned()
+                .collect(),
+            separators: BTreeSet::new(),
+            is_repetition_upper_bound_relaxed: false,
+            is_ipv4_structured: false,
+            is_date_time_structured: false,
+            is_hex_detected: false,
+            is_csv_structured: false,
+            is_skeleton_grouped: false,
+            is_semver_structured: false,
+            is_suffix_factored: false,
+            any_grapheme_threshold: None,
+            word_class_threshold: None,
+            class_conversion_coverage: None,
+            quantifier_threshold: None,
+            placeholder: None,
+            mutations: vec![],
+            maximum_branches: None,
+            maximum_factor_depth: None,
+            ..self.clone()
+        }
+    }
+
+    pub(crate) fn without_pattern_incompatible_settings(&self) -> Self {
+        Self {
+            is_verbose_mode_enabled: false,
+            is_branch_commented: false,
+            ..self.without_generalization()
+        }
+    }
+
+    pub(crate) fn without_prefix_incompatible_settings(&self) -> Self {
+        Self {
+            conversion_features: self
+                .conversion_features
+                .iter()
+                .filter(|&it| *it != Feature::Repetition)
+                .cloned()
+                .collect(),
+            is_repetition_upper_bound_relaxed: false,
+            is_ipv4_structured: false,
+            is_date_time_structured: false,
+            is_hex_detected: false,
+            is_csv_structured: false,
+            is_skeleton_grouped: false,
+            is_semver_structured: false,
+            is_suffix_factored: false,
+            is_substring_branch_kept: false,
+            quantifier_threshold: None,
+            any_grapheme_threshold: None,
+            word_class_threshold: None,
+            maximum_branches: None,
+            maximum_factor_depth: None,
+            ..self.clone()
+        }
+    }
+
+    pub(crate) fn without_unbounded_settings(&self) -> Self {
+        Self {
+            is_repetition_upper_bound_relaxed: false,
+            is_semver_structured: false,
+            ..self.clone()
+        }
+    }
+
+    pub(crate) fn without_unsupported_syntax(&self) -> Self {
+        if !self.flavor.is_posix() {
+            return self.clone();
+        }
+        Self {
+            conversion_features: self
+                .conversion_features
+                .iter()
+                .filter(|&it| *it != Feature::CaseInsensitivity)
+                .cloned()
+                .collect(),
+            mutations: self
+                .mutations
+                .iter()
+                .filter(|&it| *it != Mutation::CaseFlip)
+                .cloned()
+                .collect(),
+            is_non_ascii_char_escaped: false,
+            is_astral_code_point_converted_to_surrogate: false,
+            is_control_char_escaped: false,
+            is_verbose_mode_enabled: false,
+            is_branch_commented: false,
+            is_semver_structured: false,
+            ..self.clone()
+        }
+    }
+
+    pub(crate) fn is_char_escaped(&self, c: char) -> bool {
+        self.escaped_chars.contains(&c) || self.flavor.is_metachar(c) && !self.is_char_unescaped(c)
+    }
+
+    // The backslash is always escaped because it would change the meaning of the next char
+    pub(crate) fn is_char_unescaped(&self, c: char) -> bool {
+        c != '\\' && self.unescaped_chars.contains(&c)
+    }
+
+    pub(crate) fn without_verbose_mode(&self) -> Self {
+        Self {
+            is_verbose_mode_enabled: false,
+            is_branch_commented: false,
+            ..self.clone()
+        }
+    }
+
+    pub(crate) fn without_memory_budget(&self) -> Self {
+        Self {
+            memory_budget: None,
+            ..self.clone()
+        }
+    }
+
+    pub(crate) fn check_memory_budget(
+        &self,
+        estimated_memory: usize,
+    ) -> Result<(), GenerationError> {
+        match self.memory_budget {
+            Some(budget) if estimated_memory > budget => {
+                Err(GenerationError::MemoryBudgetExceeded(budget))
+            }
+            _ => Ok(()),
         }
     }
 
@@ -62,6 +345,11 @@ impl RegExpConfig {
         self.conversion_features.contains(&Feature::NonWord)
     }
 
+    pub(crate) fn is_script_converted(&self) -> bool {
+        self.conversion_features.contains(&Feature::Script)
+            && self.flavor.is_script_class_supported()
+    }
+
     pub(crate) fn is_repetition_converted(&self) -> bool {
         self.conversion_features.contains(&Feature::Repetition)
     }
@@ -69,13 +357,149 @@ impl RegExpConfig {
     pub(crate) fn is_case_insensitive_matching(&self) -> bool {
         self.conversion_features
             .contains(&Feature::CaseInsensitivity)
+            || self.mutations.contains(&Mutation::CaseFlip)
+            || self.is_case_expanded_to_classes
+    }
+
+    pub(crate) fn transposition_limit(&self) -> Option<u32> {
+        self.mutations.iter().find_map(|mutation| match mutation {
+            Mutation::Transposition(limit) => Some(*limit),
+            _ => None,
+        })
+    }
+
+    pub(crate) fn is_ignore_case_flag_used(&self) -> bool {
+        self.is_case_insensitive_matching() && !self.is_case_expanded_to_classes
+    }
+
+    pub(crate) fn class_syntax(&self) -> ClassSyntax {
+        self.class_syntax_preference
+            .iter()
+            .copied()
+            .find(|&it| self.flavor.is_class_syntax_supported(it))
+            .unwrap_or_else(|| self.flavor.default_class_syntax())
+    }
+
+    pub(crate) fn is_class_notation_ascii_only(&self) -> bool {
+        self.class_syntax() != ClassSyntax::Perl
     }
 
     pub(crate) fn is_capturing_group_enabled(&self) -> bool {
         self.conversion_features.contains(&Feature::CapturingGroup)
     }
 
+    pub(crate) fn is_any_grapheme_converted(&self) -> bool {
+        self.any_grapheme_threshold.is_some() && self.flavor.is_any_grapheme_supported()
+    }
+
+    pub(crate) fn is_continuation_anchor_enabled(&self) -> bool {
+        self.is_continuation_anchor_used && self.flavor.is_continuation_anchor_supported()
+    }
+
+    pub(crate) fn is_subpattern_definition_enabled(&self) -> bool {
+        self.is_subpattern_defined
+            && self.flavor.is_subroutine_supported()
+            && !self.is_verbose_mode_enabled
+    }
+
+    pub(crate) fn lookbehind_assertion(&self) -> Option<&String> {
+        self.lookbehind
+            .as_ref()
+            .filter(|_| self.flavor.is_lookaround_supported())
+    }
+
+    pub(crate) fn is_permutation_lookahead_used(&self) -> bool {
+        self.flavor.is_lookaround_supported()
+            && !self.is_verbose_mode_enabled
+            && matches!(&self.permutation_separator, Some(separator) if !separator.is_empty())
+    }
+
+    pub(crate) fn lookahead_assertion(&self) -> Option<&String> {
+        self.lookahead
+            .as_ref()
+            .filter(|_| self.flavor.is_lookaround_supported())
+    }
+
+    #[cfg(feature = "unicode-names")]
+    pub(crate) fn is_unicode_name_enabled(&self) -> bool {
+        self.is_unicode_name_used
+            && self.is_non_ascii_char_escaped
+            && self.flavor.is_unicode_name_supported()
+    }
+
+    pub(crate) fn is_surrogate_pair_conversion_enabled(&self) -> bool {
+        self.is_astral_code_point_converted_to_surrogate
+            && self.is_non_ascii_char_escaped
+            && self.flavor.is_surrogate_pair_supported()
+    }
+
+    pub(crate) fn is_unicode_word_class_enabled(&self) -> bool {
+        self.is_word_char_unicode_aware
+            && self.flavor.is_shorthand_class_ascii_only()
+            && self.class_syntax() == ClassSyntax::Perl
+    }
+
+    pub(crate) fn is_atomic_grouping_enabled(&self) -> bool {
+        self.is_atomic_grouping_used && self.flavor.is_atomic_group_supported()
+    }
+
+    pub(crate) fn is_wildcard(&self, s: &str) -> bool {
+        // Pattern fragments must be taken verbatim just like wildcards
+        if self.is_input_treated_as_pattern {
+            return true;
+        }
+        match &self.placeholder {
+            Some((_, wildcard)) => s == wildcard.value(),
+            None => false,
+        }
+    }
+
     pub(crate) fn is_char_class_feature_enabled(&self) -> bool {
         self.conversion_features.iter().any(|it| it.is_char_class())
     }
 }
+
+/// A user-provided function which is applied to each grapheme cluster.
+/// Two transformations are only equal if they share the same function.
+#[derive(Clone)]
+pub(crate) struct ClusterTransformation(
+    pub(crate) Arc<dyn Fn(&GraphemeCluster) -> GraphemeCluster + Send + Sync>,
+);
+
+impl ClusterTransformation {
+    fn address(&self) -> usize {
+        Arc::as_ptr(&self.0) as *const () as usize
+    }
+}
+
+impl Debug for ClusterTransformation {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "ClusterTransformation({:#x})", self.address())
+    }
+}
+
+impl Hash for ClusterTransformation {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.address().hash(state);
+    }
+}
+
+impl PartialEq for ClusterTransformation {
+    fn eq(&self, other: &Self) -> bool {
+        self.address() == other.address()
+    }
+}
+
+impl Eq for ClusterTransformation {}
+
+impl PartialOrd for ClusterTransformation {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for ClusterTransformation {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.address().cmp(&other.address())
+    }
+}
@@ -0,0 +1,184 @@
+/*
+ * Copyright © 2019-2020 Peter M. Stahl pemistahl@gmail.com
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ * http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either expressed or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use std::error::Error;
+use std::fmt::{Display, Formatter, Result};
+use std::io;
+
+/// This enum describes the errors which can occur when reading test cases from a file with
+/// [`RegExpBuilder::from_path`](./struct.RegExpBuilder.html#method.from_path).
+#[derive(Debug)]
+pub enum InputFileError {
+    /// The file could not be read, e.g. because it does not exist
+    /// or because of conflicting permissions.
+    Io(io::Error),
+
+    /// The file's content is not valid UTF-8 data.
+    InvalidUtf8,
+
+    /// The file starts with a UTF-16 byte order mark,
+    /// but its content is not valid UTF-16 data.
+    InvalidUtf16,
+}
+
+impl Display for InputFileError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        match self {
+            InputFileError::Io(error) => write!(f, "the file could not be read: {}", error),
+            InputFileError::InvalidUtf8 => write!(f, "the file's encoding is not valid UTF-8"),
+            InputFileError::InvalidUtf16 => write!(f, "the file's encoding is not valid UTF-16"),
+        }
+    }
+}
+
+impl Error for InputFileError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            InputFileError::Io(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for InputFileError {
+    fn from(error: io::Error) -> Self {
+        InputFileError::Io(error)
+    }
+}
+
+/// This enum describes the errors which can occur when parsing a regular expression with
+/// [`RegExpBuilder::from_regexp`](./struct.RegExpBuilder.html#method.from_regexp).
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ParseError {
+    /// The regular expression is malformed at the given character position,
+    /// e.g. because of an unclosed group or character class.
+    InvalidSyntax(usize),
+
+    /// The regular expression contains the given construct which is valid syntax
+    /// but not supported, such as lookarounds, backreferences or negated character classes.
+    UnsupportedSyntax(String),
+
+    /// The regular expression matches infinitely many strings,
+    /// e.g. because of the quantifiers `*` or `+`.
+    UnboundedLanguage,
+
+    /// The regular expression matches too many strings to be enumerated as test cases.
+    LanguageTooLarge,
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        match self {
+            ParseError::InvalidSyntax(position) => {
+                write!(f, "invalid syntax at character position {}", position)
+            }
+            ParseError::UnsupportedSyntax(construct) => {
+                write!(f, "unsupported syntax: {}", construct)
+            }
+            ParseError::UnboundedLanguage => {
+                write!(f, "the regular expression matches infinitely many strings")
+            }
+            ParseError::LanguageTooLarge => {
+                write!(f, "the regular expression matches too many strings")
+            }
+        }
+    }
+}
+
+impl Error for ParseError {}
+
+/// This enum describes the errors which can occur when reading a profile with
+/// [`RegExpConfig::from_profile`](./struct.RegExpConfig.html#method.from_profile).
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ProfileError {
+    /// The given line of the profile is neither a section header
+    /// nor a valid key-value pair.
+    InvalidSyntax(usize),
+
+    /// The section `config` of the profile contains the given key
+    /// which does not denote any setting.
+    UnknownKey(String),
+
+    /// The value of the given setting has the wrong type or is out of range,
+    /// e.g. because a quantity is zero or a flavor name is unknown.
+    InvalidValue(String),
+}
+
+impl Display for ProfileError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        match self {
+            ProfileError::InvalidSyntax(line_number) => {
+                write!(f, "invalid syntax in line {}", line_number)
+            }
+            ProfileError::UnknownKey(key) => write!(f, "unknown setting: {}", key),
+            ProfileError::InvalidValue(key) => write!(f, "invalid value of setting: {}", key),
+        }
+    }
+}
+
+impl Error for ProfileError {}
+
+/// This enum describes the errors which can occur when generating a regular expression with
+/// [`RegExpBuilder::try_build_regexp`](./struct.RegExpBuilder.html#method.try_build_regexp).
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum GenerationError {
+    /// The intermediate data structures would have exceeded the given memory budget in bytes
+    /// which has been set with
+    /// [`RegExpBuilder::with_memory_budget`](./struct.RegExpBuilder.html#method.with_memory_budget).
+    MemoryBudgetExceeded(usize),
+
+    /// The given test case contains the replacement character U+FFFD which usually stems
+    /// from invalid data having been decoded lossily. This error only occurs if the rejection
+    /// of replacement characters has been enabled with
+    /// [`RegExpBuilder::with_rejection_of_replacement_chars`](./struct.RegExpBuilder.html#method.with_rejection_of_replacement_chars).
+    ReplacementCharacterFound(String),
+
+    /// The regular expression would have required an unbounded quantifier such as `*` or `+`.
+    /// This error only occurs if bounded-only generation has been enabled with
+    /// [`RegExpBuilder::with_bounded_quantifiers_only`](./struct.RegExpBuilder.html#method.with_bounded_quantifiers_only).
+    UnboundedQuantifierRequired,
+
+    /// The given test case is not a valid regular expression fragment of the chosen flavor,
+    /// e.g. because of an unclosed group. This error only occurs if test cases are treated
+    /// as patterns with
+    /// [`RegExpBuilder::with_test_cases_as_patterns`](./struct.RegExpBuilder.html#method.with_test_cases_as_patterns).
+    InvalidPatternFragment(String),
+}
+
+impl Display for GenerationError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        match self {
+            GenerationError::MemoryBudgetExceeded(budget) => {
+                write!(f, "the memory budget of {} bytes has been exceeded", budget)
+            }
+            GenerationError::ReplacementCharacterFound(test_case) => write!(
+                f,
+                "the test case {:?} contains the replacement character U+FFFD",
+                test_case
+            ),
+            GenerationError::UnboundedQuantifierRequired => {
+                write!(f, "the regular expression requires an unbounded quantifier")
+            }
+            GenerationError::InvalidPatternFragment(test_case) => write!(
+                f,
+                "the test case {:?} is not a valid regular expression fragment",
+                test_case
+            ),
+        }
+    }
+}
+
+impl Error for GenerationError {}
@@ -39,6 +39,10 @@ pub enum Feature {
     /// It takes precedence over the
     /// [`NonSpace`](./enum.Feature.html#variant.NonSpace) feature if both are set.
     /// Non-digits which are also non-space characters are converted to `\D`.
+    ///
+    /// Alternatives at the same position which are entirely matched by `\D`,
+    /// such as `\s` produced by the [`Space`](./enum.Feature.html#variant.Space) feature,
+    /// are merged into `\D`. The same holds for `\S` and `\W`.
     NonDigit,
 
     /// This feature converts any Unicode whitespace character to character class `\s`.
@@ -75,12 +79,27 @@ pub enum Feature {
     /// Non-words which are also non-space characters are converted to `\W`.
     NonWord,
 
+    /// This feature converts runs of letters which all belong to the same Unicode script
+    /// to the script's property class, e.g. `\p{Greek}` or `\p{Han}`.
+    ///
+    /// Runs are delimited by characters which are common to several scripts, such as
+    /// whitespace, digits and punctuation. Runs mixing several scripts are left as they are,
+    /// so that the other features are applied to their characters instead.
+    ///
+    /// It takes precedence over all other features converting to character classes.
+    /// It only takes effect if the chosen [`Flavor`](./enum.Flavor.html) supports script
+    /// property classes, which is the case for all flavors except for the POSIX ones.
+    Script,
+
     /// This feature detects repeated non-overlapping substrings and
     /// converts them to `{min,max}` quantifier notation.
     Repetition,
 
     /// This feature enables case-insensitive matching of test cases
     /// so that letters match both upper and lower case.
+    ///
+    /// The flag `(?i)` is omitted if all letters have been converted to character classes
+    /// which cover both upper and lower case already, such as `\w`.
     CaseInsensitivity,
 
     /// This feature replaces non-capturing groups by capturing ones.
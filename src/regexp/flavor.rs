@@ -0,0 +1,198 @@
+/*
+ * Copyright © 2019-2020 Peter M. Stahl pemistahl@gmail.com
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ * http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either expressed or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use crate::regexp::ClassSyntax;
+
+/// This enum specifies the supported regular expression flavors which can be passed to method
+/// [`RegExpBuilder.with_flavor`](./struct.RegExpBuilder.html#method.with_flavor).
+///
+/// Some settings produce syntax which is only understood by certain regular expression engines.
+/// Those settings only take effect if a flavor supporting the respective syntax is chosen.
+///
+/// The flavor also determines how the NUL character is escaped. It is written as `\x00`
+/// for every flavor except for [`Pcre`](./enum.Flavor.html#variant.Pcre) which uses the
+/// shorter `\0` unless an octal digit follows that would change its meaning.
+///
+/// The POSIX flavors are meant for piping the generated regular expression into `grep`.
+/// [`PosixBasic`](./enum.Flavor.html#variant.PosixBasic) suits plain `grep`,
+/// [`PosixExtended`](./enum.Flavor.html#variant.PosixExtended) suits `grep -E` and
+/// [`Pcre`](./enum.Flavor.html#variant.Pcre) suits `grep -P`.
+#[derive(Clone, Copy, Debug, Hash, Ord, PartialOrd, Eq, PartialEq)]
+pub enum Flavor {
+    /// The flavor of the [*regex*](https://crates.io/crates/regex) crate.
+    /// This is the default flavor.
+    Rust,
+
+    /// The flavor of [Perl-compatible regular expressions](https://www.pcre.org).
+    Pcre,
+
+    /// The flavor of POSIX basic regular expressions (BRE) as understood by `grep`.
+    ///
+    /// Groups and bounds are written as `\(`, `\)`, `\{` and `\}`, and the optional
+    /// quantifier `?` is written as `\{0,1\}`. Shorthand character classes are translated
+    /// to POSIX bracket expressions, e.g. `\d` becomes `[[:digit:]]`.
+    ///
+    /// This translation is lossy:
+    /// - Alternations are written as `\|`. This is a GNU extension which is not part of
+    ///   the POSIX standard, so other implementations of `grep` may not support it.
+    /// - All groups are capturing groups because non-capturing groups do not exist.
+    /// - POSIX bracket expressions depend on the current locale instead of
+    ///   Unicode properties, so only ASCII characters are converted to them.
+    /// - Case-insensitive matching, verbose mode and the escaping of non-ascii characters
+    ///   are not supported. Use `grep -i` for case-insensitive matching instead.
+    PosixBasic,
+
+    /// The flavor of POSIX extended regular expressions (ERE) as understood by `grep -E`.
+    ///
+    /// Shorthand character classes are translated to POSIX bracket expressions,
+    /// e.g. `\d` becomes `[[:digit:]]`.
+    ///
+    /// This translation is lossy:
+    /// - All groups are capturing groups because non-capturing groups do not exist.
+    /// - POSIX bracket expressions depend on the current locale instead of
+    ///   Unicode properties, so only ASCII characters are converted to them.
+    /// - Case-insensitive matching, verbose mode and the escaping of non-ascii characters
+    ///   are not supported. Use `grep -i` for case-insensitive matching instead.
+    PosixExtended,
+}
+
+impl Flavor {
+    pub(crate) fn is_any_grapheme_supported(&self) -> bool {
+        match self {
+            Flavor::Pcre => true,
+            Flavor::Rust | Flavor::PosixBasic | Flavor::PosixExtended => false,
+        }
+    }
+
+    pub(crate) fn nul_escape(&self, is_followed_by_non_octal_digit: bool) -> &'static str {
+        match self {
+            Flavor::Pcre if is_followed_by_non_octal_digit => "\\0",
+            _ => "\\x00",
+        }
+    }
+
+    pub(crate) fn code_point_escape(&self, c: char) -> String {
+        self.code_unit_escape(c as u32)
+    }
+
+    // Surrogates are no valid chars, so they are escaped as plain code units
+    pub(crate) fn code_unit_escape(&self, code_unit: u32) -> String {
+        match self {
+            Flavor::Pcre => format!("\\x{{{:x}}}", code_unit),
+            Flavor::Rust | Flavor::PosixBasic | Flavor::PosixExtended => {
+                format!("\\u{{{:x}}}", code_unit)
+            }
+        }
+    }
+
+    // Character classes must not be empty, so an unsatisfiable anchor is used instead
+    pub(crate) fn never_matching_pattern(&self) -> &'static str {
+        match self {
+            Flavor::Rust | Flavor::Pcre => "^\\b\\B$",
+            Flavor::PosixBasic => "^.\\(^\\)$",
+            Flavor::PosixExtended => "^.^$",
+        }
+    }
+
+    pub(crate) fn is_surrogate_pair_supported(&self) -> bool {
+        match self {
+            Flavor::Rust => true,
+            Flavor::Pcre | Flavor::PosixBasic | Flavor::PosixExtended => false,
+        }
+    }
+
+    pub(crate) fn is_continuation_anchor_supported(&self) -> bool {
+        match self {
+            Flavor::Pcre => true,
+            Flavor::Rust | Flavor::PosixBasic | Flavor::PosixExtended => false,
+        }
+    }
+
+    pub(crate) fn is_unicode_name_supported(&self) -> bool {
+        match self {
+            Flavor::Pcre => true,
+            Flavor::Rust | Flavor::PosixBasic | Flavor::PosixExtended => false,
+        }
+    }
+
+    pub(crate) fn is_shorthand_class_ascii_only(&self) -> bool {
+        match self {
+            Flavor::Pcre => true,
+            Flavor::Rust | Flavor::PosixBasic | Flavor::PosixExtended => false,
+        }
+    }
+
+    pub(crate) fn is_subroutine_supported(&self) -> bool {
+        match self {
+            Flavor::Pcre => true,
+            Flavor::Rust | Flavor::PosixBasic | Flavor::PosixExtended => false,
+        }
+    }
+
+    pub(crate) fn is_lookaround_supported(&self) -> bool {
+        match self {
+            Flavor::Pcre => true,
+            Flavor::Rust | Flavor::PosixBasic | Flavor::PosixExtended => false,
+        }
+    }
+
+    pub(crate) fn is_atomic_group_supported(&self) -> bool {
+        match self {
+            Flavor::Pcre => true,
+            Flavor::Rust | Flavor::PosixBasic | Flavor::PosixExtended => false,
+        }
+    }
+
+    pub(crate) fn is_class_syntax_supported(&self, class_syntax: ClassSyntax) -> bool {
+        match class_syntax {
+            ClassSyntax::Perl => !self.is_posix(),
+            ClassSyntax::Posix | ClassSyntax::Literal => true,
+        }
+    }
+
+    pub(crate) fn default_class_syntax(&self) -> ClassSyntax {
+        if self.is_posix() {
+            ClassSyntax::Posix
+        } else {
+            ClassSyntax::Perl
+        }
+    }
+
+    pub(crate) fn is_script_class_supported(&self) -> bool {
+        !self.is_posix()
+    }
+
+    pub(crate) fn is_posix(&self) -> bool {
+        match self {
+            Flavor::PosixBasic | Flavor::PosixExtended => true,
+            Flavor::Rust | Flavor::Pcre => false,
+        }
+    }
+
+    pub(crate) fn chars_to_escape(&self) -> &'static [&'static str] {
+        match self {
+            Flavor::Rust | Flavor::Pcre => &[
+                "(", ")", "[", "]", "{", "}", "+", "*", "-", ".", "?", "|", "^", "$",
+            ],
+            Flavor::PosixBasic => &["[", "*", ".", "^", "$"],
+            Flavor::PosixExtended => &["(", ")", "[", "{", "+", "*", ".", "?", "|", "^", "$"],
+        }
+    }
+
+    pub(crate) fn is_metachar(&self, c: char) -> bool {
+        c == '\\' || self.chars_to_escape().contains(&c.to_string().as_str())
+    }
+}
@@ -0,0 +1,140 @@
+/*
+ * Copyright © 2019-2020 Peter M. Stahl pemistahl@gmail.com
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ * http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either expressed or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use crate::regexp::Flavor;
+use std::iter::Peekable;
+use std::str::Chars;
+
+#[derive(Clone, Copy, Eq, PartialEq)]
+enum Token {
+    None,
+    Atom,
+    Quantifier,
+    QuantifierModifier,
+}
+
+/// Checks the syntax of a regular expression fragment of the given flavor.
+///
+/// Returns whether the fragment contains an alternation outside of any group,
+/// or `None` if its groups or bracket expressions are unbalanced, if it ends
+/// with a single backslash or if a quantifier does not follow anything quantifiable.
+pub(crate) fn scan_pattern_fragment(fragment: &str, flavor: Flavor) -> Option<bool> {
+    let is_basic = flavor == Flavor::PosixBasic;
+    let mut chars = fragment.chars().peekable();
+    let mut depth = 0usize;
+    let mut has_top_level_alternation = false;
+    let mut previous_token = Token::None;
+
+    while let Some(c) = chars.next() {
+        let is_escaped = c == '\\';
+        let c = if is_escaped { chars.next()? } else { c };
+        // Groups, alternations and intervals are escaped in basic POSIX syntax only
+        let is_operator = is_escaped == is_basic;
+
+        previous_token = match c {
+            '(' if is_operator => {
+                depth += 1;
+                if !flavor.is_posix() && chars.peek() == Some(&'?') {
+                    chars.next();
+                }
+                Token::None
+            }
+            ')' if is_operator => {
+                depth = depth.checked_sub(1)?;
+                Token::Atom
+            }
+            '|' if is_operator => {
+                if depth == 0 {
+                    has_top_level_alternation = true;
+                }
+                Token::None
+            }
+            '{' if is_operator && is_interval(chars.clone(), is_basic) => {
+                if previous_token != Token::Atom {
+                    return None;
+                }
+                skip_interval(&mut chars);
+                Token::Quantifier
+            }
+            _ if is_escaped => Token::Atom,
+            '[' => {
+                skip_bracket_expression(&mut chars, flavor.is_posix())?;
+                Token::Atom
+            }
+            // A leading asterisk is a literal in basic POSIX syntax
+            '*' if is_basic && previous_token == Token::None => Token::Atom,
+            '*' | '+' | '?' if !is_basic || c == '*' => match previous_token {
+                Token::Atom => Token::Quantifier,
+                Token::Quantifier if !flavor.is_posix() && c != '*' => Token::QuantifierModifier,
+                _ => return None,
+            },
+            _ => Token::Atom,
+        };
+    }
+
+    if depth == 0 {
+        Some(has_top_level_alternation)
+    } else {
+        None
+    }
+}
+
+fn is_interval(mut chars: Peekable<Chars>, is_basic: bool) -> bool {
+    let mut digit_count = 0;
+    let mut comma_count = 0;
+
+    while let Some(c) = chars.next() {
+        match c {
+            '0'..='9' => digit_count += 1,
+            ',' if digit_count > 0 && comma_count == 0 => comma_count += 1,
+            '\\' if is_basic => return digit_count > 0 && chars.next() == Some('}'),
+            '}' if !is_basic => return digit_count > 0,
+            _ => return false,
+        }
+    }
+    false
+}
+
+fn skip_interval(chars: &mut Peekable<Chars>) {
+    for c in chars {
+        if c == '}' {
+            break;
+        }
+    }
+}
+
+fn skip_bracket_expression(chars: &mut Peekable<Chars>, is_posix: bool) -> Option<()> {
+    if chars.peek() == Some(&'^') {
+        chars.next();
+    }
+    if chars.peek() == Some(&']') {
+        chars.next();
+    }
+    loop {
+        match chars.next()? {
+            ']' => return Some(()),
+            '\\' if !is_posix => {
+                chars.next()?;
+            }
+            '[' if matches!(chars.peek(), Some(':') | Some('=') | Some('.')) => {
+                let delimiter = chars.next()?;
+                while !(chars.next()? == delimiter && chars.peek() == Some(&']')) {}
+                chars.next();
+            }
+            _ => {}
+        }
+    }
+}
@@ -0,0 +1,37 @@
+/*
+ * Copyright © 2019-2020 Peter M. Stahl pemistahl@gmail.com
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ * http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either expressed or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+/// This enum specifies the line endings which multi-line test cases can be normalized to
+/// by method
+/// [`RegExpBuilder.with_line_ending_normalization`](./struct.RegExpBuilder.html#method.with_line_ending_normalization).
+#[derive(Clone, Copy, Debug, Hash, Ord, PartialOrd, Eq, PartialEq)]
+pub enum LineEnding {
+    /// Normalizes all line endings to a line feed (`\n`).
+    Lf,
+
+    /// Normalizes all line endings to a carriage return followed by a line feed (`\r\n`).
+    CrLf,
+}
+
+impl LineEnding {
+    pub(crate) fn normalize(&self, value: &str) -> String {
+        let normalized_value = value.replace("\r\n", "\n");
+        match self {
+            LineEnding::Lf => normalized_value,
+            LineEnding::CrLf => normalized_value.replace('\n', "\r\n"),
+        }
+    }
+}
@@ -14,22 +14,56 @@
  * limitations under the License.
  */
 
+mod automaton;
 mod builder;
+mod class_syntax;
 mod config;
+mod error;
 mod feature;
+mod flavor;
+mod fragment;
+mod line_ending;
+mod mutation;
+mod profile;
 
 #[allow(clippy::module_inception)]
 mod regexp;
+mod shell;
+mod snippet;
+mod stats;
+mod streaming;
+mod timings;
+mod warning;
+mod wildcard;
 
+pub use automaton::AutomatonTable;
 pub use builder::RegExpBuilder;
+pub use class_syntax::ClassSyntax;
+pub(crate) use config::ClusterTransformation;
 pub use config::RegExpConfig;
+pub use error::{GenerationError, InputFileError, ParseError, ProfileError};
 pub use feature::Feature;
+pub use flavor::Flavor;
+pub(crate) use fragment::scan_pattern_fragment;
+pub use line_ending::LineEnding;
+pub use mutation::Mutation;
+pub use profile::ProfileFormat;
+pub(crate) use profile::{read_profile, write_profile};
 pub use regexp::RegExp;
+pub use shell::Shell;
+pub use snippet::SnippetLanguage;
+pub use stats::MatchStats;
+pub use streaming::StreamingRegExpBuilder;
+pub use timings::GenerationTimings;
+pub use warning::GenerationWarning;
+pub use wildcard::Wildcard;
 
 #[cfg(test)]
 mod tests {
     use crate::regexp::Feature;
+    use crate::regexp::Mutation;
     use crate::regexp::RegExpBuilder;
+    use crate::regexp::Wildcard;
 
     #[test]
     #[should_panic(expected = "No test cases have been provided for regular expression generation")]
@@ -45,12 +79,42 @@ mod tests {
         RegExpBuilder::from(&["abc"]).with_conversion_of(&Vec::<Feature>::new());
     }
 
+    #[test]
+    #[should_panic(expected = "No test cases have been provided for regular expression generation")]
+    fn regexp_builder_panics_without_categorized_test_cases() {
+        RegExpBuilder::from_categorized(&Vec::<(String, String)>::new());
+    }
+
+    #[test]
+    #[should_panic(expected = "Categories must be valid capture group names")]
+    fn regexp_builder_panics_if_category_is_invalid_group_name() {
+        RegExpBuilder::from_categorized(&[("fruit", "apple"), ("1st-category", "cat")]);
+    }
+
     #[test]
     #[should_panic(expected = "The specified file could not be found")]
     fn regexp_builder_panics_if_file_does_not_exist() {
         RegExpBuilder::from_file("/path/to/non-existing/file");
     }
 
+    #[test]
+    #[should_panic(expected = "A backslash cannot be used as placeholder")]
+    fn regexp_builder_panics_if_placeholder_is_backslash() {
+        RegExpBuilder::from(&["abc"]).with_placeholder('\\', Wildcard::AnyChar);
+    }
+
+    #[test]
+    #[should_panic(expected = "Quantity of maximum branches must not be zero")]
+    fn regexp_builder_panics_if_maximum_branches_is_zero() {
+        RegExpBuilder::from(&["abc"]).with_maximum_branches(0);
+    }
+
+    #[test]
+    #[should_panic(expected = "Maximum factor depth must not be zero")]
+    fn regexp_builder_panics_if_maximum_factor_depth_is_zero() {
+        RegExpBuilder::from(&["abc"]).with_maximum_factor_depth(0);
+    }
+
     #[test]
     #[should_panic(expected = "Quantity of minimum repetitions must not be zero")]
     fn regexp_builder_panics_if_minimum_repetitions_is_less_than_two() {
@@ -62,4 +126,88 @@ mod tests {
     fn regexp_builder_panics_if_minimum_substring_length_is_zero() {
         RegExpBuilder::from(&["abc"]).with_minimum_substring_length(0);
     }
+
+    #[test]
+    #[should_panic(expected = "Quantity of distinct graphemes must not be zero")]
+    fn regexp_builder_panics_if_any_grapheme_threshold_is_zero() {
+        RegExpBuilder::from(&["abc"]).with_any_grapheme_threshold(0);
+    }
+
+    #[test]
+    #[should_panic(expected = "Quantity of distinct word characters must not be zero")]
+    fn regexp_builder_panics_if_word_class_threshold_is_zero() {
+        RegExpBuilder::from(&["abc"]).with_word_class_threshold(0);
+    }
+
+    #[test]
+    #[should_panic(expected = "Percentage of test cases must be between 1 and 100")]
+    fn regexp_builder_panics_if_percentage_of_test_cases_exceeds_hundred() {
+        RegExpBuilder::from(&["abc"]).with_class_conversion_at_common_positions(101);
+    }
+
+    #[test]
+    #[should_panic(expected = "Quantifier threshold must not be zero")]
+    fn regexp_builder_panics_if_quantifier_threshold_is_zero() {
+        RegExpBuilder::from(&["abc"]).with_quantifier_threshold(0);
+    }
+
+    #[test]
+    #[should_panic(expected = "Characters to be escaped must be ASCII punctuation characters")]
+    fn regexp_builder_panics_if_escaped_char_is_not_ascii_punctuation() {
+        RegExpBuilder::from(&["abc"]).with_escaping_of(&['/', 'a']);
+    }
+
+    #[test]
+    #[should_panic(expected = "Memory budget must not be zero")]
+    fn regexp_builder_panics_if_memory_budget_is_zero() {
+        RegExpBuilder::from(&["abc"]).with_memory_budget(0);
+    }
+
+    #[test]
+    #[should_panic(expected = "Separators must not be whitespace characters")]
+    fn regexp_builder_panics_if_separator_is_whitespace() {
+        RegExpBuilder::from(&["a b"]).with_optional_whitespace_around(&[',', ' ']);
+    }
+
+    #[test]
+    #[should_panic(expected = "No character class syntaxes have been provided")]
+    fn regexp_builder_panics_if_class_syntax_preference_is_empty() {
+        RegExpBuilder::from(&["abc"]).with_class_syntax_preference(&[]);
+    }
+
+    #[test]
+    #[should_panic(expected = "No mutations have been provided for regular expression generation")]
+    fn regexp_builder_panics_if_mutations_are_empty() {
+        RegExpBuilder::from(&["abc"]).with_mutations(&[]);
+    }
+
+    #[test]
+    #[should_panic(expected = "Quantity of transpositions must not be zero")]
+    fn regexp_builder_panics_if_quantity_of_transpositions_is_zero() {
+        RegExpBuilder::from(&["abc"]).with_mutations(&[Mutation::Transposition(0)]);
+    }
+
+    #[test]
+    #[should_panic(expected = "Lookbehind assertion must be a valid regular expression fragment")]
+    fn regexp_builder_panics_if_lookbehind_is_empty() {
+        RegExpBuilder::from(&["abc"]).with_lookbehind("");
+    }
+
+    #[test]
+    #[should_panic(expected = "Lookahead assertion must be a valid regular expression fragment")]
+    fn regexp_builder_panics_if_lookahead_is_invalid() {
+        RegExpBuilder::from(&["abc"]).with_lookahead("(a");
+    }
+
+    #[test]
+    #[should_panic(expected = "Minimum quantity of tokens must not be zero")]
+    fn regexp_builder_panics_if_minimum_quantity_of_tokens_is_zero() {
+        RegExpBuilder::from(&["abc"]).with_repeated_tokens(",", 0, 2);
+    }
+
+    #[test]
+    #[should_panic(expected = "Minimum quantity of tokens must not exceed maximum quantity")]
+    fn regexp_builder_panics_if_minimum_quantity_of_tokens_exceeds_maximum() {
+        RegExpBuilder::from(&["abc"]).with_repeated_tokens(",", 3, 2);
+    }
 }
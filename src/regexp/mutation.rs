@@ -0,0 +1,35 @@
+/*
+ * Copyright © 2019-2020 Peter M. Stahl pemistahl@gmail.com
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ * http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either expressed or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+/// This enum specifies the variations of the test cases which should be matched as well
+/// and which can be passed to method
+/// [`RegExpBuilder.with_mutations`](./struct.RegExpBuilder.html#method.with_mutations).
+#[derive(Clone, Copy, Debug, Hash, Ord, PartialOrd, Eq, PartialEq)]
+pub enum Mutation {
+    /// Lets every digit be replaced with any other digit by converting it to `\d`
+    /// which matches any Unicode decimal digit.
+    DigitForDigit,
+
+    /// Lets every letter be replaced with its counterpart in the other case
+    /// by matching the resulting regular expression case-insensitively.
+    /// This mutation is not supported by the POSIX flavors.
+    CaseFlip,
+
+    /// Lets up to the given number of pairs of adjacent graphemes be swapped,
+    /// so that the test case `form` also matches the typo `from`.
+    /// The swapped pairs of one test case do not overlap.
+    Transposition(u32),
+}
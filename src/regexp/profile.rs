@@ -0,0 +1,638 @@
+/*
+ * Copyright © 2019-2020 Peter M. Stahl pemistahl@gmail.com
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ * http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either expressed or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use crate::regexp::{
+    ClassSyntax, Feature, Flavor, LineEnding, Mutation, ProfileError, RegExpConfig, Wildcard,
+};
+use std::collections::BTreeSet;
+use std::fmt::Debug;
+
+/// This enum specifies the document formats in which method
+/// [`RegExp.to_profile`](./struct.RegExp.html#method.to_profile) writes a profile.
+/// Both formats can be read back with
+/// [`RegExpConfig::from_profile`](./struct.RegExpConfig.html#method.from_profile).
+#[derive(Clone, Copy, Debug, Hash, Ord, PartialOrd, Eq, PartialEq)]
+pub enum ProfileFormat {
+    /// Writes the profile as a [TOML](https://toml.io) document
+    /// with the tables `[metrics]` and `[config]`.
+    Toml,
+
+    /// Writes the profile as a [YAML](https://yaml.org) document
+    /// with the mappings `metrics` and `config`.
+    Yaml,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+enum Value {
+    Bool(bool),
+    Integer(u64),
+    Text(String),
+    List(Vec<Value>),
+}
+
+macro_rules! boolean_settings {
+    ($($field:ident),* $(,)?) => {
+        fn boolean_entries(config: &RegExpConfig) -> Vec<(&'static str, Value)> {
+            vec![$((stringify!($field), Value::Bool(config.$field))),*]
+        }
+
+        fn boolean_setting<'a>(config: &'a mut RegExpConfig, key: &str) -> Option<&'a mut bool> {
+            match key {
+                $(stringify!($field) => Some(&mut config.$field),)*
+                _ => None,
+            }
+        }
+    };
+}
+
+boolean_settings!(
+    is_non_ascii_char_escaped,
+    is_astral_code_point_converted_to_surrogate,
+    is_control_char_escaped,
+    is_unicode_name_used,
+    is_output_colorized,
+    is_repetition_upper_bound_relaxed,
+    is_repetition_strict,
+    is_atomic_grouping_used,
+    is_word_char_unicode_aware,
+    is_case_expanded_to_classes,
+    is_case_folding_full,
+    is_deduplication_case_insensitive,
+    is_last_duplicate_kept,
+    is_branch_order_weighted,
+    is_bounded_only,
+    is_whole_pattern_optional,
+    is_continuation_anchor_used,
+    is_start_anchor_disabled,
+    is_end_anchor_disabled,
+    is_start_word_boundary_used,
+    is_end_word_boundary_used,
+    is_trailing_catch_all_used,
+    is_internal_word_boundary_used,
+    is_ipv4_structured,
+    is_date_time_structured,
+    is_hex_detected,
+    is_csv_structured,
+    is_skeleton_grouped,
+    is_semver_structured,
+    is_verbose_mode_enabled,
+    is_ignore_case_flag_scoped,
+    is_outer_group_always_used,
+    is_subpattern_defined,
+    is_length_minimized,
+    is_dot_matching_newlines,
+    is_branch_commented,
+    is_zwj_sequence_split,
+    is_suffix_factored,
+    is_literal_only,
+    is_input_treated_as_pattern,
+    is_substring_branch_kept,
+    is_prefix_matched,
+    is_input_retained,
+    is_replacement_char_rejected,
+);
+
+const FEATURES: [Feature; 10] = [
+    Feature::Digit,
+    Feature::NonDigit,
+    Feature::Space,
+    Feature::NonSpace,
+    Feature::Word,
+    Feature::NonWord,
+    Feature::Script,
+    Feature::Repetition,
+    Feature::CaseInsensitivity,
+    Feature::CapturingGroup,
+];
+const FLAVORS: [Flavor; 4] = [
+    Flavor::Rust,
+    Flavor::Pcre,
+    Flavor::PosixBasic,
+    Flavor::PosixExtended,
+];
+const CLASS_SYNTAXES: [ClassSyntax; 3] =
+    [ClassSyntax::Perl, ClassSyntax::Posix, ClassSyntax::Literal];
+const LINE_ENDINGS: [LineEnding; 2] = [LineEnding::Lf, LineEnding::CrLf];
+const WILDCARDS: [Wildcard; 3] = [Wildcard::AnyChar, Wildcard::Digit, Wildcard::Word];
+
+pub(crate) fn write_profile(
+    format: ProfileFormat,
+    pattern: &str,
+    metrics: &[(&str, usize)],
+    config: &RegExpConfig,
+) -> String {
+    let sections = vec![
+        (
+            "metrics",
+            metrics
+                .iter()
+                .map(|&(key, metric)| (key, Value::Integer(metric as u64)))
+                .collect(),
+        ),
+        ("config", config_entries(config)),
+    ];
+    let mut profile = String::new();
+
+    match format {
+        ProfileFormat::Toml => {
+            profile.push_str(&format!("pattern = {}\n", quote(pattern)));
+            for (section, entries) in sections {
+                profile.push_str(&format!("\n[{}]\n", section));
+                for (key, value) in entries {
+                    profile.push_str(&format!("{} = {}\n", key, render(&value)));
+                }
+            }
+        }
+        ProfileFormat::Yaml => {
+            profile.push_str(&format!("pattern: {}\n", quote(pattern)));
+            for (section, entries) in sections {
+                profile.push_str(&format!("{}:\n", section));
+                for (key, value) in entries {
+                    profile.push_str(&format!("  {}: {}\n", key, render(&value)));
+                }
+            }
+        }
+    }
+
+    profile
+}
+
+pub(crate) fn read_profile(profile: &str) -> Result<RegExpConfig, ProfileError> {
+    let mut config = RegExpConfig::new();
+    let mut section = String::new();
+
+    for (index, line) in profile.lines().enumerate() {
+        let line_number = index + 1;
+        let trimmed_line = line.trim();
+
+        if trimmed_line.is_empty() || trimmed_line.starts_with('#') || trimmed_line == "---" {
+            continue;
+        }
+        if trimmed_line.starts_with('[') {
+            match trimmed_line
+                .strip_prefix('[')
+                .and_then(|it| it.strip_suffix(']'))
+            {
+                Some(name) if is_key(name.trim()) => section = name.trim().to_string(),
+                _ => return Err(ProfileError::InvalidSyntax(line_number)),
+            }
+            continue;
+        }
+
+        let key_length = trimmed_line
+            .find(|c: char| !(c.is_ascii_alphanumeric() || c == '_'))
+            .unwrap_or(trimmed_line.len());
+        let (key, rest) = trimmed_line.split_at(key_length);
+        let mut rest = rest.trim_start().chars();
+        let separator = rest.next();
+        let rest = rest.as_str().trim();
+        if key.is_empty() || !matches!(separator, Some('=') | Some(':')) {
+            return Err(ProfileError::InvalidSyntax(line_number));
+        }
+
+        // Unindented YAML keys belong to the top level, unlike the keys of a TOML table
+        if separator == Some(':') && !line.starts_with(char::is_whitespace) {
+            if rest.is_empty() {
+                section = key.to_string();
+                continue;
+            }
+            section.clear();
+        }
+
+        let value = parse_value(rest).ok_or(ProfileError::InvalidSyntax(line_number))?;
+        if section == "config" {
+            apply_entry(&mut config, key, value)?;
+        }
+    }
+
+    Ok(config)
+}
+
+fn config_entries(config: &RegExpConfig) -> Vec<(&'static str, Value)> {
+    let mut entries = vec![
+        ("flavor", name_of(&config.flavor)),
+        (
+            "conversion_features",
+            Value::List(config.conversion_features.iter().map(name_of).collect()),
+        ),
+        (
+            "minimum_repetitions",
+            Value::Integer(config.minimum_repetitions as u64),
+        ),
+        (
+            "minimum_substring_length",
+            Value::Integer(config.minimum_substring_length as u64),
+        ),
+        (
+            "repetition_excluded_chars",
+            chars_of(&config.repetition_excluded_chars),
+        ),
+        ("escaped_chars", chars_of(&config.escaped_chars)),
+        ("unescaped_chars", chars_of(&config.unescaped_chars)),
+        ("separators", chars_of(&config.separators)),
+        (
+            "class_syntax_preference",
+            Value::List(config.class_syntax_preference.iter().map(name_of).collect()),
+        ),
+        (
+            "mutations",
+            Value::List(config.mutations.iter().map(name_of).collect()),
+        ),
+    ];
+
+    entries.extend(boolean_entries(config));
+
+    let optional_entries = vec![
+        ("line_ending", config.line_ending.as_ref().map(name_of)),
+        (
+            "token_repetition",
+            config.token_repetition.as_ref().map(|(token, min, max)| {
+                Value::List(vec![
+                    Value::Text(token.clone()),
+                    Value::Integer(*min as u64),
+                    Value::Integer(*max as u64),
+                ])
+            }),
+        ),
+        (
+            "permutation_separator",
+            config.permutation_separator.clone().map(Value::Text),
+        ),
+        ("lookbehind", config.lookbehind.clone().map(Value::Text)),
+        ("lookahead", config.lookahead.clone().map(Value::Text)),
+        (
+            "any_grapheme_threshold",
+            config
+                .any_grapheme_threshold
+                .map(|it| Value::Integer(it as u64)),
+        ),
+        (
+            "word_class_threshold",
+            config
+                .word_class_threshold
+                .map(|it| Value::Integer(it as u64)),
+        ),
+        (
+            "class_conversion_coverage",
+            config
+                .class_conversion_coverage
+                .map(|it| Value::Integer(it as u64)),
+        ),
+        (
+            "quantifier_threshold",
+            config
+                .quantifier_threshold
+                .map(|it| Value::Integer(it as u64)),
+        ),
+        (
+            "placeholder",
+            config.placeholder.as_ref().map(|(c, wildcard)| {
+                Value::List(vec![Value::Text(c.to_string()), name_of(wildcard)])
+            }),
+        ),
+        (
+            "maximum_branches",
+            config.maximum_branches.map(|it| Value::Integer(it as u64)),
+        ),
+        (
+            "maximum_factor_depth",
+            config
+                .maximum_factor_depth
+                .map(|it| Value::Integer(it as u64)),
+        ),
+        (
+            "memory_budget",
+            config.memory_budget.map(|it| Value::Integer(it as u64)),
+        ),
+    ];
+
+    for (key, value) in optional_entries {
+        if let Some(value) = value {
+            entries.push((key, value));
+        }
+    }
+
+    entries
+}
+
+fn apply_entry(config: &mut RegExpConfig, key: &str, value: Value) -> Result<(), ProfileError> {
+    let invalid_value = || ProfileError::InvalidValue(key.to_string());
+
+    if let Some(setting) = boolean_setting(config, key) {
+        *setting = match value {
+            Value::Bool(flag) => flag,
+            _ => return Err(invalid_value()),
+        };
+        return Ok(());
+    }
+
+    match key {
+        "flavor" => config.flavor = variant_of(&value, &FLAVORS).ok_or_else(invalid_value)?,
+        "conversion_features" => {
+            config.conversion_features =
+                list_of(&value, |it| variant_of(it, &FEATURES)).ok_or_else(invalid_value)?
+        }
+        "minimum_repetitions" => {
+            config.minimum_repetitions = positive_number_of(&value).ok_or_else(invalid_value)?
+        }
+        "minimum_substring_length" => {
+            config.minimum_substring_length =
+                positive_number_of(&value).ok_or_else(invalid_value)?
+        }
+        "repetition_excluded_chars" => {
+            config.repetition_excluded_chars = char_set_of(&value).ok_or_else(invalid_value)?
+        }
+        "escaped_chars" => config.escaped_chars = char_set_of(&value).ok_or_else(invalid_value)?,
+        "unescaped_chars" => {
+            config.unescaped_chars = char_set_of(&value).ok_or_else(invalid_value)?
+        }
+        "separators" => config.separators = char_set_of(&value).ok_or_else(invalid_value)?,
+        "class_syntax_preference" => {
+            config.class_syntax_preference =
+                list_of(&value, |it| variant_of(it, &CLASS_SYNTAXES)).ok_or_else(invalid_value)?
+        }
+        "mutations" => config.mutations = list_of(&value, mutation_of).ok_or_else(invalid_value)?,
+        "line_ending" => {
+            config.line_ending = Some(variant_of(&value, &LINE_ENDINGS).ok_or_else(invalid_value)?)
+        }
+        "token_repetition" => {
+            config.token_repetition = match &value {
+                Value::List(items) => match items.as_slice() {
+                    [Value::Text(token), min, max] => {
+                        match (positive_number_of(min), positive_number_of(max)) {
+                            (Some(min), Some(max)) if !token.is_empty() && min <= max => {
+                                Some((token.clone(), min, max))
+                            }
+                            _ => None,
+                        }
+                    }
+                    _ => None,
+                },
+                _ => None,
+            }
+            .map(Some)
+            .ok_or_else(invalid_value)?
+        }
+        "lookbehind" => config.lookbehind = Some(text_of(&value).ok_or_else(invalid_value)?),
+        "permutation_separator" => {
+            config.permutation_separator = Some(text_of(&value).ok_or_else(invalid_value)?)
+        }
+        "lookahead" => config.lookahead = Some(text_of(&value).ok_or_else(invalid_value)?),
+        "any_grapheme_threshold" => {
+            config.any_grapheme_threshold =
+                Some(positive_number_of(&value).ok_or_else(invalid_value)?)
+        }
+        "word_class_threshold" => {
+            config.word_class_threshold =
+                Some(positive_number_of(&value).ok_or_else(invalid_value)?)
+        }
+        "class_conversion_coverage" => {
+            config.class_conversion_coverage = Some(
+                positive_number_of(&value)
+                    .filter(|&it| it <= 100)
+                    .ok_or_else(invalid_value)?,
+            )
+        }
+        "quantifier_threshold" => {
+            config.quantifier_threshold =
+                Some(positive_number_of(&value).ok_or_else(invalid_value)?)
+        }
+        "placeholder" => {
+            config.placeholder = match &value {
+                Value::List(items) => match items.as_slice() {
+                    [c, wildcard] => match (char_set_of(c), variant_of(wildcard, &WILDCARDS)) {
+                        (Some(chars), Some(wildcard)) if chars.len() == 1 => {
+                            chars.into_iter().next().map(|c| (c, wildcard))
+                        }
+                        _ => None,
+                    },
+                    _ => None,
+                },
+                _ => None,
+            }
+            .filter(|(c, _)| *c != '\\')
+            .map(Some)
+            .ok_or_else(invalid_value)?
+        }
+        "maximum_branches" => {
+            config.maximum_branches = Some(positive_number_of(&value).ok_or_else(invalid_value)?)
+        }
+        "maximum_factor_depth" => {
+            config.maximum_factor_depth =
+                Some(positive_number_of(&value).ok_or_else(invalid_value)?)
+        }
+        "memory_budget" => {
+            config.memory_budget = match value {
+                Value::Integer(budget) if budget > 0 => Some(budget as usize),
+                _ => return Err(invalid_value()),
+            }
+        }
+        _ => return Err(ProfileError::UnknownKey(key.to_string())),
+    }
+
+    Ok(())
+}
+
+fn name_of<T: Debug>(variant: &T) -> Value {
+    Value::Text(format!("{:?}", variant))
+}
+
+fn chars_of(chars: &BTreeSet<char>) -> Value {
+    Value::Text(chars.iter().collect())
+}
+
+fn variant_of<T: Clone + Debug>(value: &Value, variants: &[T]) -> Option<T> {
+    match value {
+        Value::Text(name) => variants
+            .iter()
+            .find(|it| format!("{:?}", it) == *name)
+            .cloned(),
+        _ => None,
+    }
+}
+
+fn mutation_of(value: &Value) -> Option<Mutation> {
+    match value {
+        Value::Text(name) => match name.as_str() {
+            "DigitForDigit" => Some(Mutation::DigitForDigit),
+            "CaseFlip" => Some(Mutation::CaseFlip),
+            _ => name
+                .strip_prefix("Transposition(")
+                .and_then(|it| it.strip_suffix(')'))
+                .and_then(|it| it.parse::<u32>().ok())
+                .filter(|&it| it > 0)
+                .map(Mutation::Transposition),
+        },
+        _ => None,
+    }
+}
+
+fn list_of<T>(value: &Value, item_of: impl Fn(&Value) -> Option<T>) -> Option<Vec<T>> {
+    match value {
+        Value::List(items) => items.iter().map(item_of).collect(),
+        _ => None,
+    }
+}
+
+fn char_set_of(value: &Value) -> Option<BTreeSet<char>> {
+    text_of(value).map(|it| it.chars().collect())
+}
+
+fn text_of(value: &Value) -> Option<String> {
+    match value {
+        Value::Text(text) => Some(text.clone()),
+        _ => None,
+    }
+}
+
+fn positive_number_of(value: &Value) -> Option<u32> {
+    match value {
+        Value::Integer(number) if *number > 0 && *number <= u32::MAX as u64 => Some(*number as u32),
+        _ => None,
+    }
+}
+
+fn is_key(s: &str) -> bool {
+    !s.is_empty() && s.chars().all(|c| c.is_ascii_alphanumeric() || c == '_')
+}
+
+fn render(value: &Value) -> String {
+    match value {
+        Value::Bool(flag) => flag.to_string(),
+        Value::Integer(number) => number.to_string(),
+        Value::Text(text) => quote(text),
+        Value::List(items) => format!(
+            "[{}]",
+            items.iter().map(render).collect::<Vec<_>>().join(", ")
+        ),
+    }
+}
+
+// Double-quoted strings with these escape sequences are valid in both TOML and YAML
+fn quote(s: &str) -> String {
+    let mut quoted = String::from('"');
+    for c in s.chars() {
+        match c {
+            '"' => quoted.push_str("\\\""),
+            '\\' => quoted.push_str("\\\\"),
+            '\n' => quoted.push_str("\\n"),
+            '\r' => quoted.push_str("\\r"),
+            '\t' => quoted.push_str("\\t"),
+            c if c.is_control() => quoted.push_str(&format!("\\u{:04X}", c as u32)),
+            c => quoted.push(c),
+        }
+    }
+    quoted.push('"');
+    quoted
+}
+
+fn parse_value(s: &str) -> Option<Value> {
+    let mut chars = s.chars().peekable();
+    let value = parse_next_value(&mut chars)?;
+    let rest = chars.collect::<String>();
+    let rest = rest.trim();
+    if rest.is_empty() || rest.starts_with('#') {
+        Some(value)
+    } else {
+        None
+    }
+}
+
+fn parse_next_value(chars: &mut std::iter::Peekable<std::str::Chars>) -> Option<Value> {
+    skip_whitespace(chars);
+    match chars.peek()? {
+        '"' => {
+            chars.next();
+            parse_quoted_text(chars).map(Value::Text)
+        }
+        '[' => {
+            chars.next();
+            let mut items = vec![];
+            skip_whitespace(chars);
+            if chars.peek() == Some(&']') {
+                chars.next();
+                return Some(Value::List(items));
+            }
+            loop {
+                items.push(parse_next_value(chars)?);
+                skip_whitespace(chars);
+                match chars.next()? {
+                    ',' => continue,
+                    ']' => return Some(Value::List(items)),
+                    _ => return None,
+                }
+            }
+        }
+        _ => {
+            let mut word = String::new();
+            while let Some(&c) = chars.peek() {
+                if !c.is_ascii_alphanumeric() {
+                    break;
+                }
+                word.push(c);
+                chars.next();
+            }
+            match word.as_str() {
+                "true" => Some(Value::Bool(true)),
+                "false" => Some(Value::Bool(false)),
+                _ => word.parse::<u64>().ok().map(Value::Integer),
+            }
+        }
+    }
+}
+
+fn parse_quoted_text(chars: &mut std::iter::Peekable<std::str::Chars>) -> Option<String> {
+    let mut text = String::new();
+    loop {
+        match chars.next()? {
+            '"' => return Some(text),
+            '\\' => {
+                let c = match chars.next()? {
+                    '"' => '"',
+                    '\\' => '\\',
+                    '/' => '/',
+                    'n' => '\n',
+                    'r' => '\r',
+                    't' => '\t',
+                    'u' => parse_code_point(chars, 4)?,
+                    'U' => parse_code_point(chars, 8)?,
+                    _ => return None,
+                };
+                text.push(c);
+            }
+            c => text.push(c),
+        }
+    }
+}
+
+fn parse_code_point(
+    chars: &mut std::iter::Peekable<std::str::Chars>,
+    digit_count: usize,
+) -> Option<char> {
+    let digits = chars.take(digit_count).collect::<String>();
+    if digits.len() != digit_count {
+        return None;
+    }
+    u32::from_str_radix(&digits, 16)
+        .ok()
+        .and_then(std::char::from_u32)
+}
+
+fn skip_whitespace(chars: &mut std::iter::Peekable<std::str::Chars>) {
+    while matches!(chars.peek(), Some(c) if c.is_whitespace()) {
+        chars.next();
+    }
+}
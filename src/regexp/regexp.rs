@@ -15,41 +15,1712 @@
  */
 
 use crate::ast::Expression;
-use crate::char::{ColorizableString, GraphemeCluster};
+use crate::char::{
+    case_variants, ColorizableString, Grapheme, GraphemeCluster, DIGIT_PLACEHOLDER,
+    HEX_DIGIT_CLASSES,
+};
 use crate::fsm::DFA;
 use crate::regexp::config::RegExpConfig;
+use crate::regexp::timings::measure;
+use crate::regexp::{
+    scan_pattern_fragment, write_profile, AutomatonTable, ClassSyntax, ClusterTransformation,
+    Feature, GenerationError, GenerationTimings, GenerationWarning, LineEnding, MatchStats,
+    ProfileFormat, Shell, SnippetLanguage,
+};
 use colored::ColoredString;
 use itertools::Itertools;
-use std::cmp::Ordering;
+use std::cmp::{Ordering, Reverse};
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
 use std::fmt::{Display, Formatter, Result};
+use std::io::{self, Write};
+use unicode_segmentation::UnicodeSegmentation;
 
+const MAXIMUM_PERMUTED_TOKENS: usize = 5;
+const DATE_TIME_LAYOUTS: [&str; 3] = ["####-##-##T##:##:##", "####-##-##", "##:##:##"];
+
+/// This struct represents a regular expression generated from user-provided test cases.
+/// It is created by [`RegExpBuilder::build_regexp`](./struct.RegExpBuilder.html#method.build_regexp).
 pub struct RegExp {
     ast: Expression,
+    categories: Vec<(String, Expression)>,
+    dfa: DFA,
+    alphabet: BTreeSet<char>,
+    inputs: Vec<String>,
+    test_cases: Vec<String>,
+    grapheme_clusters: Vec<GraphemeCluster>,
     config: RegExpConfig,
 }
 
 impl RegExp {
-    pub(crate) fn from(test_cases: &mut Vec<String>, config: &RegExpConfig) -> Self {
+    pub(crate) fn from(
+        test_cases: &[String],
+        config: &RegExpConfig,
+    ) -> std::result::Result<Self, GenerationError> {
+        Ok(Self::from_with_warnings(test_cases, config)?.0)
+    }
+
+    pub(crate) fn from_with_warnings(
+        test_cases: &[String],
+        config: &RegExpConfig,
+    ) -> std::result::Result<(Self, Vec<GenerationWarning>), GenerationError> {
+        Self::generate(&mut test_cases.to_vec(), config, &mut None)
+    }
+
+    pub(crate) fn from_timed(
+        test_cases: &[String],
+        config: &RegExpConfig,
+    ) -> std::result::Result<(Self, GenerationTimings), GenerationError> {
+        let mut timings = Some(GenerationTimings::default());
+        let (regexp, _) = Self::generate(&mut test_cases.to_vec(), config, &mut timings)?;
+        Ok((regexp, timings.unwrap()))
+    }
+
+    pub(crate) fn from_categorized(
+        categorized_test_cases: &[(String, String)],
+        config: &RegExpConfig,
+    ) -> std::result::Result<Self, GenerationError> {
+        Ok(Self::from_categorized_with_warnings(categorized_test_cases, config)?.0)
+    }
+
+    pub(crate) fn from_categorized_with_warnings(
+        categorized_test_cases: &[(String, String)],
+        config: &RegExpConfig,
+    ) -> std::result::Result<(Self, Vec<GenerationWarning>), GenerationError> {
+        Self::generate_categorized(categorized_test_cases, config, &mut None)
+    }
+
+    pub(crate) fn from_categorized_timed(
+        categorized_test_cases: &[(String, String)],
+        config: &RegExpConfig,
+    ) -> std::result::Result<(Self, GenerationTimings), GenerationError> {
+        let mut timings = Some(GenerationTimings::default());
+        let (regexp, _) = Self::generate_categorized(categorized_test_cases, config, &mut timings)?;
+        Ok((regexp, timings.unwrap()))
+    }
+
+    fn generate_categorized(
+        categorized_test_cases: &[(String, String)],
+        config: &RegExpConfig,
+        timings: &mut Option<GenerationTimings>,
+    ) -> std::result::Result<(Self, Vec<GenerationWarning>), GenerationError> {
+        let mut test_cases = categorized_test_cases
+            .iter()
+            .map(|(_, test_case)| test_case.clone())
+            .collect_vec();
+        let categorized_config = RegExpConfig {
+            permutation_separator: None,
+            ..config.clone()
+        };
+        let (mut regexp, mut warnings) =
+            Self::generate(&mut test_cases, &categorized_config, timings)?;
+
+        let mut categories: Vec<(&String, Vec<String>)> = vec![];
+        for (category, test_case) in categorized_test_cases {
+            match categories.iter_mut().find(|(name, _)| *name == category) {
+                Some((_, category_test_cases)) => category_test_cases.push(test_case.clone()),
+                None => categories.push((category, vec![test_case.clone()])),
+            }
+        }
+        for (category, mut category_test_cases) in categories {
+            let (category_regexp, _) =
+                Self::generate(&mut category_test_cases, &categorized_config, timings)?;
+            regexp
+                .categories
+                .push((category.clone(), category_regexp.ast));
+        }
+
+        if config.flavor.is_posix() {
+            warnings.push(GenerationWarning::SettingIgnored(
+                "named capture groups are not supported by the chosen flavor".to_string(),
+            ));
+        }
+        if config.token_repetition.is_some() {
+            warnings.push(GenerationWarning::SettingIgnored(
+                "repeated tokens are not supported for categorized test cases".to_string(),
+            ));
+        }
+        if config.permutation_separator.is_some() {
+            warnings.push(GenerationWarning::SettingIgnored(
+                "permuted tokens are not supported for categorized test cases".to_string(),
+            ));
+        }
+
+        Ok((regexp, warnings))
+    }
+
+    fn generate(
+        test_cases: &mut Vec<String>,
+        config: &RegExpConfig,
+        timings: &mut Option<GenerationTimings>,
+    ) -> std::result::Result<(Self, Vec<GenerationWarning>), GenerationError> {
+        if config.is_replacement_char_rejected {
+            if let Some(test_case) = test_cases.iter().find(|it| it.contains('\u{fffd}')) {
+                return Err(GenerationError::ReplacementCharacterFound(
+                    test_case.clone(),
+                ));
+            }
+        }
+        if config.is_input_treated_as_pattern {
+            if let Some(test_case) = test_cases
+                .iter()
+                .find(|it| scan_pattern_fragment(it, config.flavor).is_none())
+            {
+                return Err(GenerationError::InvalidPatternFragment(test_case.clone()));
+            }
+        }
+        let inputs = if config.is_input_retained {
+            test_cases.clone()
+        } else {
+            vec![]
+        };
+        let mut warnings = Self::collect_ignored_settings(config);
+        for &c in config.unescaped_chars.iter() {
+            if config.flavor.is_metachar(c)
+                && config.is_char_unescaped(c)
+                && !config.escaped_chars.contains(&c)
+            {
+                warnings.push(GenerationWarning::MetacharacterUnescaped(c));
+            }
+        }
+        let effective_config = Self::effective_config(config);
+        let config = &effective_config;
+        let test_case_count = test_cases.len();
+
+        if let Some(line_ending) = config.line_ending {
+            Self::normalize_line_endings(test_cases, line_ending);
+        }
         if config.is_case_insensitive_matching() {
             Self::convert_to_lowercase(test_cases);
+        } else if config.is_deduplication_case_insensitive {
+            Self::remove_case_insensitive_duplicates(test_cases, config.is_last_duplicate_kept);
         }
+        let frequencies = if config.is_branch_order_weighted {
+            test_cases
+                .iter()
+                .fold(HashMap::new(), |mut frequencies, it| {
+                    *frequencies.entry(it.clone()).or_insert(0) += 1;
+                    frequencies
+                })
+        } else {
+            HashMap::new()
+        };
         Self::sort(test_cases);
-        let grapheme_clusters = Self::grapheme_clusters(&test_cases, config);
-        let dfa = DFA::from(grapheme_clusters, config);
-        let ast = Expression::from(dfa, config);
-        Self {
+
+        if test_cases.len() < test_case_count {
+            warnings.push(GenerationWarning::DuplicateTestCasesRemoved(
+                test_case_count - test_cases.len(),
+            ));
+        }
+        if !config.separators.is_empty() {
+            Self::remove_whitespace_around_separators(test_cases, &config.separators);
+            Self::sort(test_cases);
+        }
+        let permuted_config;
+        let config = if let Some(separator) = &config.permutation_separator {
+            permuted_config = Self::permute_tokens(test_cases, separator, config, &mut warnings);
+            &permuted_config
+        } else {
+            config
+        };
+
+        let mut grapheme_clusters = measure(
+            timings,
+            |it| &mut it.clustering,
+            || {
+                if config.is_input_treated_as_pattern {
+                    test_cases
+                        .iter()
+                        .map(|it| GraphemeCluster::from_pattern_fragment(it, config))
+                        .collect_vec()
+                } else {
+                    test_cases
+                        .iter()
+                        .map(|it| GraphemeCluster::from(it, config))
+                        .collect_vec()
+                }
+            },
+        );
+        let alphabet = Self::collect_alphabet(&grapheme_clusters, config);
+        if !config.mutations.is_empty() {
+            for cluster in grapheme_clusters.iter_mut() {
+                cluster.apply_mutations();
+            }
+        }
+        let shorthands = match Self::find_ipv4_octet_bounds(test_cases) {
+            Some(octet_bounds) if config.is_ipv4_structured => {
+                let cluster = GraphemeCluster::from_ipv4_octet_bounds(&octet_bounds, config);
+                grapheme_clusters = vec![cluster; test_cases.len()];
+                btreeset!["\\d".to_string()]
+            }
+            _ => match Self::find_date_time_layout(test_cases) {
+                Some(layout) if config.is_date_time_structured => {
+                    let cluster = GraphemeCluster::from_digit_layout(layout, config);
+                    grapheme_clusters = vec![cluster; test_cases.len()];
+                    btreeset!["\\d".to_string()]
+                }
+                _ => match Self::find_hex_digit_bounds(test_cases) {
+                    Some((hex_class, min, max)) if config.is_hex_detected => {
+                        let cluster = GraphemeCluster::from_hex_digits(hex_class, min, max, config);
+                        grapheme_clusters = vec![cluster; test_cases.len()];
+                        btreeset![hex_class.to_string()]
+                    }
+                    _ => Self::convert_grapheme_clusters(&mut grapheme_clusters, config, timings),
+                },
+            },
+        };
+        for shorthand in shorthands {
+            warnings.push(GenerationWarning::CharacterClassGeneralized(shorthand));
+        }
+        if let Some(ClusterTransformation(transformation)) = &config.cluster_transformation {
+            grapheme_clusters = grapheme_clusters
+                .iter()
+                .map(|it| transformation(it))
+                .collect_vec();
+        }
+        if let Some(limit) = config.transposition_limit() {
+            let transposed_clusters = grapheme_clusters
+                .iter()
+                .flat_map(|it| it.transpositions(limit))
+                .collect_vec();
+            grapheme_clusters.extend(transposed_clusters);
+        }
+
+        let dfa = measure(
+            timings,
+            |it| &mut it.dfa_construction,
+            || {
+                if config.is_prefix_matched {
+                    DFA::from(Self::collect_prefixes(&grapheme_clusters, config), config)
+                } else {
+                    DFA::from(grapheme_clusters.clone(), config)
+                }
+            },
+        )?;
+        let ast = measure(
+            timings,
+            |it| &mut it.ast_rendering,
+            || Self::build_ast(&dfa, &grapheme_clusters, test_cases, config, &mut warnings),
+        )?;
+
+        let mut regexp = Self {
             ast,
+            categories: vec![],
+            dfa,
+            alphabet,
+            inputs,
+            test_cases: test_cases.clone(),
+            grapheme_clusters,
             config: config.clone(),
+        };
+
+        if config.is_skeleton_grouped {
+            let skeleton_groups = Self::group_by_skeleton(test_cases);
+            if skeleton_groups.len() > 1 {
+                regexp.ast =
+                    Self::build_skeleton_ast(skeleton_groups, config, timings, &mut warnings)?;
+            }
+        }
+        if config.is_csv_structured {
+            if let Some(columns) = Self::split_csv_columns(test_cases) {
+                regexp.ast = Self::build_csv_ast(columns, config, timings, &mut warnings)?;
+            }
+        }
+        if config.is_branch_order_weighted {
+            regexp.order_branches_by_frequency(&frequencies);
+        }
+        if config.is_semver_structured {
+            if let Some((has_pre_release, has_build_metadata)) = Self::find_semver_parts(test_cases)
+            {
+                regexp.ast = Expression::from_semver(has_pre_release, has_build_metadata, config);
+                let mut shorthands = vec!["\\d"];
+                if has_pre_release || has_build_metadata {
+                    shorthands.push("\\w");
+                }
+                for shorthand in shorthands {
+                    let warning =
+                        GenerationWarning::CharacterClassGeneralized(shorthand.to_string());
+                    if !warnings.contains(&warning) {
+                        warnings.push(warning);
+                    }
+                }
+            }
+        }
+
+        if config.is_bounded_only && regexp.ast.has_unbounded_quantifier() {
+            return Err(GenerationError::UnboundedQuantifierRequired);
+        }
+
+        Ok((regexp, warnings))
+    }
+
+    pub(crate) fn effective_config(config: &RegExpConfig) -> RegExpConfig {
+        let mut effective_config = config.without_unsupported_syntax();
+        if config.is_literal_only {
+            effective_config = effective_config.without_generalization();
+        }
+        if config.is_input_treated_as_pattern {
+            effective_config = effective_config.without_pattern_incompatible_settings();
+        }
+        if config.is_prefix_matched {
+            effective_config = effective_config.without_prefix_incompatible_settings();
+        }
+        if config.is_bounded_only {
+            effective_config = effective_config.without_unbounded_settings();
+        }
+        effective_config
+    }
+
+    pub(crate) fn from_streamed_dfa(
+        dfa: DFA,
+        alphabet: BTreeSet<char>,
+        config: &RegExpConfig,
+    ) -> std::result::Result<Self, GenerationError> {
+        let ast = Self::build_ast(&dfa, &[], &[], config, &mut vec![])?;
+        Ok(Self {
+            ast,
+            categories: vec![],
+            dfa,
+            alphabet,
+            inputs: vec![],
+            test_cases: vec![],
+            grapheme_clusters: vec![],
+            config: config.clone(),
+        })
+    }
+
+    fn build_csv_ast(
+        columns: Vec<Vec<String>>,
+        config: &RegExpConfig,
+        timings: &mut Option<GenerationTimings>,
+        warnings: &mut Vec<GenerationWarning>,
+    ) -> std::result::Result<Expression, GenerationError> {
+        let column_config = RegExpConfig {
+            is_csv_structured: false,
+            ..config.clone()
+        };
+        let mut column_asts = vec![];
+
+        for mut values in columns {
+            let is_quoted = values.iter().all(|it| is_quoted_csv_value(it));
+            if is_quoted {
+                values = values
+                    .iter()
+                    .map(|it| it[1..it.len() - 1].to_string())
+                    .collect_vec();
+            }
+            let (column_regexp, column_warnings) =
+                Self::generate(&mut values, &column_config, timings)?;
+            for warning in column_warnings {
+                let is_duplicate_warning =
+                    matches!(warning, GenerationWarning::DuplicateTestCasesRemoved(_));
+                if !is_duplicate_warning && !warnings.contains(&warning) {
+                    warnings.push(warning);
+                }
+            }
+            column_asts.push((column_regexp.ast, is_quoted));
+        }
+
+        Ok(Expression::from_csv_columns(column_asts, config))
+    }
+
+    fn build_skeleton_ast(
+        groups: Vec<Vec<String>>,
+        config: &RegExpConfig,
+        timings: &mut Option<GenerationTimings>,
+        warnings: &mut Vec<GenerationWarning>,
+    ) -> std::result::Result<Expression, GenerationError> {
+        let group_config = RegExpConfig {
+            is_skeleton_grouped: false,
+            ..config.clone()
+        };
+        let mut group_asts = vec![];
+
+        for mut values in groups {
+            let (group_regexp, group_warnings) =
+                Self::generate(&mut values, &group_config, timings)?;
+            for warning in group_warnings {
+                let is_duplicate_warning =
+                    matches!(warning, GenerationWarning::DuplicateTestCasesRemoved(_));
+                if !is_duplicate_warning && !warnings.contains(&warning) {
+                    warnings.push(warning);
+                }
+            }
+            group_asts.push(group_regexp.ast);
+        }
+
+        Ok(Expression::from_alternatives(group_asts, config))
+    }
+
+    fn build_ast(
+        dfa: &DFA,
+        grapheme_clusters: &[GraphemeCluster],
+        test_cases: &[String],
+        config: &RegExpConfig,
+        warnings: &mut Vec<GenerationWarning>,
+    ) -> std::result::Result<Expression, GenerationError> {
+        let mut ast = if config.is_prefix_matched {
+            Expression::from_prefixes(grapheme_clusters, config)
+        } else if config.is_literal_only {
+            Expression::from_literals(grapheme_clusters, config)
+        } else if config.is_substring_branch_kept {
+            Expression::from_with_substring_branches(grapheme_clusters, test_cases, config)?
+        } else {
+            Expression::from(dfa, config)?
+        };
+
+        if config.is_non_digit_converted()
+            || config.is_non_space_converted()
+            || config.is_non_word_converted()
+        {
+            ast.merge_into_negated_shorthands();
+        }
+
+        if config.is_suffix_factored {
+            ast.factor_common_suffixes(config);
+        }
+
+        if let Some(threshold) = config.word_class_threshold {
+            let is_converted = ast.convert_to_word_class(threshold as usize, config);
+            let warning = GenerationWarning::CharacterClassGeneralized("\\w".to_string());
+            if is_converted && !warnings.contains(&warning) {
+                warnings.push(warning);
+            }
+        }
+        if config.is_any_grapheme_converted() {
+            let threshold = config.any_grapheme_threshold.unwrap() as usize;
+            if ast.convert_to_any_grapheme(threshold, config) {
+                warnings.push(GenerationWarning::CharacterClassGeneralized(
+                    "\\X".to_string(),
+                ));
+            }
+        }
+        if let Some(max_branches) = config.maximum_branches {
+            if ast.limit_branches(max_branches as usize, config) {
+                warnings.push(GenerationWarning::AlternationBranchesMerged);
+            }
+        }
+        if config.is_repetition_upper_bound_relaxed && ast.has_repetition_range() {
+            warnings.push(GenerationWarning::RepetitionUpperBoundRelaxed);
+        }
+        if config.is_whole_pattern_optional {
+            ast.make_optional(config);
+        }
+
+        Ok(ast)
+    }
+
+    /// Returns the bare regular expression without any flags, anchors or
+    /// surrounding group, so that it can be embedded into other regular expressions.
+    ///
+    /// If the returned expression is an alternation, it needs to be enclosed
+    /// in a group before it can be concatenated with other expressions.
+    pub fn inner_pattern(&self) -> String {
+        self.ast.to_string()
+    }
+
+    /// Returns the regular expression on a single line, even if verbose mode has been enabled
+    /// with [`RegExpBuilder.with_verbose_mode`](./struct.RegExpBuilder.html#method.with_verbose_mode).
+    ///
+    /// The result is identical to the one of [`to_string`](#method.to_string) without
+    /// verbose mode, so whitespace and `#` are not escaped and no branch comments are added.
+    /// This allows to display the verbose form while storing the compact one.
+    pub fn to_compact(&self) -> String {
+        if !self.config.is_verbose_mode_enabled {
+            return self.to_string();
+        }
+        let config = self.config.without_verbose_mode();
+        let mut ast = self.ast.clone();
+        ast.set_config(&config);
+        let categories = self
+            .categories
+            .iter()
+            .map(|(category, category_ast)| {
+                let mut category_ast = category_ast.clone();
+                category_ast.set_config(&config);
+                (category.clone(), category_ast)
+            })
+            .collect_vec();
+
+        Self {
+            ast,
+            categories,
+            dfa: self.dfa.clone(),
+            alphabet: self.alphabet.clone(),
+            inputs: vec![],
+            test_cases: vec![],
+            grapheme_clusters: vec![],
+            config,
+        }
+        .to_string()
+    }
+
+    pub(crate) fn branch_count(&self) -> usize {
+        match &self.ast {
+            Expression::Alternation(options, _) => options.len(),
+            _ => 1,
+        }
+    }
+
+    /// Returns the number of states of the minimal deterministic finite automaton
+    /// which this regular expression has been generated from.
+    ///
+    /// The automaton is minimized with Hopcroft's algorithm, so the count does not
+    /// depend on the surface syntax of the expression, such as the order of branches
+    /// or the chosen flavor. It is a useful metric for the complexity of the test cases
+    /// and for how strongly they have been generalized.
+    pub fn min_states(&self) -> usize {
+        self.dfa.state_count()
+    }
+
+    /// Splits this regular expression into several ones whose lengths in bytes
+    /// do not exceed `max_len`, so that they can be applied in sequence with engines
+    /// which limit the length of patterns.
+    ///
+    /// The test cases are partitioned into consecutive chunks, and a separate regular
+    /// expression is generated for each chunk with the same settings. Each of them is
+    /// valid and anchored on its own, and together they match all of the test cases.
+    /// If this regular expression already fits, it is returned as the only element.
+    ///
+    /// ⚠ A test case whose own regular expression exceeds `max_len` cannot be split
+    /// any further, so it is returned as a chunk of its own regardless of its length.
+    /// Named groups of categorized test cases are not retained in the chunks.
+    pub fn split_under_limit(&self, max_len: usize) -> Vec<String> {
+        let pattern = self.to_string();
+        if pattern.len() <= max_len || self.test_cases.len() < 2 {
+            return vec![pattern];
+        }
+        let config = self.config.without_memory_budget();
+        let generate = |test_cases: &[String]| {
+            Self::generate(&mut test_cases.to_vec(), &config, &mut None)
+                .map(|(regexp, _)| regexp.to_string())
+                .ok()
+        };
+        let mut patterns = vec![];
+        let mut remaining = &self.test_cases[..];
+
+        while !remaining.is_empty() {
+            let mut chunk_size = 1;
+            let mut chunk_pattern = generate(&remaining[..1])
+                .expect("test cases have already been accepted for generation");
+            let mut upper_bound = remaining.len() + 1;
+
+            while chunk_size * 2 < upper_bound {
+                match generate(&remaining[..chunk_size * 2]) {
+                    Some(pattern) if pattern.len() <= max_len => {
+                        chunk_size *= 2;
+                        chunk_pattern = pattern;
+                    }
+                    _ => upper_bound = chunk_size * 2,
+                }
+            }
+            while chunk_size + 1 < upper_bound {
+                let size = chunk_size + (upper_bound - chunk_size) / 2;
+                match generate(&remaining[..size]) {
+                    Some(pattern) if pattern.len() <= max_len => {
+                        chunk_size = size;
+                        chunk_pattern = pattern;
+                    }
+                    _ => upper_bound = size,
+                }
+            }
+            patterns.push(chunk_pattern);
+            remaining = &remaining[chunk_size..];
+        }
+
+        patterns
+    }
+
+    /// Returns the settings which have no effect on this regular expression, so that
+    /// they can be dropped from the [`RegExpBuilder`](./struct.RegExpBuilder.html).
+    ///
+    /// Every enabled conversion feature and optimization is turned off one at a time,
+    /// and the regular expression is generated again from the same test cases.
+    /// A setting is reported if the result is identical to this regular expression.
+    /// Settings are reported by the name of the method which has enabled them,
+    /// e.g. `with_conversion_of(Feature::Repetition)` or `with_suffix_factoring`.
+    ///
+    /// Settings which have already been discarded before generation because of other
+    /// settings, such as conversion features combined with literal-only output, are not
+    /// reported. Regular expressions built from categorized test cases never report any settings.
+    pub fn ineffective_options(&self) -> Vec<String> {
+        if self.test_cases.is_empty() {
+            return vec![];
+        }
+        let pattern = self.to_string();
+        let config = self.config.without_memory_budget();
+        let mut candidates = config
+            .conversion_features
+            .iter()
+            .map(|feature| {
+                let disabled_config = RegExpConfig {
+                    conversion_features: config
+                        .conversion_features
+                        .iter()
+                        .filter(|&it| it != feature)
+                        .cloned()
+                        .collect_vec(),
+                    ..config.clone()
+                };
+                (
+                    format!("with_conversion_of(Feature::{:?})", feature),
+                    disabled_config,
+                )
+            })
+            .collect_vec();
+        let toggles = vec![
+            (
+                "with_atomic_grouping",
+                config.is_atomic_grouping_used,
+                RegExpConfig {
+                    is_atomic_grouping_used: false,
+                    ..config.clone()
+                },
+            ),
+            (
+                "with_open_ended_repetitions",
+                config.is_repetition_upper_bound_relaxed,
+                RegExpConfig {
+                    is_repetition_upper_bound_relaxed: false,
+                    ..config.clone()
+                },
+            ),
+            (
+                "with_suffix_factoring",
+                config.is_suffix_factored,
+                RegExpConfig {
+                    is_suffix_factored: false,
+                    ..config.clone()
+                },
+            ),
+            (
+                "with_frequency_ordered_branches",
+                config.is_branch_order_weighted,
+                RegExpConfig {
+                    is_branch_order_weighted: false,
+                    ..config.clone()
+                },
+            ),
+            (
+                "with_structured_ipv4",
+                config.is_ipv4_structured,
+                RegExpConfig {
+                    is_ipv4_structured: false,
+                    ..config.clone()
+                },
+            ),
+            (
+                "with_structured_date_time",
+                config.is_date_time_structured,
+                RegExpConfig {
+                    is_date_time_structured: false,
+                    ..config.clone()
+                },
+            ),
+            (
+                "with_hex_detection",
+                config.is_hex_detected,
+                RegExpConfig {
+                    is_hex_detected: false,
+                    ..config.clone()
+                },
+            ),
+            (
+                "with_structured_csv",
+                config.is_csv_structured,
+                RegExpConfig {
+                    is_csv_structured: false,
+                    ..config.clone()
+                },
+            ),
+            (
+                "with_permuted_tokens",
+                config.permutation_separator.is_some(),
+                RegExpConfig {
+                    permutation_separator: None,
+                    ..config.clone()
+                },
+            ),
+            (
+                "with_skeleton_grouping",
+                config.is_skeleton_grouped,
+                RegExpConfig {
+                    is_skeleton_grouped: false,
+                    ..config.clone()
+                },
+            ),
+            (
+                "with_structured_semver",
+                config.is_semver_structured,
+                RegExpConfig {
+                    is_semver_structured: false,
+                    ..config.clone()
+                },
+            ),
+            (
+                "with_subpattern_definitions",
+                config.is_subpattern_defined,
+                RegExpConfig {
+                    is_subpattern_defined: false,
+                    ..config.clone()
+                },
+            ),
+            (
+                "with_internal_word_boundaries",
+                config.is_internal_word_boundary_used,
+                RegExpConfig {
+                    is_internal_word_boundary_used: false,
+                    ..config.clone()
+                },
+            ),
+            (
+                "with_minimized_length",
+                config.is_length_minimized,
+                RegExpConfig {
+                    is_length_minimized: false,
+                    ..config.clone()
+                },
+            ),
+            (
+                "with_any_grapheme_threshold",
+                config.any_grapheme_threshold.is_some(),
+                RegExpConfig {
+                    any_grapheme_threshold: None,
+                    ..config.clone()
+                },
+            ),
+            (
+                "with_word_class_threshold",
+                config.word_class_threshold.is_some(),
+                RegExpConfig {
+                    word_class_threshold: None,
+                    ..config.clone()
+                },
+            ),
+            (
+                "with_class_conversion_at_common_positions",
+                config.class_conversion_coverage.is_some(),
+                RegExpConfig {
+                    class_conversion_coverage: None,
+                    ..config.clone()
+                },
+            ),
+            (
+                "with_maximum_branches",
+                config.maximum_branches.is_some(),
+                RegExpConfig {
+                    maximum_branches: None,
+                    ..config.clone()
+                },
+            ),
+            (
+                "with_scoped_ignore_case_flag",
+                config.is_ignore_case_flag_scoped,
+                RegExpConfig {
+                    is_ignore_case_flag_scoped: false,
+                    ..config.clone()
+                },
+            ),
+        ];
+        for (name, is_enabled, disabled_config) in toggles {
+            if is_enabled {
+                candidates.push((name.to_string(), disabled_config));
+            }
+        }
+
+        candidates
+            .into_iter()
+            .filter(|(_, disabled_config)| {
+                matches!(
+                    Self::generate(&mut self.test_cases.clone(), disabled_config, &mut None),
+                    Ok((regexp, _)) if regexp.to_string() == pattern
+                )
+            })
+            .map(|(name, _)| name)
+            .collect_vec()
+    }
+
+    /// Returns the literal substrings which must appear in every string
+    /// matched by this regular expression.
+    ///
+    /// Literals are collected from every branch of an alternation, and only those
+    /// which are shared by all branches are kept. Character classes and optional
+    /// or repeated parts of the expression never contribute any literals.
+    /// If no literal is required, the returned vector is empty.
+    ///
+    /// If case-insensitive matching is enabled, the literals are returned in lower case.
+    pub fn required_literals(&self) -> Vec<String> {
+        self.ast.required_literals()
+    }
+
+    /// Returns an estimate of the number of distinct strings matched by this
+    /// regular expression, or `None` if it matches infinitely many strings.
+    ///
+    /// The estimate is computed from the structure of the expression. Each character
+    /// class counts with the number of characters it contains, so `\\d` alone already
+    /// matches several hundred strings. Strings which can be matched in more than one
+    /// way are counted more than once, so the estimate is an upper bound of the exact
+    /// number. Numbers too large to be represented are capped at `u128::MAX`.
+    ///
+    /// Comparing the estimate with the number of test cases reveals how strongly
+    /// the test cases have been generalized.
+    pub fn language_size_estimate(&self) -> Option<u128> {
+        self.ast.language_size()
+    }
+
+    /// Writes the regular expression to the given writer.
+    ///
+    /// The output is identical to the one of [`to_string`](#method.to_string), but the
+    /// regular expression is written piece by piece while walking through its syntax tree.
+    /// This avoids to hold very large regular expressions in memory as a whole.
+    pub fn write_to<W: Write>(&self, writer: &mut W) -> io::Result<()> {
+        write!(writer, "{}", self)
+    }
+
+    /// Returns the test cases exactly as they have been passed to `RegExpBuilder`,
+    /// including duplicates and in their original order and case.
+    ///
+    /// ⚠ The test cases are only retained if this has been requested with method
+    /// [`RegExpBuilder.with_input_retention`](./struct.RegExpBuilder.html#method.with_input_retention).
+    /// Otherwise, the returned slice is empty.
+    pub fn inputs(&self) -> &[String] {
+        &self.inputs
+    }
+
+    /// Returns the set of characters which occur in the test cases.
+    ///
+    /// Graphemes consisting of several code points, such as emojis with skin tone modifiers
+    /// or letters with combining marks, contribute each of their code points to the set.
+    /// Placeholder characters which have been converted to wildcards are not part of it.
+    /// If case-insensitive matching is enabled, the characters are collected in lower case.
+    pub fn alphabet(&self) -> BTreeSet<char> {
+        self.alphabet.clone()
+    }
+
+    /// Returns the set of characters which any match of the regular expression can start with.
+    /// It is derived from the transitions leaving the start state of the underlying automaton,
+    /// so it covers all branches of the regular expression. This allows to dispatch quickly
+    /// on the first character of an input before running the full regular expression.
+    ///
+    /// If case-insensitive matching is enabled, both the lower case and the upper case
+    /// variants of the characters are contained. The empty string does not contribute
+    /// any characters, even if it is matched.
+    ///
+    /// ⚠ If a match can start with a shorthand character class such as `\d` or with
+    /// a wildcard, the set only contains the characters observed at the start of the
+    /// test cases, not every character matched by the class.
+    pub fn first_set(&self) -> BTreeSet<char> {
+        let mut first_set = BTreeSet::new();
+        let mut is_generalized = false;
+
+        for grapheme in self.dfa.initial_edge_labels() {
+            match first_char(grapheme, &self.config) {
+                Some(c) => {
+                    first_set.insert(c);
+                }
+                None => is_generalized = true,
+            }
+        }
+        if is_generalized {
+            let placeholder = self.config.placeholder.map(|(placeholder, _)| placeholder);
+            first_set.extend(
+                self.test_cases
+                    .iter()
+                    .filter_map(|test_case| test_case.chars().next())
+                    .filter(|&c| Some(c) != placeholder),
+            );
+        }
+        if self.config.is_case_expanded_to_classes {
+            first_set = first_set
+                .iter()
+                .flat_map(|&c| case_variants(c, &self.config))
+                .collect();
+        } else if self.config.is_case_insensitive_matching() {
+            first_set = first_set
+                .iter()
+                .flat_map(|c| c.to_lowercase().chain(c.to_uppercase()))
+                .collect();
+        }
+
+        first_set
+    }
+
+    /// Returns the branches of alternations whose matched strings are all matched by
+    /// another branch of the same alternation as well, so that they could be removed
+    /// without changing the language of the regular expression.
+    ///
+    /// Redundant branches are a sign of over-generalization. They may occur if branches
+    /// are merged into wildcards or shorthand character classes, e.g. by method
+    /// [`RegExpBuilder.with_maximum_branches`](./struct.RegExpBuilder.html#method.with_maximum_branches).
+    ///
+    /// The check is performed over the [`alphabet`](#method.alphabet) of the test cases:
+    /// The strings matched by a branch are enumerated using the characters of the alphabet
+    /// only, and the branch is redundant if all of them are matched by another branch.
+    /// Unbounded repetitions are enumerated up to two times. Branches matching too many
+    /// strings to be enumerated are never reported. If two branches match the same strings,
+    /// both of them are reported.
+    pub fn redundant_branches(&self) -> Vec<String> {
+        let mut redundant_branches = vec![];
+        self.collect_redundant_branches(&self.ast, &mut redundant_branches);
+        redundant_branches
+    }
+
+    /// Returns a regular expression which matches every string over the alphabet
+    /// of the test cases except for the test cases themselves.
+    ///
+    /// The alphabet consists of the distinct graphemes found in the test cases.
+    /// If repetitions or character classes have been converted, each repeated substring
+    /// and each shorthand character class counts as a single symbol of the alphabet.
+    ///
+    /// If the test cases do not contain any graphemes at all, there is nothing left to match.
+    /// In this case, a regular expression is returned which never matches in the chosen flavor.
+    pub fn with_complement(&self) -> String {
+        match self.dfa.complement() {
+            Some(dfa) => {
+                let ast = Expression::from(&dfa, &self.config.without_memory_budget())
+                    .expect("no memory budget is set");
+                Self {
+                    ast,
+                    categories: vec![],
+                    dfa,
+                    alphabet: self.alphabet.clone(),
+                    inputs: vec![],
+                    test_cases: vec![],
+                    grapheme_clusters: vec![],
+                    config: self.config.clone(),
+                }
+                .to_string()
+            }
+            None => self.config.flavor.never_matching_pattern().to_string(),
+        }
+    }
+
+    /// Returns a regular expression which matches exactly those strings which are matched
+    /// by both this regular expression and the given one, or `None` if there is no such
+    /// string at all.
+    ///
+    /// The intersection is computed on the underlying automata, so the result is exact
+    /// rather than an approximation. The settings of this regular expression are used
+    /// for the result.
+    ///
+    /// ⚠ Both regular expressions should be built with the same settings. Transitions are
+    /// only considered equal if their graphemes are equal, so a converted character class
+    /// such as `\d` never intersects with a literal digit.
+    pub fn intersect(&self, other: &RegExp) -> Option<RegExp> {
+        let dfa = self.dfa.intersection(&other.dfa)?;
+        let ast = Expression::from(&dfa, &self.config.without_memory_budget())
+            .expect("no memory budget is set");
+        let test_cases = self
+            .test_cases
+            .iter()
+            .filter(|&test_case| other.test_cases.contains(test_case))
+            .cloned()
+            .collect_vec();
+        let grapheme_clusters = self
+            .grapheme_clusters
+            .iter()
+            .zip(self.test_cases.iter())
+            .filter(|(_, test_case)| other.test_cases.contains(test_case))
+            .map(|(cluster, _)| cluster.clone())
+            .collect_vec();
+
+        Some(Self {
+            ast,
+            categories: vec![],
+            dfa,
+            alphabet: self
+                .alphabet
+                .intersection(&other.alphabet)
+                .copied()
+                .collect(),
+            inputs: vec![],
+            test_cases,
+            grapheme_clusters,
+            config: self.config.clone(),
+        })
+    }
+
+    /// Returns `true` if this regular expression and the given one match exactly
+    /// the same strings, regardless of differences in their textual form such as
+    /// the order of branches, the syntax of groups, verbose mode or the chosen flavor.
+    ///
+    /// The comparison is performed on the underlying minimal automata, so the result is exact
+    /// rather than an approximation.
+    ///
+    /// ⚠ Both regular expressions should be built with the same conversion features.
+    /// Transitions are only considered equal if their graphemes are equal, so a converted
+    /// character class such as `\d` is never equivalent to a set of literal digits.
+    /// Generalizations applied after the construction of the automaton, such as those of method
+    /// [`RegExpBuilder.with_maximum_branches`](./struct.RegExpBuilder.html#method.with_maximum_branches),
+    /// are not taken into account.
+    pub fn is_equivalent(&self, other: &RegExp) -> bool {
+        self.dfa.is_equivalent(&other.dfa)
+    }
+
+    /// Returns the source code of a test function in the given language which asserts
+    /// that the regular expression matches each of the test cases.
+    /// It is meant to be pasted into a test suite as a ready-made regression test.
+    ///
+    /// The test cases are listed in the order in which they have been processed,
+    /// without duplicates. If case-insensitive matching is enabled, they are listed
+    /// in lower case. The regular expression is embedded as it is, so it should be
+    /// generated with a [`Flavor`](./enum.Flavor.html) which the targeted regular expression
+    /// engine understands.
+    ///
+    /// ⚠ Syntax highlighting must not be enabled, otherwise the embedded regular expression
+    /// contains ANSI escape codes.
+    pub fn to_test_snippet(&self, language: SnippetLanguage) -> String {
+        language.format_test(&self.to_string(), &self.test_cases)
+    }
+
+    /// Returns the transition table of the minimal deterministic finite automaton
+    /// which the regular expression has been derived from. This exposes the states,
+    /// the alphabet, the transitions, the start state and the accepting states
+    /// in a machine-readable form for educational and debugging purposes.
+    ///
+    /// ⚠ Syntax highlighting must not be enabled, otherwise the symbols of the alphabet
+    /// contain ANSI escape codes.
+    pub fn automaton_table(&self) -> AutomatonTable {
+        self.dfa.to_table()
+    }
+
+    /// Returns `true` if the regular expression does not contain any backreferences
+    /// such as `\1`, `\k<name>` or `\g1`.
+    ///
+    /// As the regular expression is derived from a finite automaton, it never contains
+    /// backreferences by construction. This method certifies it by inspecting the generated
+    /// expression, so that users of engines without backreference support, such as RE2 or Go,
+    /// can assert on it. This also covers the literals returned by a
+    /// [`cluster transformation`](./struct.RegExpBuilder.html#method.with_cluster_transformation).
+    pub fn is_backreference_free(&self) -> bool {
+        !self.ast.has_backreference()
+            && !self
+                .categories
+                .iter()
+                .any(|(_, expr)| expr.has_backreference())
+    }
+
+    /// Returns how many strings of the given sample are matched by the regular expression
+    /// as a whole. This allows to evaluate the generalization of the regular expression
+    /// against a held-out set of strings which have not been used as test cases.
+    ///
+    /// The sample strings are matched against the generated expression directly, so no
+    /// regular expression engine is required. Case-insensitive matching is taken into account,
+    /// whereas anchors and word boundaries are not, so each string must be matched
+    /// in its entirety.
+    pub fn match_stats(&self, sample: &[String]) -> MatchStats {
+        MatchStats {
+            sample_count: sample.len(),
+            matched_count: sample.iter().filter(|it| self.matches_entirely(it)).count(),
+        }
+    }
+
+    /// Returns the longest prefix and the longest suffix which all test cases have in common.
+    /// Both are computed on grapheme clusters, so a grapheme consisting of several
+    /// characters is never split. If the test cases do not share a prefix or suffix,
+    /// the respective string is empty.
+    ///
+    /// The prefix and the suffix may overlap, e.g. if only a single test case is given.
+    /// If case-insensitive matching is enabled, they are returned in lower case.
+    pub fn common_affixes(&self) -> (String, String) {
+        let graphemes = self
+            .test_cases
+            .iter()
+            .map(|it| it.graphemes(true).collect_vec())
+            .collect_vec();
+        let prefix_length = count_common_graphemes(&graphemes, |it, i| it.get(i).copied());
+        let suffix_length = count_common_graphemes(&graphemes, |it, i| {
+            it.len()
+                .checked_sub(i + 1)
+                .and_then(|idx| it.get(idx).copied())
+        });
+
+        match graphemes.first() {
+            Some(first) => (
+                first[..prefix_length].concat(),
+                first[first.len() - suffix_length..].concat(),
+            ),
+            None => (String::new(), String::new()),
+        }
+    }
+
+    /// Returns the regular expression quoted as a single argument for the given shell,
+    /// so that it can be passed to command-line tools such as `grep` without being
+    /// altered by the shell. Single quotes within the regular expression are escaped
+    /// according to the quoting rules of the respective shell.
+    ///
+    /// ⚠ Syntax highlighting must not be enabled, otherwise the quoted regular expression
+    /// contains ANSI escape codes.
+    pub fn to_shell_arg(&self, shell: Shell) -> String {
+        shell.quote(&self.to_string())
+    }
+
+    /// Returns a profile of this regular expression in the given document format,
+    /// so that its generation can be reproduced later on. The profile contains the
+    /// regular expression, the complexity metrics `pattern_length`, `branch_count`
+    /// and `state_count` as well as all settings which have been applied.
+    /// The latter can be read back with
+    /// [`RegExpConfig::from_profile`](./struct.RegExpConfig.html#method.from_profile).
+    ///
+    /// ⚠ Settings which are not supported by the chosen flavor or which are overridden
+    /// by other settings are written as they have been applied, i.e. disabled.
+    pub fn to_profile(&self, format: ProfileFormat) -> String {
+        let pattern = self.to_string();
+        let metrics = [
+            ("pattern_length", pattern.chars().count()),
+            ("branch_count", self.branch_count()),
+            ("state_count", self.min_states()),
+        ];
+        write_profile(format, &pattern, &metrics, &self.config)
+    }
+
+    fn collect_redundant_branches(&self, expr: &Expression, redundant_branches: &mut Vec<String>) {
+        match expr {
+            Expression::Alternation(options, _) => {
+                for (i, option) in options.iter().enumerate() {
+                    if self.is_subsumed_by_other_option(i, options) {
+                        redundant_branches.push(option.to_string());
+                    } else {
+                        self.collect_redundant_branches(option, redundant_branches);
+                    }
+                }
+            }
+            Expression::Concatenation(expr1, expr2, _) => {
+                self.collect_redundant_branches(expr1, redundant_branches);
+                self.collect_redundant_branches(expr2, redundant_branches);
+            }
+            Expression::Repetition(expr, _, _) => {
+                self.collect_redundant_branches(expr, redundant_branches);
+            }
+            Expression::CharacterClass(_, _)
+            | Expression::Literal(_, _)
+            | Expression::SubroutineCall(_, _) => (),
+        }
+    }
+
+    fn is_subsumed_by_other_option(&self, index: usize, options: &[Expression]) -> bool {
+        let samples = match options[index].sample_strings(&self.alphabet) {
+            Some(samples) => samples,
+            None => return false,
+        };
+        let clusters = samples
+            .iter()
+            .map(|it| GraphemeCluster::from(it, &self.config))
+            .collect_vec();
+        let mut converted_clusters = clusters.clone();
+        Self::convert_grapheme_clusters(&mut converted_clusters, &self.config, &mut None);
+
+        options.iter().enumerate().any(|(i, option)| {
+            i != index
+                && clusters.iter().zip(converted_clusters.iter()).all(
+                    |(cluster, converted_cluster)| {
+                        option.matches(cluster.graphemes())
+                            || option.matches(converted_cluster.graphemes())
+                    },
+                )
+        })
+    }
+
+    fn matches_entirely(&self, value: &str) -> bool {
+        let value = if self.config.is_case_insensitive_matching() {
+            value.to_lowercase()
+        } else {
+            value.to_string()
+        };
+        let cluster = GraphemeCluster::from(&value, &self.config);
+        let mut converted_clusters = vec![cluster.clone()];
+        Self::convert_grapheme_clusters(&mut converted_clusters, &self.config, &mut None);
+
+        self.ast.matches(cluster.graphemes()) || self.ast.matches(converted_clusters[0].graphemes())
+    }
+
+    fn order_branches_by_frequency(&mut self, frequencies: &HashMap<String, usize>) {
+        let option_frequencies = match &self.ast {
+            Expression::Alternation(options, _) => options
+                .iter()
+                .map(|option| {
+                    self.find_matched_test_cases(option)
+                        .iter()
+                        .map(|it| frequencies.get(*it).copied().unwrap_or(1))
+                        .sum::<usize>()
+                })
+                .collect_vec(),
+            _ => return,
+        };
+        if let Expression::Alternation(options, _) = &mut self.ast {
+            let mut weighted_options = options.drain(..).zip(option_frequencies).collect_vec();
+            weighted_options.sort_by(|(_, a), (_, b)| b.cmp(a));
+            *options = weighted_options
+                .into_iter()
+                .map(|(option, _)| option)
+                .collect_vec();
+        }
+    }
+
+    // The flag for case-insensitive matching is redundant if all characters
+    // with case variants have been converted to character classes already
+    fn is_ignore_case_flag_scoped(&self) -> bool {
+        self.config.is_ignore_case_flag_scoped
+            && self.config.is_ignore_case_flag_used()
+            && !self.config.flavor.is_posix()
+            && self.has_cased_char()
+    }
+
+    fn enclose_in_flag_scope(&self, pattern: String, is_grouped: bool) -> String {
+        if !self.is_enclosed_in_flag_scope(is_grouped) {
+            return pattern;
         }
+        format!(
+            "{}{}{}",
+            ColorizableString::ScopedIgnoreCaseLeftParenthesis.to_colorized_string(&self.config),
+            pattern,
+            ColorizableString::RightParenthesis.to_colorized_string(&self.config)
+        )
     }
 
-    fn convert_to_lowercase(test_cases: &mut Vec<String>) {
+    fn is_enclosed_in_flag_scope(&self, is_grouped: bool) -> bool {
+        // Without capturing groups, the outer group has already been replaced by the scoped one
+        self.is_ignore_case_flag_scoped()
+            && (!is_grouped || self.config.is_capturing_group_enabled())
+    }
+
+    fn has_cased_char(&self) -> bool {
+        self.ast.has_cased_char()
+            || self
+                .categories
+                .iter()
+                .any(|(_, expr)| expr.has_cased_char())
+    }
+
+    fn find_matched_test_cases(&self, branch: &Expression) -> Vec<&String> {
+        self.test_cases
+            .iter()
+            .zip(self.grapheme_clusters.iter())
+            .filter(|(_, cluster)| branch.matches(cluster.graphemes()))
+            .map(|(test_case, _)| test_case)
+            .collect_vec()
+    }
+
+    fn format_verbose_branch(&self, branch: &Expression, indentation: &str) -> String {
+        let matched_test_cases = if self.config.is_branch_commented {
+            self.find_matched_test_cases(branch)
+        } else {
+            vec![]
+        };
+
+        if matched_test_cases.is_empty() {
+            format!("{}{}", indentation, branch)
+        } else {
+            format!(
+                "{}{}  # matches: {}",
+                indentation,
+                branch,
+                matched_test_cases
+                    .iter()
+                    .map(|it| it.escape_debug())
+                    .join(", ")
+            )
+        }
+    }
+
+    // Each token must be enclosed by separators or the bounds of the string, so that
+    // a token does not count as present if it is only part of another token
+    fn format_permutation_lookaheads(&self) -> String {
+        let separator = match &self.config.permutation_separator {
+            Some(separator) if self.config.is_permutation_lookahead_used() => separator,
+            _ => return String::new(),
+        };
+        // The assertions are colorized as a whole, so their contents are not
+        let token_config = RegExpConfig {
+            is_output_colorized: false,
+            ..self.config.clone()
+        };
+        let separator_config = RegExpConfig {
+            is_input_treated_as_pattern: false,
+            placeholder: None,
+            ..token_config.clone()
+        };
+        let separator = Expression::Literal(
+            GraphemeCluster::from(separator, &separator_config),
+            separator_config,
+        );
+
+        self.test_cases
+            .iter()
+            .map(|test_case| {
+                let cluster = if token_config.is_input_treated_as_pattern {
+                    GraphemeCluster::from_pattern_fragment(test_case, &token_config)
+                } else {
+                    GraphemeCluster::from(test_case, &token_config)
+                };
+                let token = Expression::Literal(cluster, token_config.clone());
+                ColorizableString::Lookahead(format!("(?:.*{0})?{1}(?:{0}|$)", separator, token))
+                    .to_colorized_string(&self.config)
+            })
+            .join("")
+    }
+
+    fn format_token_repetition(&self, token: &str, right_parenthesis: &ColoredString) -> String {
+        // The first token is not preceded by a separator
+        let (separator, min, max) = match &self.config.token_repetition {
+            Some((separator, min, max)) if *max > 1 => (separator, min - 1, max - 1),
+            _ => return String::new(),
+        };
+        // The separator is always a literal, even if the test cases are not
+        let separator_config = RegExpConfig {
+            is_input_treated_as_pattern: false,
+            placeholder: None,
+            ..self.config.clone()
+        };
+        let separator = Expression::Literal(
+            GraphemeCluster::from(separator, &separator_config),
+            separator_config.clone(),
+        );
+        if (min, max) == (1, 1) {
+            return format!("{}{}", separator, token);
+        }
+        let left_parenthesis = if self.config.is_capturing_group_enabled() {
+            ColorizableString::CapturingLeftParenthesis
+        } else {
+            ColorizableString::NonCapturingLeftParenthesis
+        }
+        .to_colorized_string(&self.config);
+        let quantifier = match (min, max) {
+            (0, 1) => vec![ColorizableString::QuestionMark],
+            _ if min == max => vec![
+                ColorizableString::LeftBrace,
+                ColorizableString::Number(max),
+                ColorizableString::RightBrace,
+            ],
+            _ => vec![
+                ColorizableString::LeftBrace,
+                ColorizableString::Number(min),
+                ColorizableString::Comma,
+                ColorizableString::Number(max),
+                ColorizableString::RightBrace,
+            ],
+        };
+
+        format!(
+            "{}{}{}{}{}",
+            left_parenthesis,
+            separator,
+            token,
+            right_parenthesis,
+            quantifier
+                .iter()
+                .map(|it| it.to_colorized_string(&self.config))
+                .join("")
+        )
+    }
+
+    // The groups are substituted on the given expression trees before they are rendered,
+    // so that only whole alternations are replaced, regardless of how they are written
+    fn define_subpatterns(
+        &self,
+        ast: &mut Expression,
+        categories: &mut Vec<(String, Expression)>,
+    ) -> Vec<(String, Expression)> {
+        let mut alternations = vec![];
+        self.ast.collect_alternations(&mut alternations);
+        for (_, category_ast) in self.categories.iter() {
+            category_ast.collect_alternations(&mut alternations);
+        }
+        // Larger groups are defined first, so that smaller ones nested
+        // within them are only defined if they still occur repeatedly
+        let mut bodies: Vec<&Expression> = vec![];
+        for alternation in alternations {
+            if !bodies.contains(&alternation) {
+                bodies.push(alternation);
+            }
+        }
+        bodies.sort_by_cached_key(|it| Reverse(it.to_string().len()));
+
+        let mut definitions: Vec<(String, Expression)> = vec![];
+
+        for body in bodies {
+            let name = format!("sub{}", definitions.len() + 1);
+            let call = Expression::SubroutineCall(name.clone(), self.config.clone());
+            let mut substituted_ast = ast.clone();
+            let mut substituted_categories = categories.clone();
+            let mut substituted_definitions = definitions.clone();
+            let occurrence_count = substituted_ast.replace_subexpressions(body, &call)
+                + substituted_categories
+                    .iter_mut()
+                    .chain(substituted_definitions.iter_mut())
+                    .map(|(_, expr)| expr.replace_subexpressions(body, &call))
+                    .sum::<usize>();
+            if occurrence_count < 2 {
+                continue;
+            }
+            *ast = substituted_ast;
+            *categories = substituted_categories;
+            definitions = substituted_definitions;
+            definitions.push((name, body.clone()));
+        }
+
+        definitions
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn format_anchored_pattern<W: std::fmt::Write>(
+        &self,
+        f: &mut W,
+        ast: &Expression,
+        categories: &[(String, Expression)],
+        left_anchor: &ColoredString,
+        left_parenthesis: &ColoredString,
+        right_parenthesis: &ColoredString,
+        right_anchor: &ColoredString,
+    ) -> Result {
+        if !categories.is_empty() {
+            return self.format_categories(
+                f,
+                categories,
+                left_anchor,
+                left_parenthesis,
+                right_parenthesis,
+                right_anchor,
+            );
+        }
+
+        if self.config.is_verbose_mode_enabled {
+            let (verbose_flag, pipe) = [ColorizableString::VerboseFlag, ColorizableString::Pipe]
+                .iter()
+                .map(|it| it.to_colorized_string(&self.config))
+                .collect_tuple()
+                .unwrap();
+            let branches = match ast {
+                Expression::Alternation(options, _) => format!(
+                    "{}\n{}\n{}",
+                    left_parenthesis,
+                    options
+                        .iter()
+                        .map(|it| self.format_verbose_branch(it, "  "))
+                        .join(&format!("\n  {}\n", pipe)),
+                    right_parenthesis
+                ),
+                _ if self.is_grouped(ast) => format!(
+                    "{}\n{}\n{}",
+                    left_parenthesis,
+                    self.format_verbose_branch(ast, "  "),
+                    right_parenthesis
+                ),
+                _ => self.format_verbose_branch(ast, ""),
+            };
+            let branches = self.enclose_in_flag_scope(branches, self.is_grouped(ast));
+            return write!(
+                f,
+                "{}\n{}\n{}\n{}",
+                verbose_flag, left_anchor, branches, right_anchor
+            );
+        }
+
+        write!(f, "{}{}", left_anchor, self.format_permutation_lookaheads())?;
+        // The token is only buffered if its repetition has to be written as well
+        if self.is_token_repeated() {
+            let mut token = String::new();
+            self.write_token(&mut token, ast, left_parenthesis, right_parenthesis)?;
+            write!(
+                f,
+                "{}{}",
+                token,
+                self.format_token_repetition(&token, right_parenthesis)
+            )?;
+        } else {
+            self.write_token(f, ast, left_parenthesis, right_parenthesis)?;
+        }
+        write!(f, "{}", right_anchor)
+    }
+
+    fn write_token<W: std::fmt::Write>(
+        &self,
+        f: &mut W,
+        ast: &Expression,
+        left_parenthesis: &ColoredString,
+        right_parenthesis: &ColoredString,
+    ) -> Result {
+        let is_grouped = self.is_grouped(ast);
+        let is_scoped = self.is_enclosed_in_flag_scope(is_grouped);
+        if is_scoped {
+            write!(
+                f,
+                "{}",
+                ColorizableString::ScopedIgnoreCaseLeftParenthesis
+                    .to_colorized_string(&self.config)
+            )?;
+        }
+        if is_grouped {
+            write!(f, "{}{}{}", left_parenthesis, ast, right_parenthesis)?;
+        } else {
+            write!(f, "{}", ast)?;
+        }
+        if is_scoped {
+            write!(
+                f,
+                "{}",
+                ColorizableString::RightParenthesis.to_colorized_string(&self.config)
+            )?;
+        }
+        Ok(())
+    }
+
+    fn is_token_repeated(&self) -> bool {
+        matches!(&self.config.token_repetition, Some((_, _, max)) if *max > 1)
+    }
+
+    fn is_grouped(&self, ast: &Expression) -> bool {
+        self.config.is_outer_group_always_used || matches!(ast, Expression::Alternation(_, _))
+    }
+
+    fn format_categories<W: std::fmt::Write>(
+        &self,
+        f: &mut W,
+        categories: &[(String, Expression)],
+        left_anchor: &ColoredString,
+        left_parenthesis: &ColoredString,
+        right_parenthesis: &ColoredString,
+        right_anchor: &ColoredString,
+    ) -> Result {
+        let (verbose_flag, pipe) = [ColorizableString::VerboseFlag, ColorizableString::Pipe]
+            .iter()
+            .map(|it| it.to_colorized_string(&self.config))
+            .collect_tuple()
+            .unwrap();
+        let groups = categories
+            .iter()
+            .map(|(category, ast)| {
+                format!(
+                    "{}{}{}",
+                    ColorizableString::NamedLeftParenthesis(category.clone())
+                        .to_colorized_string(&self.config),
+                    ast,
+                    right_parenthesis
+                )
+            })
+            .collect_vec();
+
+        if self.config.is_verbose_mode_enabled {
+            let branches = if groups.len() > 1 {
+                format!(
+                    "{}\n  {}\n{}",
+                    left_parenthesis,
+                    groups.join(&format!("\n  {}\n  ", pipe)),
+                    right_parenthesis
+                )
+            } else {
+                groups.join("")
+            };
+            let branches = self.enclose_in_flag_scope(branches, groups.len() > 1);
+            write!(
+                f,
+                "{}\n{}\n{}\n{}",
+                verbose_flag, left_anchor, branches, right_anchor
+            )
+        } else if groups.len() > 1 {
+            write!(
+                f,
+                "{}{}{}{}{}",
+                left_anchor,
+                left_parenthesis,
+                groups.join(&pipe.to_string()),
+                right_parenthesis,
+                right_anchor
+            )
+        } else {
+            write!(
+                f,
+                "{}{}{}",
+                left_anchor,
+                self.enclose_in_flag_scope(groups.join(""), false),
+                right_anchor
+            )
+        }
+    }
+
+    pub(crate) fn convert_to_lowercase(test_cases: &mut Vec<String>) {
         std::mem::replace(
             test_cases,
             test_cases.iter().map(|it| it.to_lowercase()).collect_vec(),
         );
     }
 
+    pub(crate) fn normalize_line_endings(test_cases: &mut [String], line_ending: LineEnding) {
+        for test_case in test_cases.iter_mut() {
+            *test_case = line_ending.normalize(test_case);
+        }
+    }
+
+    fn remove_case_insensitive_duplicates(test_cases: &mut Vec<String>, is_last_kept: bool) {
+        let mut lowercase_test_cases = HashSet::new();
+        if is_last_kept {
+            test_cases.reverse();
+        }
+        test_cases.retain(|it| lowercase_test_cases.insert(it.to_lowercase()));
+        if is_last_kept {
+            test_cases.reverse();
+        }
+    }
+
+    pub(crate) fn remove_whitespace_around_separators(
+        test_cases: &mut [String],
+        separators: &BTreeSet<char>,
+    ) {
+        for test_case in test_cases.iter_mut() {
+            let chars = test_case.chars().collect_vec();
+            *test_case = chars
+                .iter()
+                .enumerate()
+                .filter(|&(i, c)| {
+                    !c.is_whitespace() || !is_next_to_separator(&chars, i, separators)
+                })
+                .map(|(_, c)| c)
+                .collect();
+        }
+    }
+
+    fn permute_tokens(
+        test_cases: &mut Vec<String>,
+        separator: &str,
+        config: &RegExpConfig,
+        warnings: &mut Vec<GenerationWarning>,
+    ) -> RegExpConfig {
+        let token_count = test_cases.len();
+
+        if token_count > MAXIMUM_PERMUTED_TOKENS {
+            warnings.push(GenerationWarning::SettingIgnored(format!(
+                "permuted tokens are limited to {} test cases",
+                MAXIMUM_PERMUTED_TOKENS
+            )));
+            return RegExpConfig {
+                permutation_separator: None,
+                ..config.clone()
+            };
+        }
+        if config.is_permutation_lookahead_used() {
+            return RegExpConfig {
+                token_repetition: Some((
+                    separator.to_string(),
+                    token_count as u32,
+                    token_count as u32,
+                )),
+                ..config.clone()
+            };
+        }
+
+        *test_cases = test_cases
+            .iter()
+            .permutations(token_count)
+            .map(|tokens| tokens.into_iter().join(separator))
+            .collect_vec();
+        Self::sort(test_cases);
+
+        RegExpConfig {
+            permutation_separator: None,
+            token_repetition: None,
+            ..config.clone()
+        }
+    }
+
     fn sort(test_cases: &mut Vec<String>) {
         test_cases.sort();
         test_cases.dedup();
@@ -59,25 +1730,408 @@ impl RegExp {
         });
     }
 
-    fn grapheme_clusters(test_cases: &[String], config: &RegExpConfig) -> Vec<GraphemeCluster> {
-        let mut clusters = test_cases
+    pub(crate) fn collect_alphabet(
+        clusters: &[GraphemeCluster],
+        config: &RegExpConfig,
+    ) -> BTreeSet<char> {
+        clusters
             .iter()
-            .map(|it| GraphemeCluster::from(it, config))
-            .collect_vec();
+            .flat_map(|cluster| cluster.graphemes())
+            .map(|grapheme| grapheme.value())
+            .filter(|value| !config.is_wildcard(value))
+            .flat_map(|value| {
+                let is_escaped = value.starts_with('\\') && value.chars().count() > 1;
+                value
+                    .chars()
+                    .skip(if is_escaped { 1 } else { 0 })
+                    .collect_vec()
+            })
+            .collect()
+    }
 
-        if config.is_char_class_feature_enabled() {
-            for cluster in clusters.iter_mut() {
-                cluster.convert_to_char_classes();
+    fn collect_prefixes(
+        clusters: &[GraphemeCluster],
+        config: &RegExpConfig,
+    ) -> Vec<GraphemeCluster> {
+        clusters
+            .iter()
+            .flat_map(|cluster| {
+                let graphemes = cluster.graphemes();
+                (0..=graphemes.len()).map(move |i| graphemes[..i].to_vec())
+            })
+            .unique()
+            .map(|graphemes| GraphemeCluster::from_graphemes(graphemes, config))
+            .collect_vec()
+    }
+
+    fn group_by_skeleton(test_cases: &[String]) -> Vec<Vec<String>> {
+        let mut groups: BTreeMap<String, Vec<String>> = BTreeMap::new();
+        for test_case in test_cases {
+            groups
+                .entry(skeleton_of(test_case))
+                .or_default()
+                .push(test_case.clone());
+        }
+        groups.into_values().collect_vec()
+    }
+
+    fn split_csv_columns(test_cases: &[String]) -> Option<Vec<Vec<String>>> {
+        let rows = test_cases
+            .iter()
+            .map(|it| split_csv_row(it))
+            .collect::<Option<Vec<_>>>()?;
+        let column_count = rows.first()?.len();
+
+        if column_count < 2 || rows.iter().any(|row| row.len() != column_count) {
+            return None;
+        }
+
+        Some(
+            (0..column_count)
+                .map(|i| rows.iter().map(|row| row[i].clone()).collect_vec())
+                .collect_vec(),
+        )
+    }
+
+    fn find_ipv4_octet_bounds(test_cases: &[String]) -> Option<Vec<(u32, u32)>> {
+        let mut octet_bounds = vec![(u32::MAX, 0); 4];
+
+        for test_case in test_cases {
+            let octets = test_case.split('.').collect_vec();
+            if octets.len() != 4 {
+                return None;
+            }
+            for (octet, (min, max)) in octets.iter().zip(octet_bounds.iter_mut()) {
+                let is_valid_octet = (1..=3).contains(&octet.len())
+                    && octet.chars().all(|c| c.is_ascii_digit())
+                    && octet.parse::<u32>().unwrap() <= 255;
+                if !is_valid_octet {
+                    return None;
+                }
+                *min = (*min).min(octet.len() as u32);
+                *max = (*max).max(octet.len() as u32);
+            }
+        }
+
+        Some(octet_bounds)
+    }
+
+    fn find_semver_parts(test_cases: &[String]) -> Option<(bool, bool)> {
+        let mut has_pre_release = false;
+        let mut has_build_metadata = false;
+
+        for test_case in test_cases {
+            let mut parts = test_case.splitn(2, '+');
+            let version = parts.next().unwrap();
+            let build_metadata = parts.next();
+            let mut parts = version.splitn(2, '-');
+            let core = parts.next().unwrap();
+            let pre_release = parts.next();
+
+            let is_valid_core = core.split('.').count() == 3
+                && core
+                    .split('.')
+                    .all(|it| !it.is_empty() && it.chars().all(|c| c.is_ascii_digit()));
+            let is_valid_identifier = |identifier: &str| {
+                !identifier.is_empty()
+                    && identifier
+                        .chars()
+                        .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '.')
+            };
+            let are_valid_identifiers = pre_release
+                .iter()
+                .chain(build_metadata.iter())
+                .all(|it| is_valid_identifier(it));
+            if !is_valid_core || !are_valid_identifiers {
+                return None;
             }
+            has_pre_release |= pre_release.is_some();
+            has_build_metadata |= build_metadata.is_some();
+        }
+
+        Some((has_pre_release, has_build_metadata))
+    }
+
+    fn find_hex_digit_bounds(test_cases: &[String]) -> Option<(&'static str, u32, u32)> {
+        let mut has_lowercase_letter = false;
+        let mut has_uppercase_letter = false;
+        let mut min = u32::MAX;
+        let mut max = 0;
+
+        for test_case in test_cases {
+            if test_case.is_empty() || !test_case.chars().all(|c| c.is_ascii_hexdigit()) {
+                return None;
+            }
+            has_lowercase_letter |= test_case.chars().any(|c| c.is_ascii_lowercase());
+            has_uppercase_letter |= test_case.chars().any(|c| c.is_ascii_uppercase());
+            min = min.min(test_case.len() as u32);
+            max = max.max(test_case.len() as u32);
+        }
+
+        match (has_lowercase_letter, has_uppercase_letter) {
+            (true, true) => Some((HEX_DIGIT_CLASSES[0], min, max)),
+            (true, false) => Some((HEX_DIGIT_CLASSES[1], min, max)),
+            (false, true) => Some((HEX_DIGIT_CLASSES[2], min, max)),
+            (false, false) => None,
+        }
+    }
+
+    fn find_date_time_layout(test_cases: &[String]) -> Option<&'static str> {
+        DATE_TIME_LAYOUTS
+            .iter()
+            .copied()
+            .find(|layout| test_cases.iter().all(|it| is_valid_date_time(it, layout)))
+    }
+
+    pub(crate) fn convert_grapheme_clusters(
+        clusters: &mut [GraphemeCluster],
+        config: &RegExpConfig,
+        timings: &mut Option<GenerationTimings>,
+    ) -> BTreeSet<String> {
+        let mut shorthands = BTreeSet::new();
+
+        if config.is_char_class_feature_enabled() {
+            let position_limit = Self::count_common_positions(clusters, config);
+            measure(
+                timings,
+                |it| &mut it.char_class_conversion,
+                || {
+                    for cluster in clusters.iter_mut() {
+                        shorthands.extend(cluster.convert_to_char_classes(position_limit));
+                    }
+                },
+            );
         }
 
         if config.is_repetition_converted() {
-            for cluster in clusters.iter_mut() {
-                cluster.convert_repetitions();
+            measure(
+                timings,
+                |it| &mut it.repetition_conversion,
+                || {
+                    for cluster in clusters.iter_mut() {
+                        cluster.convert_repetitions();
+                    }
+                },
+            );
+        }
+
+        shorthands
+    }
+
+    // Positions are reached by fewer clusters the further they are from the start,
+    // so the positions reached by the required share of clusters form a common prefix
+    fn count_common_positions(clusters: &[GraphemeCluster], config: &RegExpConfig) -> usize {
+        match config.class_conversion_coverage {
+            Some(percentage) if !clusters.is_empty() => {
+                let mut sizes = clusters.iter().map(|it| it.size()).collect_vec();
+                sizes.sort_unstable_by(|a, b| b.cmp(a));
+                let required_count = (clusters.len() * percentage as usize).div_ceil(100);
+                sizes[required_count.max(1) - 1]
             }
+            _ => usize::MAX,
         }
+    }
 
-        clusters
+    fn collect_ignored_settings(config: &RegExpConfig) -> Vec<GenerationWarning> {
+        let mut warnings = vec![];
+
+        if config.any_grapheme_threshold.is_some() && !config.is_any_grapheme_converted() {
+            warnings.push(GenerationWarning::SettingIgnored(
+                "conversion to \\X is not supported by the chosen flavor".to_string(),
+            ));
+        }
+        if config.conversion_features.contains(&Feature::Script) && !config.is_script_converted() {
+            warnings.push(GenerationWarning::SettingIgnored(
+                "script classes are not supported by the chosen flavor".to_string(),
+            ));
+        }
+        if config.is_non_ascii_char_escaped
+            && config.is_astral_code_point_converted_to_surrogate
+            && !config.flavor.is_posix()
+            && !config.flavor.is_surrogate_pair_supported()
+        {
+            warnings.push(GenerationWarning::SettingIgnored(
+                "surrogate pairs are not supported by the chosen flavor".to_string(),
+            ));
+        }
+        if config.is_atomic_grouping_used && !config.flavor.is_atomic_group_supported() {
+            warnings.push(GenerationWarning::SettingIgnored(
+                "atomic groups are not supported by the chosen flavor".to_string(),
+            ));
+        }
+        if config.is_unicode_name_used && !config.flavor.is_unicode_name_supported() {
+            warnings.push(GenerationWarning::SettingIgnored(
+                "unicode names are not supported by the chosen flavor".to_string(),
+            ));
+        } else if config.is_unicode_name_used && !config.is_non_ascii_char_escaped {
+            warnings.push(GenerationWarning::SettingIgnored(
+                "unicode names require the escaping of non-ascii characters".to_string(),
+            ));
+        }
+        if config.is_continuation_anchor_used && !config.is_continuation_anchor_enabled() {
+            warnings.push(GenerationWarning::SettingIgnored(
+                "anchor \\G is not supported by the chosen flavor".to_string(),
+            ));
+        }
+        if config.is_continuation_anchor_enabled()
+            && (config.is_start_word_boundary_used
+                || config.is_start_anchor_disabled
+                || config.lookbehind_assertion().is_some())
+        {
+            warnings.push(GenerationWarning::SettingIgnored(
+                "anchor \\G is replaced by the chosen start of the regular expression".to_string(),
+            ));
+        }
+        if config.is_trailing_catch_all_used
+            && (config.is_end_anchor_disabled
+                || config.is_end_word_boundary_used
+                || config.lookahead_assertion().is_some())
+        {
+            warnings.push(GenerationWarning::SettingIgnored(
+                "the end of the regular expression is replaced by a catch-all".to_string(),
+            ));
+        }
+        if config.is_case_folding_full && !config.is_case_expanded_to_classes {
+            warnings.push(GenerationWarning::SettingIgnored(
+                "unicode case folding requires case-insensitive classes".to_string(),
+            ));
+        }
+        if config.is_word_char_unicode_aware && config.flavor.is_posix() {
+            warnings.push(GenerationWarning::SettingIgnored(
+                "unicode word characters are not supported by the chosen flavor".to_string(),
+            ));
+        } else if config.is_word_char_unicode_aware && config.class_syntax() != ClassSyntax::Perl {
+            warnings.push(GenerationWarning::SettingIgnored(
+                "unicode word characters require the perl class syntax".to_string(),
+            ));
+        }
+        if config.token_repetition.is_some() && config.is_verbose_mode_enabled {
+            warnings.push(GenerationWarning::SettingIgnored(
+                "repeated tokens are not supported in verbose mode".to_string(),
+            ));
+        }
+        if config.is_subpattern_defined {
+            if !config.flavor.is_subroutine_supported() {
+                warnings.push(GenerationWarning::SettingIgnored(
+                    "subpattern definitions are not supported by the chosen flavor".to_string(),
+                ));
+            } else if config.is_verbose_mode_enabled {
+                warnings.push(GenerationWarning::SettingIgnored(
+                    "subpattern definitions are not supported in verbose mode".to_string(),
+                ));
+            }
+        }
+        if (config.lookbehind.is_some() || config.lookahead.is_some())
+            && !config.flavor.is_lookaround_supported()
+        {
+            warnings.push(GenerationWarning::SettingIgnored(
+                "lookaround assertions are not supported by the chosen flavor".to_string(),
+            ));
+        }
+        if config.is_start_word_boundary_used && config.lookbehind_assertion().is_some()
+            || config.is_end_word_boundary_used && config.lookahead_assertion().is_some()
+        {
+            warnings.push(GenerationWarning::SettingIgnored(
+                "word boundaries are replaced by lookaround assertions".to_string(),
+            ));
+        }
+        if config.flavor.is_posix() {
+            if config.is_ignore_case_flag_used() {
+                warnings.push(GenerationWarning::SettingIgnored(
+                    "case-insensitive matching is not supported by the chosen flavor".to_string(),
+                ));
+            }
+            if config.is_verbose_mode_enabled {
+                warnings.push(GenerationWarning::SettingIgnored(
+                    "verbose mode is not supported by the chosen flavor".to_string(),
+                ));
+            }
+            if config.is_non_ascii_char_escaped {
+                warnings.push(GenerationWarning::SettingIgnored(
+                    "escaping of non-ascii characters is not supported by the chosen flavor"
+                        .to_string(),
+                ));
+            }
+            if config.is_control_char_escaped {
+                warnings.push(GenerationWarning::SettingIgnored(
+                    "escaping of control characters is not supported by the chosen flavor"
+                        .to_string(),
+                ));
+            }
+            if config.is_semver_structured {
+                warnings.push(GenerationWarning::SettingIgnored(
+                    "structured semantic versions are not supported by the chosen flavor"
+                        .to_string(),
+                ));
+            }
+        }
+        if config.is_deduplication_case_insensitive && config.is_case_insensitive_matching() {
+            warnings.push(GenerationWarning::SettingIgnored(
+                "case-insensitive deduplication is implied by case-insensitive matching"
+                    .to_string(),
+            ));
+        }
+        if config.is_bounded_only && config.without_unbounded_settings() != *config {
+            warnings.push(GenerationWarning::SettingIgnored(
+                "unbounded quantifiers are disabled by bounded-only generation".to_string(),
+            ));
+        }
+        if config.is_literal_only && config.without_generalization() != *config {
+            warnings.push(GenerationWarning::SettingIgnored(
+                "generalizing settings are disabled by literal-only generation".to_string(),
+            ));
+        }
+        if config.is_input_treated_as_pattern
+            && config.without_pattern_incompatible_settings() != *config
+        {
+            warnings.push(GenerationWarning::SettingIgnored(
+                "generalizing settings and verbose mode are disabled by pattern fragments"
+                    .to_string(),
+            ));
+        }
+        if config.is_prefix_matched && config.without_prefix_incompatible_settings() != *config {
+            warnings.push(GenerationWarning::SettingIgnored(
+                "conversion of repetitions and restructuring settings are disabled by prefix matching"
+                    .to_string(),
+            ));
+        }
+        if config.maximum_factor_depth.is_some() && !config.is_suffix_factored {
+            warnings.push(GenerationWarning::SettingIgnored(
+                "maximum factoring depth requires the factoring of suffixes".to_string(),
+            ));
+        }
+        if config.is_repetition_upper_bound_relaxed && !config.is_repetition_converted() {
+            warnings.push(GenerationWarning::SettingIgnored(
+                "open-ended repetitions require the conversion of repetitions".to_string(),
+            ));
+        }
+        if config.is_repetition_strict && !config.is_repetition_converted() {
+            warnings.push(GenerationWarning::SettingIgnored(
+                "strict repetitions require the conversion of repetitions".to_string(),
+            ));
+        }
+        if config.is_repetition_strict && config.is_repetition_upper_bound_relaxed {
+            warnings.push(GenerationWarning::SettingIgnored(
+                "open-ended repetitions are disabled by strict repetitions".to_string(),
+            ));
+        }
+        if config.unescaped_chars.contains(&'\\') {
+            warnings.push(GenerationWarning::SettingIgnored(
+                "the backslash is always escaped".to_string(),
+            ));
+        }
+        if config.class_conversion_coverage.is_some() && !config.is_char_class_feature_enabled() {
+            warnings.push(GenerationWarning::SettingIgnored(
+                "common positions require the conversion of character classes".to_string(),
+            ));
+        }
+        if config.quantifier_threshold.is_some() && !config.is_repetition_converted() {
+            warnings.push(GenerationWarning::SettingIgnored(
+                "the quantifier threshold requires the conversion of repetitions".to_string(),
+            ));
+        }
+
+        warnings
     }
 }
 
@@ -86,43 +2140,102 @@ impl Display for RegExp {
         let (flag, left_anchor, left_parenthesis, right_parenthesis, right_anchor) =
             to_colorized_string(
                 vec![
-                    if self.config.is_case_insensitive_matching() {
+                    if self.config.is_ignore_case_flag_used()
+                        && self.has_cased_char()
+                        && !self.is_ignore_case_flag_scoped()
+                    {
                         ColorizableString::IgnoreCaseFlag
                     } else {
                         ColorizableString::EmptyString
                     },
-                    ColorizableString::Caret,
+                    if let Some(assertion) = self.config.lookbehind_assertion() {
+                        ColorizableString::Lookbehind(assertion.clone())
+                    } else if self.config.is_start_word_boundary_used {
+                        ColorizableString::WordBoundary
+                    } else if self.config.is_start_anchor_disabled {
+                        ColorizableString::EmptyString
+                    } else if self.config.is_continuation_anchor_enabled() {
+                        ColorizableString::ContinuationAnchor
+                    } else {
+                        ColorizableString::Caret
+                    },
                     if self.config.is_capturing_group_enabled() {
                         ColorizableString::CapturingLeftParenthesis
+                    } else if self.is_ignore_case_flag_scoped() {
+                        ColorizableString::ScopedIgnoreCaseLeftParenthesis
                     } else {
                         ColorizableString::NonCapturingLeftParenthesis
                     },
                     ColorizableString::RightParenthesis,
-                    ColorizableString::DollarSign,
+                    if self.config.is_trailing_catch_all_used {
+                        ColorizableString::CatchAll
+                    } else if let Some(assertion) = self.config.lookahead_assertion() {
+                        ColorizableString::Lookahead(assertion.clone())
+                    } else if self.config.is_end_word_boundary_used {
+                        ColorizableString::WordBoundary
+                    } else if self.config.is_end_anchor_disabled {
+                        ColorizableString::EmptyString
+                    } else {
+                        ColorizableString::DollarSign
+                    },
                 ],
                 &self.config,
             );
+        let dot_all_flag = if self.config.is_dot_matching_newlines
+            && (self.config.is_trailing_catch_all_used || self.ast.has_any_char_wildcard())
+        {
+            ColorizableString::DotAllFlag
+        } else {
+            ColorizableString::EmptyString
+        };
+        write!(
+            f,
+            "{}{}",
+            flag,
+            dot_all_flag.to_colorized_string(&self.config)
+        )?;
 
-        match self.ast {
-            Expression::Alternation(_, _) => write!(
-                f,
-                "{}{}{}{}{}{}",
-                flag,
-                left_anchor,
-                left_parenthesis,
-                self.ast.to_string(),
-                right_parenthesis,
-                right_anchor
-            ),
-            _ => write!(
+        if self.config.is_subpattern_definition_enabled() {
+            let mut ast = self.ast.clone();
+            let mut categories = self.categories.clone();
+            let definitions = self.define_subpatterns(&mut ast, &mut categories);
+            if !definitions.is_empty() {
+                write!(
+                    f,
+                    "{}",
+                    ColorizableString::DefineLeftParenthesis.to_colorized_string(&self.config)
+                )?;
+                for (name, body) in definitions.iter() {
+                    write!(
+                        f,
+                        "{}{}{}",
+                        ColorizableString::NamedLeftParenthesis(name.clone())
+                            .to_colorized_string(&self.config),
+                        body,
+                        right_parenthesis
+                    )?;
+                }
+                write!(f, "{}", right_parenthesis)?;
+            }
+            return self.format_anchored_pattern(
                 f,
-                "{}{}{}{}",
-                flag,
-                left_anchor,
-                self.ast.to_string(),
-                right_anchor
-            ),
+                &ast,
+                &categories,
+                &left_anchor,
+                &left_parenthesis,
+                &right_parenthesis,
+                &right_anchor,
+            );
         }
+        self.format_anchored_pattern(
+            f,
+            &self.ast,
+            &self.categories,
+            &left_anchor,
+            &left_parenthesis,
+            &right_parenthesis,
+            &right_anchor,
+        )
     }
 }
 
@@ -138,7 +2251,7 @@ fn to_colorized_string(
 ) {
     let v = strings
         .iter()
-        .map(|it| it.to_colorized_string(config.is_output_colorized))
+        .map(|it| it.to_colorized_string(config))
         .collect_vec();
 
     (
@@ -149,3 +2262,116 @@ fn to_colorized_string(
         v[4].clone(),
     )
 }
+
+fn count_common_graphemes<'a, F>(graphemes: &[Vec<&'a str>], nth_grapheme: F) -> usize
+where
+    F: Fn(&[&'a str], usize) -> Option<&'a str>,
+{
+    let first = match graphemes.first() {
+        Some(first) => first,
+        None => return 0,
+    };
+    (0..first.len())
+        .take_while(|&i| {
+            let grapheme = nth_grapheme(first, i);
+            graphemes.iter().all(|it| nth_grapheme(it, i) == grapheme)
+        })
+        .count()
+}
+
+fn is_valid_date_time(s: &str, layout: &str) -> bool {
+    let has_layout = s.chars().count() == layout.chars().count()
+        && s.chars().zip(layout.chars()).all(|(c, l)| {
+            if l == DIGIT_PLACEHOLDER {
+                c.is_ascii_digit()
+            } else {
+                c == l
+            }
+        });
+    if !has_layout {
+        return false;
+    }
+    let mut numbers = s
+        .split(|c: char| !c.is_ascii_digit())
+        .map(|it| it.parse::<u32>().unwrap())
+        .collect_vec();
+    if layout.starts_with("####") {
+        let (month, day) = (numbers[1], numbers[2]);
+        if !(1..=12).contains(&month) || !(1..=31).contains(&day) {
+            return false;
+        }
+        numbers.drain(..3);
+    }
+    match numbers.as_slice() {
+        [] => true,
+        [hour, minute, second] => *hour <= 23 && *minute <= 59 && *second <= 59,
+        _ => false,
+    }
+}
+
+fn is_next_to_separator(chars: &[char], idx: usize, separators: &BTreeSet<char>) -> bool {
+    let preceding = chars[..idx].iter().rev().find(|c| !c.is_whitespace());
+    let following = chars[idx + 1..].iter().find(|c| !c.is_whitespace());
+    matches!(preceding, Some(c) if separators.contains(c))
+        || matches!(following, Some(c) if separators.contains(c))
+}
+
+fn split_csv_row(row: &str) -> Option<Vec<String>> {
+    let mut values = vec![];
+    let mut value = String::new();
+    let mut is_within_quotes = false;
+
+    for c in row.chars() {
+        match c {
+            '"' => {
+                is_within_quotes = !is_within_quotes;
+                value.push(c);
+            }
+            ',' if !is_within_quotes => values.push(std::mem::take(&mut value)),
+            _ => value.push(c),
+        }
+    }
+
+    if is_within_quotes {
+        return None;
+    }
+    values.push(value);
+    Some(values)
+}
+
+fn skeleton_of(test_case: &str) -> String {
+    let mut skeleton = String::new();
+
+    for c in test_case.chars() {
+        let symbol = if c.is_numeric() {
+            'D'
+        } else if c.is_alphabetic() {
+            'L'
+        } else {
+            c
+        };
+        let is_same_run = matches!(symbol, 'D' | 'L') && skeleton.ends_with(symbol);
+        if !is_same_run {
+            skeleton.push(symbol);
+        }
+    }
+
+    skeleton
+}
+
+fn is_quoted_csv_value(value: &str) -> bool {
+    value.len() >= 2 && value.starts_with('"') && value.ends_with('"')
+}
+
+fn first_char(grapheme: &Grapheme, config: &RegExpConfig) -> Option<char> {
+    if grapheme.has_repetitions() {
+        return first_char(grapheme.repetitions.first()?, config);
+    }
+    let value = grapheme.chars().first()?;
+    let is_char_class = value.starts_with('\\') && value.chars().count() > 1;
+    if is_char_class || config.is_wildcard(value) {
+        None
+    } else {
+        value.chars().next()
+    }
+}
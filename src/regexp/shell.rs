@@ -0,0 +1,52 @@
+/*
+ * Copyright © 2019-2020 Peter M. Stahl pemistahl@gmail.com
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ * http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either expressed or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+/// This enum specifies the shells whose quoting rules are applied by method
+/// [`RegExp.to_shell_arg`](./struct.RegExp.html#method.to_shell_arg).
+#[derive(Clone, Copy, Debug, Hash, Ord, PartialOrd, Eq, PartialEq)]
+pub enum Shell {
+    /// Quotes the regular expression for POSIX-compatible shells such as `sh`, `bash` and `zsh`.
+    /// It is enclosed in single quotes, and each single quote within it is written as `'\''`.
+    Posix,
+
+    /// Quotes the regular expression for [PowerShell](https://docs.microsoft.com/powershell).
+    /// It is enclosed in single quotes, and each single quote within it is doubled.
+    /// PowerShell treats typographic single quotes like ASCII ones, so they are doubled as well.
+    PowerShell,
+}
+
+impl Shell {
+    pub(crate) fn quote(&self, pattern: &str) -> String {
+        let quoted_pattern: String = match self {
+            Shell::Posix => pattern.replace('\'', "'\\''"),
+            Shell::PowerShell => pattern
+                .chars()
+                .flat_map(|c| {
+                    if is_powershell_single_quote(c) {
+                        vec![c, c]
+                    } else {
+                        vec![c]
+                    }
+                })
+                .collect(),
+        };
+        format!("'{}'", quoted_pattern)
+    }
+}
+
+fn is_powershell_single_quote(c: char) -> bool {
+    matches!(c, '\'' | '\u{2018}' | '\u{2019}' | '\u{201a}' | '\u{201b}')
+}
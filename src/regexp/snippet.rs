@@ -0,0 +1,90 @@
+/*
+ * Copyright © 2019-2020 Peter M. Stahl pemistahl@gmail.com
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ * http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either expressed or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use itertools::Itertools;
+
+/// This enum specifies the programming languages which test snippets can be generated for
+/// by method [`RegExp.to_test_snippet`](./struct.RegExp.html#method.to_test_snippet).
+#[derive(Clone, Copy, Debug, Hash, Ord, PartialOrd, Eq, PartialEq)]
+pub enum SnippetLanguage {
+    /// Generates a test function which compiles the regular expression
+    /// with the [*regex*](https://crates.io/crates/regex) crate.
+    Rust,
+
+    /// Generates a test function for [*pytest*](https://pytest.org) which compiles
+    /// the regular expression with the `re` module of the standard library.
+    Python,
+}
+
+impl SnippetLanguage {
+    pub(crate) fn format_test(&self, pattern: &str, test_cases: &[String]) -> String {
+        match self {
+            SnippetLanguage::Rust => format!(
+                "#[test]\nfn regexp_matches_test_cases() {{\n    \
+                 let regexp = regex::Regex::new({}).unwrap();\n{}\n}}\n",
+                to_rust_raw_literal(pattern),
+                test_cases
+                    .iter()
+                    .map(|it| format!("    assert!(regexp.is_match({:?}));", it))
+                    .join("\n")
+            ),
+            SnippetLanguage::Python => format!(
+                "import re\n\n\ndef test_regexp_matches_test_cases():\n    \
+                 regexp = re.compile({})\n{}\n",
+                to_python_literal(pattern, true),
+                test_cases
+                    .iter()
+                    .map(|it| format!("    assert regexp.search({})", to_python_literal(it, false)))
+                    .join("\n")
+            ),
+        }
+    }
+}
+
+fn to_rust_raw_literal(s: &str) -> String {
+    let hash_count = s
+        .split('"')
+        .skip(1)
+        .map(|it| it.chars().take_while(|&c| c == '#').count() + 1)
+        .max()
+        .unwrap_or(0);
+    let hashes = "#".repeat(hash_count);
+    format!("r{}\"{}\"{}", hashes, s, hashes)
+}
+
+fn to_python_literal(s: &str, prefer_raw: bool) -> String {
+    let is_raw_possible =
+        !s.contains('"') && !s.ends_with('\\') && !s.chars().any(|c| c.is_control());
+
+    if prefer_raw && is_raw_possible {
+        return format!("r\"{}\"", s);
+    }
+
+    let escaped = s
+        .chars()
+        .map(|c| match c {
+            '\\' => "\\\\".to_string(),
+            '"' => "\\\"".to_string(),
+            '\n' => "\\n".to_string(),
+            '\r' => "\\r".to_string(),
+            '\t' => "\\t".to_string(),
+            _ if c.is_control() => format!("\\x{:02x}", c as u32),
+            _ => c.to_string(),
+        })
+        .join("");
+
+    format!("\"{}\"", escaped)
+}
@@ -0,0 +1,55 @@
+/*
+ * Copyright © 2019-2020 Peter M. Stahl pemistahl@gmail.com
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ * http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either expressed or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+/// This struct reports how many strings of a sample are matched by a regular expression.
+/// It is returned by method
+/// [`RegExp.match_stats`](./struct.RegExp.html#method.match_stats).
+///
+/// If the sample consists of strings which should be matched, the ratio of matched strings
+/// is the recall of the regular expression. If it consists of strings which should not be
+/// matched, the ratio of unmatched strings helps to assess its precision.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct MatchStats {
+    pub(crate) sample_count: usize,
+    pub(crate) matched_count: usize,
+}
+
+impl MatchStats {
+    /// Returns the number of strings in the sample.
+    pub fn sample_count(&self) -> usize {
+        self.sample_count
+    }
+
+    /// Returns the number of strings in the sample which are matched.
+    pub fn matched_count(&self) -> usize {
+        self.matched_count
+    }
+
+    /// Returns the number of strings in the sample which are not matched.
+    pub fn unmatched_count(&self) -> usize {
+        self.sample_count - self.matched_count
+    }
+
+    /// Returns the ratio of matched strings to all strings in the sample,
+    /// or `None` if the sample is empty.
+    pub fn matched_ratio(&self) -> Option<f64> {
+        if self.sample_count == 0 {
+            None
+        } else {
+            Some(self.matched_count as f64 / self.sample_count as f64)
+        }
+    }
+}
@@ -0,0 +1,148 @@
+/*
+ * Copyright © 2019-2020 Peter M. Stahl pemistahl@gmail.com
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ * http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either expressed or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use crate::char::GraphemeCluster;
+use crate::fsm::DFA;
+use crate::regexp::{ClusterTransformation, RegExp, RegExpConfig};
+use std::collections::BTreeSet;
+
+/// This struct builds regular expressions incrementally from test cases which arrive over time,
+/// e.g. while reading a log stream. It is created with
+/// [`RegExpBuilder::to_streaming`](./struct.RegExpBuilder.html#method.to_streaming)
+/// which carries over all settings and test cases given to the builder so far.
+///
+/// Instead of buffering all test cases, it only keeps the distinct prefixes of the
+/// test cases seen so far in a non-minimized automaton. A snapshot of the regular expression
+/// matching all of them can be retrieved at any time with
+/// [`current_pattern`](./struct.StreamingRegExpBuilder.html#method.current_pattern).
+///
+/// ⚠ Settings which need to inspect all test cases at once are ignored. These are the
+/// detection of IPv4 addresses, dates, hexadecimal numbers, semantic versions and CSV rows,
+/// prefix matching, literal-only alternations, substring branches, branch ordering by frequency,
+/// test cases treated as patterns, the rejection of replacement characters,
+/// case-insensitive deduplication and the memory budget.
+pub struct StreamingRegExpBuilder {
+    dfa: DFA,
+    alphabet: BTreeSet<char>,
+    test_case_count: usize,
+    config: RegExpConfig,
+}
+
+impl StreamingRegExpBuilder {
+    pub(crate) fn from(test_cases: &[String], config: &RegExpConfig) -> Self {
+        let config = RegExpConfig {
+            is_literal_only: false,
+            ..RegExp::effective_config(&RegExpConfig {
+                is_ipv4_structured: false,
+                is_date_time_structured: false,
+                is_hex_detected: false,
+                is_semver_structured: false,
+                is_csv_structured: false,
+                is_skeleton_grouped: false,
+                permutation_separator: None,
+                is_prefix_matched: false,
+                is_substring_branch_kept: false,
+                is_branch_order_weighted: false,
+                is_input_treated_as_pattern: false,
+                is_replacement_char_rejected: false,
+                is_deduplication_case_insensitive: false,
+                class_conversion_coverage: None,
+                ..config.without_memory_budget()
+            })
+        };
+        let mut builder = Self {
+            dfa: DFA::new(&config),
+            alphabet: BTreeSet::new(),
+            test_case_count: 0,
+            config,
+        };
+        builder.add_test_cases(test_cases);
+        builder
+    }
+
+    /// Adds a further batch of test cases to those seen so far.
+    /// The given test cases are not retained after they have been inserted into the automaton.
+    pub fn add_test_cases<T: Clone + Into<String>>(&mut self, test_cases: &[T]) -> &mut Self {
+        let mut test_cases = test_cases
+            .iter()
+            .cloned()
+            .map(|it| it.into())
+            .collect::<Vec<String>>();
+        let config = &self.config;
+
+        if let Some(line_ending) = config.line_ending {
+            RegExp::normalize_line_endings(&mut test_cases, line_ending);
+        }
+        if config.is_case_insensitive_matching() {
+            RegExp::convert_to_lowercase(&mut test_cases);
+        }
+        if !config.separators.is_empty() {
+            RegExp::remove_whitespace_around_separators(&mut test_cases, &config.separators);
+        }
+
+        let mut grapheme_clusters = test_cases
+            .iter()
+            .map(|it| GraphemeCluster::from(it, config))
+            .collect::<Vec<_>>();
+        self.alphabet
+            .extend(RegExp::collect_alphabet(&grapheme_clusters, config));
+        if !config.mutations.is_empty() {
+            for cluster in grapheme_clusters.iter_mut() {
+                cluster.apply_mutations();
+            }
+        }
+        RegExp::convert_grapheme_clusters(&mut grapheme_clusters, config, &mut None);
+        if let Some(ClusterTransformation(transformation)) = &config.cluster_transformation {
+            grapheme_clusters = grapheme_clusters
+                .iter()
+                .map(|it| transformation(it))
+                .collect();
+        }
+        if let Some(limit) = config.transposition_limit() {
+            let transposed_clusters = grapheme_clusters
+                .iter()
+                .flat_map(|it| it.transpositions(limit))
+                .collect::<Vec<_>>();
+            grapheme_clusters.extend(transposed_clusters);
+        }
+
+        for cluster in grapheme_clusters {
+            self.dfa.insert(cluster);
+        }
+        self.test_case_count += test_cases.len();
+        self
+    }
+
+    /// Returns the number of test cases seen so far, including duplicates.
+    pub fn test_case_count(&self) -> usize {
+        self.test_case_count
+    }
+
+    /// Returns a regular expression matching all test cases seen so far.
+    /// The order of alternation branches follows the order in which the test cases arrived,
+    /// so it may differ from the one produced by [`RegExpBuilder`](./struct.RegExpBuilder.html).
+    /// As long as no test case has been seen, the returned expression does not match anything.
+    pub fn current_pattern(&self) -> String {
+        if self.test_case_count == 0 {
+            return "^\\b\\B$".to_string();
+        }
+        let mut dfa = self.dfa.clone();
+        dfa.minimize();
+        RegExp::from_streamed_dfa(dfa, self.alphabet.clone(), &self.config)
+            .unwrap_or_else(|error| panic!("Regular expression generation failed: {}", error))
+            .to_string()
+    }
+}
@@ -0,0 +1,84 @@
+/*
+ * Copyright © 2019-2020 Peter M. Stahl pemistahl@gmail.com
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ * http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either expressed or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use std::time::{Duration, Instant};
+
+/// This struct reports how long the individual stages of regular expression generation took.
+/// It is returned by method
+/// [`RegExpBuilder.build_with_timings`](./struct.RegExpBuilder.html#method.build_with_timings).
+///
+/// Stages which have been skipped because of the chosen settings report a duration of zero.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct GenerationTimings {
+    pub(crate) clustering: Duration,
+    pub(crate) char_class_conversion: Duration,
+    pub(crate) repetition_conversion: Duration,
+    pub(crate) dfa_construction: Duration,
+    pub(crate) ast_rendering: Duration,
+}
+
+impl GenerationTimings {
+    /// Returns the time it took to split the test cases into grapheme clusters.
+    pub fn clustering(&self) -> Duration {
+        self.clustering
+    }
+
+    /// Returns the time it took to convert characters to character classes.
+    pub fn char_class_conversion(&self) -> Duration {
+        self.char_class_conversion
+    }
+
+    /// Returns the time it took to detect repeated substrings.
+    pub fn repetition_conversion(&self) -> Duration {
+        self.repetition_conversion
+    }
+
+    /// Returns the time it took to construct and minimize the finite automaton.
+    pub fn dfa_construction(&self) -> Duration {
+        self.dfa_construction
+    }
+
+    /// Returns the time it took to derive the expression tree from the finite automaton,
+    /// including all subsequent transformations such as suffix factoring.
+    pub fn ast_rendering(&self) -> Duration {
+        self.ast_rendering
+    }
+
+    /// Returns the sum of all stage durations.
+    pub fn total(&self) -> Duration {
+        self.clustering
+            + self.char_class_conversion
+            + self.repetition_conversion
+            + self.dfa_construction
+            + self.ast_rendering
+    }
+}
+
+pub(crate) fn measure<T, F: FnOnce() -> T>(
+    timings: &mut Option<GenerationTimings>,
+    stage: fn(&mut GenerationTimings) -> &mut Duration,
+    f: F,
+) -> T {
+    match timings {
+        Some(timings) => {
+            let start = Instant::now();
+            let result = f();
+            *stage(timings) += start.elapsed();
+            result
+        }
+        None => f(),
+    }
+}
@@ -0,0 +1,81 @@
+/*
+ * Copyright © 2019-2020 Peter M. Stahl pemistahl@gmail.com
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ * http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either expressed or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use std::fmt::{Display, Formatter, Result};
+
+/// This enum describes notable decisions taken during regular expression generation
+/// which are returned by method
+/// [`RegExpBuilder.build_with_warnings`](./struct.RegExpBuilder.html#method.build_with_warnings).
+///
+/// None of them is an error. They help to understand why the resulting regular expression
+/// matches more or fewer strings than expected.
+#[derive(Clone, Debug, Hash, Ord, PartialOrd, Eq, PartialEq)]
+pub enum GenerationWarning {
+    /// Characters of the test cases have been replaced by the given shorthand
+    /// character class, such as `\d`. The resulting regular expression matches
+    /// characters which have not been observed in the test cases.
+    CharacterClassGeneralized(String),
+
+    /// The upper bound of at least one repetition range has been omitted,
+    /// so more repetitions are matched than have been observed in the test cases.
+    RepetitionUpperBoundRelaxed,
+
+    /// The branches of at least one alternation have been merged into a catch-all branch
+    /// because they exceeded the maximum quantity of branches.
+    AlternationBranchesMerged,
+
+    /// The given quantity of test cases has been removed because they were duplicates
+    /// of other test cases.
+    DuplicateTestCasesRemoved(usize),
+
+    /// The given metacharacter has not been escaped because it has been passed to method
+    /// [`RegExpBuilder.without_escaping_of`](./struct.RegExpBuilder.html#method.without_escaping_of).
+    /// It keeps its special meaning, so the test cases containing it are not matched literally.
+    MetacharacterUnescaped(char),
+
+    /// A setting has had no effect. The given string describes the reason.
+    SettingIgnored(String),
+}
+
+impl Display for GenerationWarning {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        match self {
+            GenerationWarning::CharacterClassGeneralized(shorthand) => write!(
+                f,
+                "characters have been generalized to character class {}",
+                shorthand
+            ),
+            GenerationWarning::RepetitionUpperBoundRelaxed => {
+                write!(f, "upper bound of repetition ranges has been relaxed")
+            }
+            GenerationWarning::AlternationBranchesMerged => {
+                write!(
+                    f,
+                    "alternation branches have been merged into a catch-all branch"
+                )
+            }
+            GenerationWarning::DuplicateTestCasesRemoved(quantity) => {
+                write!(f, "{} duplicate test case(s) have been removed", quantity)
+            }
+            GenerationWarning::MetacharacterUnescaped(c) => {
+                write!(f, "metacharacter {} has not been escaped", c)
+            }
+            GenerationWarning::SettingIgnored(reason) => {
+                write!(f, "setting has been ignored: {}", reason)
+            }
+        }
+    }
+}
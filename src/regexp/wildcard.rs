@@ -0,0 +1,40 @@
+/*
+ * Copyright © 2019-2020 Peter M. Stahl pemistahl@gmail.com
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ * http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either expressed or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+/// This enum specifies the regular expression constructs which a placeholder character
+/// can be converted to by method
+/// [`RegExpBuilder.with_placeholder`](./struct.RegExpBuilder.html#method.with_placeholder).
+#[derive(Clone, Copy, Debug, Hash, Ord, PartialOrd, Eq, PartialEq)]
+pub enum Wildcard {
+    /// Converts the placeholder to `.` which matches any character except for line breaks.
+    AnyChar,
+
+    /// Converts the placeholder to `\d` which matches any Unicode decimal digit.
+    Digit,
+
+    /// Converts the placeholder to `\w` which matches any Unicode word character.
+    Word,
+}
+
+impl Wildcard {
+    pub(crate) fn value(&self) -> &'static str {
+        match self {
+            Wildcard::AnyChar => ".",
+            Wildcard::Digit => "\\d",
+            Wildcard::Word => "\\w",
+        }
+    }
+}
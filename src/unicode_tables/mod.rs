@@ -14,10 +14,18 @@
  * limitations under the License.
  */
 
+mod case_folding;
 mod decimal;
+#[cfg(feature = "unicode-names")]
+mod name;
+mod script;
 mod space;
 mod word;
 
+pub use case_folding::CASE_FOLDING_SIMPLE;
 pub use decimal::DECIMAL_NUMBER;
+#[cfg(feature = "unicode-names")]
+pub use name::BY_CHAR as NAMES;
+pub use script::BY_NAME as SCRIPTS;
 pub use space::WHITE_SPACE;
 pub use word::WORD;
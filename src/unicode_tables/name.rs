@@ -0,0 +1,1872 @@
+/*
+ * Copyright © 2019-2020 Peter M. Stahl pemistahl@gmail.com
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ * http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either expressed or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+// DO NOT EDIT THIS FILE. IT WAS AUTOMATICALLY GENERATED FROM:
+//
+// UnicodeData.txt of ucd-14.0.0, restricted to the following blocks:
+//
+// U+0080..U+00FF Latin-1 Supplement
+// U+0100..U+017F Latin Extended-A
+// U+0180..U+024F Latin Extended-B
+// U+0250..U+02AF IPA Extensions
+// U+02B0..U+02FF Spacing Modifier Letters
+// U+0300..U+036F Combining Diacritical Marks
+// U+0370..U+03FF Greek and Coptic
+// U+0400..U+04FF Cyrillic
+// U+0500..U+052F Cyrillic Supplement
+// U+2000..U+206F General Punctuation
+// U+20A0..U+20CF Currency Symbols
+// U+2100..U+214F Letterlike Symbols
+// U+2190..U+21FF Arrows
+// U+2200..U+22FF Mathematical Operators
+
+pub const BY_CHAR: &[(char, &str)] = &[
+    ('\u{a0}', "NO-BREAK SPACE"),
+    ('\u{a1}', "INVERTED EXCLAMATION MARK"),
+    ('\u{a2}', "CENT SIGN"),
+    ('\u{a3}', "POUND SIGN"),
+    ('\u{a4}', "CURRENCY SIGN"),
+    ('\u{a5}', "YEN SIGN"),
+    ('\u{a6}', "BROKEN BAR"),
+    ('\u{a7}', "SECTION SIGN"),
+    ('\u{a8}', "DIAERESIS"),
+    ('\u{a9}', "COPYRIGHT SIGN"),
+    ('\u{aa}', "FEMININE ORDINAL INDICATOR"),
+    ('\u{ab}', "LEFT-POINTING DOUBLE ANGLE QUOTATION MARK"),
+    ('\u{ac}', "NOT SIGN"),
+    ('\u{ad}', "SOFT HYPHEN"),
+    ('\u{ae}', "REGISTERED SIGN"),
+    ('\u{af}', "MACRON"),
+    ('\u{b0}', "DEGREE SIGN"),
+    ('\u{b1}', "PLUS-MINUS SIGN"),
+    ('\u{b2}', "SUPERSCRIPT TWO"),
+    ('\u{b3}', "SUPERSCRIPT THREE"),
+    ('\u{b4}', "ACUTE ACCENT"),
+    ('\u{b5}', "MICRO SIGN"),
+    ('\u{b6}', "PILCROW SIGN"),
+    ('\u{b7}', "MIDDLE DOT"),
+    ('\u{b8}', "CEDILLA"),
+    ('\u{b9}', "SUPERSCRIPT ONE"),
+    ('\u{ba}', "MASCULINE ORDINAL INDICATOR"),
+    ('\u{bb}', "RIGHT-POINTING DOUBLE ANGLE QUOTATION MARK"),
+    ('\u{bc}', "VULGAR FRACTION ONE QUARTER"),
+    ('\u{bd}', "VULGAR FRACTION ONE HALF"),
+    ('\u{be}', "VULGAR FRACTION THREE QUARTERS"),
+    ('\u{bf}', "INVERTED QUESTION MARK"),
+    ('\u{c0}', "LATIN CAPITAL LETTER A WITH GRAVE"),
+    ('\u{c1}', "LATIN CAPITAL LETTER A WITH ACUTE"),
+    ('\u{c2}', "LATIN CAPITAL LETTER A WITH CIRCUMFLEX"),
+    ('\u{c3}', "LATIN CAPITAL LETTER A WITH TILDE"),
+    ('\u{c4}', "LATIN CAPITAL LETTER A WITH DIAERESIS"),
+    ('\u{c5}', "LATIN CAPITAL LETTER A WITH RING ABOVE"),
+    ('\u{c6}', "LATIN CAPITAL LETTER AE"),
+    ('\u{c7}', "LATIN CAPITAL LETTER C WITH CEDILLA"),
+    ('\u{c8}', "LATIN CAPITAL LETTER E WITH GRAVE"),
+    ('\u{c9}', "LATIN CAPITAL LETTER E WITH ACUTE"),
+    ('\u{ca}', "LATIN CAPITAL LETTER E WITH CIRCUMFLEX"),
+    ('\u{cb}', "LATIN CAPITAL LETTER E WITH DIAERESIS"),
+    ('\u{cc}', "LATIN CAPITAL LETTER I WITH GRAVE"),
+    ('\u{cd}', "LATIN CAPITAL LETTER I WITH ACUTE"),
+    ('\u{ce}', "LATIN CAPITAL LETTER I WITH CIRCUMFLEX"),
+    ('\u{cf}', "LATIN CAPITAL LETTER I WITH DIAERESIS"),
+    ('\u{d0}', "LATIN CAPITAL LETTER ETH"),
+    ('\u{d1}', "LATIN CAPITAL LETTER N WITH TILDE"),
+    ('\u{d2}', "LATIN CAPITAL LETTER O WITH GRAVE"),
+    ('\u{d3}', "LATIN CAPITAL LETTER O WITH ACUTE"),
+    ('\u{d4}', "LATIN CAPITAL LETTER O WITH CIRCUMFLEX"),
+    ('\u{d5}', "LATIN CAPITAL LETTER O WITH TILDE"),
+    ('\u{d6}', "LATIN CAPITAL LETTER O WITH DIAERESIS"),
+    ('\u{d7}', "MULTIPLICATION SIGN"),
+    ('\u{d8}', "LATIN CAPITAL LETTER O WITH STROKE"),
+    ('\u{d9}', "LATIN CAPITAL LETTER U WITH GRAVE"),
+    ('\u{da}', "LATIN CAPITAL LETTER U WITH ACUTE"),
+    ('\u{db}', "LATIN CAPITAL LETTER U WITH CIRCUMFLEX"),
+    ('\u{dc}', "LATIN CAPITAL LETTER U WITH DIAERESIS"),
+    ('\u{dd}', "LATIN CAPITAL LETTER Y WITH ACUTE"),
+    ('\u{de}', "LATIN CAPITAL LETTER THORN"),
+    ('\u{df}', "LATIN SMALL LETTER SHARP S"),
+    ('\u{e0}', "LATIN SMALL LETTER A WITH GRAVE"),
+    ('\u{e1}', "LATIN SMALL LETTER A WITH ACUTE"),
+    ('\u{e2}', "LATIN SMALL LETTER A WITH CIRCUMFLEX"),
+    ('\u{e3}', "LATIN SMALL LETTER A WITH TILDE"),
+    ('\u{e4}', "LATIN SMALL LETTER A WITH DIAERESIS"),
+    ('\u{e5}', "LATIN SMALL LETTER A WITH RING ABOVE"),
+    ('\u{e6}', "LATIN SMALL LETTER AE"),
+    ('\u{e7}', "LATIN SMALL LETTER C WITH CEDILLA"),
+    ('\u{e8}', "LATIN SMALL LETTER E WITH GRAVE"),
+    ('\u{e9}', "LATIN SMALL LETTER E WITH ACUTE"),
+    ('\u{ea}', "LATIN SMALL LETTER E WITH CIRCUMFLEX"),
+    ('\u{eb}', "LATIN SMALL LETTER E WITH DIAERESIS"),
+    ('\u{ec}', "LATIN SMALL LETTER I WITH GRAVE"),
+    ('\u{ed}', "LATIN SMALL LETTER I WITH ACUTE"),
+    ('\u{ee}', "LATIN SMALL LETTER I WITH CIRCUMFLEX"),
+    ('\u{ef}', "LATIN SMALL LETTER I WITH DIAERESIS"),
+    ('\u{f0}', "LATIN SMALL LETTER ETH"),
+    ('\u{f1}', "LATIN SMALL LETTER N WITH TILDE"),
+    ('\u{f2}', "LATIN SMALL LETTER O WITH GRAVE"),
+    ('\u{f3}', "LATIN SMALL LETTER O WITH ACUTE"),
+    ('\u{f4}', "LATIN SMALL LETTER O WITH CIRCUMFLEX"),
+    ('\u{f5}', "LATIN SMALL LETTER O WITH TILDE"),
+    ('\u{f6}', "LATIN SMALL LETTER O WITH DIAERESIS"),
+    ('\u{f7}', "DIVISION SIGN"),
+    ('\u{f8}', "LATIN SMALL LETTER O WITH STROKE"),
+    ('\u{f9}', "LATIN SMALL LETTER U WITH GRAVE"),
+    ('\u{fa}', "LATIN SMALL LETTER U WITH ACUTE"),
+    ('\u{fb}', "LATIN SMALL LETTER U WITH CIRCUMFLEX"),
+    ('\u{fc}', "LATIN SMALL LETTER U WITH DIAERESIS"),
+    ('\u{fd}', "LATIN SMALL LETTER Y WITH ACUTE"),
+    ('\u{fe}', "LATIN SMALL LETTER THORN"),
+    ('\u{ff}', "LATIN SMALL LETTER Y WITH DIAERESIS"),
+    ('\u{100}', "LATIN CAPITAL LETTER A WITH MACRON"),
+    ('\u{101}', "LATIN SMALL LETTER A WITH MACRON"),
+    ('\u{102}', "LATIN CAPITAL LETTER A WITH BREVE"),
+    ('\u{103}', "LATIN SMALL LETTER A WITH BREVE"),
+    ('\u{104}', "LATIN CAPITAL LETTER A WITH OGONEK"),
+    ('\u{105}', "LATIN SMALL LETTER A WITH OGONEK"),
+    ('\u{106}', "LATIN CAPITAL LETTER C WITH ACUTE"),
+    ('\u{107}', "LATIN SMALL LETTER C WITH ACUTE"),
+    ('\u{108}', "LATIN CAPITAL LETTER C WITH CIRCUMFLEX"),
+    ('\u{109}', "LATIN SMALL LETTER C WITH CIRCUMFLEX"),
+    ('\u{10a}', "LATIN CAPITAL LETTER C WITH DOT ABOVE"),
+    ('\u{10b}', "LATIN SMALL LETTER C WITH DOT ABOVE"),
+    ('\u{10c}', "LATIN CAPITAL LETTER C WITH CARON"),
+    ('\u{10d}', "LATIN SMALL LETTER C WITH CARON"),
+    ('\u{10e}', "LATIN CAPITAL LETTER D WITH CARON"),
+    ('\u{10f}', "LATIN SMALL LETTER D WITH CARON"),
+    ('\u{110}', "LATIN CAPITAL LETTER D WITH STROKE"),
+    ('\u{111}', "LATIN SMALL LETTER D WITH STROKE"),
+    ('\u{112}', "LATIN CAPITAL LETTER E WITH MACRON"),
+    ('\u{113}', "LATIN SMALL LETTER E WITH MACRON"),
+    ('\u{114}', "LATIN CAPITAL LETTER E WITH BREVE"),
+    ('\u{115}', "LATIN SMALL LETTER E WITH BREVE"),
+    ('\u{116}', "LATIN CAPITAL LETTER E WITH DOT ABOVE"),
+    ('\u{117}', "LATIN SMALL LETTER E WITH DOT ABOVE"),
+    ('\u{118}', "LATIN CAPITAL LETTER E WITH OGONEK"),
+    ('\u{119}', "LATIN SMALL LETTER E WITH OGONEK"),
+    ('\u{11a}', "LATIN CAPITAL LETTER E WITH CARON"),
+    ('\u{11b}', "LATIN SMALL LETTER E WITH CARON"),
+    ('\u{11c}', "LATIN CAPITAL LETTER G WITH CIRCUMFLEX"),
+    ('\u{11d}', "LATIN SMALL LETTER G WITH CIRCUMFLEX"),
+    ('\u{11e}', "LATIN CAPITAL LETTER G WITH BREVE"),
+    ('\u{11f}', "LATIN SMALL LETTER G WITH BREVE"),
+    ('\u{120}', "LATIN CAPITAL LETTER G WITH DOT ABOVE"),
+    ('\u{121}', "LATIN SMALL LETTER G WITH DOT ABOVE"),
+    ('\u{122}', "LATIN CAPITAL LETTER G WITH CEDILLA"),
+    ('\u{123}', "LATIN SMALL LETTER G WITH CEDILLA"),
+    ('\u{124}', "LATIN CAPITAL LETTER H WITH CIRCUMFLEX"),
+    ('\u{125}', "LATIN SMALL LETTER H WITH CIRCUMFLEX"),
+    ('\u{126}', "LATIN CAPITAL LETTER H WITH STROKE"),
+    ('\u{127}', "LATIN SMALL LETTER H WITH STROKE"),
+    ('\u{128}', "LATIN CAPITAL LETTER I WITH TILDE"),
+    ('\u{129}', "LATIN SMALL LETTER I WITH TILDE"),
+    ('\u{12a}', "LATIN CAPITAL LETTER I WITH MACRON"),
+    ('\u{12b}', "LATIN SMALL LETTER I WITH MACRON"),
+    ('\u{12c}', "LATIN CAPITAL LETTER I WITH BREVE"),
+    ('\u{12d}', "LATIN SMALL LETTER I WITH BREVE"),
+    ('\u{12e}', "LATIN CAPITAL LETTER I WITH OGONEK"),
+    ('\u{12f}', "LATIN SMALL LETTER I WITH OGONEK"),
+    ('\u{130}', "LATIN CAPITAL LETTER I WITH DOT ABOVE"),
+    ('\u{131}', "LATIN SMALL LETTER DOTLESS I"),
+    ('\u{132}', "LATIN CAPITAL LIGATURE IJ"),
+    ('\u{133}', "LATIN SMALL LIGATURE IJ"),
+    ('\u{134}', "LATIN CAPITAL LETTER J WITH CIRCUMFLEX"),
+    ('\u{135}', "LATIN SMALL LETTER J WITH CIRCUMFLEX"),
+    ('\u{136}', "LATIN CAPITAL LETTER K WITH CEDILLA"),
+    ('\u{137}', "LATIN SMALL LETTER K WITH CEDILLA"),
+    ('\u{138}', "LATIN SMALL LETTER KRA"),
+    ('\u{139}', "LATIN CAPITAL LETTER L WITH ACUTE"),
+    ('\u{13a}', "LATIN SMALL LETTER L WITH ACUTE"),
+    ('\u{13b}', "LATIN CAPITAL LETTER L WITH CEDILLA"),
+    ('\u{13c}', "LATIN SMALL LETTER L WITH CEDILLA"),
+    ('\u{13d}', "LATIN CAPITAL LETTER L WITH CARON"),
+    ('\u{13e}', "LATIN SMALL LETTER L WITH CARON"),
+    ('\u{13f}', "LATIN CAPITAL LETTER L WITH MIDDLE DOT"),
+    ('\u{140}', "LATIN SMALL LETTER L WITH MIDDLE DOT"),
+    ('\u{141}', "LATIN CAPITAL LETTER L WITH STROKE"),
+    ('\u{142}', "LATIN SMALL LETTER L WITH STROKE"),
+    ('\u{143}', "LATIN CAPITAL LETTER N WITH ACUTE"),
+    ('\u{144}', "LATIN SMALL LETTER N WITH ACUTE"),
+    ('\u{145}', "LATIN CAPITAL LETTER N WITH CEDILLA"),
+    ('\u{146}', "LATIN SMALL LETTER N WITH CEDILLA"),
+    ('\u{147}', "LATIN CAPITAL LETTER N WITH CARON"),
+    ('\u{148}', "LATIN SMALL LETTER N WITH CARON"),
+    ('\u{149}', "LATIN SMALL LETTER N PRECEDED BY APOSTROPHE"),
+    ('\u{14a}', "LATIN CAPITAL LETTER ENG"),
+    ('\u{14b}', "LATIN SMALL LETTER ENG"),
+    ('\u{14c}', "LATIN CAPITAL LETTER O WITH MACRON"),
+    ('\u{14d}', "LATIN SMALL LETTER O WITH MACRON"),
+    ('\u{14e}', "LATIN CAPITAL LETTER O WITH BREVE"),
+    ('\u{14f}', "LATIN SMALL LETTER O WITH BREVE"),
+    ('\u{150}', "LATIN CAPITAL LETTER O WITH DOUBLE ACUTE"),
+    ('\u{151}', "LATIN SMALL LETTER O WITH DOUBLE ACUTE"),
+    ('\u{152}', "LATIN CAPITAL LIGATURE OE"),
+    ('\u{153}', "LATIN SMALL LIGATURE OE"),
+    ('\u{154}', "LATIN CAPITAL LETTER R WITH ACUTE"),
+    ('\u{155}', "LATIN SMALL LETTER R WITH ACUTE"),
+    ('\u{156}', "LATIN CAPITAL LETTER R WITH CEDILLA"),
+    ('\u{157}', "LATIN SMALL LETTER R WITH CEDILLA"),
+    ('\u{158}', "LATIN CAPITAL LETTER R WITH CARON"),
+    ('\u{159}', "LATIN SMALL LETTER R WITH CARON"),
+    ('\u{15a}', "LATIN CAPITAL LETTER S WITH ACUTE"),
+    ('\u{15b}', "LATIN SMALL LETTER S WITH ACUTE"),
+    ('\u{15c}', "LATIN CAPITAL LETTER S WITH CIRCUMFLEX"),
+    ('\u{15d}', "LATIN SMALL LETTER S WITH CIRCUMFLEX"),
+    ('\u{15e}', "LATIN CAPITAL LETTER S WITH CEDILLA"),
+    ('\u{15f}', "LATIN SMALL LETTER S WITH CEDILLA"),
+    ('\u{160}', "LATIN CAPITAL LETTER S WITH CARON"),
+    ('\u{161}', "LATIN SMALL LETTER S WITH CARON"),
+    ('\u{162}', "LATIN CAPITAL LETTER T WITH CEDILLA"),
+    ('\u{163}', "LATIN SMALL LETTER T WITH CEDILLA"),
+    ('\u{164}', "LATIN CAPITAL LETTER T WITH CARON"),
+    ('\u{165}', "LATIN SMALL LETTER T WITH CARON"),
+    ('\u{166}', "LATIN CAPITAL LETTER T WITH STROKE"),
+    ('\u{167}', "LATIN SMALL LETTER T WITH STROKE"),
+    ('\u{168}', "LATIN CAPITAL LETTER U WITH TILDE"),
+    ('\u{169}', "LATIN SMALL LETTER U WITH TILDE"),
+    ('\u{16a}', "LATIN CAPITAL LETTER U WITH MACRON"),
+    ('\u{16b}', "LATIN SMALL LETTER U WITH MACRON"),
+    ('\u{16c}', "LATIN CAPITAL LETTER U WITH BREVE"),
+    ('\u{16d}', "LATIN SMALL LETTER U WITH BREVE"),
+    ('\u{16e}', "LATIN CAPITAL LETTER U WITH RING ABOVE"),
+    ('\u{16f}', "LATIN SMALL LETTER U WITH RING ABOVE"),
+    ('\u{170}', "LATIN CAPITAL LETTER U WITH DOUBLE ACUTE"),
+    ('\u{171}', "LATIN SMALL LETTER U WITH DOUBLE ACUTE"),
+    ('\u{172}', "LATIN CAPITAL LETTER U WITH OGONEK"),
+    ('\u{173}', "LATIN SMALL LETTER U WITH OGONEK"),
+    ('\u{174}', "LATIN CAPITAL LETTER W WITH CIRCUMFLEX"),
+    ('\u{175}', "LATIN SMALL LETTER W WITH CIRCUMFLEX"),
+    ('\u{176}', "LATIN CAPITAL LETTER Y WITH CIRCUMFLEX"),
+    ('\u{177}', "LATIN SMALL LETTER Y WITH CIRCUMFLEX"),
+    ('\u{178}', "LATIN CAPITAL LETTER Y WITH DIAERESIS"),
+    ('\u{179}', "LATIN CAPITAL LETTER Z WITH ACUTE"),
+    ('\u{17a}', "LATIN SMALL LETTER Z WITH ACUTE"),
+    ('\u{17b}', "LATIN CAPITAL LETTER Z WITH DOT ABOVE"),
+    ('\u{17c}', "LATIN SMALL LETTER Z WITH DOT ABOVE"),
+    ('\u{17d}', "LATIN CAPITAL LETTER Z WITH CARON"),
+    ('\u{17e}', "LATIN SMALL LETTER Z WITH CARON"),
+    ('\u{17f}', "LATIN SMALL LETTER LONG S"),
+    ('\u{180}', "LATIN SMALL LETTER B WITH STROKE"),
+    ('\u{181}', "LATIN CAPITAL LETTER B WITH HOOK"),
+    ('\u{182}', "LATIN CAPITAL LETTER B WITH TOPBAR"),
+    ('\u{183}', "LATIN SMALL LETTER B WITH TOPBAR"),
+    ('\u{184}', "LATIN CAPITAL LETTER TONE SIX"),
+    ('\u{185}', "LATIN SMALL LETTER TONE SIX"),
+    ('\u{186}', "LATIN CAPITAL LETTER OPEN O"),
+    ('\u{187}', "LATIN CAPITAL LETTER C WITH HOOK"),
+    ('\u{188}', "LATIN SMALL LETTER C WITH HOOK"),
+    ('\u{189}', "LATIN CAPITAL LETTER AFRICAN D"),
+    ('\u{18a}', "LATIN CAPITAL LETTER D WITH HOOK"),
+    ('\u{18b}', "LATIN CAPITAL LETTER D WITH TOPBAR"),
+    ('\u{18c}', "LATIN SMALL LETTER D WITH TOPBAR"),
+    ('\u{18d}', "LATIN SMALL LETTER TURNED DELTA"),
+    ('\u{18e}', "LATIN CAPITAL LETTER REVERSED E"),
+    ('\u{18f}', "LATIN CAPITAL LETTER SCHWA"),
+    ('\u{190}', "LATIN CAPITAL LETTER OPEN E"),
+    ('\u{191}', "LATIN CAPITAL LETTER F WITH HOOK"),
+    ('\u{192}', "LATIN SMALL LETTER F WITH HOOK"),
+    ('\u{193}', "LATIN CAPITAL LETTER G WITH HOOK"),
+    ('\u{194}', "LATIN CAPITAL LETTER GAMMA"),
+    ('\u{195}', "LATIN SMALL LETTER HV"),
+    ('\u{196}', "LATIN CAPITAL LETTER IOTA"),
+    ('\u{197}', "LATIN CAPITAL LETTER I WITH STROKE"),
+    ('\u{198}', "LATIN CAPITAL LETTER K WITH HOOK"),
+    ('\u{199}', "LATIN SMALL LETTER K WITH HOOK"),
+    ('\u{19a}', "LATIN SMALL LETTER L WITH BAR"),
+    ('\u{19b}', "LATIN SMALL LETTER LAMBDA WITH STROKE"),
+    ('\u{19c}', "LATIN CAPITAL LETTER TURNED M"),
+    ('\u{19d}', "LATIN CAPITAL LETTER N WITH LEFT HOOK"),
+    ('\u{19e}', "LATIN SMALL LETTER N WITH LONG RIGHT LEG"),
+    ('\u{19f}', "LATIN CAPITAL LETTER O WITH MIDDLE TILDE"),
+    ('\u{1a0}', "LATIN CAPITAL LETTER O WITH HORN"),
+    ('\u{1a1}', "LATIN SMALL LETTER O WITH HORN"),
+    ('\u{1a2}', "LATIN CAPITAL LETTER OI"),
+    ('\u{1a3}', "LATIN SMALL LETTER OI"),
+    ('\u{1a4}', "LATIN CAPITAL LETTER P WITH HOOK"),
+    ('\u{1a5}', "LATIN SMALL LETTER P WITH HOOK"),
+    ('\u{1a6}', "LATIN LETTER YR"),
+    ('\u{1a7}', "LATIN CAPITAL LETTER TONE TWO"),
+    ('\u{1a8}', "LATIN SMALL LETTER TONE TWO"),
+    ('\u{1a9}', "LATIN CAPITAL LETTER ESH"),
+    ('\u{1aa}', "LATIN LETTER REVERSED ESH LOOP"),
+    ('\u{1ab}', "LATIN SMALL LETTER T WITH PALATAL HOOK"),
+    ('\u{1ac}', "LATIN CAPITAL LETTER T WITH HOOK"),
+    ('\u{1ad}', "LATIN SMALL LETTER T WITH HOOK"),
+    ('\u{1ae}', "LATIN CAPITAL LETTER T WITH RETROFLEX HOOK"),
+    ('\u{1af}', "LATIN CAPITAL LETTER U WITH HORN"),
+    ('\u{1b0}', "LATIN SMALL LETTER U WITH HORN"),
+    ('\u{1b1}', "LATIN CAPITAL LETTER UPSILON"),
+    ('\u{1b2}', "LATIN CAPITAL LETTER V WITH HOOK"),
+    ('\u{1b3}', "LATIN CAPITAL LETTER Y WITH HOOK"),
+    ('\u{1b4}', "LATIN SMALL LETTER Y WITH HOOK"),
+    ('\u{1b5}', "LATIN CAPITAL LETTER Z WITH STROKE"),
+    ('\u{1b6}', "LATIN SMALL LETTER Z WITH STROKE"),
+    ('\u{1b7}', "LATIN CAPITAL LETTER EZH"),
+    ('\u{1b8}', "LATIN CAPITAL LETTER EZH REVERSED"),
+    ('\u{1b9}', "LATIN SMALL LETTER EZH REVERSED"),
+    ('\u{1ba}', "LATIN SMALL LETTER EZH WITH TAIL"),
+    ('\u{1bb}', "LATIN LETTER TWO WITH STROKE"),
+    ('\u{1bc}', "LATIN CAPITAL LETTER TONE FIVE"),
+    ('\u{1bd}', "LATIN SMALL LETTER TONE FIVE"),
+    ('\u{1be}', "LATIN LETTER INVERTED GLOTTAL STOP WITH STROKE"),
+    ('\u{1bf}', "LATIN LETTER WYNN"),
+    ('\u{1c0}', "LATIN LETTER DENTAL CLICK"),
+    ('\u{1c1}', "LATIN LETTER LATERAL CLICK"),
+    ('\u{1c2}', "LATIN LETTER ALVEOLAR CLICK"),
+    ('\u{1c3}', "LATIN LETTER RETROFLEX CLICK"),
+    ('\u{1c4}', "LATIN CAPITAL LETTER DZ WITH CARON"),
+    (
+        '\u{1c5}',
+        "LATIN CAPITAL LETTER D WITH SMALL LETTER Z WITH CARON",
+    ),
+    ('\u{1c6}', "LATIN SMALL LETTER DZ WITH CARON"),
+    ('\u{1c7}', "LATIN CAPITAL LETTER LJ"),
+    ('\u{1c8}', "LATIN CAPITAL LETTER L WITH SMALL LETTER J"),
+    ('\u{1c9}', "LATIN SMALL LETTER LJ"),
+    ('\u{1ca}', "LATIN CAPITAL LETTER NJ"),
+    ('\u{1cb}', "LATIN CAPITAL LETTER N WITH SMALL LETTER J"),
+    ('\u{1cc}', "LATIN SMALL LETTER NJ"),
+    ('\u{1cd}', "LATIN CAPITAL LETTER A WITH CARON"),
+    ('\u{1ce}', "LATIN SMALL LETTER A WITH CARON"),
+    ('\u{1cf}', "LATIN CAPITAL LETTER I WITH CARON"),
+    ('\u{1d0}', "LATIN SMALL LETTER I WITH CARON"),
+    ('\u{1d1}', "LATIN CAPITAL LETTER O WITH CARON"),
+    ('\u{1d2}', "LATIN SMALL LETTER O WITH CARON"),
+    ('\u{1d3}', "LATIN CAPITAL LETTER U WITH CARON"),
+    ('\u{1d4}', "LATIN SMALL LETTER U WITH CARON"),
+    (
+        '\u{1d5}',
+        "LATIN CAPITAL LETTER U WITH DIAERESIS AND MACRON",
+    ),
+    ('\u{1d6}', "LATIN SMALL LETTER U WITH DIAERESIS AND MACRON"),
+    ('\u{1d7}', "LATIN CAPITAL LETTER U WITH DIAERESIS AND ACUTE"),
+    ('\u{1d8}', "LATIN SMALL LETTER U WITH DIAERESIS AND ACUTE"),
+    ('\u{1d9}', "LATIN CAPITAL LETTER U WITH DIAERESIS AND CARON"),
+    ('\u{1da}', "LATIN SMALL LETTER U WITH DIAERESIS AND CARON"),
+    ('\u{1db}', "LATIN CAPITAL LETTER U WITH DIAERESIS AND GRAVE"),
+    ('\u{1dc}', "LATIN SMALL LETTER U WITH DIAERESIS AND GRAVE"),
+    ('\u{1dd}', "LATIN SMALL LETTER TURNED E"),
+    (
+        '\u{1de}',
+        "LATIN CAPITAL LETTER A WITH DIAERESIS AND MACRON",
+    ),
+    ('\u{1df}', "LATIN SMALL LETTER A WITH DIAERESIS AND MACRON"),
+    (
+        '\u{1e0}',
+        "LATIN CAPITAL LETTER A WITH DOT ABOVE AND MACRON",
+    ),
+    ('\u{1e1}', "LATIN SMALL LETTER A WITH DOT ABOVE AND MACRON"),
+    ('\u{1e2}', "LATIN CAPITAL LETTER AE WITH MACRON"),
+    ('\u{1e3}', "LATIN SMALL LETTER AE WITH MACRON"),
+    ('\u{1e4}', "LATIN CAPITAL LETTER G WITH STROKE"),
+    ('\u{1e5}', "LATIN SMALL LETTER G WITH STROKE"),
+    ('\u{1e6}', "LATIN CAPITAL LETTER G WITH CARON"),
+    ('\u{1e7}', "LATIN SMALL LETTER G WITH CARON"),
+    ('\u{1e8}', "LATIN CAPITAL LETTER K WITH CARON"),
+    ('\u{1e9}', "LATIN SMALL LETTER K WITH CARON"),
+    ('\u{1ea}', "LATIN CAPITAL LETTER O WITH OGONEK"),
+    ('\u{1eb}', "LATIN SMALL LETTER O WITH OGONEK"),
+    ('\u{1ec}', "LATIN CAPITAL LETTER O WITH OGONEK AND MACRON"),
+    ('\u{1ed}', "LATIN SMALL LETTER O WITH OGONEK AND MACRON"),
+    ('\u{1ee}', "LATIN CAPITAL LETTER EZH WITH CARON"),
+    ('\u{1ef}', "LATIN SMALL LETTER EZH WITH CARON"),
+    ('\u{1f0}', "LATIN SMALL LETTER J WITH CARON"),
+    ('\u{1f1}', "LATIN CAPITAL LETTER DZ"),
+    ('\u{1f2}', "LATIN CAPITAL LETTER D WITH SMALL LETTER Z"),
+    ('\u{1f3}', "LATIN SMALL LETTER DZ"),
+    ('\u{1f4}', "LATIN CAPITAL LETTER G WITH ACUTE"),
+    ('\u{1f5}', "LATIN SMALL LETTER G WITH ACUTE"),
+    ('\u{1f6}', "LATIN CAPITAL LETTER HWAIR"),
+    ('\u{1f7}', "LATIN CAPITAL LETTER WYNN"),
+    ('\u{1f8}', "LATIN CAPITAL LETTER N WITH GRAVE"),
+    ('\u{1f9}', "LATIN SMALL LETTER N WITH GRAVE"),
+    (
+        '\u{1fa}',
+        "LATIN CAPITAL LETTER A WITH RING ABOVE AND ACUTE",
+    ),
+    ('\u{1fb}', "LATIN SMALL LETTER A WITH RING ABOVE AND ACUTE"),
+    ('\u{1fc}', "LATIN CAPITAL LETTER AE WITH ACUTE"),
+    ('\u{1fd}', "LATIN SMALL LETTER AE WITH ACUTE"),
+    ('\u{1fe}', "LATIN CAPITAL LETTER O WITH STROKE AND ACUTE"),
+    ('\u{1ff}', "LATIN SMALL LETTER O WITH STROKE AND ACUTE"),
+    ('\u{200}', "LATIN CAPITAL LETTER A WITH DOUBLE GRAVE"),
+    ('\u{201}', "LATIN SMALL LETTER A WITH DOUBLE GRAVE"),
+    ('\u{202}', "LATIN CAPITAL LETTER A WITH INVERTED BREVE"),
+    ('\u{203}', "LATIN SMALL LETTER A WITH INVERTED BREVE"),
+    ('\u{204}', "LATIN CAPITAL LETTER E WITH DOUBLE GRAVE"),
+    ('\u{205}', "LATIN SMALL LETTER E WITH DOUBLE GRAVE"),
+    ('\u{206}', "LATIN CAPITAL LETTER E WITH INVERTED BREVE"),
+    ('\u{207}', "LATIN SMALL LETTER E WITH INVERTED BREVE"),
+    ('\u{208}', "LATIN CAPITAL LETTER I WITH DOUBLE GRAVE"),
+    ('\u{209}', "LATIN SMALL LETTER I WITH DOUBLE GRAVE"),
+    ('\u{20a}', "LATIN CAPITAL LETTER I WITH INVERTED BREVE"),
+    ('\u{20b}', "LATIN SMALL LETTER I WITH INVERTED BREVE"),
+    ('\u{20c}', "LATIN CAPITAL LETTER O WITH DOUBLE GRAVE"),
+    ('\u{20d}', "LATIN SMALL LETTER O WITH DOUBLE GRAVE"),
+    ('\u{20e}', "LATIN CAPITAL LETTER O WITH INVERTED BREVE"),
+    ('\u{20f}', "LATIN SMALL LETTER O WITH INVERTED BREVE"),
+    ('\u{210}', "LATIN CAPITAL LETTER R WITH DOUBLE GRAVE"),
+    ('\u{211}', "LATIN SMALL LETTER R WITH DOUBLE GRAVE"),
+    ('\u{212}', "LATIN CAPITAL LETTER R WITH INVERTED BREVE"),
+    ('\u{213}', "LATIN SMALL LETTER R WITH INVERTED BREVE"),
+    ('\u{214}', "LATIN CAPITAL LETTER U WITH DOUBLE GRAVE"),
+    ('\u{215}', "LATIN SMALL LETTER U WITH DOUBLE GRAVE"),
+    ('\u{216}', "LATIN CAPITAL LETTER U WITH INVERTED BREVE"),
+    ('\u{217}', "LATIN SMALL LETTER U WITH INVERTED BREVE"),
+    ('\u{218}', "LATIN CAPITAL LETTER S WITH COMMA BELOW"),
+    ('\u{219}', "LATIN SMALL LETTER S WITH COMMA BELOW"),
+    ('\u{21a}', "LATIN CAPITAL LETTER T WITH COMMA BELOW"),
+    ('\u{21b}', "LATIN SMALL LETTER T WITH COMMA BELOW"),
+    ('\u{21c}', "LATIN CAPITAL LETTER YOGH"),
+    ('\u{21d}', "LATIN SMALL LETTER YOGH"),
+    ('\u{21e}', "LATIN CAPITAL LETTER H WITH CARON"),
+    ('\u{21f}', "LATIN SMALL LETTER H WITH CARON"),
+    ('\u{220}', "LATIN CAPITAL LETTER N WITH LONG RIGHT LEG"),
+    ('\u{221}', "LATIN SMALL LETTER D WITH CURL"),
+    ('\u{222}', "LATIN CAPITAL LETTER OU"),
+    ('\u{223}', "LATIN SMALL LETTER OU"),
+    ('\u{224}', "LATIN CAPITAL LETTER Z WITH HOOK"),
+    ('\u{225}', "LATIN SMALL LETTER Z WITH HOOK"),
+    ('\u{226}', "LATIN CAPITAL LETTER A WITH DOT ABOVE"),
+    ('\u{227}', "LATIN SMALL LETTER A WITH DOT ABOVE"),
+    ('\u{228}', "LATIN CAPITAL LETTER E WITH CEDILLA"),
+    ('\u{229}', "LATIN SMALL LETTER E WITH CEDILLA"),
+    (
+        '\u{22a}',
+        "LATIN CAPITAL LETTER O WITH DIAERESIS AND MACRON",
+    ),
+    ('\u{22b}', "LATIN SMALL LETTER O WITH DIAERESIS AND MACRON"),
+    ('\u{22c}', "LATIN CAPITAL LETTER O WITH TILDE AND MACRON"),
+    ('\u{22d}', "LATIN SMALL LETTER O WITH TILDE AND MACRON"),
+    ('\u{22e}', "LATIN CAPITAL LETTER O WITH DOT ABOVE"),
+    ('\u{22f}', "LATIN SMALL LETTER O WITH DOT ABOVE"),
+    (
+        '\u{230}',
+        "LATIN CAPITAL LETTER O WITH DOT ABOVE AND MACRON",
+    ),
+    ('\u{231}', "LATIN SMALL LETTER O WITH DOT ABOVE AND MACRON"),
+    ('\u{232}', "LATIN CAPITAL LETTER Y WITH MACRON"),
+    ('\u{233}', "LATIN SMALL LETTER Y WITH MACRON"),
+    ('\u{234}', "LATIN SMALL LETTER L WITH CURL"),
+    ('\u{235}', "LATIN SMALL LETTER N WITH CURL"),
+    ('\u{236}', "LATIN SMALL LETTER T WITH CURL"),
+    ('\u{237}', "LATIN SMALL LETTER DOTLESS J"),
+    ('\u{238}', "LATIN SMALL LETTER DB DIGRAPH"),
+    ('\u{239}', "LATIN SMALL LETTER QP DIGRAPH"),
+    ('\u{23a}', "LATIN CAPITAL LETTER A WITH STROKE"),
+    ('\u{23b}', "LATIN CAPITAL LETTER C WITH STROKE"),
+    ('\u{23c}', "LATIN SMALL LETTER C WITH STROKE"),
+    ('\u{23d}', "LATIN CAPITAL LETTER L WITH BAR"),
+    ('\u{23e}', "LATIN CAPITAL LETTER T WITH DIAGONAL STROKE"),
+    ('\u{23f}', "LATIN SMALL LETTER S WITH SWASH TAIL"),
+    ('\u{240}', "LATIN SMALL LETTER Z WITH SWASH TAIL"),
+    ('\u{241}', "LATIN CAPITAL LETTER GLOTTAL STOP"),
+    ('\u{242}', "LATIN SMALL LETTER GLOTTAL STOP"),
+    ('\u{243}', "LATIN CAPITAL LETTER B WITH STROKE"),
+    ('\u{244}', "LATIN CAPITAL LETTER U BAR"),
+    ('\u{245}', "LATIN CAPITAL LETTER TURNED V"),
+    ('\u{246}', "LATIN CAPITAL LETTER E WITH STROKE"),
+    ('\u{247}', "LATIN SMALL LETTER E WITH STROKE"),
+    ('\u{248}', "LATIN CAPITAL LETTER J WITH STROKE"),
+    ('\u{249}', "LATIN SMALL LETTER J WITH STROKE"),
+    ('\u{24a}', "LATIN CAPITAL LETTER SMALL Q WITH HOOK TAIL"),
+    ('\u{24b}', "LATIN SMALL LETTER Q WITH HOOK TAIL"),
+    ('\u{24c}', "LATIN CAPITAL LETTER R WITH STROKE"),
+    ('\u{24d}', "LATIN SMALL LETTER R WITH STROKE"),
+    ('\u{24e}', "LATIN CAPITAL LETTER Y WITH STROKE"),
+    ('\u{24f}', "LATIN SMALL LETTER Y WITH STROKE"),
+    ('\u{250}', "LATIN SMALL LETTER TURNED A"),
+    ('\u{251}', "LATIN SMALL LETTER ALPHA"),
+    ('\u{252}', "LATIN SMALL LETTER TURNED ALPHA"),
+    ('\u{253}', "LATIN SMALL LETTER B WITH HOOK"),
+    ('\u{254}', "LATIN SMALL LETTER OPEN O"),
+    ('\u{255}', "LATIN SMALL LETTER C WITH CURL"),
+    ('\u{256}', "LATIN SMALL LETTER D WITH TAIL"),
+    ('\u{257}', "LATIN SMALL LETTER D WITH HOOK"),
+    ('\u{258}', "LATIN SMALL LETTER REVERSED E"),
+    ('\u{259}', "LATIN SMALL LETTER SCHWA"),
+    ('\u{25a}', "LATIN SMALL LETTER SCHWA WITH HOOK"),
+    ('\u{25b}', "LATIN SMALL LETTER OPEN E"),
+    ('\u{25c}', "LATIN SMALL LETTER REVERSED OPEN E"),
+    ('\u{25d}', "LATIN SMALL LETTER REVERSED OPEN E WITH HOOK"),
+    ('\u{25e}', "LATIN SMALL LETTER CLOSED REVERSED OPEN E"),
+    ('\u{25f}', "LATIN SMALL LETTER DOTLESS J WITH STROKE"),
+    ('\u{260}', "LATIN SMALL LETTER G WITH HOOK"),
+    ('\u{261}', "LATIN SMALL LETTER SCRIPT G"),
+    ('\u{262}', "LATIN LETTER SMALL CAPITAL G"),
+    ('\u{263}', "LATIN SMALL LETTER GAMMA"),
+    ('\u{264}', "LATIN SMALL LETTER RAMS HORN"),
+    ('\u{265}', "LATIN SMALL LETTER TURNED H"),
+    ('\u{266}', "LATIN SMALL LETTER H WITH HOOK"),
+    ('\u{267}', "LATIN SMALL LETTER HENG WITH HOOK"),
+    ('\u{268}', "LATIN SMALL LETTER I WITH STROKE"),
+    ('\u{269}', "LATIN SMALL LETTER IOTA"),
+    ('\u{26a}', "LATIN LETTER SMALL CAPITAL I"),
+    ('\u{26b}', "LATIN SMALL LETTER L WITH MIDDLE TILDE"),
+    ('\u{26c}', "LATIN SMALL LETTER L WITH BELT"),
+    ('\u{26d}', "LATIN SMALL LETTER L WITH RETROFLEX HOOK"),
+    ('\u{26e}', "LATIN SMALL LETTER LEZH"),
+    ('\u{26f}', "LATIN SMALL LETTER TURNED M"),
+    ('\u{270}', "LATIN SMALL LETTER TURNED M WITH LONG LEG"),
+    ('\u{271}', "LATIN SMALL LETTER M WITH HOOK"),
+    ('\u{272}', "LATIN SMALL LETTER N WITH LEFT HOOK"),
+    ('\u{273}', "LATIN SMALL LETTER N WITH RETROFLEX HOOK"),
+    ('\u{274}', "LATIN LETTER SMALL CAPITAL N"),
+    ('\u{275}', "LATIN SMALL LETTER BARRED O"),
+    ('\u{276}', "LATIN LETTER SMALL CAPITAL OE"),
+    ('\u{277}', "LATIN SMALL LETTER CLOSED OMEGA"),
+    ('\u{278}', "LATIN SMALL LETTER PHI"),
+    ('\u{279}', "LATIN SMALL LETTER TURNED R"),
+    ('\u{27a}', "LATIN SMALL LETTER TURNED R WITH LONG LEG"),
+    ('\u{27b}', "LATIN SMALL LETTER TURNED R WITH HOOK"),
+    ('\u{27c}', "LATIN SMALL LETTER R WITH LONG LEG"),
+    ('\u{27d}', "LATIN SMALL LETTER R WITH TAIL"),
+    ('\u{27e}', "LATIN SMALL LETTER R WITH FISHHOOK"),
+    ('\u{27f}', "LATIN SMALL LETTER REVERSED R WITH FISHHOOK"),
+    ('\u{280}', "LATIN LETTER SMALL CAPITAL R"),
+    ('\u{281}', "LATIN LETTER SMALL CAPITAL INVERTED R"),
+    ('\u{282}', "LATIN SMALL LETTER S WITH HOOK"),
+    ('\u{283}', "LATIN SMALL LETTER ESH"),
+    (
+        '\u{284}',
+        "LATIN SMALL LETTER DOTLESS J WITH STROKE AND HOOK",
+    ),
+    ('\u{285}', "LATIN SMALL LETTER SQUAT REVERSED ESH"),
+    ('\u{286}', "LATIN SMALL LETTER ESH WITH CURL"),
+    ('\u{287}', "LATIN SMALL LETTER TURNED T"),
+    ('\u{288}', "LATIN SMALL LETTER T WITH RETROFLEX HOOK"),
+    ('\u{289}', "LATIN SMALL LETTER U BAR"),
+    ('\u{28a}', "LATIN SMALL LETTER UPSILON"),
+    ('\u{28b}', "LATIN SMALL LETTER V WITH HOOK"),
+    ('\u{28c}', "LATIN SMALL LETTER TURNED V"),
+    ('\u{28d}', "LATIN SMALL LETTER TURNED W"),
+    ('\u{28e}', "LATIN SMALL LETTER TURNED Y"),
+    ('\u{28f}', "LATIN LETTER SMALL CAPITAL Y"),
+    ('\u{290}', "LATIN SMALL LETTER Z WITH RETROFLEX HOOK"),
+    ('\u{291}', "LATIN SMALL LETTER Z WITH CURL"),
+    ('\u{292}', "LATIN SMALL LETTER EZH"),
+    ('\u{293}', "LATIN SMALL LETTER EZH WITH CURL"),
+    ('\u{294}', "LATIN LETTER GLOTTAL STOP"),
+    ('\u{295}', "LATIN LETTER PHARYNGEAL VOICED FRICATIVE"),
+    ('\u{296}', "LATIN LETTER INVERTED GLOTTAL STOP"),
+    ('\u{297}', "LATIN LETTER STRETCHED C"),
+    ('\u{298}', "LATIN LETTER BILABIAL CLICK"),
+    ('\u{299}', "LATIN LETTER SMALL CAPITAL B"),
+    ('\u{29a}', "LATIN SMALL LETTER CLOSED OPEN E"),
+    ('\u{29b}', "LATIN LETTER SMALL CAPITAL G WITH HOOK"),
+    ('\u{29c}', "LATIN LETTER SMALL CAPITAL H"),
+    ('\u{29d}', "LATIN SMALL LETTER J WITH CROSSED-TAIL"),
+    ('\u{29e}', "LATIN SMALL LETTER TURNED K"),
+    ('\u{29f}', "LATIN LETTER SMALL CAPITAL L"),
+    ('\u{2a0}', "LATIN SMALL LETTER Q WITH HOOK"),
+    ('\u{2a1}', "LATIN LETTER GLOTTAL STOP WITH STROKE"),
+    ('\u{2a2}', "LATIN LETTER REVERSED GLOTTAL STOP WITH STROKE"),
+    ('\u{2a3}', "LATIN SMALL LETTER DZ DIGRAPH"),
+    ('\u{2a4}', "LATIN SMALL LETTER DEZH DIGRAPH"),
+    ('\u{2a5}', "LATIN SMALL LETTER DZ DIGRAPH WITH CURL"),
+    ('\u{2a6}', "LATIN SMALL LETTER TS DIGRAPH"),
+    ('\u{2a7}', "LATIN SMALL LETTER TESH DIGRAPH"),
+    ('\u{2a8}', "LATIN SMALL LETTER TC DIGRAPH WITH CURL"),
+    ('\u{2a9}', "LATIN SMALL LETTER FENG DIGRAPH"),
+    ('\u{2aa}', "LATIN SMALL LETTER LS DIGRAPH"),
+    ('\u{2ab}', "LATIN SMALL LETTER LZ DIGRAPH"),
+    ('\u{2ac}', "LATIN LETTER BILABIAL PERCUSSIVE"),
+    ('\u{2ad}', "LATIN LETTER BIDENTAL PERCUSSIVE"),
+    ('\u{2ae}', "LATIN SMALL LETTER TURNED H WITH FISHHOOK"),
+    (
+        '\u{2af}',
+        "LATIN SMALL LETTER TURNED H WITH FISHHOOK AND TAIL",
+    ),
+    ('\u{2b0}', "MODIFIER LETTER SMALL H"),
+    ('\u{2b1}', "MODIFIER LETTER SMALL H WITH HOOK"),
+    ('\u{2b2}', "MODIFIER LETTER SMALL J"),
+    ('\u{2b3}', "MODIFIER LETTER SMALL R"),
+    ('\u{2b4}', "MODIFIER LETTER SMALL TURNED R"),
+    ('\u{2b5}', "MODIFIER LETTER SMALL TURNED R WITH HOOK"),
+    ('\u{2b6}', "MODIFIER LETTER SMALL CAPITAL INVERTED R"),
+    ('\u{2b7}', "MODIFIER LETTER SMALL W"),
+    ('\u{2b8}', "MODIFIER LETTER SMALL Y"),
+    ('\u{2b9}', "MODIFIER LETTER PRIME"),
+    ('\u{2ba}', "MODIFIER LETTER DOUBLE PRIME"),
+    ('\u{2bb}', "MODIFIER LETTER TURNED COMMA"),
+    ('\u{2bc}', "MODIFIER LETTER APOSTROPHE"),
+    ('\u{2bd}', "MODIFIER LETTER REVERSED COMMA"),
+    ('\u{2be}', "MODIFIER LETTER RIGHT HALF RING"),
+    ('\u{2bf}', "MODIFIER LETTER LEFT HALF RING"),
+    ('\u{2c0}', "MODIFIER LETTER GLOTTAL STOP"),
+    ('\u{2c1}', "MODIFIER LETTER REVERSED GLOTTAL STOP"),
+    ('\u{2c2}', "MODIFIER LETTER LEFT ARROWHEAD"),
+    ('\u{2c3}', "MODIFIER LETTER RIGHT ARROWHEAD"),
+    ('\u{2c4}', "MODIFIER LETTER UP ARROWHEAD"),
+    ('\u{2c5}', "MODIFIER LETTER DOWN ARROWHEAD"),
+    ('\u{2c6}', "MODIFIER LETTER CIRCUMFLEX ACCENT"),
+    ('\u{2c7}', "CARON"),
+    ('\u{2c8}', "MODIFIER LETTER VERTICAL LINE"),
+    ('\u{2c9}', "MODIFIER LETTER MACRON"),
+    ('\u{2ca}', "MODIFIER LETTER ACUTE ACCENT"),
+    ('\u{2cb}', "MODIFIER LETTER GRAVE ACCENT"),
+    ('\u{2cc}', "MODIFIER LETTER LOW VERTICAL LINE"),
+    ('\u{2cd}', "MODIFIER LETTER LOW MACRON"),
+    ('\u{2ce}', "MODIFIER LETTER LOW GRAVE ACCENT"),
+    ('\u{2cf}', "MODIFIER LETTER LOW ACUTE ACCENT"),
+    ('\u{2d0}', "MODIFIER LETTER TRIANGULAR COLON"),
+    ('\u{2d1}', "MODIFIER LETTER HALF TRIANGULAR COLON"),
+    ('\u{2d2}', "MODIFIER LETTER CENTRED RIGHT HALF RING"),
+    ('\u{2d3}', "MODIFIER LETTER CENTRED LEFT HALF RING"),
+    ('\u{2d4}', "MODIFIER LETTER UP TACK"),
+    ('\u{2d5}', "MODIFIER LETTER DOWN TACK"),
+    ('\u{2d6}', "MODIFIER LETTER PLUS SIGN"),
+    ('\u{2d7}', "MODIFIER LETTER MINUS SIGN"),
+    ('\u{2d8}', "BREVE"),
+    ('\u{2d9}', "DOT ABOVE"),
+    ('\u{2da}', "RING ABOVE"),
+    ('\u{2db}', "OGONEK"),
+    ('\u{2dc}', "SMALL TILDE"),
+    ('\u{2dd}', "DOUBLE ACUTE ACCENT"),
+    ('\u{2de}', "MODIFIER LETTER RHOTIC HOOK"),
+    ('\u{2df}', "MODIFIER LETTER CROSS ACCENT"),
+    ('\u{2e0}', "MODIFIER LETTER SMALL GAMMA"),
+    ('\u{2e1}', "MODIFIER LETTER SMALL L"),
+    ('\u{2e2}', "MODIFIER LETTER SMALL S"),
+    ('\u{2e3}', "MODIFIER LETTER SMALL X"),
+    ('\u{2e4}', "MODIFIER LETTER SMALL REVERSED GLOTTAL STOP"),
+    ('\u{2e5}', "MODIFIER LETTER EXTRA-HIGH TONE BAR"),
+    ('\u{2e6}', "MODIFIER LETTER HIGH TONE BAR"),
+    ('\u{2e7}', "MODIFIER LETTER MID TONE BAR"),
+    ('\u{2e8}', "MODIFIER LETTER LOW TONE BAR"),
+    ('\u{2e9}', "MODIFIER LETTER EXTRA-LOW TONE BAR"),
+    ('\u{2ea}', "MODIFIER LETTER YIN DEPARTING TONE MARK"),
+    ('\u{2eb}', "MODIFIER LETTER YANG DEPARTING TONE MARK"),
+    ('\u{2ec}', "MODIFIER LETTER VOICING"),
+    ('\u{2ed}', "MODIFIER LETTER UNASPIRATED"),
+    ('\u{2ee}', "MODIFIER LETTER DOUBLE APOSTROPHE"),
+    ('\u{2ef}', "MODIFIER LETTER LOW DOWN ARROWHEAD"),
+    ('\u{2f0}', "MODIFIER LETTER LOW UP ARROWHEAD"),
+    ('\u{2f1}', "MODIFIER LETTER LOW LEFT ARROWHEAD"),
+    ('\u{2f2}', "MODIFIER LETTER LOW RIGHT ARROWHEAD"),
+    ('\u{2f3}', "MODIFIER LETTER LOW RING"),
+    ('\u{2f4}', "MODIFIER LETTER MIDDLE GRAVE ACCENT"),
+    ('\u{2f5}', "MODIFIER LETTER MIDDLE DOUBLE GRAVE ACCENT"),
+    ('\u{2f6}', "MODIFIER LETTER MIDDLE DOUBLE ACUTE ACCENT"),
+    ('\u{2f7}', "MODIFIER LETTER LOW TILDE"),
+    ('\u{2f8}', "MODIFIER LETTER RAISED COLON"),
+    ('\u{2f9}', "MODIFIER LETTER BEGIN HIGH TONE"),
+    ('\u{2fa}', "MODIFIER LETTER END HIGH TONE"),
+    ('\u{2fb}', "MODIFIER LETTER BEGIN LOW TONE"),
+    ('\u{2fc}', "MODIFIER LETTER END LOW TONE"),
+    ('\u{2fd}', "MODIFIER LETTER SHELF"),
+    ('\u{2fe}', "MODIFIER LETTER OPEN SHELF"),
+    ('\u{2ff}', "MODIFIER LETTER LOW LEFT ARROW"),
+    ('\u{300}', "COMBINING GRAVE ACCENT"),
+    ('\u{301}', "COMBINING ACUTE ACCENT"),
+    ('\u{302}', "COMBINING CIRCUMFLEX ACCENT"),
+    ('\u{303}', "COMBINING TILDE"),
+    ('\u{304}', "COMBINING MACRON"),
+    ('\u{305}', "COMBINING OVERLINE"),
+    ('\u{306}', "COMBINING BREVE"),
+    ('\u{307}', "COMBINING DOT ABOVE"),
+    ('\u{308}', "COMBINING DIAERESIS"),
+    ('\u{309}', "COMBINING HOOK ABOVE"),
+    ('\u{30a}', "COMBINING RING ABOVE"),
+    ('\u{30b}', "COMBINING DOUBLE ACUTE ACCENT"),
+    ('\u{30c}', "COMBINING CARON"),
+    ('\u{30d}', "COMBINING VERTICAL LINE ABOVE"),
+    ('\u{30e}', "COMBINING DOUBLE VERTICAL LINE ABOVE"),
+    ('\u{30f}', "COMBINING DOUBLE GRAVE ACCENT"),
+    ('\u{310}', "COMBINING CANDRABINDU"),
+    ('\u{311}', "COMBINING INVERTED BREVE"),
+    ('\u{312}', "COMBINING TURNED COMMA ABOVE"),
+    ('\u{313}', "COMBINING COMMA ABOVE"),
+    ('\u{314}', "COMBINING REVERSED COMMA ABOVE"),
+    ('\u{315}', "COMBINING COMMA ABOVE RIGHT"),
+    ('\u{316}', "COMBINING GRAVE ACCENT BELOW"),
+    ('\u{317}', "COMBINING ACUTE ACCENT BELOW"),
+    ('\u{318}', "COMBINING LEFT TACK BELOW"),
+    ('\u{319}', "COMBINING RIGHT TACK BELOW"),
+    ('\u{31a}', "COMBINING LEFT ANGLE ABOVE"),
+    ('\u{31b}', "COMBINING HORN"),
+    ('\u{31c}', "COMBINING LEFT HALF RING BELOW"),
+    ('\u{31d}', "COMBINING UP TACK BELOW"),
+    ('\u{31e}', "COMBINING DOWN TACK BELOW"),
+    ('\u{31f}', "COMBINING PLUS SIGN BELOW"),
+    ('\u{320}', "COMBINING MINUS SIGN BELOW"),
+    ('\u{321}', "COMBINING PALATALIZED HOOK BELOW"),
+    ('\u{322}', "COMBINING RETROFLEX HOOK BELOW"),
+    ('\u{323}', "COMBINING DOT BELOW"),
+    ('\u{324}', "COMBINING DIAERESIS BELOW"),
+    ('\u{325}', "COMBINING RING BELOW"),
+    ('\u{326}', "COMBINING COMMA BELOW"),
+    ('\u{327}', "COMBINING CEDILLA"),
+    ('\u{328}', "COMBINING OGONEK"),
+    ('\u{329}', "COMBINING VERTICAL LINE BELOW"),
+    ('\u{32a}', "COMBINING BRIDGE BELOW"),
+    ('\u{32b}', "COMBINING INVERTED DOUBLE ARCH BELOW"),
+    ('\u{32c}', "COMBINING CARON BELOW"),
+    ('\u{32d}', "COMBINING CIRCUMFLEX ACCENT BELOW"),
+    ('\u{32e}', "COMBINING BREVE BELOW"),
+    ('\u{32f}', "COMBINING INVERTED BREVE BELOW"),
+    ('\u{330}', "COMBINING TILDE BELOW"),
+    ('\u{331}', "COMBINING MACRON BELOW"),
+    ('\u{332}', "COMBINING LOW LINE"),
+    ('\u{333}', "COMBINING DOUBLE LOW LINE"),
+    ('\u{334}', "COMBINING TILDE OVERLAY"),
+    ('\u{335}', "COMBINING SHORT STROKE OVERLAY"),
+    ('\u{336}', "COMBINING LONG STROKE OVERLAY"),
+    ('\u{337}', "COMBINING SHORT SOLIDUS OVERLAY"),
+    ('\u{338}', "COMBINING LONG SOLIDUS OVERLAY"),
+    ('\u{339}', "COMBINING RIGHT HALF RING BELOW"),
+    ('\u{33a}', "COMBINING INVERTED BRIDGE BELOW"),
+    ('\u{33b}', "COMBINING SQUARE BELOW"),
+    ('\u{33c}', "COMBINING SEAGULL BELOW"),
+    ('\u{33d}', "COMBINING X ABOVE"),
+    ('\u{33e}', "COMBINING VERTICAL TILDE"),
+    ('\u{33f}', "COMBINING DOUBLE OVERLINE"),
+    ('\u{340}', "COMBINING GRAVE TONE MARK"),
+    ('\u{341}', "COMBINING ACUTE TONE MARK"),
+    ('\u{342}', "COMBINING GREEK PERISPOMENI"),
+    ('\u{343}', "COMBINING GREEK KORONIS"),
+    ('\u{344}', "COMBINING GREEK DIALYTIKA TONOS"),
+    ('\u{345}', "COMBINING GREEK YPOGEGRAMMENI"),
+    ('\u{346}', "COMBINING BRIDGE ABOVE"),
+    ('\u{347}', "COMBINING EQUALS SIGN BELOW"),
+    ('\u{348}', "COMBINING DOUBLE VERTICAL LINE BELOW"),
+    ('\u{349}', "COMBINING LEFT ANGLE BELOW"),
+    ('\u{34a}', "COMBINING NOT TILDE ABOVE"),
+    ('\u{34b}', "COMBINING HOMOTHETIC ABOVE"),
+    ('\u{34c}', "COMBINING ALMOST EQUAL TO ABOVE"),
+    ('\u{34d}', "COMBINING LEFT RIGHT ARROW BELOW"),
+    ('\u{34e}', "COMBINING UPWARDS ARROW BELOW"),
+    ('\u{34f}', "COMBINING GRAPHEME JOINER"),
+    ('\u{350}', "COMBINING RIGHT ARROWHEAD ABOVE"),
+    ('\u{351}', "COMBINING LEFT HALF RING ABOVE"),
+    ('\u{352}', "COMBINING FERMATA"),
+    ('\u{353}', "COMBINING X BELOW"),
+    ('\u{354}', "COMBINING LEFT ARROWHEAD BELOW"),
+    ('\u{355}', "COMBINING RIGHT ARROWHEAD BELOW"),
+    (
+        '\u{356}',
+        "COMBINING RIGHT ARROWHEAD AND UP ARROWHEAD BELOW",
+    ),
+    ('\u{357}', "COMBINING RIGHT HALF RING ABOVE"),
+    ('\u{358}', "COMBINING DOT ABOVE RIGHT"),
+    ('\u{359}', "COMBINING ASTERISK BELOW"),
+    ('\u{35a}', "COMBINING DOUBLE RING BELOW"),
+    ('\u{35b}', "COMBINING ZIGZAG ABOVE"),
+    ('\u{35c}', "COMBINING DOUBLE BREVE BELOW"),
+    ('\u{35d}', "COMBINING DOUBLE BREVE"),
+    ('\u{35e}', "COMBINING DOUBLE MACRON"),
+    ('\u{35f}', "COMBINING DOUBLE MACRON BELOW"),
+    ('\u{360}', "COMBINING DOUBLE TILDE"),
+    ('\u{361}', "COMBINING DOUBLE INVERTED BREVE"),
+    ('\u{362}', "COMBINING DOUBLE RIGHTWARDS ARROW BELOW"),
+    ('\u{363}', "COMBINING LATIN SMALL LETTER A"),
+    ('\u{364}', "COMBINING LATIN SMALL LETTER E"),
+    ('\u{365}', "COMBINING LATIN SMALL LETTER I"),
+    ('\u{366}', "COMBINING LATIN SMALL LETTER O"),
+    ('\u{367}', "COMBINING LATIN SMALL LETTER U"),
+    ('\u{368}', "COMBINING LATIN SMALL LETTER C"),
+    ('\u{369}', "COMBINING LATIN SMALL LETTER D"),
+    ('\u{36a}', "COMBINING LATIN SMALL LETTER H"),
+    ('\u{36b}', "COMBINING LATIN SMALL LETTER M"),
+    ('\u{36c}', "COMBINING LATIN SMALL LETTER R"),
+    ('\u{36d}', "COMBINING LATIN SMALL LETTER T"),
+    ('\u{36e}', "COMBINING LATIN SMALL LETTER V"),
+    ('\u{36f}', "COMBINING LATIN SMALL LETTER X"),
+    ('\u{370}', "GREEK CAPITAL LETTER HETA"),
+    ('\u{371}', "GREEK SMALL LETTER HETA"),
+    ('\u{372}', "GREEK CAPITAL LETTER ARCHAIC SAMPI"),
+    ('\u{373}', "GREEK SMALL LETTER ARCHAIC SAMPI"),
+    ('\u{374}', "GREEK NUMERAL SIGN"),
+    ('\u{375}', "GREEK LOWER NUMERAL SIGN"),
+    ('\u{376}', "GREEK CAPITAL LETTER PAMPHYLIAN DIGAMMA"),
+    ('\u{377}', "GREEK SMALL LETTER PAMPHYLIAN DIGAMMA"),
+    ('\u{37a}', "GREEK YPOGEGRAMMENI"),
+    ('\u{37b}', "GREEK SMALL REVERSED LUNATE SIGMA SYMBOL"),
+    ('\u{37c}', "GREEK SMALL DOTTED LUNATE SIGMA SYMBOL"),
+    ('\u{37d}', "GREEK SMALL REVERSED DOTTED LUNATE SIGMA SYMBOL"),
+    ('\u{37e}', "GREEK QUESTION MARK"),
+    ('\u{37f}', "GREEK CAPITAL LETTER YOT"),
+    ('\u{384}', "GREEK TONOS"),
+    ('\u{385}', "GREEK DIALYTIKA TONOS"),
+    ('\u{386}', "GREEK CAPITAL LETTER ALPHA WITH TONOS"),
+    ('\u{387}', "GREEK ANO TELEIA"),
+    ('\u{388}', "GREEK CAPITAL LETTER EPSILON WITH TONOS"),
+    ('\u{389}', "GREEK CAPITAL LETTER ETA WITH TONOS"),
+    ('\u{38a}', "GREEK CAPITAL LETTER IOTA WITH TONOS"),
+    ('\u{38c}', "GREEK CAPITAL LETTER OMICRON WITH TONOS"),
+    ('\u{38e}', "GREEK CAPITAL LETTER UPSILON WITH TONOS"),
+    ('\u{38f}', "GREEK CAPITAL LETTER OMEGA WITH TONOS"),
+    (
+        '\u{390}',
+        "GREEK SMALL LETTER IOTA WITH DIALYTIKA AND TONOS",
+    ),
+    ('\u{391}', "GREEK CAPITAL LETTER ALPHA"),
+    ('\u{392}', "GREEK CAPITAL LETTER BETA"),
+    ('\u{393}', "GREEK CAPITAL LETTER GAMMA"),
+    ('\u{394}', "GREEK CAPITAL LETTER DELTA"),
+    ('\u{395}', "GREEK CAPITAL LETTER EPSILON"),
+    ('\u{396}', "GREEK CAPITAL LETTER ZETA"),
+    ('\u{397}', "GREEK CAPITAL LETTER ETA"),
+    ('\u{398}', "GREEK CAPITAL LETTER THETA"),
+    ('\u{399}', "GREEK CAPITAL LETTER IOTA"),
+    ('\u{39a}', "GREEK CAPITAL LETTER KAPPA"),
+    ('\u{39b}', "GREEK CAPITAL LETTER LAMDA"),
+    ('\u{39c}', "GREEK CAPITAL LETTER MU"),
+    ('\u{39d}', "GREEK CAPITAL LETTER NU"),
+    ('\u{39e}', "GREEK CAPITAL LETTER XI"),
+    ('\u{39f}', "GREEK CAPITAL LETTER OMICRON"),
+    ('\u{3a0}', "GREEK CAPITAL LETTER PI"),
+    ('\u{3a1}', "GREEK CAPITAL LETTER RHO"),
+    ('\u{3a3}', "GREEK CAPITAL LETTER SIGMA"),
+    ('\u{3a4}', "GREEK CAPITAL LETTER TAU"),
+    ('\u{3a5}', "GREEK CAPITAL LETTER UPSILON"),
+    ('\u{3a6}', "GREEK CAPITAL LETTER PHI"),
+    ('\u{3a7}', "GREEK CAPITAL LETTER CHI"),
+    ('\u{3a8}', "GREEK CAPITAL LETTER PSI"),
+    ('\u{3a9}', "GREEK CAPITAL LETTER OMEGA"),
+    ('\u{3aa}', "GREEK CAPITAL LETTER IOTA WITH DIALYTIKA"),
+    ('\u{3ab}', "GREEK CAPITAL LETTER UPSILON WITH DIALYTIKA"),
+    ('\u{3ac}', "GREEK SMALL LETTER ALPHA WITH TONOS"),
+    ('\u{3ad}', "GREEK SMALL LETTER EPSILON WITH TONOS"),
+    ('\u{3ae}', "GREEK SMALL LETTER ETA WITH TONOS"),
+    ('\u{3af}', "GREEK SMALL LETTER IOTA WITH TONOS"),
+    (
+        '\u{3b0}',
+        "GREEK SMALL LETTER UPSILON WITH DIALYTIKA AND TONOS",
+    ),
+    ('\u{3b1}', "GREEK SMALL LETTER ALPHA"),
+    ('\u{3b2}', "GREEK SMALL LETTER BETA"),
+    ('\u{3b3}', "GREEK SMALL LETTER GAMMA"),
+    ('\u{3b4}', "GREEK SMALL LETTER DELTA"),
+    ('\u{3b5}', "GREEK SMALL LETTER EPSILON"),
+    ('\u{3b6}', "GREEK SMALL LETTER ZETA"),
+    ('\u{3b7}', "GREEK SMALL LETTER ETA"),
+    ('\u{3b8}', "GREEK SMALL LETTER THETA"),
+    ('\u{3b9}', "GREEK SMALL LETTER IOTA"),
+    ('\u{3ba}', "GREEK SMALL LETTER KAPPA"),
+    ('\u{3bb}', "GREEK SMALL LETTER LAMDA"),
+    ('\u{3bc}', "GREEK SMALL LETTER MU"),
+    ('\u{3bd}', "GREEK SMALL LETTER NU"),
+    ('\u{3be}', "GREEK SMALL LETTER XI"),
+    ('\u{3bf}', "GREEK SMALL LETTER OMICRON"),
+    ('\u{3c0}', "GREEK SMALL LETTER PI"),
+    ('\u{3c1}', "GREEK SMALL LETTER RHO"),
+    ('\u{3c2}', "GREEK SMALL LETTER FINAL SIGMA"),
+    ('\u{3c3}', "GREEK SMALL LETTER SIGMA"),
+    ('\u{3c4}', "GREEK SMALL LETTER TAU"),
+    ('\u{3c5}', "GREEK SMALL LETTER UPSILON"),
+    ('\u{3c6}', "GREEK SMALL LETTER PHI"),
+    ('\u{3c7}', "GREEK SMALL LETTER CHI"),
+    ('\u{3c8}', "GREEK SMALL LETTER PSI"),
+    ('\u{3c9}', "GREEK SMALL LETTER OMEGA"),
+    ('\u{3ca}', "GREEK SMALL LETTER IOTA WITH DIALYTIKA"),
+    ('\u{3cb}', "GREEK SMALL LETTER UPSILON WITH DIALYTIKA"),
+    ('\u{3cc}', "GREEK SMALL LETTER OMICRON WITH TONOS"),
+    ('\u{3cd}', "GREEK SMALL LETTER UPSILON WITH TONOS"),
+    ('\u{3ce}', "GREEK SMALL LETTER OMEGA WITH TONOS"),
+    ('\u{3cf}', "GREEK CAPITAL KAI SYMBOL"),
+    ('\u{3d0}', "GREEK BETA SYMBOL"),
+    ('\u{3d1}', "GREEK THETA SYMBOL"),
+    ('\u{3d2}', "GREEK UPSILON WITH HOOK SYMBOL"),
+    ('\u{3d3}', "GREEK UPSILON WITH ACUTE AND HOOK SYMBOL"),
+    ('\u{3d4}', "GREEK UPSILON WITH DIAERESIS AND HOOK SYMBOL"),
+    ('\u{3d5}', "GREEK PHI SYMBOL"),
+    ('\u{3d6}', "GREEK PI SYMBOL"),
+    ('\u{3d7}', "GREEK KAI SYMBOL"),
+    ('\u{3d8}', "GREEK LETTER ARCHAIC KOPPA"),
+    ('\u{3d9}', "GREEK SMALL LETTER ARCHAIC KOPPA"),
+    ('\u{3da}', "GREEK LETTER STIGMA"),
+    ('\u{3db}', "GREEK SMALL LETTER STIGMA"),
+    ('\u{3dc}', "GREEK LETTER DIGAMMA"),
+    ('\u{3dd}', "GREEK SMALL LETTER DIGAMMA"),
+    ('\u{3de}', "GREEK LETTER KOPPA"),
+    ('\u{3df}', "GREEK SMALL LETTER KOPPA"),
+    ('\u{3e0}', "GREEK LETTER SAMPI"),
+    ('\u{3e1}', "GREEK SMALL LETTER SAMPI"),
+    ('\u{3e2}', "COPTIC CAPITAL LETTER SHEI"),
+    ('\u{3e3}', "COPTIC SMALL LETTER SHEI"),
+    ('\u{3e4}', "COPTIC CAPITAL LETTER FEI"),
+    ('\u{3e5}', "COPTIC SMALL LETTER FEI"),
+    ('\u{3e6}', "COPTIC CAPITAL LETTER KHEI"),
+    ('\u{3e7}', "COPTIC SMALL LETTER KHEI"),
+    ('\u{3e8}', "COPTIC CAPITAL LETTER HORI"),
+    ('\u{3e9}', "COPTIC SMALL LETTER HORI"),
+    ('\u{3ea}', "COPTIC CAPITAL LETTER GANGIA"),
+    ('\u{3eb}', "COPTIC SMALL LETTER GANGIA"),
+    ('\u{3ec}', "COPTIC CAPITAL LETTER SHIMA"),
+    ('\u{3ed}', "COPTIC SMALL LETTER SHIMA"),
+    ('\u{3ee}', "COPTIC CAPITAL LETTER DEI"),
+    ('\u{3ef}', "COPTIC SMALL LETTER DEI"),
+    ('\u{3f0}', "GREEK KAPPA SYMBOL"),
+    ('\u{3f1}', "GREEK RHO SYMBOL"),
+    ('\u{3f2}', "GREEK LUNATE SIGMA SYMBOL"),
+    ('\u{3f3}', "GREEK LETTER YOT"),
+    ('\u{3f4}', "GREEK CAPITAL THETA SYMBOL"),
+    ('\u{3f5}', "GREEK LUNATE EPSILON SYMBOL"),
+    ('\u{3f6}', "GREEK REVERSED LUNATE EPSILON SYMBOL"),
+    ('\u{3f7}', "GREEK CAPITAL LETTER SHO"),
+    ('\u{3f8}', "GREEK SMALL LETTER SHO"),
+    ('\u{3f9}', "GREEK CAPITAL LUNATE SIGMA SYMBOL"),
+    ('\u{3fa}', "GREEK CAPITAL LETTER SAN"),
+    ('\u{3fb}', "GREEK SMALL LETTER SAN"),
+    ('\u{3fc}', "GREEK RHO WITH STROKE SYMBOL"),
+    ('\u{3fd}', "GREEK CAPITAL REVERSED LUNATE SIGMA SYMBOL"),
+    ('\u{3fe}', "GREEK CAPITAL DOTTED LUNATE SIGMA SYMBOL"),
+    (
+        '\u{3ff}',
+        "GREEK CAPITAL REVERSED DOTTED LUNATE SIGMA SYMBOL",
+    ),
+    ('\u{400}', "CYRILLIC CAPITAL LETTER IE WITH GRAVE"),
+    ('\u{401}', "CYRILLIC CAPITAL LETTER IO"),
+    ('\u{402}', "CYRILLIC CAPITAL LETTER DJE"),
+    ('\u{403}', "CYRILLIC CAPITAL LETTER GJE"),
+    ('\u{404}', "CYRILLIC CAPITAL LETTER UKRAINIAN IE"),
+    ('\u{405}', "CYRILLIC CAPITAL LETTER DZE"),
+    (
+        '\u{406}',
+        "CYRILLIC CAPITAL LETTER BYELORUSSIAN-UKRAINIAN I",
+    ),
+    ('\u{407}', "CYRILLIC CAPITAL LETTER YI"),
+    ('\u{408}', "CYRILLIC CAPITAL LETTER JE"),
+    ('\u{409}', "CYRILLIC CAPITAL LETTER LJE"),
+    ('\u{40a}', "CYRILLIC CAPITAL LETTER NJE"),
+    ('\u{40b}', "CYRILLIC CAPITAL LETTER TSHE"),
+    ('\u{40c}', "CYRILLIC CAPITAL LETTER KJE"),
+    ('\u{40d}', "CYRILLIC CAPITAL LETTER I WITH GRAVE"),
+    ('\u{40e}', "CYRILLIC CAPITAL LETTER SHORT U"),
+    ('\u{40f}', "CYRILLIC CAPITAL LETTER DZHE"),
+    ('\u{410}', "CYRILLIC CAPITAL LETTER A"),
+    ('\u{411}', "CYRILLIC CAPITAL LETTER BE"),
+    ('\u{412}', "CYRILLIC CAPITAL LETTER VE"),
+    ('\u{413}', "CYRILLIC CAPITAL LETTER GHE"),
+    ('\u{414}', "CYRILLIC CAPITAL LETTER DE"),
+    ('\u{415}', "CYRILLIC CAPITAL LETTER IE"),
+    ('\u{416}', "CYRILLIC CAPITAL LETTER ZHE"),
+    ('\u{417}', "CYRILLIC CAPITAL LETTER ZE"),
+    ('\u{418}', "CYRILLIC CAPITAL LETTER I"),
+    ('\u{419}', "CYRILLIC CAPITAL LETTER SHORT I"),
+    ('\u{41a}', "CYRILLIC CAPITAL LETTER KA"),
+    ('\u{41b}', "CYRILLIC CAPITAL LETTER EL"),
+    ('\u{41c}', "CYRILLIC CAPITAL LETTER EM"),
+    ('\u{41d}', "CYRILLIC CAPITAL LETTER EN"),
+    ('\u{41e}', "CYRILLIC CAPITAL LETTER O"),
+    ('\u{41f}', "CYRILLIC CAPITAL LETTER PE"),
+    ('\u{420}', "CYRILLIC CAPITAL LETTER ER"),
+    ('\u{421}', "CYRILLIC CAPITAL LETTER ES"),
+    ('\u{422}', "CYRILLIC CAPITAL LETTER TE"),
+    ('\u{423}', "CYRILLIC CAPITAL LETTER U"),
+    ('\u{424}', "CYRILLIC CAPITAL LETTER EF"),
+    ('\u{425}', "CYRILLIC CAPITAL LETTER HA"),
+    ('\u{426}', "CYRILLIC CAPITAL LETTER TSE"),
+    ('\u{427}', "CYRILLIC CAPITAL LETTER CHE"),
+    ('\u{428}', "CYRILLIC CAPITAL LETTER SHA"),
+    ('\u{429}', "CYRILLIC CAPITAL LETTER SHCHA"),
+    ('\u{42a}', "CYRILLIC CAPITAL LETTER HARD SIGN"),
+    ('\u{42b}', "CYRILLIC CAPITAL LETTER YERU"),
+    ('\u{42c}', "CYRILLIC CAPITAL LETTER SOFT SIGN"),
+    ('\u{42d}', "CYRILLIC CAPITAL LETTER E"),
+    ('\u{42e}', "CYRILLIC CAPITAL LETTER YU"),
+    ('\u{42f}', "CYRILLIC CAPITAL LETTER YA"),
+    ('\u{430}', "CYRILLIC SMALL LETTER A"),
+    ('\u{431}', "CYRILLIC SMALL LETTER BE"),
+    ('\u{432}', "CYRILLIC SMALL LETTER VE"),
+    ('\u{433}', "CYRILLIC SMALL LETTER GHE"),
+    ('\u{434}', "CYRILLIC SMALL LETTER DE"),
+    ('\u{435}', "CYRILLIC SMALL LETTER IE"),
+    ('\u{436}', "CYRILLIC SMALL LETTER ZHE"),
+    ('\u{437}', "CYRILLIC SMALL LETTER ZE"),
+    ('\u{438}', "CYRILLIC SMALL LETTER I"),
+    ('\u{439}', "CYRILLIC SMALL LETTER SHORT I"),
+    ('\u{43a}', "CYRILLIC SMALL LETTER KA"),
+    ('\u{43b}', "CYRILLIC SMALL LETTER EL"),
+    ('\u{43c}', "CYRILLIC SMALL LETTER EM"),
+    ('\u{43d}', "CYRILLIC SMALL LETTER EN"),
+    ('\u{43e}', "CYRILLIC SMALL LETTER O"),
+    ('\u{43f}', "CYRILLIC SMALL LETTER PE"),
+    ('\u{440}', "CYRILLIC SMALL LETTER ER"),
+    ('\u{441}', "CYRILLIC SMALL LETTER ES"),
+    ('\u{442}', "CYRILLIC SMALL LETTER TE"),
+    ('\u{443}', "CYRILLIC SMALL LETTER U"),
+    ('\u{444}', "CYRILLIC SMALL LETTER EF"),
+    ('\u{445}', "CYRILLIC SMALL LETTER HA"),
+    ('\u{446}', "CYRILLIC SMALL LETTER TSE"),
+    ('\u{447}', "CYRILLIC SMALL LETTER CHE"),
+    ('\u{448}', "CYRILLIC SMALL LETTER SHA"),
+    ('\u{449}', "CYRILLIC SMALL LETTER SHCHA"),
+    ('\u{44a}', "CYRILLIC SMALL LETTER HARD SIGN"),
+    ('\u{44b}', "CYRILLIC SMALL LETTER YERU"),
+    ('\u{44c}', "CYRILLIC SMALL LETTER SOFT SIGN"),
+    ('\u{44d}', "CYRILLIC SMALL LETTER E"),
+    ('\u{44e}', "CYRILLIC SMALL LETTER YU"),
+    ('\u{44f}', "CYRILLIC SMALL LETTER YA"),
+    ('\u{450}', "CYRILLIC SMALL LETTER IE WITH GRAVE"),
+    ('\u{451}', "CYRILLIC SMALL LETTER IO"),
+    ('\u{452}', "CYRILLIC SMALL LETTER DJE"),
+    ('\u{453}', "CYRILLIC SMALL LETTER GJE"),
+    ('\u{454}', "CYRILLIC SMALL LETTER UKRAINIAN IE"),
+    ('\u{455}', "CYRILLIC SMALL LETTER DZE"),
+    ('\u{456}', "CYRILLIC SMALL LETTER BYELORUSSIAN-UKRAINIAN I"),
+    ('\u{457}', "CYRILLIC SMALL LETTER YI"),
+    ('\u{458}', "CYRILLIC SMALL LETTER JE"),
+    ('\u{459}', "CYRILLIC SMALL LETTER LJE"),
+    ('\u{45a}', "CYRILLIC SMALL LETTER NJE"),
+    ('\u{45b}', "CYRILLIC SMALL LETTER TSHE"),
+    ('\u{45c}', "CYRILLIC SMALL LETTER KJE"),
+    ('\u{45d}', "CYRILLIC SMALL LETTER I WITH GRAVE"),
+    ('\u{45e}', "CYRILLIC SMALL LETTER SHORT U"),
+    ('\u{45f}', "CYRILLIC SMALL LETTER DZHE"),
+    ('\u{460}', "CYRILLIC CAPITAL LETTER OMEGA"),
+    ('\u{461}', "CYRILLIC SMALL LETTER OMEGA"),
+    ('\u{462}', "CYRILLIC CAPITAL LETTER YAT"),
+    ('\u{463}', "CYRILLIC SMALL LETTER YAT"),
+    ('\u{464}', "CYRILLIC CAPITAL LETTER IOTIFIED E"),
+    ('\u{465}', "CYRILLIC SMALL LETTER IOTIFIED E"),
+    ('\u{466}', "CYRILLIC CAPITAL LETTER LITTLE YUS"),
+    ('\u{467}', "CYRILLIC SMALL LETTER LITTLE YUS"),
+    ('\u{468}', "CYRILLIC CAPITAL LETTER IOTIFIED LITTLE YUS"),
+    ('\u{469}', "CYRILLIC SMALL LETTER IOTIFIED LITTLE YUS"),
+    ('\u{46a}', "CYRILLIC CAPITAL LETTER BIG YUS"),
+    ('\u{46b}', "CYRILLIC SMALL LETTER BIG YUS"),
+    ('\u{46c}', "CYRILLIC CAPITAL LETTER IOTIFIED BIG YUS"),
+    ('\u{46d}', "CYRILLIC SMALL LETTER IOTIFIED BIG YUS"),
+    ('\u{46e}', "CYRILLIC CAPITAL LETTER KSI"),
+    ('\u{46f}', "CYRILLIC SMALL LETTER KSI"),
+    ('\u{470}', "CYRILLIC CAPITAL LETTER PSI"),
+    ('\u{471}', "CYRILLIC SMALL LETTER PSI"),
+    ('\u{472}', "CYRILLIC CAPITAL LETTER FITA"),
+    ('\u{473}', "CYRILLIC SMALL LETTER FITA"),
+    ('\u{474}', "CYRILLIC CAPITAL LETTER IZHITSA"),
+    ('\u{475}', "CYRILLIC SMALL LETTER IZHITSA"),
+    (
+        '\u{476}',
+        "CYRILLIC CAPITAL LETTER IZHITSA WITH DOUBLE GRAVE ACCENT",
+    ),
+    (
+        '\u{477}',
+        "CYRILLIC SMALL LETTER IZHITSA WITH DOUBLE GRAVE ACCENT",
+    ),
+    ('\u{478}', "CYRILLIC CAPITAL LETTER UK"),
+    ('\u{479}', "CYRILLIC SMALL LETTER UK"),
+    ('\u{47a}', "CYRILLIC CAPITAL LETTER ROUND OMEGA"),
+    ('\u{47b}', "CYRILLIC SMALL LETTER ROUND OMEGA"),
+    ('\u{47c}', "CYRILLIC CAPITAL LETTER OMEGA WITH TITLO"),
+    ('\u{47d}', "CYRILLIC SMALL LETTER OMEGA WITH TITLO"),
+    ('\u{47e}', "CYRILLIC CAPITAL LETTER OT"),
+    ('\u{47f}', "CYRILLIC SMALL LETTER OT"),
+    ('\u{480}', "CYRILLIC CAPITAL LETTER KOPPA"),
+    ('\u{481}', "CYRILLIC SMALL LETTER KOPPA"),
+    ('\u{482}', "CYRILLIC THOUSANDS SIGN"),
+    ('\u{483}', "COMBINING CYRILLIC TITLO"),
+    ('\u{484}', "COMBINING CYRILLIC PALATALIZATION"),
+    ('\u{485}', "COMBINING CYRILLIC DASIA PNEUMATA"),
+    ('\u{486}', "COMBINING CYRILLIC PSILI PNEUMATA"),
+    ('\u{487}', "COMBINING CYRILLIC POKRYTIE"),
+    ('\u{488}', "COMBINING CYRILLIC HUNDRED THOUSANDS SIGN"),
+    ('\u{489}', "COMBINING CYRILLIC MILLIONS SIGN"),
+    ('\u{48a}', "CYRILLIC CAPITAL LETTER SHORT I WITH TAIL"),
+    ('\u{48b}', "CYRILLIC SMALL LETTER SHORT I WITH TAIL"),
+    ('\u{48c}', "CYRILLIC CAPITAL LETTER SEMISOFT SIGN"),
+    ('\u{48d}', "CYRILLIC SMALL LETTER SEMISOFT SIGN"),
+    ('\u{48e}', "CYRILLIC CAPITAL LETTER ER WITH TICK"),
+    ('\u{48f}', "CYRILLIC SMALL LETTER ER WITH TICK"),
+    ('\u{490}', "CYRILLIC CAPITAL LETTER GHE WITH UPTURN"),
+    ('\u{491}', "CYRILLIC SMALL LETTER GHE WITH UPTURN"),
+    ('\u{492}', "CYRILLIC CAPITAL LETTER GHE WITH STROKE"),
+    ('\u{493}', "CYRILLIC SMALL LETTER GHE WITH STROKE"),
+    ('\u{494}', "CYRILLIC CAPITAL LETTER GHE WITH MIDDLE HOOK"),
+    ('\u{495}', "CYRILLIC SMALL LETTER GHE WITH MIDDLE HOOK"),
+    ('\u{496}', "CYRILLIC CAPITAL LETTER ZHE WITH DESCENDER"),
+    ('\u{497}', "CYRILLIC SMALL LETTER ZHE WITH DESCENDER"),
+    ('\u{498}', "CYRILLIC CAPITAL LETTER ZE WITH DESCENDER"),
+    ('\u{499}', "CYRILLIC SMALL LETTER ZE WITH DESCENDER"),
+    ('\u{49a}', "CYRILLIC CAPITAL LETTER KA WITH DESCENDER"),
+    ('\u{49b}', "CYRILLIC SMALL LETTER KA WITH DESCENDER"),
+    ('\u{49c}', "CYRILLIC CAPITAL LETTER KA WITH VERTICAL STROKE"),
+    ('\u{49d}', "CYRILLIC SMALL LETTER KA WITH VERTICAL STROKE"),
+    ('\u{49e}', "CYRILLIC CAPITAL LETTER KA WITH STROKE"),
+    ('\u{49f}', "CYRILLIC SMALL LETTER KA WITH STROKE"),
+    ('\u{4a0}', "CYRILLIC CAPITAL LETTER BASHKIR KA"),
+    ('\u{4a1}', "CYRILLIC SMALL LETTER BASHKIR KA"),
+    ('\u{4a2}', "CYRILLIC CAPITAL LETTER EN WITH DESCENDER"),
+    ('\u{4a3}', "CYRILLIC SMALL LETTER EN WITH DESCENDER"),
+    ('\u{4a4}', "CYRILLIC CAPITAL LIGATURE EN GHE"),
+    ('\u{4a5}', "CYRILLIC SMALL LIGATURE EN GHE"),
+    ('\u{4a6}', "CYRILLIC CAPITAL LETTER PE WITH MIDDLE HOOK"),
+    ('\u{4a7}', "CYRILLIC SMALL LETTER PE WITH MIDDLE HOOK"),
+    ('\u{4a8}', "CYRILLIC CAPITAL LETTER ABKHASIAN HA"),
+    ('\u{4a9}', "CYRILLIC SMALL LETTER ABKHASIAN HA"),
+    ('\u{4aa}', "CYRILLIC CAPITAL LETTER ES WITH DESCENDER"),
+    ('\u{4ab}', "CYRILLIC SMALL LETTER ES WITH DESCENDER"),
+    ('\u{4ac}', "CYRILLIC CAPITAL LETTER TE WITH DESCENDER"),
+    ('\u{4ad}', "CYRILLIC SMALL LETTER TE WITH DESCENDER"),
+    ('\u{4ae}', "CYRILLIC CAPITAL LETTER STRAIGHT U"),
+    ('\u{4af}', "CYRILLIC SMALL LETTER STRAIGHT U"),
+    ('\u{4b0}', "CYRILLIC CAPITAL LETTER STRAIGHT U WITH STROKE"),
+    ('\u{4b1}', "CYRILLIC SMALL LETTER STRAIGHT U WITH STROKE"),
+    ('\u{4b2}', "CYRILLIC CAPITAL LETTER HA WITH DESCENDER"),
+    ('\u{4b3}', "CYRILLIC SMALL LETTER HA WITH DESCENDER"),
+    ('\u{4b4}', "CYRILLIC CAPITAL LIGATURE TE TSE"),
+    ('\u{4b5}', "CYRILLIC SMALL LIGATURE TE TSE"),
+    ('\u{4b6}', "CYRILLIC CAPITAL LETTER CHE WITH DESCENDER"),
+    ('\u{4b7}', "CYRILLIC SMALL LETTER CHE WITH DESCENDER"),
+    (
+        '\u{4b8}',
+        "CYRILLIC CAPITAL LETTER CHE WITH VERTICAL STROKE",
+    ),
+    ('\u{4b9}', "CYRILLIC SMALL LETTER CHE WITH VERTICAL STROKE"),
+    ('\u{4ba}', "CYRILLIC CAPITAL LETTER SHHA"),
+    ('\u{4bb}', "CYRILLIC SMALL LETTER SHHA"),
+    ('\u{4bc}', "CYRILLIC CAPITAL LETTER ABKHASIAN CHE"),
+    ('\u{4bd}', "CYRILLIC SMALL LETTER ABKHASIAN CHE"),
+    (
+        '\u{4be}',
+        "CYRILLIC CAPITAL LETTER ABKHASIAN CHE WITH DESCENDER",
+    ),
+    (
+        '\u{4bf}',
+        "CYRILLIC SMALL LETTER ABKHASIAN CHE WITH DESCENDER",
+    ),
+    ('\u{4c0}', "CYRILLIC LETTER PALOCHKA"),
+    ('\u{4c1}', "CYRILLIC CAPITAL LETTER ZHE WITH BREVE"),
+    ('\u{4c2}', "CYRILLIC SMALL LETTER ZHE WITH BREVE"),
+    ('\u{4c3}', "CYRILLIC CAPITAL LETTER KA WITH HOOK"),
+    ('\u{4c4}', "CYRILLIC SMALL LETTER KA WITH HOOK"),
+    ('\u{4c5}', "CYRILLIC CAPITAL LETTER EL WITH TAIL"),
+    ('\u{4c6}', "CYRILLIC SMALL LETTER EL WITH TAIL"),
+    ('\u{4c7}', "CYRILLIC CAPITAL LETTER EN WITH HOOK"),
+    ('\u{4c8}', "CYRILLIC SMALL LETTER EN WITH HOOK"),
+    ('\u{4c9}', "CYRILLIC CAPITAL LETTER EN WITH TAIL"),
+    ('\u{4ca}', "CYRILLIC SMALL LETTER EN WITH TAIL"),
+    ('\u{4cb}', "CYRILLIC CAPITAL LETTER KHAKASSIAN CHE"),
+    ('\u{4cc}', "CYRILLIC SMALL LETTER KHAKASSIAN CHE"),
+    ('\u{4cd}', "CYRILLIC CAPITAL LETTER EM WITH TAIL"),
+    ('\u{4ce}', "CYRILLIC SMALL LETTER EM WITH TAIL"),
+    ('\u{4cf}', "CYRILLIC SMALL LETTER PALOCHKA"),
+    ('\u{4d0}', "CYRILLIC CAPITAL LETTER A WITH BREVE"),
+    ('\u{4d1}', "CYRILLIC SMALL LETTER A WITH BREVE"),
+    ('\u{4d2}', "CYRILLIC CAPITAL LETTER A WITH DIAERESIS"),
+    ('\u{4d3}', "CYRILLIC SMALL LETTER A WITH DIAERESIS"),
+    ('\u{4d4}', "CYRILLIC CAPITAL LIGATURE A IE"),
+    ('\u{4d5}', "CYRILLIC SMALL LIGATURE A IE"),
+    ('\u{4d6}', "CYRILLIC CAPITAL LETTER IE WITH BREVE"),
+    ('\u{4d7}', "CYRILLIC SMALL LETTER IE WITH BREVE"),
+    ('\u{4d8}', "CYRILLIC CAPITAL LETTER SCHWA"),
+    ('\u{4d9}', "CYRILLIC SMALL LETTER SCHWA"),
+    ('\u{4da}', "CYRILLIC CAPITAL LETTER SCHWA WITH DIAERESIS"),
+    ('\u{4db}', "CYRILLIC SMALL LETTER SCHWA WITH DIAERESIS"),
+    ('\u{4dc}', "CYRILLIC CAPITAL LETTER ZHE WITH DIAERESIS"),
+    ('\u{4dd}', "CYRILLIC SMALL LETTER ZHE WITH DIAERESIS"),
+    ('\u{4de}', "CYRILLIC CAPITAL LETTER ZE WITH DIAERESIS"),
+    ('\u{4df}', "CYRILLIC SMALL LETTER ZE WITH DIAERESIS"),
+    ('\u{4e0}', "CYRILLIC CAPITAL LETTER ABKHASIAN DZE"),
+    ('\u{4e1}', "CYRILLIC SMALL LETTER ABKHASIAN DZE"),
+    ('\u{4e2}', "CYRILLIC CAPITAL LETTER I WITH MACRON"),
+    ('\u{4e3}', "CYRILLIC SMALL LETTER I WITH MACRON"),
+    ('\u{4e4}', "CYRILLIC CAPITAL LETTER I WITH DIAERESIS"),
+    ('\u{4e5}', "CYRILLIC SMALL LETTER I WITH DIAERESIS"),
+    ('\u{4e6}', "CYRILLIC CAPITAL LETTER O WITH DIAERESIS"),
+    ('\u{4e7}', "CYRILLIC SMALL LETTER O WITH DIAERESIS"),
+    ('\u{4e8}', "CYRILLIC CAPITAL LETTER BARRED O"),
+    ('\u{4e9}', "CYRILLIC SMALL LETTER BARRED O"),
+    ('\u{4ea}', "CYRILLIC CAPITAL LETTER BARRED O WITH DIAERESIS"),
+    ('\u{4eb}', "CYRILLIC SMALL LETTER BARRED O WITH DIAERESIS"),
+    ('\u{4ec}', "CYRILLIC CAPITAL LETTER E WITH DIAERESIS"),
+    ('\u{4ed}', "CYRILLIC SMALL LETTER E WITH DIAERESIS"),
+    ('\u{4ee}', "CYRILLIC CAPITAL LETTER U WITH MACRON"),
+    ('\u{4ef}', "CYRILLIC SMALL LETTER U WITH MACRON"),
+    ('\u{4f0}', "CYRILLIC CAPITAL LETTER U WITH DIAERESIS"),
+    ('\u{4f1}', "CYRILLIC SMALL LETTER U WITH DIAERESIS"),
+    ('\u{4f2}', "CYRILLIC CAPITAL LETTER U WITH DOUBLE ACUTE"),
+    ('\u{4f3}', "CYRILLIC SMALL LETTER U WITH DOUBLE ACUTE"),
+    ('\u{4f4}', "CYRILLIC CAPITAL LETTER CHE WITH DIAERESIS"),
+    ('\u{4f5}', "CYRILLIC SMALL LETTER CHE WITH DIAERESIS"),
+    ('\u{4f6}', "CYRILLIC CAPITAL LETTER GHE WITH DESCENDER"),
+    ('\u{4f7}', "CYRILLIC SMALL LETTER GHE WITH DESCENDER"),
+    ('\u{4f8}', "CYRILLIC CAPITAL LETTER YERU WITH DIAERESIS"),
+    ('\u{4f9}', "CYRILLIC SMALL LETTER YERU WITH DIAERESIS"),
+    (
+        '\u{4fa}',
+        "CYRILLIC CAPITAL LETTER GHE WITH STROKE AND HOOK",
+    ),
+    ('\u{4fb}', "CYRILLIC SMALL LETTER GHE WITH STROKE AND HOOK"),
+    ('\u{4fc}', "CYRILLIC CAPITAL LETTER HA WITH HOOK"),
+    ('\u{4fd}', "CYRILLIC SMALL LETTER HA WITH HOOK"),
+    ('\u{4fe}', "CYRILLIC CAPITAL LETTER HA WITH STROKE"),
+    ('\u{4ff}', "CYRILLIC SMALL LETTER HA WITH STROKE"),
+    ('\u{500}', "CYRILLIC CAPITAL LETTER KOMI DE"),
+    ('\u{501}', "CYRILLIC SMALL LETTER KOMI DE"),
+    ('\u{502}', "CYRILLIC CAPITAL LETTER KOMI DJE"),
+    ('\u{503}', "CYRILLIC SMALL LETTER KOMI DJE"),
+    ('\u{504}', "CYRILLIC CAPITAL LETTER KOMI ZJE"),
+    ('\u{505}', "CYRILLIC SMALL LETTER KOMI ZJE"),
+    ('\u{506}', "CYRILLIC CAPITAL LETTER KOMI DZJE"),
+    ('\u{507}', "CYRILLIC SMALL LETTER KOMI DZJE"),
+    ('\u{508}', "CYRILLIC CAPITAL LETTER KOMI LJE"),
+    ('\u{509}', "CYRILLIC SMALL LETTER KOMI LJE"),
+    ('\u{50a}', "CYRILLIC CAPITAL LETTER KOMI NJE"),
+    ('\u{50b}', "CYRILLIC SMALL LETTER KOMI NJE"),
+    ('\u{50c}', "CYRILLIC CAPITAL LETTER KOMI SJE"),
+    ('\u{50d}', "CYRILLIC SMALL LETTER KOMI SJE"),
+    ('\u{50e}', "CYRILLIC CAPITAL LETTER KOMI TJE"),
+    ('\u{50f}', "CYRILLIC SMALL LETTER KOMI TJE"),
+    ('\u{510}', "CYRILLIC CAPITAL LETTER REVERSED ZE"),
+    ('\u{511}', "CYRILLIC SMALL LETTER REVERSED ZE"),
+    ('\u{512}', "CYRILLIC CAPITAL LETTER EL WITH HOOK"),
+    ('\u{513}', "CYRILLIC SMALL LETTER EL WITH HOOK"),
+    ('\u{514}', "CYRILLIC CAPITAL LETTER LHA"),
+    ('\u{515}', "CYRILLIC SMALL LETTER LHA"),
+    ('\u{516}', "CYRILLIC CAPITAL LETTER RHA"),
+    ('\u{517}', "CYRILLIC SMALL LETTER RHA"),
+    ('\u{518}', "CYRILLIC CAPITAL LETTER YAE"),
+    ('\u{519}', "CYRILLIC SMALL LETTER YAE"),
+    ('\u{51a}', "CYRILLIC CAPITAL LETTER QA"),
+    ('\u{51b}', "CYRILLIC SMALL LETTER QA"),
+    ('\u{51c}', "CYRILLIC CAPITAL LETTER WE"),
+    ('\u{51d}', "CYRILLIC SMALL LETTER WE"),
+    ('\u{51e}', "CYRILLIC CAPITAL LETTER ALEUT KA"),
+    ('\u{51f}', "CYRILLIC SMALL LETTER ALEUT KA"),
+    ('\u{520}', "CYRILLIC CAPITAL LETTER EL WITH MIDDLE HOOK"),
+    ('\u{521}', "CYRILLIC SMALL LETTER EL WITH MIDDLE HOOK"),
+    ('\u{522}', "CYRILLIC CAPITAL LETTER EN WITH MIDDLE HOOK"),
+    ('\u{523}', "CYRILLIC SMALL LETTER EN WITH MIDDLE HOOK"),
+    ('\u{524}', "CYRILLIC CAPITAL LETTER PE WITH DESCENDER"),
+    ('\u{525}', "CYRILLIC SMALL LETTER PE WITH DESCENDER"),
+    ('\u{526}', "CYRILLIC CAPITAL LETTER SHHA WITH DESCENDER"),
+    ('\u{527}', "CYRILLIC SMALL LETTER SHHA WITH DESCENDER"),
+    ('\u{528}', "CYRILLIC CAPITAL LETTER EN WITH LEFT HOOK"),
+    ('\u{529}', "CYRILLIC SMALL LETTER EN WITH LEFT HOOK"),
+    ('\u{52a}', "CYRILLIC CAPITAL LETTER DZZHE"),
+    ('\u{52b}', "CYRILLIC SMALL LETTER DZZHE"),
+    ('\u{52c}', "CYRILLIC CAPITAL LETTER DCHE"),
+    ('\u{52d}', "CYRILLIC SMALL LETTER DCHE"),
+    ('\u{52e}', "CYRILLIC CAPITAL LETTER EL WITH DESCENDER"),
+    ('\u{52f}', "CYRILLIC SMALL LETTER EL WITH DESCENDER"),
+    ('\u{2000}', "EN QUAD"),
+    ('\u{2001}', "EM QUAD"),
+    ('\u{2002}', "EN SPACE"),
+    ('\u{2003}', "EM SPACE"),
+    ('\u{2004}', "THREE-PER-EM SPACE"),
+    ('\u{2005}', "FOUR-PER-EM SPACE"),
+    ('\u{2006}', "SIX-PER-EM SPACE"),
+    ('\u{2007}', "FIGURE SPACE"),
+    ('\u{2008}', "PUNCTUATION SPACE"),
+    ('\u{2009}', "THIN SPACE"),
+    ('\u{200a}', "HAIR SPACE"),
+    ('\u{200b}', "ZERO WIDTH SPACE"),
+    ('\u{200c}', "ZERO WIDTH NON-JOINER"),
+    ('\u{200d}', "ZERO WIDTH JOINER"),
+    ('\u{200e}', "LEFT-TO-RIGHT MARK"),
+    ('\u{200f}', "RIGHT-TO-LEFT MARK"),
+    ('\u{2010}', "HYPHEN"),
+    ('\u{2011}', "NON-BREAKING HYPHEN"),
+    ('\u{2012}', "FIGURE DASH"),
+    ('\u{2013}', "EN DASH"),
+    ('\u{2014}', "EM DASH"),
+    ('\u{2015}', "HORIZONTAL BAR"),
+    ('\u{2016}', "DOUBLE VERTICAL LINE"),
+    ('\u{2017}', "DOUBLE LOW LINE"),
+    ('\u{2018}', "LEFT SINGLE QUOTATION MARK"),
+    ('\u{2019}', "RIGHT SINGLE QUOTATION MARK"),
+    ('\u{201a}', "SINGLE LOW-9 QUOTATION MARK"),
+    ('\u{201b}', "SINGLE HIGH-REVERSED-9 QUOTATION MARK"),
+    ('\u{201c}', "LEFT DOUBLE QUOTATION MARK"),
+    ('\u{201d}', "RIGHT DOUBLE QUOTATION MARK"),
+    ('\u{201e}', "DOUBLE LOW-9 QUOTATION MARK"),
+    ('\u{201f}', "DOUBLE HIGH-REVERSED-9 QUOTATION MARK"),
+    ('\u{2020}', "DAGGER"),
+    ('\u{2021}', "DOUBLE DAGGER"),
+    ('\u{2022}', "BULLET"),
+    ('\u{2023}', "TRIANGULAR BULLET"),
+    ('\u{2024}', "ONE DOT LEADER"),
+    ('\u{2025}', "TWO DOT LEADER"),
+    ('\u{2026}', "HORIZONTAL ELLIPSIS"),
+    ('\u{2027}', "HYPHENATION POINT"),
+    ('\u{2028}', "LINE SEPARATOR"),
+    ('\u{2029}', "PARAGRAPH SEPARATOR"),
+    ('\u{202a}', "LEFT-TO-RIGHT EMBEDDING"),
+    ('\u{202b}', "RIGHT-TO-LEFT EMBEDDING"),
+    ('\u{202c}', "POP DIRECTIONAL FORMATTING"),
+    ('\u{202d}', "LEFT-TO-RIGHT OVERRIDE"),
+    ('\u{202e}', "RIGHT-TO-LEFT OVERRIDE"),
+    ('\u{202f}', "NARROW NO-BREAK SPACE"),
+    ('\u{2030}', "PER MILLE SIGN"),
+    ('\u{2031}', "PER TEN THOUSAND SIGN"),
+    ('\u{2032}', "PRIME"),
+    ('\u{2033}', "DOUBLE PRIME"),
+    ('\u{2034}', "TRIPLE PRIME"),
+    ('\u{2035}', "REVERSED PRIME"),
+    ('\u{2036}', "REVERSED DOUBLE PRIME"),
+    ('\u{2037}', "REVERSED TRIPLE PRIME"),
+    ('\u{2038}', "CARET"),
+    ('\u{2039}', "SINGLE LEFT-POINTING ANGLE QUOTATION MARK"),
+    ('\u{203a}', "SINGLE RIGHT-POINTING ANGLE QUOTATION MARK"),
+    ('\u{203b}', "REFERENCE MARK"),
+    ('\u{203c}', "DOUBLE EXCLAMATION MARK"),
+    ('\u{203d}', "INTERROBANG"),
+    ('\u{203e}', "OVERLINE"),
+    ('\u{203f}', "UNDERTIE"),
+    ('\u{2040}', "CHARACTER TIE"),
+    ('\u{2041}', "CARET INSERTION POINT"),
+    ('\u{2042}', "ASTERISM"),
+    ('\u{2043}', "HYPHEN BULLET"),
+    ('\u{2044}', "FRACTION SLASH"),
+    ('\u{2045}', "LEFT SQUARE BRACKET WITH QUILL"),
+    ('\u{2046}', "RIGHT SQUARE BRACKET WITH QUILL"),
+    ('\u{2047}', "DOUBLE QUESTION MARK"),
+    ('\u{2048}', "QUESTION EXCLAMATION MARK"),
+    ('\u{2049}', "EXCLAMATION QUESTION MARK"),
+    ('\u{204a}', "TIRONIAN SIGN ET"),
+    ('\u{204b}', "REVERSED PILCROW SIGN"),
+    ('\u{204c}', "BLACK LEFTWARDS BULLET"),
+    ('\u{204d}', "BLACK RIGHTWARDS BULLET"),
+    ('\u{204e}', "LOW ASTERISK"),
+    ('\u{204f}', "REVERSED SEMICOLON"),
+    ('\u{2050}', "CLOSE UP"),
+    ('\u{2051}', "TWO ASTERISKS ALIGNED VERTICALLY"),
+    ('\u{2052}', "COMMERCIAL MINUS SIGN"),
+    ('\u{2053}', "SWUNG DASH"),
+    ('\u{2054}', "INVERTED UNDERTIE"),
+    ('\u{2055}', "FLOWER PUNCTUATION MARK"),
+    ('\u{2056}', "THREE DOT PUNCTUATION"),
+    ('\u{2057}', "QUADRUPLE PRIME"),
+    ('\u{2058}', "FOUR DOT PUNCTUATION"),
+    ('\u{2059}', "FIVE DOT PUNCTUATION"),
+    ('\u{205a}', "TWO DOT PUNCTUATION"),
+    ('\u{205b}', "FOUR DOT MARK"),
+    ('\u{205c}', "DOTTED CROSS"),
+    ('\u{205d}', "TRICOLON"),
+    ('\u{205e}', "VERTICAL FOUR DOTS"),
+    ('\u{205f}', "MEDIUM MATHEMATICAL SPACE"),
+    ('\u{2060}', "WORD JOINER"),
+    ('\u{2061}', "FUNCTION APPLICATION"),
+    ('\u{2062}', "INVISIBLE TIMES"),
+    ('\u{2063}', "INVISIBLE SEPARATOR"),
+    ('\u{2064}', "INVISIBLE PLUS"),
+    ('\u{2066}', "LEFT-TO-RIGHT ISOLATE"),
+    ('\u{2067}', "RIGHT-TO-LEFT ISOLATE"),
+    ('\u{2068}', "FIRST STRONG ISOLATE"),
+    ('\u{2069}', "POP DIRECTIONAL ISOLATE"),
+    ('\u{206a}', "INHIBIT SYMMETRIC SWAPPING"),
+    ('\u{206b}', "ACTIVATE SYMMETRIC SWAPPING"),
+    ('\u{206c}', "INHIBIT ARABIC FORM SHAPING"),
+    ('\u{206d}', "ACTIVATE ARABIC FORM SHAPING"),
+    ('\u{206e}', "NATIONAL DIGIT SHAPES"),
+    ('\u{206f}', "NOMINAL DIGIT SHAPES"),
+    ('\u{20a0}', "EURO-CURRENCY SIGN"),
+    ('\u{20a1}', "COLON SIGN"),
+    ('\u{20a2}', "CRUZEIRO SIGN"),
+    ('\u{20a3}', "FRENCH FRANC SIGN"),
+    ('\u{20a4}', "LIRA SIGN"),
+    ('\u{20a5}', "MILL SIGN"),
+    ('\u{20a6}', "NAIRA SIGN"),
+    ('\u{20a7}', "PESETA SIGN"),
+    ('\u{20a8}', "RUPEE SIGN"),
+    ('\u{20a9}', "WON SIGN"),
+    ('\u{20aa}', "NEW SHEQEL SIGN"),
+    ('\u{20ab}', "DONG SIGN"),
+    ('\u{20ac}', "EURO SIGN"),
+    ('\u{20ad}', "KIP SIGN"),
+    ('\u{20ae}', "TUGRIK SIGN"),
+    ('\u{20af}', "DRACHMA SIGN"),
+    ('\u{20b0}', "GERMAN PENNY SIGN"),
+    ('\u{20b1}', "PESO SIGN"),
+    ('\u{20b2}', "GUARANI SIGN"),
+    ('\u{20b3}', "AUSTRAL SIGN"),
+    ('\u{20b4}', "HRYVNIA SIGN"),
+    ('\u{20b5}', "CEDI SIGN"),
+    ('\u{20b6}', "LIVRE TOURNOIS SIGN"),
+    ('\u{20b7}', "SPESMILO SIGN"),
+    ('\u{20b8}', "TENGE SIGN"),
+    ('\u{20b9}', "INDIAN RUPEE SIGN"),
+    ('\u{20ba}', "TURKISH LIRA SIGN"),
+    ('\u{20bb}', "NORDIC MARK SIGN"),
+    ('\u{20bc}', "MANAT SIGN"),
+    ('\u{20bd}', "RUBLE SIGN"),
+    ('\u{20be}', "LARI SIGN"),
+    ('\u{20bf}', "BITCOIN SIGN"),
+    ('\u{20c0}', "SOM SIGN"),
+    ('\u{2100}', "ACCOUNT OF"),
+    ('\u{2101}', "ADDRESSED TO THE SUBJECT"),
+    ('\u{2102}', "DOUBLE-STRUCK CAPITAL C"),
+    ('\u{2103}', "DEGREE CELSIUS"),
+    ('\u{2104}', "CENTRE LINE SYMBOL"),
+    ('\u{2105}', "CARE OF"),
+    ('\u{2106}', "CADA UNA"),
+    ('\u{2107}', "EULER CONSTANT"),
+    ('\u{2108}', "SCRUPLE"),
+    ('\u{2109}', "DEGREE FAHRENHEIT"),
+    ('\u{210a}', "SCRIPT SMALL G"),
+    ('\u{210b}', "SCRIPT CAPITAL H"),
+    ('\u{210c}', "BLACK-LETTER CAPITAL H"),
+    ('\u{210d}', "DOUBLE-STRUCK CAPITAL H"),
+    ('\u{210e}', "PLANCK CONSTANT"),
+    ('\u{210f}', "PLANCK CONSTANT OVER TWO PI"),
+    ('\u{2110}', "SCRIPT CAPITAL I"),
+    ('\u{2111}', "BLACK-LETTER CAPITAL I"),
+    ('\u{2112}', "SCRIPT CAPITAL L"),
+    ('\u{2113}', "SCRIPT SMALL L"),
+    ('\u{2114}', "L B BAR SYMBOL"),
+    ('\u{2115}', "DOUBLE-STRUCK CAPITAL N"),
+    ('\u{2116}', "NUMERO SIGN"),
+    ('\u{2117}', "SOUND RECORDING COPYRIGHT"),
+    ('\u{2118}', "SCRIPT CAPITAL P"),
+    ('\u{2119}', "DOUBLE-STRUCK CAPITAL P"),
+    ('\u{211a}', "DOUBLE-STRUCK CAPITAL Q"),
+    ('\u{211b}', "SCRIPT CAPITAL R"),
+    ('\u{211c}', "BLACK-LETTER CAPITAL R"),
+    ('\u{211d}', "DOUBLE-STRUCK CAPITAL R"),
+    ('\u{211e}', "PRESCRIPTION TAKE"),
+    ('\u{211f}', "RESPONSE"),
+    ('\u{2120}', "SERVICE MARK"),
+    ('\u{2121}', "TELEPHONE SIGN"),
+    ('\u{2122}', "TRADE MARK SIGN"),
+    ('\u{2123}', "VERSICLE"),
+    ('\u{2124}', "DOUBLE-STRUCK CAPITAL Z"),
+    ('\u{2125}', "OUNCE SIGN"),
+    ('\u{2126}', "OHM SIGN"),
+    ('\u{2127}', "INVERTED OHM SIGN"),
+    ('\u{2128}', "BLACK-LETTER CAPITAL Z"),
+    ('\u{2129}', "TURNED GREEK SMALL LETTER IOTA"),
+    ('\u{212a}', "KELVIN SIGN"),
+    ('\u{212b}', "ANGSTROM SIGN"),
+    ('\u{212c}', "SCRIPT CAPITAL B"),
+    ('\u{212d}', "BLACK-LETTER CAPITAL C"),
+    ('\u{212e}', "ESTIMATED SYMBOL"),
+    ('\u{212f}', "SCRIPT SMALL E"),
+    ('\u{2130}', "SCRIPT CAPITAL E"),
+    ('\u{2131}', "SCRIPT CAPITAL F"),
+    ('\u{2132}', "TURNED CAPITAL F"),
+    ('\u{2133}', "SCRIPT CAPITAL M"),
+    ('\u{2134}', "SCRIPT SMALL O"),
+    ('\u{2135}', "ALEF SYMBOL"),
+    ('\u{2136}', "BET SYMBOL"),
+    ('\u{2137}', "GIMEL SYMBOL"),
+    ('\u{2138}', "DALET SYMBOL"),
+    ('\u{2139}', "INFORMATION SOURCE"),
+    ('\u{213a}', "ROTATED CAPITAL Q"),
+    ('\u{213b}', "FACSIMILE SIGN"),
+    ('\u{213c}', "DOUBLE-STRUCK SMALL PI"),
+    ('\u{213d}', "DOUBLE-STRUCK SMALL GAMMA"),
+    ('\u{213e}', "DOUBLE-STRUCK CAPITAL GAMMA"),
+    ('\u{213f}', "DOUBLE-STRUCK CAPITAL PI"),
+    ('\u{2140}', "DOUBLE-STRUCK N-ARY SUMMATION"),
+    ('\u{2141}', "TURNED SANS-SERIF CAPITAL G"),
+    ('\u{2142}', "TURNED SANS-SERIF CAPITAL L"),
+    ('\u{2143}', "REVERSED SANS-SERIF CAPITAL L"),
+    ('\u{2144}', "TURNED SANS-SERIF CAPITAL Y"),
+    ('\u{2145}', "DOUBLE-STRUCK ITALIC CAPITAL D"),
+    ('\u{2146}', "DOUBLE-STRUCK ITALIC SMALL D"),
+    ('\u{2147}', "DOUBLE-STRUCK ITALIC SMALL E"),
+    ('\u{2148}', "DOUBLE-STRUCK ITALIC SMALL I"),
+    ('\u{2149}', "DOUBLE-STRUCK ITALIC SMALL J"),
+    ('\u{214a}', "PROPERTY LINE"),
+    ('\u{214b}', "TURNED AMPERSAND"),
+    ('\u{214c}', "PER SIGN"),
+    ('\u{214d}', "AKTIESELSKAB"),
+    ('\u{214e}', "TURNED SMALL F"),
+    ('\u{214f}', "SYMBOL FOR SAMARITAN SOURCE"),
+    ('\u{2190}', "LEFTWARDS ARROW"),
+    ('\u{2191}', "UPWARDS ARROW"),
+    ('\u{2192}', "RIGHTWARDS ARROW"),
+    ('\u{2193}', "DOWNWARDS ARROW"),
+    ('\u{2194}', "LEFT RIGHT ARROW"),
+    ('\u{2195}', "UP DOWN ARROW"),
+    ('\u{2196}', "NORTH WEST ARROW"),
+    ('\u{2197}', "NORTH EAST ARROW"),
+    ('\u{2198}', "SOUTH EAST ARROW"),
+    ('\u{2199}', "SOUTH WEST ARROW"),
+    ('\u{219a}', "LEFTWARDS ARROW WITH STROKE"),
+    ('\u{219b}', "RIGHTWARDS ARROW WITH STROKE"),
+    ('\u{219c}', "LEFTWARDS WAVE ARROW"),
+    ('\u{219d}', "RIGHTWARDS WAVE ARROW"),
+    ('\u{219e}', "LEFTWARDS TWO HEADED ARROW"),
+    ('\u{219f}', "UPWARDS TWO HEADED ARROW"),
+    ('\u{21a0}', "RIGHTWARDS TWO HEADED ARROW"),
+    ('\u{21a1}', "DOWNWARDS TWO HEADED ARROW"),
+    ('\u{21a2}', "LEFTWARDS ARROW WITH TAIL"),
+    ('\u{21a3}', "RIGHTWARDS ARROW WITH TAIL"),
+    ('\u{21a4}', "LEFTWARDS ARROW FROM BAR"),
+    ('\u{21a5}', "UPWARDS ARROW FROM BAR"),
+    ('\u{21a6}', "RIGHTWARDS ARROW FROM BAR"),
+    ('\u{21a7}', "DOWNWARDS ARROW FROM BAR"),
+    ('\u{21a8}', "UP DOWN ARROW WITH BASE"),
+    ('\u{21a9}', "LEFTWARDS ARROW WITH HOOK"),
+    ('\u{21aa}', "RIGHTWARDS ARROW WITH HOOK"),
+    ('\u{21ab}', "LEFTWARDS ARROW WITH LOOP"),
+    ('\u{21ac}', "RIGHTWARDS ARROW WITH LOOP"),
+    ('\u{21ad}', "LEFT RIGHT WAVE ARROW"),
+    ('\u{21ae}', "LEFT RIGHT ARROW WITH STROKE"),
+    ('\u{21af}', "DOWNWARDS ZIGZAG ARROW"),
+    ('\u{21b0}', "UPWARDS ARROW WITH TIP LEFTWARDS"),
+    ('\u{21b1}', "UPWARDS ARROW WITH TIP RIGHTWARDS"),
+    ('\u{21b2}', "DOWNWARDS ARROW WITH TIP LEFTWARDS"),
+    ('\u{21b3}', "DOWNWARDS ARROW WITH TIP RIGHTWARDS"),
+    ('\u{21b4}', "RIGHTWARDS ARROW WITH CORNER DOWNWARDS"),
+    ('\u{21b5}', "DOWNWARDS ARROW WITH CORNER LEFTWARDS"),
+    ('\u{21b6}', "ANTICLOCKWISE TOP SEMICIRCLE ARROW"),
+    ('\u{21b7}', "CLOCKWISE TOP SEMICIRCLE ARROW"),
+    ('\u{21b8}', "NORTH WEST ARROW TO LONG BAR"),
+    (
+        '\u{21b9}',
+        "LEFTWARDS ARROW TO BAR OVER RIGHTWARDS ARROW TO BAR",
+    ),
+    ('\u{21ba}', "ANTICLOCKWISE OPEN CIRCLE ARROW"),
+    ('\u{21bb}', "CLOCKWISE OPEN CIRCLE ARROW"),
+    ('\u{21bc}', "LEFTWARDS HARPOON WITH BARB UPWARDS"),
+    ('\u{21bd}', "LEFTWARDS HARPOON WITH BARB DOWNWARDS"),
+    ('\u{21be}', "UPWARDS HARPOON WITH BARB RIGHTWARDS"),
+    ('\u{21bf}', "UPWARDS HARPOON WITH BARB LEFTWARDS"),
+    ('\u{21c0}', "RIGHTWARDS HARPOON WITH BARB UPWARDS"),
+    ('\u{21c1}', "RIGHTWARDS HARPOON WITH BARB DOWNWARDS"),
+    ('\u{21c2}', "DOWNWARDS HARPOON WITH BARB RIGHTWARDS"),
+    ('\u{21c3}', "DOWNWARDS HARPOON WITH BARB LEFTWARDS"),
+    ('\u{21c4}', "RIGHTWARDS ARROW OVER LEFTWARDS ARROW"),
+    ('\u{21c5}', "UPWARDS ARROW LEFTWARDS OF DOWNWARDS ARROW"),
+    ('\u{21c6}', "LEFTWARDS ARROW OVER RIGHTWARDS ARROW"),
+    ('\u{21c7}', "LEFTWARDS PAIRED ARROWS"),
+    ('\u{21c8}', "UPWARDS PAIRED ARROWS"),
+    ('\u{21c9}', "RIGHTWARDS PAIRED ARROWS"),
+    ('\u{21ca}', "DOWNWARDS PAIRED ARROWS"),
+    ('\u{21cb}', "LEFTWARDS HARPOON OVER RIGHTWARDS HARPOON"),
+    ('\u{21cc}', "RIGHTWARDS HARPOON OVER LEFTWARDS HARPOON"),
+    ('\u{21cd}', "LEFTWARDS DOUBLE ARROW WITH STROKE"),
+    ('\u{21ce}', "LEFT RIGHT DOUBLE ARROW WITH STROKE"),
+    ('\u{21cf}', "RIGHTWARDS DOUBLE ARROW WITH STROKE"),
+    ('\u{21d0}', "LEFTWARDS DOUBLE ARROW"),
+    ('\u{21d1}', "UPWARDS DOUBLE ARROW"),
+    ('\u{21d2}', "RIGHTWARDS DOUBLE ARROW"),
+    ('\u{21d3}', "DOWNWARDS DOUBLE ARROW"),
+    ('\u{21d4}', "LEFT RIGHT DOUBLE ARROW"),
+    ('\u{21d5}', "UP DOWN DOUBLE ARROW"),
+    ('\u{21d6}', "NORTH WEST DOUBLE ARROW"),
+    ('\u{21d7}', "NORTH EAST DOUBLE ARROW"),
+    ('\u{21d8}', "SOUTH EAST DOUBLE ARROW"),
+    ('\u{21d9}', "SOUTH WEST DOUBLE ARROW"),
+    ('\u{21da}', "LEFTWARDS TRIPLE ARROW"),
+    ('\u{21db}', "RIGHTWARDS TRIPLE ARROW"),
+    ('\u{21dc}', "LEFTWARDS SQUIGGLE ARROW"),
+    ('\u{21dd}', "RIGHTWARDS SQUIGGLE ARROW"),
+    ('\u{21de}', "UPWARDS ARROW WITH DOUBLE STROKE"),
+    ('\u{21df}', "DOWNWARDS ARROW WITH DOUBLE STROKE"),
+    ('\u{21e0}', "LEFTWARDS DASHED ARROW"),
+    ('\u{21e1}', "UPWARDS DASHED ARROW"),
+    ('\u{21e2}', "RIGHTWARDS DASHED ARROW"),
+    ('\u{21e3}', "DOWNWARDS DASHED ARROW"),
+    ('\u{21e4}', "LEFTWARDS ARROW TO BAR"),
+    ('\u{21e5}', "RIGHTWARDS ARROW TO BAR"),
+    ('\u{21e6}', "LEFTWARDS WHITE ARROW"),
+    ('\u{21e7}', "UPWARDS WHITE ARROW"),
+    ('\u{21e8}', "RIGHTWARDS WHITE ARROW"),
+    ('\u{21e9}', "DOWNWARDS WHITE ARROW"),
+    ('\u{21ea}', "UPWARDS WHITE ARROW FROM BAR"),
+    ('\u{21eb}', "UPWARDS WHITE ARROW ON PEDESTAL"),
+    (
+        '\u{21ec}',
+        "UPWARDS WHITE ARROW ON PEDESTAL WITH HORIZONTAL BAR",
+    ),
+    (
+        '\u{21ed}',
+        "UPWARDS WHITE ARROW ON PEDESTAL WITH VERTICAL BAR",
+    ),
+    ('\u{21ee}', "UPWARDS WHITE DOUBLE ARROW"),
+    ('\u{21ef}', "UPWARDS WHITE DOUBLE ARROW ON PEDESTAL"),
+    ('\u{21f0}', "RIGHTWARDS WHITE ARROW FROM WALL"),
+    ('\u{21f1}', "NORTH WEST ARROW TO CORNER"),
+    ('\u{21f2}', "SOUTH EAST ARROW TO CORNER"),
+    ('\u{21f3}', "UP DOWN WHITE ARROW"),
+    ('\u{21f4}', "RIGHT ARROW WITH SMALL CIRCLE"),
+    ('\u{21f5}', "DOWNWARDS ARROW LEFTWARDS OF UPWARDS ARROW"),
+    ('\u{21f6}', "THREE RIGHTWARDS ARROWS"),
+    ('\u{21f7}', "LEFTWARDS ARROW WITH VERTICAL STROKE"),
+    ('\u{21f8}', "RIGHTWARDS ARROW WITH VERTICAL STROKE"),
+    ('\u{21f9}', "LEFT RIGHT ARROW WITH VERTICAL STROKE"),
+    ('\u{21fa}', "LEFTWARDS ARROW WITH DOUBLE VERTICAL STROKE"),
+    ('\u{21fb}', "RIGHTWARDS ARROW WITH DOUBLE VERTICAL STROKE"),
+    ('\u{21fc}', "LEFT RIGHT ARROW WITH DOUBLE VERTICAL STROKE"),
+    ('\u{21fd}', "LEFTWARDS OPEN-HEADED ARROW"),
+    ('\u{21fe}', "RIGHTWARDS OPEN-HEADED ARROW"),
+    ('\u{21ff}', "LEFT RIGHT OPEN-HEADED ARROW"),
+    ('\u{2200}', "FOR ALL"),
+    ('\u{2201}', "COMPLEMENT"),
+    ('\u{2202}', "PARTIAL DIFFERENTIAL"),
+    ('\u{2203}', "THERE EXISTS"),
+    ('\u{2204}', "THERE DOES NOT EXIST"),
+    ('\u{2205}', "EMPTY SET"),
+    ('\u{2206}', "INCREMENT"),
+    ('\u{2207}', "NABLA"),
+    ('\u{2208}', "ELEMENT OF"),
+    ('\u{2209}', "NOT AN ELEMENT OF"),
+    ('\u{220a}', "SMALL ELEMENT OF"),
+    ('\u{220b}', "CONTAINS AS MEMBER"),
+    ('\u{220c}', "DOES NOT CONTAIN AS MEMBER"),
+    ('\u{220d}', "SMALL CONTAINS AS MEMBER"),
+    ('\u{220e}', "END OF PROOF"),
+    ('\u{220f}', "N-ARY PRODUCT"),
+    ('\u{2210}', "N-ARY COPRODUCT"),
+    ('\u{2211}', "N-ARY SUMMATION"),
+    ('\u{2212}', "MINUS SIGN"),
+    ('\u{2213}', "MINUS-OR-PLUS SIGN"),
+    ('\u{2214}', "DOT PLUS"),
+    ('\u{2215}', "DIVISION SLASH"),
+    ('\u{2216}', "SET MINUS"),
+    ('\u{2217}', "ASTERISK OPERATOR"),
+    ('\u{2218}', "RING OPERATOR"),
+    ('\u{2219}', "BULLET OPERATOR"),
+    ('\u{221a}', "SQUARE ROOT"),
+    ('\u{221b}', "CUBE ROOT"),
+    ('\u{221c}', "FOURTH ROOT"),
+    ('\u{221d}', "PROPORTIONAL TO"),
+    ('\u{221e}', "INFINITY"),
+    ('\u{221f}', "RIGHT ANGLE"),
+    ('\u{2220}', "ANGLE"),
+    ('\u{2221}', "MEASURED ANGLE"),
+    ('\u{2222}', "SPHERICAL ANGLE"),
+    ('\u{2223}', "DIVIDES"),
+    ('\u{2224}', "DOES NOT DIVIDE"),
+    ('\u{2225}', "PARALLEL TO"),
+    ('\u{2226}', "NOT PARALLEL TO"),
+    ('\u{2227}', "LOGICAL AND"),
+    ('\u{2228}', "LOGICAL OR"),
+    ('\u{2229}', "INTERSECTION"),
+    ('\u{222a}', "UNION"),
+    ('\u{222b}', "INTEGRAL"),
+    ('\u{222c}', "DOUBLE INTEGRAL"),
+    ('\u{222d}', "TRIPLE INTEGRAL"),
+    ('\u{222e}', "CONTOUR INTEGRAL"),
+    ('\u{222f}', "SURFACE INTEGRAL"),
+    ('\u{2230}', "VOLUME INTEGRAL"),
+    ('\u{2231}', "CLOCKWISE INTEGRAL"),
+    ('\u{2232}', "CLOCKWISE CONTOUR INTEGRAL"),
+    ('\u{2233}', "ANTICLOCKWISE CONTOUR INTEGRAL"),
+    ('\u{2234}', "THEREFORE"),
+    ('\u{2235}', "BECAUSE"),
+    ('\u{2236}', "RATIO"),
+    ('\u{2237}', "PROPORTION"),
+    ('\u{2238}', "DOT MINUS"),
+    ('\u{2239}', "EXCESS"),
+    ('\u{223a}', "GEOMETRIC PROPORTION"),
+    ('\u{223b}', "HOMOTHETIC"),
+    ('\u{223c}', "TILDE OPERATOR"),
+    ('\u{223d}', "REVERSED TILDE"),
+    ('\u{223e}', "INVERTED LAZY S"),
+    ('\u{223f}', "SINE WAVE"),
+    ('\u{2240}', "WREATH PRODUCT"),
+    ('\u{2241}', "NOT TILDE"),
+    ('\u{2242}', "MINUS TILDE"),
+    ('\u{2243}', "ASYMPTOTICALLY EQUAL TO"),
+    ('\u{2244}', "NOT ASYMPTOTICALLY EQUAL TO"),
+    ('\u{2245}', "APPROXIMATELY EQUAL TO"),
+    ('\u{2246}', "APPROXIMATELY BUT NOT ACTUALLY EQUAL TO"),
+    ('\u{2247}', "NEITHER APPROXIMATELY NOR ACTUALLY EQUAL TO"),
+    ('\u{2248}', "ALMOST EQUAL TO"),
+    ('\u{2249}', "NOT ALMOST EQUAL TO"),
+    ('\u{224a}', "ALMOST EQUAL OR EQUAL TO"),
+    ('\u{224b}', "TRIPLE TILDE"),
+    ('\u{224c}', "ALL EQUAL TO"),
+    ('\u{224d}', "EQUIVALENT TO"),
+    ('\u{224e}', "GEOMETRICALLY EQUIVALENT TO"),
+    ('\u{224f}', "DIFFERENCE BETWEEN"),
+    ('\u{2250}', "APPROACHES THE LIMIT"),
+    ('\u{2251}', "GEOMETRICALLY EQUAL TO"),
+    ('\u{2252}', "APPROXIMATELY EQUAL TO OR THE IMAGE OF"),
+    ('\u{2253}', "IMAGE OF OR APPROXIMATELY EQUAL TO"),
+    ('\u{2254}', "COLON EQUALS"),
+    ('\u{2255}', "EQUALS COLON"),
+    ('\u{2256}', "RING IN EQUAL TO"),
+    ('\u{2257}', "RING EQUAL TO"),
+    ('\u{2258}', "CORRESPONDS TO"),
+    ('\u{2259}', "ESTIMATES"),
+    ('\u{225a}', "EQUIANGULAR TO"),
+    ('\u{225b}', "STAR EQUALS"),
+    ('\u{225c}', "DELTA EQUAL TO"),
+    ('\u{225d}', "EQUAL TO BY DEFINITION"),
+    ('\u{225e}', "MEASURED BY"),
+    ('\u{225f}', "QUESTIONED EQUAL TO"),
+    ('\u{2260}', "NOT EQUAL TO"),
+    ('\u{2261}', "IDENTICAL TO"),
+    ('\u{2262}', "NOT IDENTICAL TO"),
+    ('\u{2263}', "STRICTLY EQUIVALENT TO"),
+    ('\u{2264}', "LESS-THAN OR EQUAL TO"),
+    ('\u{2265}', "GREATER-THAN OR EQUAL TO"),
+    ('\u{2266}', "LESS-THAN OVER EQUAL TO"),
+    ('\u{2267}', "GREATER-THAN OVER EQUAL TO"),
+    ('\u{2268}', "LESS-THAN BUT NOT EQUAL TO"),
+    ('\u{2269}', "GREATER-THAN BUT NOT EQUAL TO"),
+    ('\u{226a}', "MUCH LESS-THAN"),
+    ('\u{226b}', "MUCH GREATER-THAN"),
+    ('\u{226c}', "BETWEEN"),
+    ('\u{226d}', "NOT EQUIVALENT TO"),
+    ('\u{226e}', "NOT LESS-THAN"),
+    ('\u{226f}', "NOT GREATER-THAN"),
+    ('\u{2270}', "NEITHER LESS-THAN NOR EQUAL TO"),
+    ('\u{2271}', "NEITHER GREATER-THAN NOR EQUAL TO"),
+    ('\u{2272}', "LESS-THAN OR EQUIVALENT TO"),
+    ('\u{2273}', "GREATER-THAN OR EQUIVALENT TO"),
+    ('\u{2274}', "NEITHER LESS-THAN NOR EQUIVALENT TO"),
+    ('\u{2275}', "NEITHER GREATER-THAN NOR EQUIVALENT TO"),
+    ('\u{2276}', "LESS-THAN OR GREATER-THAN"),
+    ('\u{2277}', "GREATER-THAN OR LESS-THAN"),
+    ('\u{2278}', "NEITHER LESS-THAN NOR GREATER-THAN"),
+    ('\u{2279}', "NEITHER GREATER-THAN NOR LESS-THAN"),
+    ('\u{227a}', "PRECEDES"),
+    ('\u{227b}', "SUCCEEDS"),
+    ('\u{227c}', "PRECEDES OR EQUAL TO"),
+    ('\u{227d}', "SUCCEEDS OR EQUAL TO"),
+    ('\u{227e}', "PRECEDES OR EQUIVALENT TO"),
+    ('\u{227f}', "SUCCEEDS OR EQUIVALENT TO"),
+    ('\u{2280}', "DOES NOT PRECEDE"),
+    ('\u{2281}', "DOES NOT SUCCEED"),
+    ('\u{2282}', "SUBSET OF"),
+    ('\u{2283}', "SUPERSET OF"),
+    ('\u{2284}', "NOT A SUBSET OF"),
+    ('\u{2285}', "NOT A SUPERSET OF"),
+    ('\u{2286}', "SUBSET OF OR EQUAL TO"),
+    ('\u{2287}', "SUPERSET OF OR EQUAL TO"),
+    ('\u{2288}', "NEITHER A SUBSET OF NOR EQUAL TO"),
+    ('\u{2289}', "NEITHER A SUPERSET OF NOR EQUAL TO"),
+    ('\u{228a}', "SUBSET OF WITH NOT EQUAL TO"),
+    ('\u{228b}', "SUPERSET OF WITH NOT EQUAL TO"),
+    ('\u{228c}', "MULTISET"),
+    ('\u{228d}', "MULTISET MULTIPLICATION"),
+    ('\u{228e}', "MULTISET UNION"),
+    ('\u{228f}', "SQUARE IMAGE OF"),
+    ('\u{2290}', "SQUARE ORIGINAL OF"),
+    ('\u{2291}', "SQUARE IMAGE OF OR EQUAL TO"),
+    ('\u{2292}', "SQUARE ORIGINAL OF OR EQUAL TO"),
+    ('\u{2293}', "SQUARE CAP"),
+    ('\u{2294}', "SQUARE CUP"),
+    ('\u{2295}', "CIRCLED PLUS"),
+    ('\u{2296}', "CIRCLED MINUS"),
+    ('\u{2297}', "CIRCLED TIMES"),
+    ('\u{2298}', "CIRCLED DIVISION SLASH"),
+    ('\u{2299}', "CIRCLED DOT OPERATOR"),
+    ('\u{229a}', "CIRCLED RING OPERATOR"),
+    ('\u{229b}', "CIRCLED ASTERISK OPERATOR"),
+    ('\u{229c}', "CIRCLED EQUALS"),
+    ('\u{229d}', "CIRCLED DASH"),
+    ('\u{229e}', "SQUARED PLUS"),
+    ('\u{229f}', "SQUARED MINUS"),
+    ('\u{22a0}', "SQUARED TIMES"),
+    ('\u{22a1}', "SQUARED DOT OPERATOR"),
+    ('\u{22a2}', "RIGHT TACK"),
+    ('\u{22a3}', "LEFT TACK"),
+    ('\u{22a4}', "DOWN TACK"),
+    ('\u{22a5}', "UP TACK"),
+    ('\u{22a6}', "ASSERTION"),
+    ('\u{22a7}', "MODELS"),
+    ('\u{22a8}', "TRUE"),
+    ('\u{22a9}', "FORCES"),
+    ('\u{22aa}', "TRIPLE VERTICAL BAR RIGHT TURNSTILE"),
+    ('\u{22ab}', "DOUBLE VERTICAL BAR DOUBLE RIGHT TURNSTILE"),
+    ('\u{22ac}', "DOES NOT PROVE"),
+    ('\u{22ad}', "NOT TRUE"),
+    ('\u{22ae}', "DOES NOT FORCE"),
+    (
+        '\u{22af}',
+        "NEGATED DOUBLE VERTICAL BAR DOUBLE RIGHT TURNSTILE",
+    ),
+    ('\u{22b0}', "PRECEDES UNDER RELATION"),
+    ('\u{22b1}', "SUCCEEDS UNDER RELATION"),
+    ('\u{22b2}', "NORMAL SUBGROUP OF"),
+    ('\u{22b3}', "CONTAINS AS NORMAL SUBGROUP"),
+    ('\u{22b4}', "NORMAL SUBGROUP OF OR EQUAL TO"),
+    ('\u{22b5}', "CONTAINS AS NORMAL SUBGROUP OR EQUAL TO"),
+    ('\u{22b6}', "ORIGINAL OF"),
+    ('\u{22b7}', "IMAGE OF"),
+    ('\u{22b8}', "MULTIMAP"),
+    ('\u{22b9}', "HERMITIAN CONJUGATE MATRIX"),
+    ('\u{22ba}', "INTERCALATE"),
+    ('\u{22bb}', "XOR"),
+    ('\u{22bc}', "NAND"),
+    ('\u{22bd}', "NOR"),
+    ('\u{22be}', "RIGHT ANGLE WITH ARC"),
+    ('\u{22bf}', "RIGHT TRIANGLE"),
+    ('\u{22c0}', "N-ARY LOGICAL AND"),
+    ('\u{22c1}', "N-ARY LOGICAL OR"),
+    ('\u{22c2}', "N-ARY INTERSECTION"),
+    ('\u{22c3}', "N-ARY UNION"),
+    ('\u{22c4}', "DIAMOND OPERATOR"),
+    ('\u{22c5}', "DOT OPERATOR"),
+    ('\u{22c6}', "STAR OPERATOR"),
+    ('\u{22c7}', "DIVISION TIMES"),
+    ('\u{22c8}', "BOWTIE"),
+    ('\u{22c9}', "LEFT NORMAL FACTOR SEMIDIRECT PRODUCT"),
+    ('\u{22ca}', "RIGHT NORMAL FACTOR SEMIDIRECT PRODUCT"),
+    ('\u{22cb}', "LEFT SEMIDIRECT PRODUCT"),
+    ('\u{22cc}', "RIGHT SEMIDIRECT PRODUCT"),
+    ('\u{22cd}', "REVERSED TILDE EQUALS"),
+    ('\u{22ce}', "CURLY LOGICAL OR"),
+    ('\u{22cf}', "CURLY LOGICAL AND"),
+    ('\u{22d0}', "DOUBLE SUBSET"),
+    ('\u{22d1}', "DOUBLE SUPERSET"),
+    ('\u{22d2}', "DOUBLE INTERSECTION"),
+    ('\u{22d3}', "DOUBLE UNION"),
+    ('\u{22d4}', "PITCHFORK"),
+    ('\u{22d5}', "EQUAL AND PARALLEL TO"),
+    ('\u{22d6}', "LESS-THAN WITH DOT"),
+    ('\u{22d7}', "GREATER-THAN WITH DOT"),
+    ('\u{22d8}', "VERY MUCH LESS-THAN"),
+    ('\u{22d9}', "VERY MUCH GREATER-THAN"),
+    ('\u{22da}', "LESS-THAN EQUAL TO OR GREATER-THAN"),
+    ('\u{22db}', "GREATER-THAN EQUAL TO OR LESS-THAN"),
+    ('\u{22dc}', "EQUAL TO OR LESS-THAN"),
+    ('\u{22dd}', "EQUAL TO OR GREATER-THAN"),
+    ('\u{22de}', "EQUAL TO OR PRECEDES"),
+    ('\u{22df}', "EQUAL TO OR SUCCEEDS"),
+    ('\u{22e0}', "DOES NOT PRECEDE OR EQUAL"),
+    ('\u{22e1}', "DOES NOT SUCCEED OR EQUAL"),
+    ('\u{22e2}', "NOT SQUARE IMAGE OF OR EQUAL TO"),
+    ('\u{22e3}', "NOT SQUARE ORIGINAL OF OR EQUAL TO"),
+    ('\u{22e4}', "SQUARE IMAGE OF OR NOT EQUAL TO"),
+    ('\u{22e5}', "SQUARE ORIGINAL OF OR NOT EQUAL TO"),
+    ('\u{22e6}', "LESS-THAN BUT NOT EQUIVALENT TO"),
+    ('\u{22e7}', "GREATER-THAN BUT NOT EQUIVALENT TO"),
+    ('\u{22e8}', "PRECEDES BUT NOT EQUIVALENT TO"),
+    ('\u{22e9}', "SUCCEEDS BUT NOT EQUIVALENT TO"),
+    ('\u{22ea}', "NOT NORMAL SUBGROUP OF"),
+    ('\u{22eb}', "DOES NOT CONTAIN AS NORMAL SUBGROUP"),
+    ('\u{22ec}', "NOT NORMAL SUBGROUP OF OR EQUAL TO"),
+    ('\u{22ed}', "DOES NOT CONTAIN AS NORMAL SUBGROUP OR EQUAL"),
+    ('\u{22ee}', "VERTICAL ELLIPSIS"),
+    ('\u{22ef}', "MIDLINE HORIZONTAL ELLIPSIS"),
+    ('\u{22f0}', "UP RIGHT DIAGONAL ELLIPSIS"),
+    ('\u{22f1}', "DOWN RIGHT DIAGONAL ELLIPSIS"),
+    ('\u{22f2}', "ELEMENT OF WITH LONG HORIZONTAL STROKE"),
+    (
+        '\u{22f3}',
+        "ELEMENT OF WITH VERTICAL BAR AT END OF HORIZONTAL STROKE",
+    ),
+    (
+        '\u{22f4}',
+        "SMALL ELEMENT OF WITH VERTICAL BAR AT END OF HORIZONTAL STROKE",
+    ),
+    ('\u{22f5}', "ELEMENT OF WITH DOT ABOVE"),
+    ('\u{22f6}', "ELEMENT OF WITH OVERBAR"),
+    ('\u{22f7}', "SMALL ELEMENT OF WITH OVERBAR"),
+    ('\u{22f8}', "ELEMENT OF WITH UNDERBAR"),
+    ('\u{22f9}', "ELEMENT OF WITH TWO HORIZONTAL STROKES"),
+    ('\u{22fa}', "CONTAINS WITH LONG HORIZONTAL STROKE"),
+    (
+        '\u{22fb}',
+        "CONTAINS WITH VERTICAL BAR AT END OF HORIZONTAL STROKE",
+    ),
+    (
+        '\u{22fc}',
+        "SMALL CONTAINS WITH VERTICAL BAR AT END OF HORIZONTAL STROKE",
+    ),
+    ('\u{22fd}', "CONTAINS WITH OVERBAR"),
+    ('\u{22fe}', "SMALL CONTAINS WITH OVERBAR"),
+    ('\u{22ff}', "Z NOTATION BAG MEMBERSHIP"),
+];
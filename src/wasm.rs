@@ -0,0 +1,212 @@
+/*
+ * Copyright © 2019-2020 Peter M. Stahl pemistahl@gmail.com
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ * http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either expressed or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use crate::regexp::{Feature, GenerationWarning, RegExp, RegExpBuilder};
+use serde::{Deserialize, Serialize};
+use wasm_bindgen::prelude::*;
+
+/// This struct holds the settings which can be passed to function `generate`.
+/// Its fields correspond to the flags and options of the command-line tool.
+#[derive(Debug, Default, Deserialize, PartialEq)]
+#[serde(default, rename_all = "camelCase")]
+pub struct GenerationOptions {
+    digits: bool,
+    non_digits: bool,
+    spaces: bool,
+    non_spaces: bool,
+    words: bool,
+    non_words: bool,
+    scripts: bool,
+    repetitions: bool,
+    ignore_case: bool,
+    capture_groups: bool,
+    escape: bool,
+    with_surrogates: bool,
+    min_repetitions: Option<u32>,
+    min_substring_length: Option<u32>,
+}
+
+/// This struct holds the outcome of function `generate`.
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GenerationResult {
+    pattern: String,
+    metrics: ComplexityMetrics,
+    warnings: Vec<String>,
+}
+
+/// This struct holds some figures describing the complexity of a generated regular expression.
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ComplexityMetrics {
+    pattern_length: usize,
+    branch_count: usize,
+    state_count: usize,
+}
+
+/// Generates a regular expression from the given test cases using the given options
+/// and returns the serialized [`GenerationResult`](./struct.GenerationResult.html).
+///
+/// The options are expected as a plain JavaScript object whose keys are the camel-cased
+/// fields of [`GenerationOptions`](./struct.GenerationOptions.html). Missing keys fall back
+/// to the default settings. If the options cannot be parsed, the default settings are used
+/// and a respective warning is added to the result.
+#[wasm_bindgen]
+pub fn generate(inputs: Vec<String>, options: JsValue) -> JsValue {
+    let mut warnings = vec![];
+    let options = if options.is_undefined() || options.is_null() {
+        GenerationOptions::default()
+    } else {
+        match serde_wasm_bindgen::from_value(options) {
+            Ok(options) => options,
+            Err(error) => {
+                warnings.push(format!("Options have been ignored: {}", error));
+                GenerationOptions::default()
+            }
+        }
+    };
+    let mut result = generate_result(&inputs, &options);
+    warnings.append(&mut result.warnings);
+    result.warnings = warnings;
+    serde_wasm_bindgen::to_value(&result).unwrap()
+}
+
+fn generate_result(inputs: &[String], options: &GenerationOptions) -> GenerationResult {
+    let (regexp, warnings) = options.to_builder(inputs).build_with_warnings();
+    GenerationResult::from(&regexp, &warnings)
+}
+
+impl GenerationOptions {
+    fn to_builder(&self, inputs: &[String]) -> RegExpBuilder {
+        let mut builder = RegExpBuilder::from(inputs);
+        let conversion_features = vec![
+            (self.digits, Feature::Digit),
+            (self.non_digits, Feature::NonDigit),
+            (self.spaces, Feature::Space),
+            (self.non_spaces, Feature::NonSpace),
+            (self.words, Feature::Word),
+            (self.non_words, Feature::NonWord),
+            (self.scripts, Feature::Script),
+            (self.repetitions, Feature::Repetition),
+            (self.ignore_case, Feature::CaseInsensitivity),
+            (self.capture_groups, Feature::CapturingGroup),
+        ]
+        .into_iter()
+        .filter(|(is_enabled, _)| *is_enabled)
+        .map(|(_, feature)| feature)
+        .collect::<Vec<_>>();
+
+        if !conversion_features.is_empty() {
+            builder.with_conversion_of(&conversion_features);
+        }
+
+        if self.escape {
+            builder.with_escaping_of_non_ascii_chars(self.with_surrogates);
+        }
+
+        if let Some(quantity) = self.min_repetitions {
+            builder.with_minimum_repetitions(quantity);
+        }
+
+        if let Some(length) = self.min_substring_length {
+            builder.with_minimum_substring_length(length);
+        }
+
+        builder
+    }
+}
+
+impl GenerationResult {
+    fn from(regexp: &RegExp, warnings: &[GenerationWarning]) -> Self {
+        let pattern = regexp.to_string();
+        Self {
+            metrics: ComplexityMetrics {
+                pattern_length: pattern.chars().count(),
+                branch_count: regexp.branch_count(),
+                state_count: regexp.min_states(),
+            },
+            pattern,
+            warnings: warnings.iter().map(|it| it.to_string()).collect(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_parsing_of_options() {
+        let options = serde_json::from_value::<GenerationOptions>(json!({
+            "digits": true,
+            "ignoreCase": true,
+            "minRepetitions": 2
+        }))
+        .unwrap();
+
+        assert_eq!(
+            options,
+            GenerationOptions {
+                digits: true,
+                ignore_case: true,
+                min_repetitions: Some(2),
+                ..GenerationOptions::default()
+            }
+        );
+    }
+
+    #[test]
+    fn test_parsing_of_empty_options() {
+        let options = serde_json::from_value::<GenerationOptions>(json!({})).unwrap();
+        assert_eq!(options, GenerationOptions::default());
+    }
+
+    #[test]
+    fn test_result_shape() {
+        let options = GenerationOptions {
+            digits: true,
+            ..GenerationOptions::default()
+        };
+        let inputs = vec!["a1".to_string(), "b22".to_string()];
+        let result = serde_json::to_value(generate_result(&inputs, &options)).unwrap();
+
+        assert_eq!(
+            result,
+            json!({
+                "pattern": "^(?:b\\d|a)\\d$",
+                "metrics": {
+                    "patternLength": 13,
+                    "branchCount": 1,
+                    "stateCount": 4
+                },
+                "warnings": ["characters have been generalized to character class \\d"]
+            })
+        );
+    }
+
+    #[test]
+    fn test_result_with_warnings() {
+        let inputs = vec!["a".to_string(), "a".to_string(), "b".to_string()];
+        let result = generate_result(&inputs, &GenerationOptions::default());
+
+        assert_eq!(result.pattern, "^[ab]$");
+        assert_eq!(
+            result.warnings,
+            vec!["1 duplicate test case(s) have been removed"]
+        );
+    }
+}
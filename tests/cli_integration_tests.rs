@@ -83,7 +83,7 @@ mod no_conversion {
             grex.args(&["-f", file.path().to_str().unwrap()]);
             grex.assert()
                 .success()
-                .stdout(predicate::eq("^(?:b\\\\n|äöü|[ac♥])$\n"));
+                .stdout(predicate::eq("^(?:b\\\\n|äöü|[ac♥])?$\n"));
         }
 
         #[test]
@@ -1660,6 +1660,36 @@ mod word_non_word_conversion {
     }
 }
 
+mod script_conversion {
+    use super::*;
+
+    mod no_repetition {
+        use super::*;
+
+        #[test]
+        fn succeeds() {
+            let mut grex = init_command();
+            grex.args(&["--scripts", "αβγ", "δεζ"]);
+            grex.assert()
+                .success()
+                .stdout(predicate::eq("^\\p{Greek}\\p{Greek}\\p{Greek}$\n"));
+        }
+    }
+
+    mod repetition {
+        use super::*;
+
+        #[test]
+        fn succeeds() {
+            let mut grex = init_command();
+            grex.args(&["--repetitions", "--scripts", "αβγ", "漢字"]);
+            grex.assert()
+                .success()
+                .stdout(predicate::eq("^(?:\\p{Greek}{3}|\\p{Han}{2})$\n"));
+        }
+    }
+}
+
 fn init_command() -> Command {
     Command::cargo_bin("grex").unwrap()
 }
@@ -14,7 +14,11 @@
  * limitations under the License.
  */
 
-use grex::{Feature, RegExpBuilder};
+use grex::{
+    ClassSyntax, Feature, Flavor, GenerationError, GenerationWarning, InputFileError, LineEnding,
+    Mutation, ParseError, ProfileError, ProfileFormat, RegExpBuilder, RegExpConfig, Shell,
+    SnippetLanguage, Wildcard,
+};
 use regex::Regex;
 use rstest::rstest;
 use std::io::Write;
@@ -28,6 +32,7 @@ mod no_conversion {
 
         #[rstest(test_cases, expected_output,
             case(vec![""], "^$"),
+            case(vec!["", "a"], "^a?$"),
             case(vec![" "], "^ $"),
             case(vec!["   "], "^   $"),
             case(vec!["["], "^\\[$"),
@@ -52,6 +57,8 @@ mod no_conversion {
             case(vec!["1", "2", "3", "8", "4", "5"], "^[1-58]$"),
             case(vec!["1", "2", "3", "8", "45"], "^(?:45|[1-38])$"),
             case(vec!["1", "2", "3", "5", "7", "8", "9"], "^[1-357-9]$"),
+            case(vec!["", "a"], "^a?$"),
+            case(vec!["", "a", "ab"], "^(?:ab?)?$"),
             case(vec!["a", "b", "bc"], "^(?:bc?|a)$"),
             case(vec!["a", "b", "bcd"], "^(?:b(?:cd)?|a)$"),
             case(vec!["a", "ab", "abc"], "^a(?:bc?)?$"),
@@ -87,6 +94,12 @@ mod no_conversion {
             case(vec!["My ♥ is yours.", "My 💩 is yours."], "^My [♥💩] is yours\\.$"),
             case(vec!["[\u{c3e}"], "^\\[\u{c3e}$"),
             case(vec!["\\\u{10376}"], "^\\\\\u{10376}$"),
+            case(vec!["\u{301}", "a"], "^[a\\u{301}]$"),
+            case(vec!["\u{300}", "\u{301}", "\u{302}"], "^[\\u{300}-\\u{302}]$"),
+            case(vec!["\u{200d}", "a"], "^[a\\u{200d}]$"),
+            case(vec!["👍🏽", "👍🏿"], "^(?:👍🏽|👍🏿)$"),
+            case(vec!["x👍🏽y", "xay"], "^x(?:a|👍🏽)y$"),
+            case(vec!["👨‍👩‍👧", "a"], "^(?:a|👨‍👩‍👧)$"),
             case(vec!["I   ♥♥♥ 36 and ٣ and y̆y̆ and 💩💩."], "^I   ♥♥♥ 36 and ٣ and y̆y̆ and 💩💩\\.$")
         )]
         fn succeeds(test_cases: Vec<&str>, expected_output: &str) {
@@ -98,6 +111,9 @@ mod no_conversion {
         #[rstest(test_cases, expected_output,
             case(vec!["ABC", "abc", "AbC", "aBc"], "(?i)^abc$"),
             case(vec!["Ä@Ö€Ü", "ä@ö€ü", "Ä@ö€Ü", "ä@Ö€ü"], "(?i)^ä@ö€ü$"),
+            case(vec!["A0", "a0", "a1"], "(?i)^a[01]$"),
+            case(vec!["0", "1", "A"], "(?i)^[01a]$"),
+            case(vec!["0", "1", "-"], "^[\\-01]$"),
         )]
         fn succeeds_with_ignore_case_option(test_cases: Vec<&str>, expected_output: &str) {
             let regexp = RegExpBuilder::from(&test_cases)
@@ -107,9 +123,70 @@ mod no_conversion {
             test_if_regexp_matches_test_cases(expected_output, test_cases);
         }
 
+        #[rstest(test_cases, expected_output,
+            case(vec!["Foo", "foo", "bar"], "^(?:Foo|bar)$"),
+            case(vec!["foo", "Foo", "bar"], "^(?:bar|foo)$"),
+            case(vec!["ABC", "abc", "AbC", "aBc"], "^ABC$"),
+            case(vec!["abc", "abd"], "^ab[cd]$")
+        )]
+        fn succeeds_with_case_insensitive_deduplication(
+            test_cases: Vec<&str>,
+            expected_output: &str,
+        ) {
+            let regexp = RegExpBuilder::from(&test_cases)
+                .with_case_insensitive_deduplication()
+                .build();
+            test_if_regexp_is_correct(regexp, expected_output, &test_cases);
+        }
+
+        #[rstest(test_cases, expected_output,
+            case(vec!["Foo", "foo", "bar"], "^(?:bar|foo)$"),
+            case(vec!["foo", "Foo", "bar"], "^(?:Foo|bar)$"),
+            case(vec!["ABC", "abc", "AbC", "aBc"], "^aBc$"),
+            case(vec!["abc", "abd"], "^ab[cd]$")
+        )]
+        fn succeeds_with_case_insensitive_deduplication_keeping_last_duplicate(
+            test_cases: Vec<&str>,
+            expected_output: &str,
+        ) {
+            let regexp = RegExpBuilder::from(&test_cases)
+                .with_case_insensitive_deduplication()
+                .with_last_duplicate_kept()
+                .build();
+            test_if_regexp_is_correct(regexp, expected_output, &test_cases);
+        }
+
+        #[test]
+        fn succeeds_with_last_duplicate_kept_without_case_insensitive_deduplication() {
+            let (regexp, warnings) = RegExpBuilder::from(&["abc", "Abc", "abc"])
+                .with_last_duplicate_kept()
+                .build_with_warnings();
+            assert_eq!(regexp.to_string(), "^[Aa]bc$");
+            assert_eq!(
+                warnings,
+                vec![GenerationWarning::DuplicateTestCasesRemoved(1)]
+            );
+        }
+
+        #[rstest(test_cases, expected_output,
+            case(vec!["Foo", "foo", "bar"], "(?i)^(?:bar|foo)$"),
+            case(vec!["ABC", "abc", "AbC", "aBc"], "(?i)^abc$")
+        )]
+        fn succeeds_with_case_insensitive_deduplication_and_ignore_case_option(
+            test_cases: Vec<&str>,
+            expected_output: &str,
+        ) {
+            let regexp = RegExpBuilder::from(&test_cases)
+                .with_conversion_of(&[Feature::CaseInsensitivity])
+                .with_case_insensitive_deduplication()
+                .build();
+            test_if_regexp_is_correct(regexp, expected_output, &test_cases);
+            test_if_regexp_matches_test_cases(expected_output, test_cases);
+        }
+
         #[rstest(test_cases, expected_output,
             case(vec!["My ♥ and 💩 is yours."], "^My \\u{2665} and \\u{1f4a9} is yours\\.$"),
-            case(vec!["My ♥ is yours.", "My 💩 is yours."], "^My (?:\\u{2665}|\\u{1f4a9}) is yours\\.$"),
+            case(vec!["My ♥ is yours.", "My 💩 is yours."], "^My [\\u{2665}\\u{1f4a9}] is yours\\.$"),
             case(
                 vec!["I   ♥♥♥ 36 and ٣ and y̆y̆ and 💩💩."],
                 "^I   \\u{2665}\\u{2665}\\u{2665} 36 and \\u{663} and y\\u{306}y\\u{306} and \\u{1f4a9}\\u{1f4a9}\\.$"
@@ -152,6 +229,52 @@ mod no_conversion {
             test_if_regexp_matches_test_cases(expected_output, test_cases);
         }
 
+        #[rstest(test_cases, expected_output,
+            case(vec!["a", "c", "e", "g", "b", "d", "f"], "^[a-g]$"),
+            case(vec!["g", "e", "c", "a"], "^[aceg]$"),
+            case(vec!["a", "b", "d", "e", "g"], "^[abdeg]$"),
+            case(vec!["a", "b", "c", "e", "g", "h", "i", "j"], "^[a-ceg-j]$"),
+            case(vec!["[", "\\", "]", "^"], "^[\\[-\\^]$"),
+            case(vec!["Z", "[", "\\"], "^[Z-\\\\]$"),
+            case(vec!["+", ",", "-", "."], "^[+-.]$"),
+            case(vec!["\t", "\n", "\u{b}", "\u{c}", "\r"], "^[\\t-\\r]$"),
+            case(vec!["ä", "ö", "ü", "ã", "â", "á", "à"], "^[à-äöü]$")
+        )]
+        fn succeeds_with_character_ranges(test_cases: Vec<&str>, expected_output: &str) {
+            let regexp = RegExpBuilder::from(&test_cases).build();
+            test_if_regexp_is_correct(regexp, expected_output, &test_cases);
+            test_if_regexp_matches_test_cases(expected_output, test_cases);
+        }
+
+        #[rstest(test_cases, expected_output, other_strings,
+            case(vec!["\\d"], "^\\\\d$", vec!["1", "d", "\\1"]),
+            case(vec!["[a-z]"], "^\\[a\\-z\\]$", vec!["a", "z", "[b]"]),
+            case(vec![".*"], "^\\.\\*$", vec!["", "a", "..", ".a"]),
+            case(vec!["(?:a|b)"], "^\\(\\?:a\\|b\\)$", vec!["a", "b", "(?:a)"]),
+            case(vec!["a{2,3}"], "^a\\{2,3\\}$", vec!["aa", "aaa"]),
+            case(vec!["^$"], "^\\^\\$$", vec!["", "^"]),
+            case(vec!["\\d", "\\w", "\\s"], "^\\\\[dsw]$", vec!["1", "a", " ", "\\1"])
+        )]
+        fn succeeds_with_regexp_syntax_in_test_cases(
+            test_cases: Vec<&str>,
+            expected_output: &str,
+            other_strings: Vec<&str>,
+        ) {
+            for features in &[
+                vec![Feature::Repetition],
+                vec![Feature::Repetition, Feature::Digit, Feature::Word],
+            ] {
+                let regexp = RegExpBuilder::from(&test_cases)
+                    .with_conversion_of(features)
+                    .build();
+                test_if_regexp_matches_test_cases(&regexp, test_cases.clone());
+            }
+            let regexp = RegExpBuilder::from(&test_cases).build();
+            test_if_regexp_is_correct(regexp, expected_output, &test_cases);
+            test_if_regexp_matches_test_cases(expected_output, test_cases);
+            test_if_regexp_does_not_match_other_strings(expected_output, other_strings);
+        }
+
         #[test]
         #[allow(unused_must_use)]
         fn succeeds_with_file_input() {
@@ -165,6 +288,49 @@ mod no_conversion {
             test_if_regexp_is_correct(regexp, expected_output, &test_cases);
             test_if_regexp_matches_test_cases(expected_output, test_cases);
         }
+
+        #[test]
+        fn succeeds_with_utf16_file_input() {
+            let mut utf8_file = NamedTempFile::new().unwrap();
+            write!(utf8_file, "a\nb\nc\r\nxyz").unwrap();
+
+            let mut utf16_file = NamedTempFile::new().unwrap();
+            let mut bytes = vec![0xFF, 0xFE];
+            for code_unit in "a\nb\nc\r\nxyz".encode_utf16() {
+                bytes.extend_from_slice(&code_unit.to_le_bytes());
+            }
+            utf16_file.write_all(&bytes).unwrap();
+
+            let expected_output = "^(?:xyz|[a-c])$";
+            let test_cases = vec!["a", "b", "c", "xyz"];
+
+            let utf8_regexp = RegExpBuilder::from_path(utf8_file.path()).unwrap().build();
+            let utf16_regexp = RegExpBuilder::from_path(utf16_file.path()).unwrap().build();
+            assert_eq!(utf8_regexp, utf16_regexp);
+            test_if_regexp_is_correct(utf16_regexp, expected_output, &test_cases);
+            test_if_regexp_matches_test_cases(expected_output, test_cases);
+        }
+
+        #[test]
+        fn fails_with_missing_or_invalid_file_input() {
+            let mut utf16_file = NamedTempFile::new().unwrap();
+            utf16_file.write_all(&[0xFF, 0xFE, 0x61]).unwrap();
+            let mut utf8_file = NamedTempFile::new().unwrap();
+            utf8_file.write_all(&[0x61, 0xFF]).unwrap();
+
+            assert!(matches!(
+                RegExpBuilder::from_path("/path/to/missing/file.txt"),
+                Err(InputFileError::Io(_))
+            ));
+            assert!(matches!(
+                RegExpBuilder::from_path(utf16_file.path()),
+                Err(InputFileError::InvalidUtf16)
+            ));
+            assert!(matches!(
+                RegExpBuilder::from_path(utf8_file.path()),
+                Err(InputFileError::InvalidUtf8)
+            ));
+        }
     }
 
     mod repetition {
@@ -201,6 +367,8 @@ mod no_conversion {
             case(vec!["axy", "abcxyxy", "adexy"], "^a(?:(?:de)?xy|bc(?:xy){2})$"),
             case(vec!["xy̆y̆y̆y̆z"], "^x(?:y̆){4}z$"),
             case(vec!["xy̆y̆z", "xy̆y̆y̆z"], "^x(?:y̆){2,3}z$"),
+            case(vec!["e\u{301}e\u{301}"], "^(?:e\u{301}){2}$"),
+            case(vec!["👍🏽👍🏽", "👍🏽"], "^(?:👍🏽){1,2}$"),
             case(vec!["xy̆y̆z", "xy̆y̆y̆y̆z"], "^x(?:(?:y̆){2}|(?:y̆){4})z$"),
             case(vec!["zyxx", "yxx"], "^z?yx{2}$"),
             case(vec!["zyxx", "yxx", "yxxx"], "^(?:zyx{2}|yx{2,3})$"),
@@ -350,6 +518,121 @@ mod no_conversion {
             test_if_regexp_is_correct(regexp, expected_output, &test_cases);
             test_if_regexp_matches_test_cases(expected_output, test_cases);
         }
+
+        #[rstest(test_cases, expected_output,
+            case(vec!["...", "aaa"], "^(?:\\.\\.\\.|a{3})$"),
+            case(vec!["1...2", "1..2"], "^1\\.\\.\\.?2$"),
+            case(vec!["a.a.a.", "bbb"], "^(?:a\\.a\\.a\\.|b{3})$"),
+            case(vec!["..xx.."], "^\\.\\.x{2}\\.\\.$")
+        )]
+        fn succeeds_with_excluded_chars(test_cases: Vec<&str>, expected_output: &str) {
+            let regexp = RegExpBuilder::from(&test_cases)
+                .with_conversion_of(&[Feature::Repetition])
+                .with_repetition_exclusion_of(&['.'])
+                .build();
+            test_if_regexp_is_correct(regexp, expected_output, &test_cases);
+            test_if_regexp_matches_test_cases(expected_output, test_cases);
+        }
+
+        #[rstest(test_cases, expected_output,
+            case(vec!["aa"], "^a{2}$"),
+            case(vec!["aa", "aaa", "aaaa"], "^a{2,}$"),
+            case(vec!["b", "ba", "baaa", "baa"], "^b(?:a{1,})?$"),
+            case(vec!["xy̆y̆z", "xy̆y̆y̆z"], "^x(?:y̆){2,}z$")
+        )]
+        fn succeeds_with_open_ended_repetitions(test_cases: Vec<&str>, expected_output: &str) {
+            let regexp = RegExpBuilder::from(&test_cases)
+                .with_conversion_of(&[Feature::Repetition])
+                .with_open_ended_repetitions()
+                .build();
+            test_if_regexp_is_correct(regexp, expected_output, &test_cases);
+            test_if_regexp_matches_test_cases(expected_output, test_cases);
+        }
+
+        #[test]
+        fn succeeds_without_open_ended_repetitions() {
+            let test_cases = vec!["aa", "aaa", "aaaa"];
+            let expected_output = "^a{2,4}$";
+            let regexp = RegExpBuilder::from(&test_cases)
+                .with_conversion_of(&[Feature::Repetition])
+                .build();
+            test_if_regexp_is_correct(regexp, expected_output, &test_cases);
+            test_if_regexp_matches_test_cases(expected_output, test_cases);
+        }
+
+        #[rstest(test_cases, expected_output,
+            case(vec!["aaa", "aaaaa"], "^(?:a{3}|a{5})$"),
+            case(vec!["aaa", "aaaa", "aaaaa"], "^(?:a{3}|a{4}|a{5})$"),
+            case(vec!["abab", "ababab"], "^(?:(?:ab){2}|(?:ab){3})$"),
+            case(vec!["xaaab", "xaaaab"], "^x(?:a{3}|a{4})b$"),
+            case(vec!["b", "aa", "aaa"], "^(?:b|a{2}|a{3})$")
+        )]
+        fn succeeds_with_strict_repetitions(test_cases: Vec<&str>, expected_output: &str) {
+            let regexp = RegExpBuilder::from(&test_cases)
+                .with_conversion_of(&[Feature::Repetition])
+                .with_strict_repetitions()
+                .build();
+            test_if_regexp_is_correct(regexp, expected_output, &test_cases);
+            test_if_regexp_does_not_match_other_strings(
+                expected_output,
+                vec!["a", "aa", "aaaaaa", "ababababab"]
+                    .into_iter()
+                    .filter(|it| !test_cases.contains(it))
+                    .collect(),
+            );
+            test_if_regexp_matches_test_cases(expected_output, test_cases);
+        }
+
+        #[test]
+        fn succeeds_without_strict_repetitions() {
+            let test_cases = vec!["aaa", "aaaa", "aaaaa"];
+            let expected_output = "^a{3,5}$";
+            let regexp = RegExpBuilder::from(&test_cases)
+                .with_conversion_of(&[Feature::Repetition])
+                .build();
+            test_if_regexp_is_correct(regexp, expected_output, &test_cases);
+        }
+
+        #[test]
+        fn warns_about_ignored_settings_with_strict_repetitions() {
+            let test_cases = vec!["aaa", "aaaa"];
+            let (regexp, warnings) = RegExpBuilder::from(&test_cases)
+                .with_conversion_of(&[Feature::Repetition])
+                .with_strict_repetitions()
+                .with_open_ended_repetitions()
+                .build_with_warnings();
+            assert_eq!(regexp.to_string(), "^(?:a{3}|a{4})$");
+            assert_eq!(
+                warnings,
+                vec![GenerationWarning::SettingIgnored(
+                    "open-ended repetitions are disabled by strict repetitions".to_string()
+                )]
+            );
+        }
+
+        #[test]
+        fn succeeds_with_long_repetitive_input() {
+            let test_case = format!("{}x{}", "abc".repeat(2_000), "ab".repeat(1_000));
+            let test_cases = vec![test_case.as_str()];
+            let expected_output = "^(?:abc){2000}xa(?:ba){999}b$";
+            let regexp = RegExpBuilder::from(&test_cases)
+                .with_conversion_of(&[Feature::Repetition])
+                .build();
+            test_if_regexp_is_correct(regexp, expected_output, &test_cases);
+            test_if_regexp_matches_test_cases(expected_output, test_cases);
+        }
+
+        #[test]
+        #[ignore]
+        fn succeeds_with_very_long_repetitive_input() {
+            let test_case = "abc".repeat(1_000_000 / 3);
+            let test_cases = vec![test_case.as_str()];
+            let expected_output = "^(?:abc){333333}$";
+            let regexp = RegExpBuilder::from(&test_cases)
+                .with_conversion_of(&[Feature::Repetition])
+                .build();
+            test_if_regexp_is_correct(regexp, expected_output, &test_cases);
+        }
     }
 }
 
@@ -463,6 +746,23 @@ mod digit_conversion {
             test_if_regexp_is_correct(regexp, expected_output, &test_cases);
         }
 
+        #[rstest(test_cases, expected_output,
+            case(vec!["1234"], "^\\d{4}$"),
+            case(vec!["1234", "5678", "9012"], "^\\d{4}$"),
+            case(vec!["0000", "1234", "2020"], "^\\d{4}$"),
+            case(vec!["12345", "67890"], "^\\d{5}$"),
+            case(vec!["AB-1234", "CD-5678"], "^(?:AB|CD)\\-\\d{4}$"),
+            case(vec!["1234-56", "7890-12"], "^\\d{4}\\-\\d{2}$"),
+            case(vec!["0123456789"], "^\\d{10}$")
+        )]
+        fn succeeds_with_fixed_width_codes(test_cases: Vec<&str>, expected_output: &str) {
+            let regexp = RegExpBuilder::from(&test_cases)
+                .with_conversion_of(&[Feature::Repetition, Feature::Digit])
+                .build();
+            test_if_regexp_is_correct(regexp, expected_output, &test_cases);
+            test_if_regexp_matches_test_cases(expected_output, test_cases);
+        }
+
         #[rstest(test_cases, expected_output,
             case(vec!["1"], "^\\d$"),
             case(vec!["12"], "^\\d\\d$"),
@@ -676,6 +976,20 @@ mod word_conversion {
                 .build();
             test_if_regexp_is_correct(regexp, expected_output, &test_cases);
         }
+
+        #[rstest(test_cases, expected_output,
+            case(vec!["abc", "DEF"], "^\\w\\w\\w$"),
+            case(vec!["a-B", "C-d"], "^\\w\\-\\w$"),
+            case(vec!["Ab", "cD", "ä"], "^\\w(?:\\w)?$"),
+            case(vec!["a♥B"], "^\\w♥\\w$")
+        )]
+        fn succeeds_with_ignore_case_option(test_cases: Vec<&str>, expected_output: &str) {
+            let regexp = RegExpBuilder::from(&test_cases)
+                .with_conversion_of(&[Feature::Word, Feature::CaseInsensitivity])
+                .build();
+            test_if_regexp_is_correct(regexp, expected_output, &test_cases);
+            test_if_regexp_matches_test_cases(expected_output, test_cases);
+        }
     }
 
     mod repetition {
@@ -1663,21 +1977,4912 @@ mod word_non_word_conversion {
     }
 }
 
-fn test_if_regexp_is_correct(regexp: String, expected_output: &str, test_cases: &[&str]) {
-    assert_eq!(
-        regexp, expected_output,
-        "\n\ninput: {:?}\nexpected: {}\nactual: {}\n\n",
-        test_cases, expected_output, regexp
-    );
+mod negated_shorthand_merging {
+    use super::*;
+
+    #[rstest(test_cases, features, expected_output,
+        case(vec!["a", "-", " "], vec![Feature::NonDigit, Feature::Space], "^\\D$"),
+        case(vec!["x-", "x "], vec![Feature::NonWord, Feature::Space], "^x\\W$"),
+        case(vec!["1", "a", "%"], vec![Feature::Digit, Feature::Word, Feature::NonSpace], "^\\S$"),
+        case(vec!["a!", "b "], vec![Feature::NonDigit, Feature::Space], "^\\D\\D$"),
+        case(vec!["a", "1"], vec![Feature::NonDigit, Feature::Digit], "^(?:\\d|\\D)$"),
+        case(vec!["a", "-"], vec![Feature::Word, Feature::NonDigit], "^(?:\\D|\\w)$")
+    )]
+    fn succeeds(test_cases: Vec<&str>, features: Vec<Feature>, expected_output: &str) {
+        let regexp = RegExpBuilder::from(&test_cases)
+            .with_conversion_of(&features)
+            .build();
+        test_if_regexp_is_correct(regexp, expected_output, &test_cases);
+        test_if_regexp_matches_test_cases(expected_output, test_cases);
+    }
+
+    #[test]
+    fn succeeds_without_negated_shorthands() {
+        let test_cases = vec!["a", "1"];
+        let regexp = RegExpBuilder::from(&test_cases)
+            .with_conversion_of(&[Feature::Digit, Feature::Word])
+            .build();
+        test_if_regexp_is_correct(regexp, "^(?:\\d|\\w)$", &test_cases);
+    }
 }
 
-fn test_if_regexp_matches_test_cases(expected_output: &str, test_cases: Vec<&str>) {
-    let re = Regex::new(expected_output).unwrap();
-    for test_case in test_cases {
-        assert!(
-            re.is_match(test_case),
-            "\n\n\"{}\" does not match regex {}\n\n",
-            test_case,
+mod any_grapheme_conversion {
+    use super::*;
+
+    #[rstest(test_cases, expected_output,
+        case(vec!["xay", "xby", "xcy"], "^x\\Xy$"),
+        case(vec!["xay", "x👍🏽y", "xcy"], "^x\\Xy$"),
+        case(vec!["xay", "xby"], "^x[ab]y$"),
+        case(vec!["a", "b", "c", "de"], "^(?:de|\\X)$"),
+        case(vec!["xa", "xb", "xc", "x"], "^x(?:\\X)?$")
+    )]
+    fn succeeds_with_pcre_flavor(test_cases: Vec<&str>, expected_output: &str) {
+        let regexp = RegExpBuilder::from(&test_cases)
+            .with_flavor(Flavor::Pcre)
+            .with_any_grapheme_threshold(3)
+            .build();
+        test_if_regexp_is_correct(regexp, expected_output, &test_cases);
+    }
+
+    #[rstest(test_cases, expected_output,
+        case(vec!["xay", "xby", "xcy"], "^x[a-c]y$"),
+        case(vec!["xay", "x👍🏽y", "xcy"], "^x(?:[ac]|👍🏽)y$")
+    )]
+    fn succeeds_with_rust_flavor(test_cases: Vec<&str>, expected_output: &str) {
+        let regexp = RegExpBuilder::from(&test_cases)
+            .with_any_grapheme_threshold(3)
+            .build();
+        test_if_regexp_is_correct(regexp, expected_output, &test_cases);
+        test_if_regexp_matches_test_cases(expected_output, test_cases);
+    }
+}
+
+mod posix_flavors {
+    use super::*;
+
+    #[rstest(test_cases, expected_output,
+        case(vec!["a", "ab", "abc"], "^a\\(bc\\{0,1\\}\\)\\{0,1\\}$"),
+        case(vec!["abc", "xyz"], "^\\(abc\\|xyz\\)$"),
+        case(vec!["x+y(z)", "a|b"], "^\\(x+y(z)\\|a|b\\)$"),
+        case(vec!["{a}", "a.b", "^$*?"], "^\\(\\^\\$\\*?\\|a\\.b\\|{a}\\)$"),
+        case(vec!["]", "^", "-", "a"], "^[]a^-]$"),
+        case(vec!["^", "-"], "^[-^]$")
+    )]
+    fn succeeds_with_basic_flavor(test_cases: Vec<&str>, expected_output: &str) {
+        let regexp = RegExpBuilder::from(&test_cases)
+            .with_flavor(Flavor::PosixBasic)
+            .build();
+        test_if_regexp_is_correct(regexp, expected_output, &test_cases);
+    }
+
+    #[rstest(test_cases, expected_output,
+        case(vec!["1234", "5678"], "^[[:digit:]]\\{4\\}$"),
+        case(vec!["abab", "cdcd"], "^[[:alnum:]_]\\{4\\}$"),
+        case(vec!["a1", "b22"], "^[[:alnum:]_][[:digit:]]\\{1,2\\}$")
+    )]
+    fn succeeds_with_basic_flavor_and_conversion(test_cases: Vec<&str>, expected_output: &str) {
+        let regexp = RegExpBuilder::from(&test_cases)
+            .with_conversion_of(&[Feature::Repetition, Feature::Digit, Feature::Word])
+            .with_flavor(Flavor::PosixBasic)
+            .build();
+        test_if_regexp_is_correct(regexp, expected_output, &test_cases);
+    }
+
+    #[rstest(test_cases, expected_output,
+        case(vec!["a", "ab", "abc"], "^a(bc?)?$"),
+        case(vec!["x+y(z)", "a|b"], "^(x\\+y\\(z\\)|a\\|b)$"),
+        case(vec!["{a}", "a.b", "^$*?"], "^(\\^\\$\\*\\?|a\\.b|\\{a})$"),
+        case(vec!["]", "^", "-", "a"], "^[]a^-]$")
+    )]
+    fn succeeds_with_extended_flavor(test_cases: Vec<&str>, expected_output: &str) {
+        let regexp = RegExpBuilder::from(&test_cases)
+            .with_flavor(Flavor::PosixExtended)
+            .build();
+        test_if_regexp_is_correct(regexp, expected_output, &test_cases);
+    }
+
+    #[rstest(test_cases, expected_output,
+        case(vec!["1234", "5678"], "^[[:digit:]]{4}$"),
+        case(vec!["a 1", "b\t2"], "^[[:alnum:]_][[:space:]][[:digit:]]$"),
+        case(vec!["a-1", "b-2"], "^[[:alnum:]_][^[:digit:]][[:digit:]]$")
+    )]
+    fn succeeds_with_extended_flavor_and_conversion(test_cases: Vec<&str>, expected_output: &str) {
+        let regexp = RegExpBuilder::from(&test_cases)
+            .with_conversion_of(&[
+                Feature::Repetition,
+                Feature::Digit,
+                Feature::NonDigit,
+                Feature::Space,
+                Feature::Word,
+            ])
+            .with_flavor(Flavor::PosixExtended)
+            .build();
+        test_if_regexp_is_correct(regexp, expected_output, &test_cases);
+    }
+
+    #[rstest(test_cases, features, expected_output,
+        case(vec!["a٣"], vec![Feature::Digit], "^a٣$"),
+        case(vec!["a٣", "é1"], vec![Feature::Word], "^([[:alnum:]_]٣|é[[:alnum:]_])$"),
+        case(vec!["a\u{3000}b"], vec![Feature::Space], "^a\u{3000}b$"),
+        case(vec!["é-"], vec![Feature::NonWord], "^é[^[:alnum:]_]$")
+    )]
+    fn succeeds_with_conversion_of_ascii_chars_only(
+        test_cases: Vec<&str>,
+        features: Vec<Feature>,
+        expected_output: &str,
+    ) {
+        let regexp = RegExpBuilder::from(&test_cases)
+            .with_conversion_of(&features)
+            .with_flavor(Flavor::PosixExtended)
+            .build();
+        test_if_regexp_is_correct(regexp, expected_output, &test_cases);
+    }
+
+    #[test]
+    fn succeeds_with_catch_all_in_extended_flavor() {
+        let test_cases = vec!["2", "ab", "a\u{301}c", "+- !?!"];
+        let regexp = RegExpBuilder::from(&test_cases)
+            .with_maximum_branches(2)
+            .with_flavor(Flavor::PosixExtended)
+            .build();
+        test_if_regexp_is_correct(regexp, "^(2|.{2,6})$", &test_cases);
+    }
+
+    #[test]
+    fn warns_about_unsupported_settings() {
+        let test_cases = vec!["abc", "ABC"];
+        let (regexp, warnings) = RegExpBuilder::from(&test_cases)
+            .with_conversion_of(&[Feature::CaseInsensitivity])
+            .with_verbose_mode()
+            .with_flavor(Flavor::PosixExtended)
+            .build_with_warnings();
+        assert_eq!(regexp.to_string(), "^(ABC|abc)$");
+        assert_eq!(
+            warnings,
+            vec![
+                GenerationWarning::SettingIgnored(
+                    "case-insensitive matching is not supported by the chosen flavor".to_string()
+                ),
+                GenerationWarning::SettingIgnored(
+                    "verbose mode is not supported by the chosen flavor".to_string()
+                ),
+            ]
+        );
+    }
+}
+
+mod continuation_anchor {
+    use super::*;
+
+    #[rstest(test_cases, expected_output,
+        case(vec!["abc"], "\\Gabc$"),
+        case(vec!["abc", "def"], "\\G(?:abc|def)$"),
+        case(vec!["a", "aa"], "\\Gaa?$")
+    )]
+    fn succeeds_with_pcre_flavor(test_cases: Vec<&str>, expected_output: &str) {
+        let regexp = RegExpBuilder::from(&test_cases)
+            .with_flavor(Flavor::Pcre)
+            .with_continuation_anchor()
+            .build();
+        test_if_regexp_is_correct(regexp, expected_output, &test_cases);
+    }
+
+    #[rstest(test_cases, expected_output,
+        case(vec!["abc"], "^abc$"),
+        case(vec!["abc", "def"], "^(?:abc|def)$")
+    )]
+    fn succeeds_with_rust_flavor(test_cases: Vec<&str>, expected_output: &str) {
+        let (regexp, warnings) = RegExpBuilder::from(&test_cases)
+            .with_continuation_anchor()
+            .build_with_warnings();
+        test_if_regexp_is_correct(regexp.to_string(), expected_output, &test_cases);
+        test_if_regexp_matches_test_cases(expected_output, test_cases);
+        assert_eq!(
+            warnings,
+            vec![GenerationWarning::SettingIgnored(
+                "anchor \\G is not supported by the chosen flavor".to_string()
+            )]
+        );
+    }
+
+    #[test]
+    fn succeeds_with_case_insensitivity() {
+        let regexp = RegExpBuilder::from(&["abc", "ABC"])
+            .with_conversion_of(&[Feature::CaseInsensitivity])
+            .with_flavor(Flavor::Pcre)
+            .with_continuation_anchor()
+            .build();
+        assert_eq!(regexp, "(?i)\\Gabc$");
+    }
+}
+
+mod anchors_and_word_boundaries {
+    use super::*;
+
+    #[rstest(test_cases, expected_output,
+        case(vec!["abc"], "abc$"),
+        case(vec!["abc", "xyz"], "(?:abc|xyz)$")
+    )]
+    fn succeeds_without_start_anchor(test_cases: Vec<&str>, expected_output: &str) {
+        let regexp = RegExpBuilder::from(&test_cases)
+            .without_start_anchor()
+            .build();
+        test_if_regexp_is_correct(regexp, expected_output, &test_cases);
+        test_if_regexp_matches_test_cases(expected_output, test_cases);
+    }
+
+    #[rstest(test_cases, expected_output,
+        case(vec!["abc"], "^abc"),
+        case(vec!["abc", "xyz"], "^(?:abc|xyz)")
+    )]
+    fn succeeds_without_end_anchor(test_cases: Vec<&str>, expected_output: &str) {
+        let regexp = RegExpBuilder::from(&test_cases)
+            .without_end_anchor()
+            .build();
+        test_if_regexp_is_correct(regexp, expected_output, &test_cases);
+        test_if_regexp_matches_test_cases(expected_output, test_cases);
+    }
+
+    #[rstest(test_cases, expected_output,
+        case(vec!["abc"], "abc"),
+        case(vec!["abc", "xyz"], "(?:abc|xyz)")
+    )]
+    fn succeeds_without_anchors(test_cases: Vec<&str>, expected_output: &str) {
+        let regexp = RegExpBuilder::from(&test_cases).without_anchors().build();
+        test_if_regexp_is_correct(regexp, expected_output, &test_cases);
+        test_if_regexp_matches_test_cases(expected_output, test_cases);
+    }
+
+    #[test]
+    fn succeeds_with_word_boundary_at_start_only() {
+        let test_cases = vec!["foo", "bar"];
+        let regexp = RegExpBuilder::from(&test_cases)
+            .with_word_boundary_at_start()
+            .without_end_anchor()
+            .build();
+        let expected_output = "\\b(?:bar|foo)";
+        test_if_regexp_is_correct(regexp, expected_output, &test_cases);
+        test_if_regexp_matches_test_cases(
+            expected_output,
+            vec!["foo", "foobar", "call foo_bar()", "bar2", "x = barometer"],
+        );
+        test_if_regexp_does_not_match_other_strings(
+            expected_output,
+            vec!["xfoo", "sidebar", "_foo", "fo", "2bar"],
+        );
+    }
+
+    #[test]
+    fn succeeds_with_word_boundary_at_start_and_end_anchor() {
+        let test_cases = vec!["foo"];
+        let regexp = RegExpBuilder::from(&test_cases)
+            .with_word_boundary_at_start()
+            .build();
+        let expected_output = "\\bfoo$";
+        test_if_regexp_is_correct(regexp, expected_output, &test_cases);
+        test_if_regexp_matches_test_cases(expected_output, vec!["foo", "a foo", "a-foo"]);
+        test_if_regexp_does_not_match_other_strings(expected_output, vec!["afoo", "foo bar"]);
+    }
+
+    #[test]
+    fn succeeds_with_word_boundaries_on_both_sides() {
+        let test_cases = vec!["foo"];
+        let regexp = RegExpBuilder::from(&test_cases)
+            .with_word_boundary_at_start()
+            .with_word_boundary_at_end()
+            .build();
+        let expected_output = "\\bfoo\\b";
+        test_if_regexp_is_correct(regexp, expected_output, &test_cases);
+        test_if_regexp_matches_test_cases(expected_output, vec!["foo", "a foo.", "(foo)"]);
+        test_if_regexp_does_not_match_other_strings(expected_output, vec!["foobar", "afoo"]);
+    }
+
+    #[test]
+    fn succeeds_with_word_boundary_taking_the_place_of_anchors() {
+        let test_cases = vec!["foo"];
+        let regexp = RegExpBuilder::from(&test_cases)
+            .without_start_anchor()
+            .with_word_boundary_at_start()
+            .with_word_boundary_at_end()
+            .without_end_anchor()
+            .build();
+        test_if_regexp_is_correct(regexp, "\\bfoo\\b", &test_cases);
+    }
+
+    #[test]
+    fn succeeds_with_word_boundary_replacing_continuation_anchor() {
+        let test_cases = vec!["foo"];
+        let (regexp, warnings) = RegExpBuilder::from(&test_cases)
+            .with_flavor(Flavor::Pcre)
+            .with_continuation_anchor()
+            .with_word_boundary_at_start()
+            .build_with_warnings();
+        test_if_regexp_is_correct(regexp.to_string(), "\\bfoo$", &test_cases);
+        assert_eq!(
+            warnings,
+            vec![GenerationWarning::SettingIgnored(
+                "anchor \\G is replaced by the chosen start of the regular expression".to_string()
+            )]
+        );
+    }
+
+    #[rstest(test_cases, expected_output,
+        case(vec!["foo-bar"], "^foo\\b\\-\\bbar$"),
+        case(vec!["foo-bar", "foo-baz"], "^foo\\b\\-\\bba[rz]$"),
+        case(vec!["hello world"], "^hello\\b \\bworld$"),
+        case(vec!["é-é"], "^é\\b\\-\\bé$"),
+        case(vec!["foo_bar1"], "^foo_bar1$"),
+        case(vec!["--"], "^\\-\\-$"),
+        case(vec!["\u{301}a"], "^\u{301}a$"),
+        case(vec!["-\u{301}a"], "^\\-\\b\u{301}a$"),
+        case(vec!["a\u{200d}-"], "^a\u{200d}\\b\\-$")
+    )]
+    fn succeeds_with_internal_word_boundaries(test_cases: Vec<&str>, expected_output: &str) {
+        let regexp = RegExpBuilder::from(&test_cases)
+            .with_internal_word_boundaries()
+            .build();
+        test_if_regexp_is_correct(regexp, expected_output, &test_cases);
+        test_if_regexp_matches_test_cases(expected_output, test_cases);
+    }
+
+    #[test]
+    fn succeeds_with_internal_word_boundaries_and_conversion_features() {
+        let test_cases = vec!["ab--cd", "hello world"];
+        let regexp = RegExpBuilder::from(&test_cases)
+            .with_conversion_of(&[Feature::Repetition, Feature::Space])
+            .with_internal_word_boundaries()
+            .build();
+        let expected_output = "^(?:hel{2}o\\b\\s\\bworl|ab\\b\\-{2}\\bc)d$";
+        test_if_regexp_is_correct(regexp, expected_output, &test_cases);
+        test_if_regexp_matches_test_cases(expected_output, test_cases);
+    }
+
+    #[rstest(test_cases, features, expected_output,
+        case(vec!["αβ9"], vec![Feature::Script], "^\\p{Greek}\\p{Greek}9$"),
+        case(vec!["9αβ"], vec![Feature::Script], "^9\\p{Greek}\\p{Greek}$"),
+        case(vec!["e\u{301}-"], vec![Feature::Word], "^\\w\\w\\b\\-$"),
+        case(vec!["a-1"], vec![Feature::Word], "^\\w\\b\\-\\b\\w$")
+    )]
+    fn succeeds_with_internal_word_boundaries_next_to_classes(
+        test_cases: Vec<&str>,
+        features: Vec<Feature>,
+        expected_output: &str,
+    ) {
+        let regexp = RegExpBuilder::from(&test_cases)
+            .with_conversion_of(&features)
+            .with_internal_word_boundaries()
+            .build();
+        test_if_regexp_is_correct(regexp, expected_output, &test_cases);
+        test_if_regexp_matches_test_cases(expected_output, test_cases);
+    }
+
+    #[test]
+    fn succeeds_with_internal_and_outer_word_boundaries() {
+        let test_cases = vec!["foo-bar"];
+        let regexp = RegExpBuilder::from(&test_cases)
+            .with_word_boundary_at_start()
+            .with_word_boundary_at_end()
+            .with_internal_word_boundaries()
+            .build();
+        let expected_output = "\\bfoo\\b\\-\\bbar\\b";
+        test_if_regexp_is_correct(regexp, expected_output, &test_cases);
+        test_if_regexp_matches_test_cases(expected_output, vec!["foo-bar", "(foo-bar)"]);
+        test_if_regexp_does_not_match_other_strings(expected_output, vec!["foo-barx", "xfoo-bar"]);
+    }
+}
+
+mod generation_timings {
+    use super::*;
+    use std::time::Duration;
+
+    #[test]
+    fn succeeds_with_all_stages_timed() {
+        let test_cases = (0..100)
+            .map(|it| format!("{}abab{}", it, it * 7))
+            .collect::<Vec<_>>();
+        let features = [Feature::Digit, Feature::Repetition];
+        let (regexp, timings) = RegExpBuilder::from(&test_cases)
+            .with_conversion_of(&features)
+            .build_with_timings();
+
+        assert_eq!(
+            regexp.to_string(),
+            RegExpBuilder::from(&test_cases)
+                .with_conversion_of(&features)
+                .build()
+        );
+        assert!(timings.clustering() > Duration::from_secs(0));
+        assert!(timings.char_class_conversion() > Duration::from_secs(0));
+        assert!(timings.repetition_conversion() > Duration::from_secs(0));
+        assert!(timings.dfa_construction() > Duration::from_secs(0));
+        assert!(timings.ast_rendering() > Duration::from_secs(0));
+        assert_eq!(
+            timings.total(),
+            timings.clustering()
+                + timings.char_class_conversion()
+                + timings.repetition_conversion()
+                + timings.dfa_construction()
+                + timings.ast_rendering()
+        );
+    }
+
+    #[test]
+    fn succeeds_with_skipped_stages_not_timed() {
+        let (_, timings) = RegExpBuilder::from(&["abc", "abd"]).build_with_timings();
+        assert!(timings.clustering() > Duration::from_secs(0));
+        assert_eq!(timings.char_class_conversion(), Duration::from_secs(0));
+        assert_eq!(timings.repetition_conversion(), Duration::from_secs(0));
+        assert!(timings.dfa_construction() > Duration::from_secs(0));
+    }
+}
+
+mod memory_budget {
+    use super::*;
+
+    #[test]
+    fn succeeds_within_budget() {
+        let test_cases = vec!["abc", "abd", "xyz"];
+        let regexp = RegExpBuilder::from(&test_cases)
+            .with_memory_budget(1_000_000)
+            .try_build_regexp()
+            .unwrap();
+        let expected_output = "^(?:ab[cd]|xyz)$";
+        test_if_regexp_is_correct(regexp.to_string(), expected_output, &test_cases);
+        test_if_regexp_matches_test_cases(expected_output, test_cases);
+    }
+
+    #[test]
+    fn fails_with_many_test_cases() {
+        let test_cases = (0..2000)
+            .map(|it| format!("{:x}", (it as u64 + 1) * 2_654_435_761))
+            .collect::<Vec<_>>();
+        let result = RegExpBuilder::from(&test_cases)
+            .with_memory_budget(100_000)
+            .try_build_regexp();
+        assert_eq!(
+            result.err(),
+            Some(GenerationError::MemoryBudgetExceeded(100_000))
+        );
+    }
+
+    #[test]
+    fn fails_with_long_test_case() {
+        let test_case = (0..100)
+            .map(|it| format!("{}", it % 10))
+            .collect::<String>();
+        let result = RegExpBuilder::from(&[test_case])
+            .with_memory_budget(1_000_000)
+            .try_build_regexp();
+        assert_eq!(
+            result.err(),
+            Some(GenerationError::MemoryBudgetExceeded(1_000_000))
+        );
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "Regular expression generation failed: the memory budget of 1000 bytes has been exceeded"
+    )]
+    fn panics_when_building_beyond_budget() {
+        RegExpBuilder::from(&["abc", "def"])
+            .with_memory_budget(1000)
+            .build();
+    }
+}
+
+mod replacement_chars {
+    use super::*;
+
+    #[rstest(test_cases, expected_output,
+        case(vec!["a\u{fffd}b"], "^a\u{fffd}b$"),
+        case(vec!["\u{fffd}", "\u{fffd}\u{fffd}\u{fffd}"], "^(?:\u{fffd}|\u{fffd}{3})$"),
+        case(vec!["\u{fffd}\u{301}"], "^\u{fffd}\u{301}$")
+    )]
+    fn succeeds_with_literal_replacement_chars(test_cases: Vec<&str>, expected_output: &str) {
+        let regexp = RegExpBuilder::from(&test_cases)
+            .with_conversion_of(&[Feature::Repetition])
+            .build();
+        test_if_regexp_is_correct(regexp, expected_output, &test_cases);
+        test_if_regexp_matches_test_cases(expected_output, test_cases);
+    }
+
+    #[test]
+    fn succeeds_with_escaping_of_replacement_chars() {
+        let test_cases = vec!["a\u{fffd}"];
+        let regexp = RegExpBuilder::from(&test_cases)
+            .with_escaping_of_non_ascii_chars(false)
+            .build();
+        test_if_regexp_is_correct(regexp, "^a\\u{fffd}$", &test_cases);
+    }
+
+    #[test]
+    fn fails_with_rejection_of_replacement_chars() {
+        let result = RegExpBuilder::from(&["abc", "a\u{fffd}c", "\u{fffd}"])
+            .with_rejection_of_replacement_chars()
+            .try_build_regexp();
+        assert_eq!(
+            result.err(),
+            Some(GenerationError::ReplacementCharacterFound(
+                "a\u{fffd}c".to_string()
+            ))
+        );
+    }
+
+    #[test]
+    fn succeeds_with_rejection_of_replacement_chars() {
+        let result = RegExpBuilder::from(&["abc", "abd"])
+            .with_rejection_of_replacement_chars()
+            .try_build_regexp();
+        assert_eq!(result.unwrap().to_string(), "^ab[cd]$");
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "Regular expression generation failed: the test case \"\u{fffd}\" contains the replacement character U+FFFD"
+    )]
+    fn panics_when_building_with_replacement_chars() {
+        RegExpBuilder::from_categorized(&[("a", "x"), ("b", "\u{fffd}")])
+            .with_rejection_of_replacement_chars()
+            .build();
+    }
+}
+
+mod regexp_parsing {
+    use super::*;
+
+    #[rstest(test_cases,
+        case(vec!["abc", "abd", "x"]),
+        case(vec!["a", "b", "c", "d", "f"]),
+        case(vec!["", "ab", "abab"]),
+        case(vec!["a.b", "a$b", "(x)", "[y]", "{z}", "a|b", "a\\b", "-^"]),
+        case(vec!["I   ♥♥♥ 36 and ٣ and y̆y̆ and 💩💩."]),
+        case(vec!["tab\tnewline\n", "nul\0"])
+    )]
+    fn succeeds_with_round_trip(test_cases: Vec<&str>) {
+        let regexp = RegExpBuilder::from(&test_cases).build();
+        let parsed_regexp = RegExpBuilder::from_regexp(&regexp).unwrap().build();
+        test_if_regexp_is_correct(parsed_regexp, &regexp, &test_cases);
+    }
+
+    #[rstest(test_cases,
+        case(vec!["💩", "a💩"]),
+        case(vec!["♥♥", "x♥"])
+    )]
+    fn succeeds_with_round_trip_of_escaped_non_ascii_chars(test_cases: Vec<&str>) {
+        for use_surrogate_pairs in vec![false, true] {
+            let regexp = RegExpBuilder::from(&test_cases)
+                .with_escaping_of_non_ascii_chars(use_surrogate_pairs)
+                .build();
+            let parsed_regexp = RegExpBuilder::from_regexp(&regexp)
+                .unwrap()
+                .with_escaping_of_non_ascii_chars(use_surrogate_pairs)
+                .build();
+            test_if_regexp_is_correct(parsed_regexp, &regexp, &test_cases);
+        }
+    }
+
+    #[rstest(
+        pattern,
+        expected_output,
+        case("foo|foobar|fob", "^fo(?:o(?:bar)?|b)$"),
+        case("^(?:abc|abd|abe)$", "^ab[c-e]$"),
+        case("(?:a|b|c)(?:x|y)?", "^[a-c][xy]?$"),
+        case("a{2,3}|b{2}", "^(?:aaa?|bb)$"),
+        case("\\bgr(a|e)y\\b", "^gr[ae]y$"),
+        case("[]a-c]|d", "^[\\]a-d]$")
+    )]
+    fn succeeds_with_minimization(pattern: &str, expected_output: &str) {
+        let regexp = RegExpBuilder::from_regexp(pattern).unwrap().build();
+        test_if_regexp_is_correct(regexp, expected_output, &[pattern]);
+    }
+
+    #[test]
+    fn succeeds_with_repetition_conversion() {
+        let regexp = RegExpBuilder::from_regexp("(?:ab){2}|abab")
+            .unwrap()
+            .with_conversion_of(&[Feature::Repetition])
+            .build();
+        assert_eq!(regexp, "^(?:ab){2}$");
+    }
+
+    #[rstest(pattern, expected_error,
+        case("(ab", ParseError::InvalidSyntax(3)),
+        case("ab)", ParseError::InvalidSyntax(2)),
+        case("[a-c", ParseError::InvalidSyntax(4)),
+        case("[c-a]", ParseError::InvalidSyntax(1)),
+        case("*a", ParseError::InvalidSyntax(0)),
+        case("a{3,2}", ParseError::InvalidSyntax(1)),
+        case("a\\d", ParseError::UnsupportedSyntax("\\d".to_string())),
+        case("[^a]", ParseError::UnsupportedSyntax("[^".to_string())),
+        case("(?i)abc", ParseError::UnsupportedSyntax("(?i".to_string())),
+        case("(?=a)b", ParseError::UnsupportedSyntax("(?=".to_string())),
+        case("a.b", ParseError::UnsupportedSyntax(".".to_string())),
+        case("a\\1", ParseError::UnsupportedSyntax("\\1".to_string())),
+        case("a??", ParseError::UnsupportedSyntax("??".to_string())),
+        case("ab*", ParseError::UnboundedLanguage),
+        case("a+", ParseError::UnboundedLanguage),
+        case("a{2,}", ParseError::UnboundedLanguage),
+        case("[a-z]{5}", ParseError::LanguageTooLarge)
+    )]
+    fn fails_with_invalid_or_unsupported_pattern(pattern: &str, expected_error: ParseError) {
+        match RegExpBuilder::from_regexp(pattern) {
+            Ok(_) => panic!("pattern {} has been parsed unexpectedly", pattern),
+            Err(error) => assert_eq!(error, expected_error),
+        }
+    }
+}
+
+mod nul_char_escaping {
+    use super::*;
+
+    #[rstest(test_cases, expected_output,
+        case(vec!["a\0b"], "^a\\x00b$"),
+        case(vec!["\0"], "^\\x00$"),
+        case(vec!["\01"], "^\\x001$"),
+        case(vec!["a\0", "b\0"], "^[ab]\\x00$"),
+        case(vec!["\0", "a"], "^[\\x00a]$"),
+        case(vec!["a\0\0b"], "^a\\x00\\x00b$")
+    )]
+    fn succeeds_with_rust_flavor(test_cases: Vec<&str>, expected_output: &str) {
+        let regexp = RegExpBuilder::from(&test_cases).build();
+        test_if_regexp_is_correct(regexp, expected_output, &test_cases);
+        test_if_regexp_matches_test_cases(expected_output, test_cases);
+    }
+
+    #[rstest(test_cases, expected_output,
+        case(vec!["a\0\0\0a"], "^a\\x00{3}a$"),
+        case(vec!["\0\0", "\0\0\0"], "^\\x00{2,3}$")
+    )]
+    fn succeeds_with_rust_flavor_and_repetition_feature(
+        test_cases: Vec<&str>,
+        expected_output: &str,
+    ) {
+        let regexp = RegExpBuilder::from(&test_cases)
+            .with_conversion_of(&[Feature::Repetition])
+            .build();
+        test_if_regexp_is_correct(regexp, expected_output, &test_cases);
+        test_if_regexp_matches_test_cases(expected_output, test_cases);
+    }
+
+    #[rstest(test_cases, expected_output,
+        case(vec!["a\0b"], "^a\\0b$"),
+        case(vec!["\0"], "^\\x00$"),
+        case(vec!["\01"], "^\\x001$"),
+        case(vec!["\08"], "^\\08$"),
+        case(vec!["\0", "a"], "^[\\0a]$"),
+        case(vec!["\0a", "\0b"], "^\\x00[ab]$")
+    )]
+    fn succeeds_with_pcre_flavor(test_cases: Vec<&str>, expected_output: &str) {
+        let regexp = RegExpBuilder::from(&test_cases)
+            .with_flavor(Flavor::Pcre)
+            .build();
+        test_if_regexp_is_correct(regexp, expected_output, &test_cases);
+    }
+}
+
+mod atomic_grouping {
+    use super::*;
+
+    #[rstest(test_cases, expected_output,
+        case(vec!["b", "baa", "baaa"], "^b(?>(?:a{2,3})?)$"),
+        case(vec!["b", "bab", "babab", "bababab"], "^b(?>(?:ab|(?:ab){2,3})?)$"),
+        case(vec!["c", "cabab", "cababab"], "^c(?>(?:(?:ab){2,3})?)$")
+    )]
+    fn succeeds_with_risky_quantifiers(test_cases: Vec<&str>, expected_output: &str) {
+        let regexp = RegExpBuilder::from(&test_cases)
+            .with_conversion_of(&[Feature::Repetition])
+            .with_atomic_grouping()
+            .with_flavor(Flavor::Pcre)
+            .build();
+        test_if_regexp_is_correct(regexp, expected_output, &test_cases);
+    }
+
+    #[rstest(test_cases, expected_output,
+        case(vec!["ab", "abc"], "^abc?$"),
+        case(vec!["aab", "aaab"], "^a{2,3}b$"),
+        case(vec!["x", "xaab", "xaaabaaab"], "^x(?:a{2}b|(?:a{3}b){2})?$")
+    )]
+    fn succeeds_without_risky_quantifiers(test_cases: Vec<&str>, expected_output: &str) {
+        let regexp = RegExpBuilder::from(&test_cases)
+            .with_conversion_of(&[Feature::Repetition])
+            .with_atomic_grouping()
+            .with_flavor(Flavor::Pcre)
+            .build();
+        test_if_regexp_is_correct(regexp, expected_output, &test_cases);
+    }
+
+    #[test]
+    fn succeeds_with_open_ended_repetitions() {
+        let test_cases = vec!["b", "baa", "baaa"];
+        let regexp = RegExpBuilder::from(&test_cases)
+            .with_conversion_of(&[Feature::Repetition])
+            .with_open_ended_repetitions()
+            .with_atomic_grouping()
+            .with_flavor(Flavor::Pcre)
+            .build();
+        test_if_regexp_is_correct(regexp, "^b(?>(?:a{2,})?)$", &test_cases);
+    }
+
+    #[test]
+    fn warns_about_unsupported_flavor() {
+        let test_cases = vec!["b", "baa", "baaa"];
+        let expected_output = "^b(?:a{2,3})?$";
+        let (regexp, warnings) = RegExpBuilder::from(&test_cases)
+            .with_conversion_of(&[Feature::Repetition])
+            .with_atomic_grouping()
+            .build_with_warnings();
+        test_if_regexp_is_correct(regexp.to_string(), expected_output, &test_cases);
+        test_if_regexp_matches_test_cases(expected_output, test_cases);
+        assert_eq!(
+            warnings,
+            vec![GenerationWarning::SettingIgnored(
+                "atomic groups are not supported by the chosen flavor".to_string()
+            )]
+        );
+    }
+}
+
+mod structured_ipv4 {
+    use super::*;
+
+    #[rstest(test_cases, expected_output,
+        case(vec!["192.168.0.1"], "^\\d{3}\\.\\d{3}\\.\\d\\.\\d$"),
+        case(vec!["192.168.0.1", "10.0.0.255"], "^\\d{2,3}\\.\\d{1,3}\\.\\d\\.\\d{1,3}$"),
+        case(vec!["1.2.3.4", "255.255.255.255", "0.0.0.0"], "^\\d{1,3}\\.\\d{1,3}\\.\\d{1,3}\\.\\d{1,3}$")
+    )]
+    fn succeeds(test_cases: Vec<&str>, expected_output: &str) {
+        let regexp = RegExpBuilder::from(&test_cases)
+            .with_structured_ipv4()
+            .build();
+        test_if_regexp_is_correct(regexp, expected_output, &test_cases);
+        test_if_regexp_matches_test_cases(expected_output, test_cases);
+    }
+
+    #[rstest(test_cases, expected_output,
+        case(vec!["192.168.0.1", "abc"], "^(?:192\\.168\\.0\\.1|abc)$"),
+        case(vec!["1.2.3.256"], "^1\\.2\\.3\\.256$"),
+        case(vec!["1.2.3"], "^1\\.2\\.3$"),
+        case(vec!["1.2.3.4.5"], "^1\\.2\\.3\\.4\\.5$"),
+        case(vec!["1.2..4"], "^1\\.2\\.\\.4$")
+    )]
+    fn succeeds_with_fallback(test_cases: Vec<&str>, expected_output: &str) {
+        let regexp = RegExpBuilder::from(&test_cases)
+            .with_structured_ipv4()
+            .build();
+        test_if_regexp_is_correct(regexp, expected_output, &test_cases);
+        test_if_regexp_matches_test_cases(expected_output, test_cases);
+    }
+
+    #[test]
+    fn succeeds_with_repetition_feature() {
+        let test_cases = vec!["10.10.10.10", "10.10.10.100"];
+        let expected_output = "^\\d{2}\\.\\d{2}\\.\\d{2}\\.\\d{2,3}$";
+        let regexp = RegExpBuilder::from(&test_cases)
+            .with_conversion_of(&[Feature::Repetition])
+            .with_structured_ipv4()
+            .build();
+        test_if_regexp_is_correct(regexp, expected_output, &test_cases);
+        test_if_regexp_matches_test_cases(expected_output, test_cases);
+    }
+}
+
+mod structured_date_time {
+    use super::*;
+
+    #[rstest(test_cases, expected_output,
+        case(vec!["2020-01-31", "1999-12-01"], "^\\d{4}\\-\\d{2}\\-\\d{2}$"),
+        case(vec!["23:59:00", "00:00:01"], "^\\d{2}:\\d{2}:\\d{2}$"),
+        case(
+            vec!["2020-01-31T23:59:00", "2021-06-15T08:30:45"],
+            "^\\d{4}\\-\\d{2}\\-\\d{2}T\\d{2}:\\d{2}:\\d{2}$"
+        )
+    )]
+    fn succeeds(test_cases: Vec<&str>, expected_output: &str) {
+        let regexp = RegExpBuilder::from(&test_cases)
+            .with_structured_date_time()
+            .build();
+        test_if_regexp_is_correct(regexp, expected_output, &test_cases);
+        test_if_regexp_matches_test_cases(expected_output, test_cases);
+    }
+
+    #[rstest(test_cases, expected_output,
+        case(vec!["2020-01-31", "abc"], "^(?:2020\\-01\\-31|abc)$"),
+        case(vec!["2020-01-31", "12:00:00"], "^(?:2020\\-01\\-31|12:00:00)$"),
+        case(vec!["2020-13-01"], "^2020\\-13\\-01$"),
+        case(vec!["24:00:00"], "^24:00:00$"),
+        case(vec!["2020-1-31"], "^2020\\-1\\-31$"),
+        case(vec!["2020/01/31"], "^2020/01/31$")
+    )]
+    fn succeeds_with_fallback(test_cases: Vec<&str>, expected_output: &str) {
+        let regexp = RegExpBuilder::from(&test_cases)
+            .with_structured_date_time()
+            .build();
+        test_if_regexp_is_correct(regexp, expected_output, &test_cases);
+        test_if_regexp_matches_test_cases(expected_output, test_cases);
+    }
+
+    #[test]
+    fn succeeds_with_warning() {
+        let (regexp, warnings) = RegExpBuilder::from(&["12:00:00"])
+            .with_structured_date_time()
+            .build_with_warnings();
+        assert_eq!(regexp.to_string(), "^\\d{2}:\\d{2}:\\d{2}$");
+        assert_eq!(
+            warnings,
+            vec![GenerationWarning::CharacterClassGeneralized(
+                "\\d".to_string()
+            )]
+        );
+    }
+}
+
+mod hex_detection {
+    use super::*;
+
+    #[rstest(test_cases, expected_output,
+        case(vec!["deadbeef", "0badf00d"], "^[0-9a-f]{8}$"),
+        case(vec!["DEADBEEF", "0BADF00D"], "^[0-9A-F]{8}$"),
+        case(vec!["DeadBeef", "0badf00d"], "^[0-9A-Fa-f]{8}$"),
+        case(vec!["ff", "1a2b", "c"], "^[0-9a-f]{1,4}$")
+    )]
+    fn succeeds(test_cases: Vec<&str>, expected_output: &str) {
+        let regexp = RegExpBuilder::from(&test_cases)
+            .with_hex_detection()
+            .build();
+        test_if_regexp_is_correct(regexp, expected_output, &test_cases);
+        test_if_regexp_matches_test_cases(expected_output, test_cases);
+    }
+
+    #[rstest(test_cases, expected_output,
+        case(vec!["123", "456"], "^(?:123|456)$"),
+        case(vec!["deadbeef", "xyz"], "^(?:deadbeef|xyz)$"),
+        case(vec!["ab", ""], "^(?:ab)?$")
+    )]
+    fn succeeds_with_fallback(test_cases: Vec<&str>, expected_output: &str) {
+        let regexp = RegExpBuilder::from(&test_cases)
+            .with_hex_detection()
+            .build();
+        test_if_regexp_is_correct(regexp, expected_output, &test_cases);
+        test_if_regexp_matches_test_cases(expected_output, test_cases);
+    }
+
+    #[test]
+    fn succeeds_with_case_insensitive_matching() {
+        let test_cases = vec!["DeadBeef", "0BADF00D"];
+        let expected_output = "(?i)^[0-9a-f]{8}$";
+        let regexp = RegExpBuilder::from(&test_cases)
+            .with_conversion_of(&[Feature::CaseInsensitivity])
+            .with_hex_detection()
+            .build();
+        test_if_regexp_is_correct(regexp, expected_output, &test_cases);
+        test_if_regexp_matches_test_cases(expected_output, test_cases);
+    }
+
+    #[test]
+    fn succeeds_with_character_class_warning() {
+        let (_, warnings) = RegExpBuilder::from(&["cafe"])
+            .with_hex_detection()
+            .build_with_warnings();
+        assert_eq!(
+            warnings,
+            vec![GenerationWarning::CharacterClassGeneralized(
+                "[0-9a-f]".to_string()
+            )]
+        );
+    }
+}
+
+mod structured_csv {
+    use super::*;
+
+    #[rstest(test_cases, expected_output,
+        case(
+            vec!["1,apple,red", "23,banana,yellow", "456,cherry,red"],
+            "^(?:456|23|1),(?:banana|cherry|apple),(?:yellow|red)$"
+        ),
+        case(
+            vec!["1,\"Doe, John\",x", "2,\"Roe, Jane\",y"],
+            "^[12],\"(?:Doe, John|Roe, Jane)\",[xy]$"
+        ),
+        case(
+            vec!["a,\"x\",1", "b,y,2"],
+            "^[ab],(?:\"x\"|y),[12]$"
+        ),
+        case(
+            vec!["a,\"say \"\"hi\"\"\"", "b,\"c\""],
+            "^[ab],\"(?:say \"\"hi\"\"|c)\"$"
+        ),
+        case(vec!["a,,b", "c,d,"], "^[ac],d?,b?$")
+    )]
+    fn succeeds(test_cases: Vec<&str>, expected_output: &str) {
+        let regexp = RegExpBuilder::from(&test_cases)
+            .with_structured_csv()
+            .build();
+        test_if_regexp_is_correct(regexp, expected_output, &test_cases);
+        test_if_regexp_matches_test_cases(expected_output, test_cases);
+    }
+
+    #[rstest(test_cases, expected_output,
+        case(vec!["a,b", "c"], "^(?:a,b|c)$"),
+        case(vec!["abc"], "^abc$"),
+        case(vec!["a,\"b", "c,d"], "^(?:a,\"b|c,d)$")
+    )]
+    fn succeeds_with_fallback(test_cases: Vec<&str>, expected_output: &str) {
+        let regexp = RegExpBuilder::from(&test_cases)
+            .with_structured_csv()
+            .build();
+        test_if_regexp_is_correct(regexp, expected_output, &test_cases);
+        test_if_regexp_matches_test_cases(expected_output, test_cases);
+    }
+
+    #[test]
+    fn succeeds_with_conversion_features() {
+        let test_cases = vec!["1,apple,\"12, Main Street\"", "2,banana,\"3, Side Road\""];
+        let expected_output = "^\\d,(?:banana|apple),\"\\d(?:\\d, Main Street|, Side Road)\"$";
+        let (regexp, warnings) = RegExpBuilder::from(&test_cases)
+            .with_conversion_of(&[Feature::Digit])
+            .with_structured_csv()
+            .build_with_warnings();
+        test_if_regexp_is_correct(regexp.to_string(), expected_output, &test_cases);
+        test_if_regexp_matches_test_cases(expected_output, test_cases);
+        test_if_regexp_does_not_match_other_strings(
+            expected_output,
+            vec!["1,apple,12, Main Street", "1,apple"],
+        );
+        assert_eq!(
+            warnings,
+            vec![GenerationWarning::CharacterClassGeneralized(
+                "\\d".to_string()
+            )]
+        );
+    }
+}
+
+mod verbose_mode {
+    use super::*;
+
+    #[rstest(test_cases, expected_output,
+        case(vec!["abc"], "(?x)\n^\nabc\n$"),
+        case(vec!["abc", "def"], "(?x)\n^\n(?:\n  abc\n  |\n  def\n)\n$"),
+        case(vec!["a b", "a#b"], "(?x)\n^\na[\\ \\#]b\n$"),
+        case(vec!["x y", "foo"], "(?x)\n^\n(?:\n  foo\n  |\n  x\\ y\n)\n$"),
+        case(vec!["a\tb"], "(?x)\n^\na\\tb\n$"),
+        case(vec!["a\tb", "a\tc"], "(?x)\n^\na\\t[bc]\n$"),
+        case(vec!["\t", "x"], "(?x)\n^\n[\\tx]\n$"),
+        case(vec!["a\u{b}b", "c\u{a0}d"], "(?x)\n^\n(?:\n  a\\u{b}b\n  |\n  c\\u{a0}d\n)\n$")
+    )]
+    fn succeeds(test_cases: Vec<&str>, expected_output: &str) {
+        let regexp = RegExpBuilder::from(&test_cases).with_verbose_mode().build();
+        test_if_regexp_is_correct(regexp, expected_output, &test_cases);
+        test_if_regexp_matches_test_cases(expected_output, test_cases);
+    }
+
+    #[rstest(test_cases, expected_output, expected_verbose_output,
+        case(vec!["a#b"], "^a#b$", "(?x)\n^\na\\#b\n$"),
+        case(vec!["#", "a"], "^[#a]$", "(?x)\n^\n[\\#a]\n$"),
+        case(vec!["a#b", "c#d#"], "^(?:c#d#|a#b)$", "(?x)\n^\n(?:\n  c\\#d\\#\n  |\n  a\\#b\n)\n$"),
+        case(vec!["\\#"], "^\\\\#$", "(?x)\n^\n\\\\\\#\n$")
+    )]
+    fn succeeds_with_hash_sign(
+        test_cases: Vec<&str>,
+        expected_output: &str,
+        expected_verbose_output: &str,
+    ) {
+        let regexp = RegExpBuilder::from(&test_cases).build();
+        test_if_regexp_is_correct(regexp, expected_output, &test_cases);
+        test_if_regexp_matches_test_cases(expected_output, test_cases.clone());
+
+        let verbose_regexp = RegExpBuilder::from(&test_cases).with_verbose_mode().build();
+        test_if_regexp_is_correct(verbose_regexp, expected_verbose_output, &test_cases);
+        test_if_regexp_matches_test_cases(expected_verbose_output, test_cases);
+    }
+
+    #[rstest(test_cases, expected_output,
+        case(vec!["a#b"], "(?x)\n^\na\\#b\n$"),
+        case(vec!["##"], "(?x)\n^\n\\#\\#\n$"),
+        case(vec!["#", "a"], "(?x)\n^\n[\\#a]\n$")
+    )]
+    fn succeeds_with_escaped_hash_sign(test_cases: Vec<&str>, expected_output: &str) {
+        let regexp = RegExpBuilder::from(&test_cases)
+            .with_escaping_of(&['#'])
+            .with_verbose_mode()
+            .build();
+        test_if_regexp_is_correct(regexp, expected_output, &test_cases);
+        test_if_regexp_matches_test_cases(expected_output, test_cases);
+    }
+
+    #[rstest(test_cases, expected_output,
+        case(vec!["\t\t\t"], "(?x)\n^\n\\t{3}\n$"),
+        case(vec!["\t", "\t\t"], "(?x)\n^\n\\t{1,2}\n$")
+    )]
+    fn succeeds_with_tabs_and_repetitions(test_cases: Vec<&str>, expected_output: &str) {
+        let regexp = RegExpBuilder::from(&test_cases)
+            .with_conversion_of(&[Feature::Repetition])
+            .with_verbose_mode()
+            .build();
+        test_if_regexp_is_correct(regexp, expected_output, &test_cases);
+        test_if_regexp_matches_test_cases(expected_output, test_cases);
+    }
+
+    #[rstest(test_cases, expected_output,
+        case(vec!["abc"], "(?x)\n^\nabc  # matches: abc\n$"),
+        case(
+            vec!["abc", "abd", "foo"],
+            "(?x)\n^\n(?:\n  ab[cd]  # matches: abc, abd\n  |\n  foo  # matches: foo\n)\n$"
+        ),
+        case(
+            vec!["a\nb", "cd"],
+            "(?x)\n^\n(?:\n  a\\nb  # matches: a\\nb\n  |\n  cd  # matches: cd\n)\n$"
+        )
+    )]
+    fn succeeds_with_branch_comments(test_cases: Vec<&str>, expected_output: &str) {
+        let regexp = RegExpBuilder::from(&test_cases)
+            .with_verbose_mode()
+            .with_branch_comments()
+            .build();
+        test_if_regexp_is_correct(regexp, expected_output, &test_cases);
+        test_if_regexp_matches_test_cases(expected_output, test_cases);
+    }
+
+    #[test]
+    fn succeeds_with_branch_comments_and_repetition_feature() {
+        let test_cases = vec!["aa", "aaa", "b"];
+        let expected_output =
+            "(?x)\n^\n(?:\n  b  # matches: b\n  |\n  a{2,3}  # matches: aa, aaa\n)\n$";
+        let regexp = RegExpBuilder::from(&test_cases)
+            .with_conversion_of(&[Feature::Repetition])
+            .with_verbose_mode()
+            .with_branch_comments()
+            .build();
+        test_if_regexp_is_correct(regexp, expected_output, &test_cases);
+        test_if_regexp_matches_test_cases(expected_output, test_cases);
+    }
+
+    #[test]
+    fn succeeds_with_branch_comments_without_verbose_mode() {
+        let regexp = RegExpBuilder::from(&["abc", "def"])
+            .with_branch_comments()
+            .build();
+        assert_eq!(regexp, "^(?:abc|def)$");
+    }
+
+    #[rstest(test_cases,
+        case(vec!["abc", "def"]),
+        case(vec!["a b", "a#b"]),
+        case(vec!["x y", "foo"]),
+        case(vec!["a\tb", "a\tc"]),
+        case(vec!["a\u{b}b", "c\u{a0}d"])
+    )]
+    fn succeeds_with_compact_form(test_cases: Vec<&str>) {
+        let verbose_regexp = RegExpBuilder::from(&test_cases)
+            .with_conversion_of(&[Feature::Repetition])
+            .with_verbose_mode()
+            .with_branch_comments()
+            .build_regexp();
+        let compact_regexp = RegExpBuilder::from(&test_cases)
+            .with_conversion_of(&[Feature::Repetition])
+            .build();
+        assert_ne!(verbose_regexp.to_string(), compact_regexp);
+        assert_eq!(verbose_regexp.to_compact(), compact_regexp);
+    }
+
+    #[test]
+    fn succeeds_with_compact_form_of_categories() {
+        let categorized_test_cases = [("space", "a b"), ("hash", "a#b")];
+        let verbose_regexp = RegExpBuilder::from_categorized(&categorized_test_cases)
+            .with_verbose_mode()
+            .build_regexp();
+        let compact_regexp = RegExpBuilder::from_categorized(&categorized_test_cases).build();
+        assert_eq!(verbose_regexp.to_compact(), compact_regexp);
+        assert_eq!(compact_regexp, "^(?:(?P<space>a b)|(?P<hash>a#b))$");
+    }
+
+    #[test]
+    fn succeeds_with_compact_form_without_verbose_mode() {
+        let regexp = RegExpBuilder::from(&["a b", "c"]).build_regexp();
+        assert_eq!(regexp.to_compact(), regexp.to_string());
+    }
+}
+
+mod zwj_sequences {
+    use super::*;
+
+    #[rstest(test_cases, expected_output,
+        case(vec!["👨‍👩‍👧"], "^👨‍👩‍👧$"),
+        case(vec!["👨‍👩‍👧", "👨‍👩‍👦"], "^(?:👨‍👩‍👦|👨‍👩‍👧)$"),
+        case(vec!["👨‍👩‍👧", "👨‍👩‍👧"], "^👨‍👩‍👧$")
+    )]
+    fn succeeds_with_atomic_sequences(test_cases: Vec<&str>, expected_output: &str) {
+        let regexp = RegExpBuilder::from(&test_cases).build();
+        test_if_regexp_is_correct(regexp, expected_output, &test_cases);
+        test_if_regexp_matches_test_cases(expected_output, test_cases);
+    }
+
+    #[rstest(test_cases, expected_output,
+        case(vec!["👨‍👩‍👧"], "^👨\u{200d}👩\u{200d}👧$"),
+        case(vec!["👨‍👩‍👧", "👨‍👩‍👦"], "^👨\u{200d}👩\u{200d}[👦👧]$"),
+        case(vec!["👨‍👩‍👧", "👨‍👩‍👧‍👦"], "^👨\u{200d}👩\u{200d}👧(?:\u{200d}👦)?$"),
+        case(vec!["👍🏽‍x"], "^👍🏽\u{200d}x$")
+    )]
+    fn succeeds_with_split_sequences(test_cases: Vec<&str>, expected_output: &str) {
+        let regexp = RegExpBuilder::from(&test_cases)
+            .with_split_zwj_sequences()
+            .build();
+        test_if_regexp_is_correct(regexp, expected_output, &test_cases);
+        test_if_regexp_matches_test_cases(expected_output, test_cases);
+    }
+
+    #[test]
+    fn succeeds_with_split_sequences_and_escape_option() {
+        let test_cases = vec!["👨‍👩‍👧", "👨‍👩‍👦"];
+        let expected_output = "^\\u{1f468}\\u{200d}\\u{1f469}\\u{200d}[\\u{1f466}\\u{1f467}]$";
+        let regexp = RegExpBuilder::from(&test_cases)
+            .with_split_zwj_sequences()
+            .with_escaping_of_non_ascii_chars(false)
+            .build();
+        test_if_regexp_is_correct(regexp, expected_output, &test_cases);
+        test_if_regexp_matches_test_cases(expected_output, test_cases);
+    }
+}
+
+mod suffix_factoring {
+    use super::*;
+
+    #[rstest(test_cases, expected_output,
+        case(vec!["testing", "running"], "^(?:runn|test)ing$"),
+        case(
+            vec!["testing", "running", "jumping", "tested", "ran"],
+            "^(?:(?:test|runn|jump)ing|tested|ran)$"
+        ),
+        case(
+            vec!["testing", "running", "ing", "tested", "ran", "fed"],
+            "^(?:(?:runn)?ing|test(?:ing|ed)|ran|fed)$"
+        ),
+        case(vec!["abcx", "defx", "gx", "hy"], "^(?:(?:abc|def|g)x|hy)$"),
+        case(vec!["a1x", "b2x", "c3y", "d4y"], "^(?:(?:a1|b2)x|c3y|d4y)$")
+    )]
+    fn succeeds(test_cases: Vec<&str>, expected_output: &str) {
+        let regexp = RegExpBuilder::from(&test_cases)
+            .with_suffix_factoring()
+            .build();
+        test_if_regexp_is_correct(regexp, expected_output, &test_cases);
+        test_if_regexp_matches_test_cases(expected_output, test_cases);
+    }
+
+    #[test]
+    fn succeeds_without_suffix_factoring() {
+        let test_cases = vec!["testing", "running", "jumping", "tested", "ran"];
+        let expected_output = "^(?:testing|running|jumping|tested|ran)$";
+        let regexp = RegExpBuilder::from(&test_cases).build();
+        test_if_regexp_is_correct(regexp, expected_output, &test_cases);
+    }
+}
+
+mod maximum_factor_depth {
+    use super::*;
+
+    #[rstest(
+        maximum_depth,
+        expected_output,
+        case(1, "^(?:(?:[ab]vvvvvvwwwwwwxxxxxx|cwwwwwwxxxxxx|dxxxxxx|e)yyyyyy|f)$"),
+        case(2, "^(?:(?:(?:[ab]vvvvvvwwwwww|cwwwwww|d)xxxxxx|e)yyyyyy|f)$"),
+        case(3, "^(?:(?:(?:(?:[ab]vvvvvv|c)wwwwww|d)xxxxxx|e)yyyyyy|f)$"),
+        case(10, "^(?:(?:(?:(?:[ab]vvvvvv|c)wwwwww|d)xxxxxx|e)yyyyyy|f)$")
+    )]
+    fn succeeds(maximum_depth: u32, expected_output: &str) {
+        let test_cases = vec![
+            "avvvvvvwwwwwwxxxxxxyyyyyy",
+            "bvvvvvvwwwwwwxxxxxxyyyyyy",
+            "cwwwwwwxxxxxxyyyyyy",
+            "dxxxxxxyyyyyy",
+            "eyyyyyy",
+            "f",
+        ];
+        let regexp = RegExpBuilder::from(&test_cases)
+            .with_suffix_factoring()
+            .with_maximum_factor_depth(maximum_depth)
+            .build();
+        test_if_regexp_is_correct(regexp, expected_output, &test_cases);
+        test_if_regexp_matches_test_cases(expected_output, test_cases);
+    }
+
+    #[test]
+    fn warns_about_ignored_settings() {
+        let test_cases = vec!["testing", "running", "jumping", "tested", "ran"];
+        let (regexp, warnings) = RegExpBuilder::from(&test_cases)
+            .with_maximum_factor_depth(1)
+            .build_with_warnings();
+        assert_eq!(
+            regexp.to_string(),
+            "^(?:testing|running|jumping|tested|ran)$"
+        );
+        assert_eq!(
+            warnings,
+            vec![GenerationWarning::SettingIgnored(
+                "maximum factoring depth requires the factoring of suffixes".to_string()
+            )]
+        );
+    }
+}
+
+mod control_char_escaping {
+    use super::*;
+
+    #[rstest(test_cases, expected_output,
+        case(vec!["\u{1}", "\u{2}", "\u{1b}", "a"], "^[\\x01\\x02\\x1ba]$"),
+        case(vec!["\u{1}", "\u{2}", "\u{3}", "\u{4}"], "^[\\x01-\\x04]$"),
+        case(vec!["\u{85}", "\u{9f}", "\n", "x"], "^[\\nx\\x85\\x9f]$"),
+        case(vec!["\u{7f}", "\u{a0}", "\u{1f}"], "^[\\x1f\u{7f}\u{a0}]$"),
+        case(vec!["\u{1b}a", "\u{1b}b"], "^\u{1b}[ab]$")
+    )]
+    fn succeeds(test_cases: Vec<&str>, expected_output: &str) {
+        let regexp = RegExpBuilder::from(&test_cases)
+            .with_escaping_of_control_chars()
+            .build();
+        test_if_regexp_is_correct(regexp, expected_output, &test_cases);
+        test_if_regexp_matches_test_cases(expected_output, test_cases);
+    }
+
+    #[rstest(test_cases, expected_output,
+        case(vec!["\u{0}", "\u{1}", "a"], "^[\\0\\x01a]$"),
+        case(vec!["\u{0}", "\u{9f}"], "^[\\0\\x9f]$")
+    )]
+    fn succeeds_with_pcre_flavor(test_cases: Vec<&str>, expected_output: &str) {
+        let regexp = RegExpBuilder::from(&test_cases)
+            .with_escaping_of_control_chars()
+            .with_flavor(Flavor::Pcre)
+            .build();
+        test_if_regexp_is_correct(regexp, expected_output, &test_cases);
+    }
+
+    #[test]
+    fn succeeds_without_control_char_escaping() {
+        let test_cases = vec!["\u{1}", "\u{2}", "\u{1b}", "a"];
+        let expected_output = "^[\u{1}\u{2}\u{1b}a]$";
+        let regexp = RegExpBuilder::from(&test_cases).build();
+        test_if_regexp_is_correct(regexp, expected_output, &test_cases);
+    }
+
+    #[test]
+    fn warns_about_ignored_settings() {
+        let test_cases = vec!["\u{1}", "\u{2}"];
+        let (regexp, warnings) = RegExpBuilder::from(&test_cases)
+            .with_escaping_of_control_chars()
+            .with_flavor(Flavor::PosixExtended)
+            .build_with_warnings();
+        assert_eq!(regexp.to_string(), "^[\u{1}\u{2}]$");
+        assert_eq!(
+            warnings,
+            vec![GenerationWarning::SettingIgnored(
+                "escaping of control characters is not supported by the chosen flavor".to_string()
+            )]
+        );
+    }
+}
+
+mod literal_only {
+    use super::*;
+
+    #[rstest(test_cases, expected_output,
+        case(vec!["abc"], "^abc$"),
+        case(vec!["a", "b", "c"], "^(?:a|b|c)$"),
+        case(vec!["abc", "abd", "abc"], "^(?:abc|abd)$"),
+        case(vec!["1234", "5678", "x.y"], "^(?:1234|5678|x\\.y)$"),
+        case(vec!["aaaa", "aa", "a"], "^(?:aaaa|aa|a)$")
+    )]
+    fn succeeds(test_cases: Vec<&str>, expected_output: &str) {
+        let regexp = RegExpBuilder::from(&test_cases)
+            .with_conversion_of(&[
+                Feature::Digit,
+                Feature::Word,
+                Feature::Repetition,
+                Feature::CaseInsensitivity,
+            ])
+            .with_suffix_factoring()
+            .with_literal_only()
+            .build();
+        test_if_regexp_is_correct(regexp, expected_output, &test_cases);
+        test_if_regexp_matches_test_cases(expected_output, test_cases);
+    }
+
+    #[test]
+    fn matches_nothing_but_test_cases() {
+        let test_cases = vec!["1234", "5678", "abcd"];
+        let regexp = RegExpBuilder::from(&test_cases)
+            .with_conversion_of(&[Feature::Digit, Feature::Repetition])
+            .with_literal_only()
+            .build();
+        let compiled_regexp = Regex::new(&regexp).unwrap();
+        for other_string in &["1235", "0000", "ABCD", "12345", "abc"] {
+            assert!(!compiled_regexp.is_match(other_string));
+        }
+    }
+
+    #[test]
+    fn warns_about_ignored_settings() {
+        let test_cases = vec!["1234", "5678"];
+        let (_, warnings) = RegExpBuilder::from(&test_cases)
+            .with_conversion_of(&[Feature::Digit])
+            .with_literal_only()
+            .build_with_warnings();
+        assert_eq!(
+            warnings,
+            vec![GenerationWarning::SettingIgnored(
+                "generalizing settings are disabled by literal-only generation".to_string()
+            )]
+        );
+    }
+}
+
+mod script_conversion {
+    use super::*;
+
+    #[rstest(test_cases, expected_output,
+        case(vec!["αβγ", "δεζη"], "^\\p{Greek}{3,4}$"),
+        case(vec!["λόγος"], "^\\p{Greek}{5}$"),
+        case(vec!["漢字", "中文字"], "^\\p{Han}{2,3}$"),
+        case(vec!["αβγ 123", "δε 45"], "^\\p{Greek}{2,3} (?:123|45)$"),
+        case(vec!["αβγ", "漢字"], "^(?:\\p{Greek}{3}|\\p{Han}{2})$")
+    )]
+    fn succeeds(test_cases: Vec<&str>, expected_output: &str) {
+        let regexp = RegExpBuilder::from(&test_cases)
+            .with_conversion_of(&[Feature::Script, Feature::Repetition])
+            .build();
+        test_if_regexp_is_correct(regexp, expected_output, &test_cases);
+        test_if_regexp_matches_test_cases(expected_output, test_cases);
+    }
+
+    #[rstest(test_cases, expected_output,
+        case(vec!["abγδ"], "^abγδ$"),
+        case(vec!["abγδ", "αβγ"], "^(?:abγδ|\\p{Greek}{3})$")
+    )]
+    fn succeeds_with_mixed_scripts(test_cases: Vec<&str>, expected_output: &str) {
+        let regexp = RegExpBuilder::from(&test_cases)
+            .with_conversion_of(&[Feature::Script, Feature::Repetition])
+            .build();
+        test_if_regexp_is_correct(regexp, expected_output, &test_cases);
+        test_if_regexp_matches_test_cases(expected_output, test_cases);
+    }
+
+    #[test]
+    fn succeeds_with_mixed_scripts_and_word_conversion() {
+        let test_cases = vec!["abγδ", "αβγ"];
+        let expected_output = "^(?:\\w{4}|\\p{Greek}{3})$";
+        let regexp = RegExpBuilder::from(&test_cases)
+            .with_conversion_of(&[Feature::Script, Feature::Word, Feature::Repetition])
+            .build();
+        test_if_regexp_is_correct(regexp, expected_output, &test_cases);
+        test_if_regexp_matches_test_cases(expected_output, test_cases);
+    }
+
+    #[rstest(test_cases, expected_output,
+        case(vec!["💩a\u{200d}💩\u{200d}a"], "^💩a\u{200d}💩\u{200d}\\p{Latin}$"),
+        case(vec!["a\u{200d}a\u{200d}"], "^(?:a\u{200d}){2}$")
+    )]
+    fn succeeds_with_multi_code_point_graphemes(test_cases: Vec<&str>, expected_output: &str) {
+        let regexp = RegExpBuilder::from(&test_cases)
+            .with_conversion_of(&[Feature::Script, Feature::Repetition])
+            .build();
+        test_if_regexp_is_correct(regexp, expected_output, &test_cases);
+        test_if_regexp_matches_test_cases(expected_output, test_cases);
+    }
+
+    #[test]
+    fn does_not_warn_without_converted_graphemes() {
+        let (_, warnings) = RegExpBuilder::from(&["a\u{200d}a\u{200d}"])
+            .with_conversion_of(&[Feature::Script])
+            .build_with_warnings();
+        assert!(warnings.is_empty());
+    }
+
+    #[test]
+    fn does_not_match_other_scripts() {
+        let regexp = RegExpBuilder::from(&["αβγ"])
+            .with_conversion_of(&[Feature::Script, Feature::Repetition])
+            .build();
+        test_if_regexp_does_not_match_other_strings(&regexp, vec!["abc", "漢字漢", "αβ"]);
+    }
+
+    #[test]
+    fn warns_about_unsupported_flavor() {
+        let (regexp, warnings) = RegExpBuilder::from(&["αβγ"])
+            .with_conversion_of(&[Feature::Script])
+            .with_flavor(Flavor::PosixExtended)
+            .build_with_warnings();
+        assert_eq!(regexp.to_string(), "^αβγ$");
+        assert_eq!(
+            warnings,
+            vec![GenerationWarning::SettingIgnored(
+                "script classes are not supported by the chosen flavor".to_string()
+            )]
+        );
+    }
+}
+
+mod substring_branches {
+    use super::*;
+
+    #[rstest(test_cases, expected_output,
+        case(vec!["ab", "abc"], "^(?:abc|ab)$"),
+        case(vec!["ab", "abc", "xy"], "^(?:abc|xy|ab)$"),
+        case(vec!["a", "ab", "abc", "abcd"], "^(?:abcd|abc|ab|a)$"),
+        case(vec!["", "ab", "abc"], "^(?:abc|ab)?$"),
+        case(vec!["abc", "abd"], "^ab[cd]$")
+    )]
+    fn succeeds(test_cases: Vec<&str>, expected_output: &str) {
+        let regexp = RegExpBuilder::from(&test_cases)
+            .with_substring_branches()
+            .build();
+        test_if_regexp_is_correct(regexp, expected_output, &test_cases);
+        test_if_regexp_matches_test_cases(expected_output, test_cases);
+    }
+
+    #[rstest(test_cases, expected_output,
+        case(vec!["ab", "abc"], "^abc?$"),
+        case(vec!["a", "ab", "abc", "abcd"], "^a(?:b(?:cd?)?)?$")
+    )]
+    fn succeeds_without_substring_branches(test_cases: Vec<&str>, expected_output: &str) {
+        let regexp = RegExpBuilder::from(&test_cases).build();
+        test_if_regexp_is_correct(regexp, expected_output, &test_cases);
+        test_if_regexp_matches_test_cases(expected_output, test_cases);
+    }
+}
+
+mod prefix_matching {
+    use super::*;
+
+    #[rstest(test_cases, expected_output, prefixes, other_strings,
+        case(vec!["abc"], "^(?:a(?:bc?)?)?$", vec!["", "a", "ab", "abc"], vec!["b", "bc", "ac", "abcd"]),
+        case(vec!["", "ab"], "^(?:ab?)?$", vec!["", "a", "ab"], vec!["b", "aa", "abb"]),
+        case(
+            vec!["car", "cat", "dog"],
+            "^(?:c(?:a[rt]?)?|d(?:og?)?)?$",
+            vec!["", "c", "ca", "car", "cat", "d", "do", "dog"],
+            vec!["a", "cr", "co", "cart", "og", "dot"]
+        ),
+        case(
+            vec!["a.b", "x"],
+            "^(?:a(?:\\.b?)?|x)?$",
+            vec!["", "a", "a.", "a.b", "x"],
+            vec!["ab", "a.x", "xa"]
+        ),
+        case(vec![""], "^$", vec![""], vec!["a"])
+    )]
+    fn succeeds(
+        test_cases: Vec<&str>,
+        expected_output: &str,
+        prefixes: Vec<&str>,
+        other_strings: Vec<&str>,
+    ) {
+        let regexp = RegExpBuilder::from(&test_cases)
+            .with_prefix_matching()
+            .build();
+        test_if_regexp_is_correct(regexp, expected_output, &test_cases);
+        test_if_regexp_matches_test_cases(expected_output, prefixes);
+        test_if_regexp_does_not_match_other_strings(expected_output, other_strings);
+    }
+
+    #[test]
+    fn succeeds_with_character_classes_but_without_repetitions() {
+        let test_cases = vec!["aaa1"];
+        let (regexp, warnings) = RegExpBuilder::from(&test_cases)
+            .with_conversion_of(&[Feature::Digit, Feature::Repetition])
+            .with_prefix_matching()
+            .build_with_warnings();
+        let expected_output = "^(?:a(?:a(?:a(?:\\d)?)?)?)?$";
+        test_if_regexp_is_correct(regexp.to_string(), expected_output, &test_cases);
+        test_if_regexp_matches_test_cases(expected_output, vec!["", "a", "aa", "aaa", "aaa7"]);
+        test_if_regexp_does_not_match_other_strings(expected_output, vec!["aaaa", "a1", "aaa12"]);
+        assert!(warnings.contains(&GenerationWarning::SettingIgnored(
+            "conversion of repetitions and restructuring settings are disabled by prefix matching"
+                .to_string()
+        )));
+    }
+}
+
+mod word_class_conversion {
+    use super::*;
+
+    #[rstest(test_cases, threshold, expected_output,
+        case(vec!["xAy", "xBy", "xay", "xby"], 5, "^x[ABab]y$"),
+        case(vec!["xAy", "xBy", "xay", "xby"], 4, "^x\\wy$"),
+        case(vec!["xAy", "xBy", "xay", "xby"], 3, "^x\\wy$"),
+        case(vec!["a", "b", "c", "1"], 4, "^\\w$"),
+        case(vec!["a", "b", "-"], 2, "^[\\-ab]$"),
+        case(vec!["ab", "ac", "d"], 2, "^(?:a\\w|d)$")
+    )]
+    fn succeeds(test_cases: Vec<&str>, threshold: u32, expected_output: &str) {
+        let regexp = RegExpBuilder::from(&test_cases)
+            .with_word_class_threshold(threshold)
+            .build();
+        test_if_regexp_is_correct(regexp, expected_output, &test_cases);
+        test_if_regexp_matches_test_cases(expected_output, test_cases);
+    }
+
+    #[test]
+    fn succeeds_with_letters_of_both_cases() {
+        let test_cases = ('A'..='Z')
+            .chain('a'..='z')
+            .map(|it| it.to_string())
+            .collect::<Vec<_>>();
+        let test_cases = test_cases.iter().map(|it| it.as_str()).collect::<Vec<_>>();
+
+        let regexp = RegExpBuilder::from(&test_cases)
+            .with_word_class_threshold(53)
+            .build();
+        test_if_regexp_is_correct(regexp, "^[A-Za-z]$", &test_cases);
+
+        let regexp = RegExpBuilder::from(&test_cases)
+            .with_word_class_threshold(52)
+            .build();
+        test_if_regexp_is_correct(regexp, "^\\w$", &test_cases);
+    }
+}
+
+mod placeholder_conversion {
+    use super::*;
+
+    #[rstest(test_cases, placeholder, wildcard, expected_output,
+        case(vec!["a?c"], '?', Wildcard::AnyChar, "^a.c$"),
+        case(vec!["a?c", "abc"], '?', Wildcard::AnyChar, "^a(?:.|b)c$"),
+        case(vec!["a?c", "a.c"], '?', Wildcard::AnyChar, "^a(?:\\.|.)c$"),
+        case(vec!["a", "a?"], '?', Wildcard::AnyChar, "^a.?$"),
+        case(vec!["x.y", "x.z"], '.', Wildcard::AnyChar, "^x.[yz]$"),
+        case(vec!["#-##"], '#', Wildcard::Digit, "^\\d\\-\\d\\d$"),
+        case(vec!["ab", "a_"], '_', Wildcard::Word, "^a(?:\\w|b)$")
+    )]
+    fn succeeds(
+        test_cases: Vec<&str>,
+        placeholder: char,
+        wildcard: Wildcard,
+        expected_output: &str,
+    ) {
+        let regexp = RegExpBuilder::from(&test_cases)
+            .with_placeholder(placeholder, wildcard)
+            .build();
+        test_if_regexp_is_correct(regexp, expected_output, &test_cases);
+    }
+
+    #[rstest(test_cases, expected_output,
+        case(vec!["a\\?c"], "^a\\?c$"),
+        case(vec!["a\\?c", "a?c"], "^a(?:.|\\?)c$"),
+        case(vec!["\\??"], "^\\?.$")
+    )]
+    fn succeeds_with_escaped_placeholder(test_cases: Vec<&str>, expected_output: &str) {
+        let regexp = RegExpBuilder::from(&test_cases)
+            .with_placeholder('?', Wildcard::AnyChar)
+            .build();
+        test_if_regexp_is_correct(regexp, expected_output, &test_cases);
+    }
+
+    #[rstest(test_cases, placeholder, wildcard, expected_output,
+        case(vec!["a??c"], '?', Wildcard::AnyChar, "^a.{2}c$"),
+        case(vec!["#-##", "1-23"], '#', Wildcard::Digit, "^\\d\\-\\d{2}$")
+    )]
+    fn succeeds_with_conversion_features(
+        test_cases: Vec<&str>,
+        placeholder: char,
+        wildcard: Wildcard,
+        expected_output: &str,
+    ) {
+        let regexp = RegExpBuilder::from(&test_cases)
+            .with_conversion_of(&[Feature::Digit, Feature::Repetition])
+            .with_placeholder(placeholder, wildcard)
+            .build();
+        test_if_regexp_is_correct(regexp, expected_output, &test_cases);
+    }
+}
+
+mod branch_limitation {
+    use super::*;
+
+    #[rstest(test_cases, max_branches, expected_output,
+        case(vec!["a", "b", "cd", "efg", "hi"], 2, "^(?:[ab]|\\w{2,3})$"),
+        case(vec!["x1", "x22", "x333", "x4444"], 2, "^x(?:1|\\d{2,4})$"),
+        case(vec!["10", "200", "3000", "x", "yz"], 3, "^(?:200|10|\\w{1,4})$"),
+        case(vec!["foo", "bar", "a b", "c\nd"], 2, "^(?:a b|\\p{Any}{3})$"),
+        case(vec!["foo", "bar", "baz"], 1, "^\\w{3}$"),
+        case(vec!["foo", "bar", "baz"], 2, "^(?:ba[rz]|foo)$"),
+        case(
+            vec!["a", "b\r\r\n", "\n\r\t", "\n\nb"],
+            2,
+            "^(?:\\n(?:\\nb|\\r\\t)|\\p{Any}{1,4})$"
+        ),
+        case(vec!["y\u{306}y\u{306}", "xx", "ab", "cd"], 2, "^(?:ab|\\w{2,4})$")
+    )]
+    fn succeeds(test_cases: Vec<&str>, max_branches: u32, expected_output: &str) {
+        let regexp = RegExpBuilder::from(&test_cases)
+            .with_maximum_branches(max_branches)
+            .build();
+        test_if_regexp_is_correct(regexp, expected_output, &test_cases);
+        test_if_regexp_matches_test_cases(expected_output, test_cases);
+    }
+
+    #[rstest(test_cases, max_branches, expected_output,
+        case(vec!["ab", "abab", "cd", "cdcdcd", "e", "f"], 2, "^(?:(?:ab){2}|\\w{1,6})$"),
+        case(vec!["a", "aa", "aaa", "b", "bbbbb"], 1, "^\\w{1,5}$")
+    )]
+    fn succeeds_with_repetition_feature(
+        test_cases: Vec<&str>,
+        max_branches: u32,
+        expected_output: &str,
+    ) {
+        let regexp = RegExpBuilder::from(&test_cases)
+            .with_conversion_of(&[Feature::Repetition])
+            .with_maximum_branches(max_branches)
+            .build();
+        test_if_regexp_is_correct(regexp, expected_output, &test_cases);
+        test_if_regexp_matches_test_cases(expected_output, test_cases);
+    }
+
+    #[test]
+    fn succeeds_with_many_test_cases() {
+        let test_cases = (1..=300)
+            .map(|it| (it * 7).to_string())
+            .chain(vec!["ab".to_string(), "xyz".to_string()])
+            .collect::<Vec<_>>();
+        let regexp = RegExpBuilder::from(&test_cases)
+            .with_maximum_branches(3)
+            .build();
+        test_if_regexp_is_correct(
+            regexp.clone(),
+            "^(?:(?:(?:13|20|6)3|(?:15|8)4|\\d{2,3})[07]|(?:(?:13|20|6)4|(?:15|8)5|\\d{2,3})4|\\w{1,4})$",
+            &test_cases.iter().map(|it| it.as_str()).collect::<Vec<_>>(),
+        );
+        test_if_regexp_matches_test_cases(
+            &regexp,
+            test_cases.iter().map(|it| it.as_str()).collect(),
+        );
+    }
+}
+
+mod required_literals {
+    use super::*;
+
+    #[rstest(test_cases, expected_literals,
+        case(vec!["abc"], vec!["abc"]),
+        case(vec!["abcxyz", "defxyz"], vec!["xyz"]),
+        case(vec!["foobarx", "yybarzz"], vec!["bar"]),
+        case(vec!["a1b", "a2b"], vec!["a", "b"]),
+        case(vec!["abc", "def"], vec![]),
+        case(vec!["abc", "abcdef"], vec!["abc"]),
+    )]
+    fn succeeds(test_cases: Vec<&str>, expected_literals: Vec<&str>) {
+        let regexp = RegExpBuilder::from(&test_cases).build_regexp();
+        assert_eq!(regexp.required_literals(), expected_literals);
+    }
+
+    #[rstest(test_cases, expected_literals,
+        case(vec!["aaab"], vec!["aaab"]),
+        case(vec!["xaab", "xaaab"], vec!["xaa", "b"])
+    )]
+    fn succeeds_with_repetition_conversion(test_cases: Vec<&str>, expected_literals: Vec<&str>) {
+        let regexp = RegExpBuilder::from(&test_cases)
+            .with_conversion_of(&[Feature::Repetition])
+            .build_regexp();
+        assert_eq!(regexp.required_literals(), expected_literals);
+    }
+
+    #[rstest(test_cases, expected_literals,
+        case(vec!["a1b2c"], vec!["a", "b", "c"]),
+        case(vec!["12", "345"], vec![])
+    )]
+    fn succeeds_with_digit_conversion(test_cases: Vec<&str>, expected_literals: Vec<&str>) {
+        let regexp = RegExpBuilder::from(&test_cases)
+            .with_conversion_of(&[Feature::Digit])
+            .build_regexp();
+        assert_eq!(regexp.required_literals(), expected_literals);
+    }
+}
+
+mod language_size_estimate {
+    use super::*;
+
+    #[rstest(test_cases, expected_size,
+        case(vec!["abc"], 1),
+        case(vec!["abc", "def", "ghi"], 3),
+        case(vec!["a", "b", "c", "xyz"], 4),
+        case(vec!["", "ab", "abc"], 3),
+        case(vec!["abc", "abd", "xbc", "xbd"], 4)
+    )]
+    fn succeeds(test_cases: Vec<&str>, expected_size: u128) {
+        let regexp = RegExpBuilder::from(&test_cases).build_regexp();
+        assert_eq!(regexp.language_size_estimate(), Some(expected_size));
+    }
+
+    #[rstest(test_cases, expected_size,
+        case(vec!["aa", "aaa"], 2),
+        case(vec!["abab", "ababab"], 2)
+    )]
+    fn succeeds_with_repetition_conversion(test_cases: Vec<&str>, expected_size: u128) {
+        let regexp = RegExpBuilder::from(&test_cases)
+            .with_conversion_of(&[Feature::Repetition])
+            .build_regexp();
+        assert_eq!(regexp.language_size_estimate(), Some(expected_size));
+    }
+
+    #[test]
+    fn succeeds_with_case_insensitivity() {
+        let regexp = RegExpBuilder::from(&["ab", "a1"])
+            .with_conversion_of(&[Feature::CaseInsensitivity])
+            .build_regexp();
+        assert_eq!(regexp.language_size_estimate(), Some(6));
+    }
+
+    #[test]
+    fn succeeds_with_escaped_literal_next_to_wildcard() {
+        let regexp = RegExpBuilder::from(&["a.b", "a_b"])
+            .with_placeholder('_', Wildcard::AnyChar)
+            .build_regexp();
+        assert_eq!(regexp.to_string(), "^a(?:\\.|.)b$");
+        assert!(regexp.language_size_estimate().is_some());
+    }
+
+    #[test]
+    fn reveals_generalization_of_digits() {
+        let test_cases = vec!["1234", "5678"];
+        let literal_regexp = RegExpBuilder::from(&test_cases).build_regexp();
+        let digit_regexp = RegExpBuilder::from(&test_cases)
+            .with_conversion_of(&[Feature::Digit, Feature::Repetition])
+            .build_regexp();
+        assert_eq!(literal_regexp.language_size_estimate(), Some(2));
+        assert!(digit_regexp.language_size_estimate().unwrap() > 10_000);
+    }
+
+    #[test]
+    fn returns_none_for_infinite_language() {
+        let regexp = RegExpBuilder::from(&["aa", "aaa"])
+            .with_conversion_of(&[Feature::Repetition])
+            .with_open_ended_repetitions()
+            .build_regexp();
+        assert_eq!(regexp.to_string(), "^a{2,}$");
+        assert_eq!(regexp.language_size_estimate(), None);
+    }
+}
+
+mod dot_matching_newlines {
+    use super::*;
+
+    #[rstest(test_cases, flavor, expected_output,
+        case(vec!["a_b", "a_c"], Flavor::Rust, "(?s)^a.[bc]$"),
+        case(vec!["a_b", "a_c"], Flavor::Pcre, "(?s)^a.[bc]$"),
+        case(vec!["a_b", "a_c"], Flavor::PosixExtended, "^a.[bc]$"),
+        case(vec!["a_b", "a_c"], Flavor::PosixBasic, "^a.[bc]$"),
+        case(vec!["abc"], Flavor::Rust, "^abc$")
+    )]
+    fn succeeds(test_cases: Vec<&str>, flavor: Flavor, expected_output: &str) {
+        let regexp = RegExpBuilder::from(&test_cases)
+            .with_placeholder('_', Wildcard::AnyChar)
+            .with_dot_matching_newlines()
+            .with_flavor(flavor)
+            .build();
+        test_if_regexp_is_correct(regexp, expected_output, &test_cases);
+    }
+
+    #[test]
+    fn succeeds_with_newline_at_wildcard_position() {
+        let test_cases = vec!["a_b", "a_c"];
+        let regexp = RegExpBuilder::from(&test_cases)
+            .with_placeholder('_', Wildcard::AnyChar)
+            .with_dot_matching_newlines()
+            .build();
+        let compiled_regexp = Regex::new(&regexp).unwrap();
+        for matched_string in &["a\nb", "a\nc", "axb", "a\rc"] {
+            assert!(compiled_regexp.is_match(matched_string));
+        }
+        assert!(!compiled_regexp.is_match("a\n\nb"));
+    }
+
+    #[test]
+    fn succeeds_without_dot_matching_newlines() {
+        let test_cases = vec!["a_b", "a_c"];
+        let regexp = RegExpBuilder::from(&test_cases)
+            .with_placeholder('_', Wildcard::AnyChar)
+            .build();
+        assert_eq!(regexp, "^a.[bc]$");
+        let compiled_regexp = Regex::new(&regexp).unwrap();
+        assert!(compiled_regexp.is_match("axb"));
+        assert!(!compiled_regexp.is_match("a\nb"));
+    }
+
+    #[rstest(test_cases, conversion_features, expected_output,
+        case(vec!["a__b"], vec![Feature::Repetition], "(?s)^a.{2}b$"),
+        case(vec!["A_b"], vec![Feature::CaseInsensitivity], "(?i)(?s)^a.b$")
+    )]
+    fn succeeds_with_conversion_features(
+        test_cases: Vec<&str>,
+        conversion_features: Vec<Feature>,
+        expected_output: &str,
+    ) {
+        let regexp = RegExpBuilder::from(&test_cases)
+            .with_placeholder('_', Wildcard::AnyChar)
+            .with_conversion_of(&conversion_features)
+            .with_dot_matching_newlines()
+            .build();
+        test_if_regexp_is_correct(regexp, expected_output, &test_cases);
+        let compiled_regexp = Regex::new(expected_output).unwrap();
+        assert!(compiled_regexp.is_match(&test_cases[0].replace('_', "\n")));
+    }
+}
+
+mod redundant_branches {
+    use super::*;
+
+    #[test]
+    fn succeeds_with_catch_all_branch() {
+        let regexp = RegExpBuilder::from(&["abc", "xyz", "12", "zzzz", "b"])
+            .with_maximum_branches(2)
+            .build_regexp();
+        assert_eq!(regexp.to_string(), "^(?:12|\\w{1,4})$");
+        assert_eq!(regexp.redundant_branches(), vec!["12"]);
+    }
+
+    #[test]
+    fn succeeds_with_wildcard() {
+        let regexp = RegExpBuilder::from(&["abc", "ab_"])
+            .with_placeholder('_', Wildcard::AnyChar)
+            .build_regexp();
+        assert_eq!(regexp.to_string(), "^ab(?:.|c)$");
+        assert_eq!(regexp.redundant_branches(), vec!["c"]);
+    }
+
+    #[test]
+    fn succeeds_with_shorthand_character_classes() {
+        let regexp = RegExpBuilder::from(&["a1", "a2", "a", "bb"])
+            .with_conversion_of(&[Feature::Digit, Feature::Word])
+            .build_regexp();
+        assert_eq!(regexp.to_string(), "^\\w(?:\\d|\\w)?$");
+        assert_eq!(regexp.redundant_branches(), vec!["\\d"]);
+    }
+
+    #[rstest(test_cases, conversion_features,
+        case(vec!["abc", "xyz"], vec![Feature::CapturingGroup]),
+        case(vec!["abc", "abd", "xyz"], vec![Feature::CapturingGroup]),
+        case(vec!["aaa", "aaaa", "bab", "x"], vec![Feature::Repetition]),
+        case(vec!["12", "ab", "a1", "a"], vec![Feature::Digit])
+    )]
+    fn succeeds_without_redundant_branches(
+        test_cases: Vec<&str>,
+        conversion_features: Vec<Feature>,
+    ) {
+        let regexp = RegExpBuilder::from(&test_cases)
+            .with_conversion_of(&conversion_features)
+            .build_regexp();
+        assert!(regexp.redundant_branches().is_empty());
+    }
+}
+
+mod write_to {
+    use super::*;
+
+    #[rstest(test_cases,
+        case(vec!["abc"]),
+        case(vec!["a", "b", "bcd", "xyz"]),
+        case(vec!["I   ♥♥♥ 36 and ٣ and y̆y̆ and 💩💩."]),
+        case(vec!["1234", "5678", "abab", "ababab", ""])
+    )]
+    fn succeeds(test_cases: Vec<&str>) {
+        let regexps = vec![
+            RegExpBuilder::from(&test_cases).build_regexp(),
+            RegExpBuilder::from(&test_cases)
+                .with_conversion_of(&[Feature::Repetition, Feature::Digit])
+                .with_escaping_of_non_ascii_chars(false)
+                .build_regexp(),
+            RegExpBuilder::from(&test_cases)
+                .with_verbose_mode()
+                .build_regexp(),
+            RegExpBuilder::from(&test_cases)
+                .with_syntax_highlighting()
+                .build_regexp(),
+        ];
+        for regexp in regexps {
+            let mut buffer = Vec::new();
+            regexp.write_to(&mut buffer).unwrap();
+            assert_eq!(String::from_utf8(buffer).unwrap(), regexp.to_string());
+        }
+    }
+}
+
+mod shell_args {
+    use super::*;
+
+    #[rstest(test_cases, shell, expected_output,
+        case(vec!["abc", "abd"], Shell::Posix, "'^ab[cd]$'"),
+        case(vec!["it's", "its"], Shell::Posix, "'^it'\\''?s$'"),
+        case(vec!["a''b"], Shell::Posix, "'^a'\\'''\\''b$'"),
+        case(vec!["abc", "abd"], Shell::PowerShell, "'^ab[cd]$'"),
+        case(vec!["it's", "its"], Shell::PowerShell, "'^it''?s$'"),
+        case(vec!["it\u{2019}s"], Shell::PowerShell, "'^it\u{2019}\u{2019}s$'"),
+        case(vec!["$HOME", "`x`"], Shell::PowerShell, "'^(?:\\$HOME|`x`)$'")
+    )]
+    fn succeeds(test_cases: Vec<&str>, shell: Shell, expected_output: &str) {
+        let regexp = RegExpBuilder::from(&test_cases).build_regexp();
+        assert_eq!(regexp.to_shell_arg(shell), expected_output);
+    }
+
+    #[test]
+    fn succeeds_with_verbose_mode() {
+        let regexp = RegExpBuilder::from(&["a'b"])
+            .with_verbose_mode()
+            .build_regexp();
+        assert_eq!(regexp.to_shell_arg(Shell::Posix), "'(?x)\n^\na'\\''b\n$'");
+    }
+}
+
+mod test_snippets {
+    use super::*;
+
+    #[test]
+    fn succeeds_with_rust() {
+        let regexp = RegExpBuilder::from(&["abc", "abd", "abc"]).build_regexp();
+        assert_eq!(
+            regexp.to_test_snippet(SnippetLanguage::Rust),
+            vec![
+                "#[test]",
+                "fn regexp_matches_test_cases() {",
+                "    let regexp = regex::Regex::new(r\"^ab[cd]$\").unwrap();",
+                "    assert!(regexp.is_match(\"abc\"));",
+                "    assert!(regexp.is_match(\"abd\"));",
+                "}",
+                ""
+            ]
+            .join("\n")
+        );
+    }
+
+    #[test]
+    fn succeeds_with_python() {
+        let regexp = RegExpBuilder::from(&["abc", "abd", "abc"]).build_regexp();
+        assert_eq!(
+            regexp.to_test_snippet(SnippetLanguage::Python),
+            vec![
+                "import re",
+                "",
+                "",
+                "def test_regexp_matches_test_cases():",
+                "    regexp = re.compile(r\"^ab[cd]$\")",
+                "    assert regexp.search(\"abc\")",
+                "    assert regexp.search(\"abd\")",
+                ""
+            ]
+            .join("\n")
+        );
+    }
+
+    #[rstest(test_cases, expected_python_assertions,
+        case(
+            vec!["a\"b#", "tab\there"],
+            vec!["assert regexp.search(\"a\\\"b#\")", "assert regexp.search(\"tab\\there\")"]
+        ),
+        case(
+            vec!["back\\slash", "\"#\"##"],
+            vec!["assert regexp.search(\"back\\\\slash\")", "assert regexp.search(\"\\\"#\\\"##\")"]
+        ),
+        case(
+            vec!["1234", "I ♥ 💩"],
+            vec!["assert regexp.search(\"1234\")", "assert regexp.search(\"I ♥ 💩\")"]
+        )
+    )]
+    fn succeeds_with_each_test_case_referenced(
+        test_cases: Vec<&str>,
+        expected_python_assertions: Vec<&str>,
+    ) {
+        let regexp = RegExpBuilder::from(&test_cases).build_regexp();
+        let rust_snippet = regexp.to_test_snippet(SnippetLanguage::Rust);
+        let python_snippet = regexp.to_test_snippet(SnippetLanguage::Python);
+        for test_case in &test_cases {
+            let assertion = format!("assert!(regexp.is_match({:?}));", test_case);
+            assert!(rust_snippet.contains(&assertion));
+        }
+        for assertion in &expected_python_assertions {
+            assert!(python_snippet.contains(assertion));
+        }
+        assert_eq!(rust_snippet.matches("assert!").count(), test_cases.len());
+        assert_eq!(python_snippet.matches("assert ").count(), test_cases.len());
+    }
+
+    #[test]
+    fn succeeds_with_quotes_in_raw_string_of_rust_snippet() {
+        let regexp = RegExpBuilder::from(&["\"#", "a"]).build_regexp();
+        assert!(regexp
+            .to_test_snippet(SnippetLanguage::Rust)
+            .contains("regex::Regex::new(r##\"^(?:\"#|a)$\"##)"));
+    }
+}
+
+mod inner_pattern {
+    use super::*;
+
+    #[rstest(test_cases, expected_inner_pattern,
+        case(vec!["abc"], "abc"),
+        case(vec!["abc", "def"], "abc|def"),
+        case(vec!["a", "ab", "abc"], "a(?:bc?)?"),
+        case(vec!["ABC", "abc"], "abc")
+    )]
+    fn succeeds(test_cases: Vec<&str>, expected_inner_pattern: &str) {
+        let regexp = RegExpBuilder::from(&test_cases)
+            .with_conversion_of(&[Feature::CaseInsensitivity])
+            .build_regexp();
+        let inner_pattern = regexp.inner_pattern();
+        assert_eq!(inner_pattern, expected_inner_pattern);
+
+        let wrapped_pattern = format!("(?i)^(?:{})$", inner_pattern);
+        test_if_regexp_matches_test_cases(&wrapped_pattern, test_cases);
+    }
+
+    #[test]
+    fn succeeds_with_embedding_into_other_pattern() {
+        let regexp = RegExpBuilder::from(&["cat", "dog"]).build_regexp();
+        let wrapped_pattern = format!("^I love my (?:{})s$", regexp.inner_pattern());
+        test_if_regexp_matches_test_cases(
+            &wrapped_pattern,
+            vec!["I love my cats", "I love my dogs"],
+        );
+        assert!(!Regex::new(&wrapped_pattern)
+            .unwrap()
+            .is_match("I love my birds"));
+    }
+}
+
+mod categorized_test_cases {
+    use super::*;
+
+    #[rstest(categorized_test_cases, expected_output,
+        case(
+            vec![("fruit", "apple"), ("animal", "cat"), ("fruit", "banana"), ("animal", "dog")],
+            "^(?:(?P<fruit>banana|apple)|(?P<animal>cat|dog))$"
+        ),
+        case(
+            vec![("digits", "123"), ("letters", "abc"), ("digits", "456")],
+            "^(?:(?P<digits>123|456)|(?P<letters>abc))$"
+        ),
+        case(vec![("x", "a"), ("x", "b")], "^(?P<x>[ab])$")
+    )]
+    fn succeeds(categorized_test_cases: Vec<(&str, &str)>, expected_output: &str) {
+        let regexp = RegExpBuilder::from_categorized(&categorized_test_cases).build();
+        let test_cases = categorized_test_cases
+            .iter()
+            .map(|&(_, test_case)| test_case)
+            .collect::<Vec<_>>();
+        test_if_regexp_is_correct(regexp, expected_output, &test_cases);
+        test_if_regexp_matches_test_cases(expected_output, test_cases);
+    }
+
+    #[test]
+    fn succeeds_with_capture_name_of_matched_category() {
+        let categorized_test_cases = vec![
+            ("fruit", "apple"),
+            ("animal", "cat"),
+            ("fruit", "apricot"),
+            ("animal", "dog"),
+        ];
+        let regexp = RegExpBuilder::from_categorized(&categorized_test_cases)
+            .with_conversion_of(&[Feature::Repetition])
+            .build();
+        let compiled_regexp = Regex::new(&regexp).unwrap();
+        for (category, test_case) in categorized_test_cases {
+            let captures = compiled_regexp.captures(test_case).unwrap();
+            let other_category = if category == "fruit" {
+                "animal"
+            } else {
+                "fruit"
+            };
+            assert_eq!(captures.name(category).unwrap().as_str(), test_case);
+            assert!(captures.name(other_category).is_none());
+        }
+        assert!(!compiled_regexp.is_match("apricat"));
+    }
+
+    #[test]
+    fn succeeds_with_verbose_mode() {
+        let regexp = RegExpBuilder::from_categorized(&[("fruit", "apple"), ("animal", "cat")])
+            .with_verbose_mode()
+            .build();
+        assert_eq!(
+            regexp,
+            "(?x)\n^\n(?:\n  (?P<fruit>apple)\n  |\n  (?P<animal>cat)\n)\n$"
+        );
+        let compiled_regexp = Regex::new(&regexp).unwrap();
+        assert_eq!(
+            compiled_regexp
+                .captures("cat")
+                .unwrap()
+                .name("animal")
+                .unwrap()
+                .as_str(),
+            "cat"
+        );
+    }
+
+    #[test]
+    fn warns_about_ignored_settings() {
+        let (regexp, warnings) =
+            RegExpBuilder::from_categorized(&[("fruit", "apple"), ("animal", "cat")])
+                .with_flavor(Flavor::PosixExtended)
+                .build_with_warnings();
+        assert_eq!(regexp.to_string(), "^((apple)|(cat))$");
+        assert_eq!(
+            warnings,
+            vec![GenerationWarning::SettingIgnored(
+                "named capture groups are not supported by the chosen flavor".to_string()
+            )]
+        );
+    }
+}
+
+mod inputs {
+    use super::*;
+
+    #[rstest(test_cases,
+        case(vec!["abc"]),
+        case(vec!["xyz", "ABC", "abc", "xyz"]),
+        case(vec!["1234", "", "I ♥ 💩"])
+    )]
+    fn succeeds_with_input_retention(test_cases: Vec<&str>) {
+        let regexp = RegExpBuilder::from(&test_cases)
+            .with_conversion_of(&[Feature::Digit, Feature::CaseInsensitivity])
+            .with_input_retention()
+            .build_regexp();
+        assert_eq!(regexp.inputs(), test_cases.as_slice());
+    }
+
+    #[test]
+    fn succeeds_without_input_retention() {
+        let regexp = RegExpBuilder::from(&["abc", "def"]).build_regexp();
+        assert!(regexp.inputs().is_empty());
+    }
+}
+
+mod alphabet {
+    use super::*;
+
+    #[rstest(test_cases, expected_alphabet,
+        case(vec!["abc", "cba"], "abc"),
+        case(vec!["a1 b2", "a\nb"], "\n 12ab"),
+        case(vec!["a👍🏽", "x"], "ax👍🏽"),
+        case(vec!["e\u{301}", "e"], "e\u{301}"),
+        case(vec![""], "")
+    )]
+    fn succeeds(test_cases: Vec<&str>, expected_alphabet: &str) {
+        let regexp = RegExpBuilder::from(&test_cases).build_regexp();
+        assert_eq!(regexp.alphabet(), expected_alphabet.chars().collect());
+    }
+
+    #[test]
+    fn succeeds_with_conversion_features() {
+        let regexp = RegExpBuilder::from(&["ABC1", "abc22"])
+            .with_conversion_of(&[Feature::Digit, Feature::CaseInsensitivity])
+            .build_regexp();
+        assert_eq!(regexp.alphabet(), "12abc".chars().collect());
+    }
+
+    #[test]
+    fn succeeds_with_placeholder() {
+        let regexp = RegExpBuilder::from(&["a?c", "a.\\?"])
+            .with_placeholder('?', Wildcard::AnyChar)
+            .build_regexp();
+        assert_eq!(regexp.alphabet(), ".?ac".chars().collect());
+    }
+}
+
+mod first_set {
+    use super::*;
+
+    #[rstest(test_cases, expected_first_set,
+        case(vec!["apple", "banana", "cherry"], "abc"),
+        case(vec!["abc", "abd", "xyz"], "ax"),
+        case(vec!["aaa", "aab"], "a"),
+        case(vec!["", "a", "b"], "ab"),
+        case(vec!["👍🏽", "x"], "x👍"),
+        case(vec![""], "")
+    )]
+    fn succeeds(test_cases: Vec<&str>, expected_first_set: &str) {
+        let regexp = RegExpBuilder::from(&test_cases).build_regexp();
+        assert_eq!(regexp.first_set(), expected_first_set.chars().collect());
+    }
+
+    #[test]
+    fn succeeds_with_repetitions() {
+        let regexp = RegExpBuilder::from(&["ababx", "cdcd"])
+            .with_conversion_of(&[Feature::Repetition])
+            .build_regexp();
+        assert_eq!(regexp.first_set(), "ac".chars().collect());
+    }
+
+    #[test]
+    fn succeeds_with_case_insensitivity() {
+        let regexp = RegExpBuilder::from(&["Apple", "banana"])
+            .with_conversion_of(&[Feature::CaseInsensitivity])
+            .build_regexp();
+        assert_eq!(regexp.first_set(), "ABab".chars().collect());
+    }
+
+    #[test]
+    fn succeeds_with_shorthand_character_class() {
+        let regexp = RegExpBuilder::from(&["1a", "23", "b"])
+            .with_conversion_of(&[Feature::Digit])
+            .build_regexp();
+        assert_eq!(regexp.first_set(), "12b".chars().collect());
+    }
+
+    #[test]
+    fn succeeds_with_placeholder() {
+        let regexp = RegExpBuilder::from(&["?b", "cd"])
+            .with_placeholder('?', Wildcard::AnyChar)
+            .build_regexp();
+        assert_eq!(regexp.first_set(), "c".chars().collect());
+    }
+}
+
+mod complement {
+    use super::*;
+
+    #[rstest(test_cases, expected_output, matching_strings, non_matching_strings,
+        case(
+            vec!["a", "b"],
+            "^(?:[ab][ab][ab]*)?$",
+            vec!["", "aa", "ab", "ba", "bab"],
+            vec!["a", "b", "c"]
+        ),
+        case(
+            vec!["ab", "abc"],
+            "^(?:a(?:b(?:c[a-c][a-c]*|[ab][a-c]*)|[ac][a-c]*)?|[bc][a-c]*)?$",
+            vec!["", "a", "b", "abb", "abcc", "cab"],
+            vec!["ab", "abc", "abd"]
+        ),
+        case(
+            vec!["", "a"],
+            "^aaa*$",
+            vec!["aa", "aaa"],
+            vec!["", "a", "b"]
+        ),
+        case(
+            vec![""],
+            "^\\b\\B$",
+            vec![],
+            vec!["", "a"]
+        )
+    )]
+    fn succeeds(
+        test_cases: Vec<&str>,
+        expected_output: &str,
+        matching_strings: Vec<&str>,
+        non_matching_strings: Vec<&str>,
+    ) {
+        let complement = RegExpBuilder::from(&test_cases)
+            .build_regexp()
+            .with_complement();
+        test_if_regexp_is_correct(complement, expected_output, &test_cases);
+        test_if_regexp_matches_test_cases(expected_output, matching_strings);
+
+        let re = Regex::new(expected_output).unwrap();
+        for test_case in test_cases.iter().chain(non_matching_strings.iter()) {
+            assert!(
+                !re.is_match(test_case),
+                "\n\n\"{}\" unexpectedly matches regex {}\n\n",
+                test_case,
+                expected_output
+            );
+        }
+    }
+
+    #[rstest(
+        flavor,
+        expected_output,
+        case(Flavor::PosixBasic, "^.\\(^\\)$"),
+        case(Flavor::PosixExtended, "^.^$")
+    )]
+    fn succeeds_without_graphemes_in_posix_flavors(flavor: Flavor, expected_output: &str) {
+        let complement = RegExpBuilder::from(&[""])
+            .with_flavor(flavor)
+            .build_regexp()
+            .with_complement();
+        assert_eq!(complement, expected_output);
+    }
+}
+
+mod intersection {
+    use super::*;
+
+    #[rstest(first_test_cases, second_test_cases, expected_output, matching_strings, non_matching_strings,
+        case(
+            vec!["abc", "abd", "xyz"],
+            vec!["abd", "xyz", "foo"],
+            "^(?:abd|xyz)$",
+            vec!["abd", "xyz"],
+            vec!["abc", "foo", "ab", "abdxyz"]
+        ),
+        case(
+            vec!["a", "aa", "aaa", "b"],
+            vec!["aa", "aaa", "aaaa", "c"],
+            "^aaa?$",
+            vec!["aa", "aaa"],
+            vec!["a", "aaaa", "b", "c"]
+        ),
+        case(
+            vec!["a", "b"],
+            vec!["a", "b"],
+            "^[ab]$",
+            vec!["a", "b"],
+            vec!["ab", "c"]
+        ),
+        case(
+            vec!["(", "2"],
+            vec!["(", "2"],
+            "^[(2]$",
+            vec!["(", "2"],
+            vec!["(2", "c"]
+        ),
+        case(
+            vec!["ac", "bc", "ad"],
+            vec!["ac", "bc", "bd"],
+            "^[ab]c$",
+            vec!["ac", "bc"],
+            vec!["ad", "bd"]
+        )
+    )]
+    fn succeeds(
+        first_test_cases: Vec<&str>,
+        second_test_cases: Vec<&str>,
+        expected_output: &str,
+        matching_strings: Vec<&str>,
+        non_matching_strings: Vec<&str>,
+    ) {
+        let first_regexp = RegExpBuilder::from(&first_test_cases).build_regexp();
+        let second_regexp = RegExpBuilder::from(&second_test_cases).build_regexp();
+        let intersection = first_regexp.intersect(&second_regexp).unwrap();
+        test_if_regexp_is_correct(intersection.to_string(), expected_output, &first_test_cases);
+        test_if_regexp_matches_test_cases(expected_output, matching_strings);
+        test_if_regexp_does_not_match_other_strings(expected_output, non_matching_strings);
+    }
+
+    #[test]
+    fn succeeds_with_conversion_features() {
+        let first_regexp = RegExpBuilder::from(&["1a", "22a", "333"])
+            .with_conversion_of(&[Feature::Digit])
+            .build_regexp();
+        let second_regexp = RegExpBuilder::from(&["4a", "5b"])
+            .with_conversion_of(&[Feature::Digit])
+            .build_regexp();
+        let intersection = first_regexp.intersect(&second_regexp).unwrap();
+        assert_eq!(intersection.to_string(), "^\\da$");
+        test_if_regexp_matches_test_cases("^\\da$", vec!["0a", "9a"]);
+        test_if_regexp_does_not_match_other_strings("^\\da$", vec!["0b", "00a", "000"]);
+    }
+
+    #[test]
+    fn returns_none_for_disjoint_test_cases() {
+        let first_regexp = RegExpBuilder::from(&["abc", "def"]).build_regexp();
+        let second_regexp = RegExpBuilder::from(&["ab", "abcd", "xyz"]).build_regexp();
+        assert!(first_regexp.intersect(&second_regexp).is_none());
+    }
+}
+
+mod optional_whitespace {
+    use super::*;
+
+    #[rstest(test_cases, expected_output,
+        case(vec!["a , b", "a,b"], "^a\\s*,\\s*b$"),
+        case(vec!["a,b", "a ;b"], "^a\\s*[,;]\\s*b$"),
+        case(vec!["a,,b"], "^a\\s*,\\s*,\\s*b$"),
+        case(vec!["x = 1", "y=2"], "^(?:x\\s*=\\s*1|y\\s*=\\s*2)$"),
+        case(vec![" , "], "^\\s*,\\s*$")
+    )]
+    fn succeeds_with_separators(test_cases: Vec<&str>, expected_output: &str) {
+        let regexp = RegExpBuilder::from(&test_cases)
+            .with_optional_whitespace_around(&[',', ';', '='])
+            .build();
+        test_if_regexp_is_correct(regexp, expected_output, &test_cases);
+        test_if_regexp_matches_test_cases(expected_output, test_cases);
+    }
+
+    #[test]
+    fn succeeds_with_other_spacing() {
+        let test_cases = vec!["a , b", "a,b"];
+        let expected_output = "^a\\s*,\\s*b$";
+        let regexp = RegExpBuilder::from(&test_cases)
+            .with_optional_whitespace_around(&[','])
+            .build();
+        test_if_regexp_is_correct(regexp, expected_output, &test_cases);
+        test_if_regexp_matches_test_cases(expected_output, vec!["a  ,b", "a,\tb", "a\n,\n b"]);
+        test_if_regexp_does_not_match_other_strings(expected_output, vec!["a b", "a,,b", " a,b"]);
+    }
+
+    #[test]
+    fn succeeds_with_repetitions() {
+        let test_cases = vec!["a, b, c", "a,b,c"];
+        let regexp = RegExpBuilder::from(&test_cases)
+            .with_optional_whitespace_around(&[','])
+            .with_conversion_of(&[Feature::Repetition, Feature::Word])
+            .build();
+        test_if_regexp_is_correct(regexp, "^\\w(?:\\s*,\\s*\\w){2}$", &test_cases);
+    }
+
+    #[test]
+    fn succeeds_with_posix_flavor() {
+        let test_cases = vec!["a , b", "a,b"];
+        let regexp = RegExpBuilder::from(&test_cases)
+            .with_optional_whitespace_around(&[','])
+            .with_flavor(Flavor::PosixExtended)
+            .build();
+        test_if_regexp_is_correct(regexp, "^a[[:space:]]*,[[:space:]]*b$", &test_cases);
+    }
+}
+
+mod escaping_overrides {
+    use super::*;
+
+    #[rstest(test_cases, escaped_chars, expected_output,
+        case(vec!["a/b", "it's"], vec!['/', '\''], "^(?:it\\'s|a\\/b)$"),
+        case(vec!["a/b", "a/c"], vec!['/'], "^a\\/[bc]$"),
+        case(vec!["/", "a", "b"], vec!['/'], "^[\\/ab]$"),
+        case(vec!["a.b"], vec!['/'], "^a\\.b$")
+    )]
+    fn succeeds_with_escaped_chars(
+        test_cases: Vec<&str>,
+        escaped_chars: Vec<char>,
+        expected_output: &str,
+    ) {
+        let regexp = RegExpBuilder::from(&test_cases)
+            .with_escaping_of(&escaped_chars)
+            .build();
+        test_if_regexp_is_correct(regexp, expected_output, &test_cases);
+    }
+
+    #[rstest(test_cases, expected_output,
+        case(vec!["a#b", "c&d"], "^(?:a\\#b|c\\&d)$"),
+        case(vec!["#", "&", "~"], "^[\\#\\&\\~]$")
+    )]
+    fn succeeds_with_matching_of_escaped_chars(test_cases: Vec<&str>, expected_output: &str) {
+        let regexp = RegExpBuilder::from(&test_cases)
+            .with_escaping_of(&['#', '&', '~'])
+            .build();
+        test_if_regexp_is_correct(regexp, expected_output, &test_cases);
+        test_if_regexp_matches_test_cases(expected_output, test_cases);
+    }
+
+    #[rstest(test_cases, features, expected_output,
+        case(
+            vec!["👨\u{200d}👩\u{200d}👧"],
+            vec![Feature::Word],
+            "^👨\\w👩\\w👧$"
+        ),
+        case(
+            vec!["👨\u{200d}👩\u{200d}👧"],
+            vec![Feature::Word, Feature::Repetition],
+            "^👨\\w👩\\w👧$"
+        ),
+        case(vec!["\\\u{301}"], vec![Feature::Word], "^\\\\\\w$")
+    )]
+    fn succeeds_with_converted_multi_code_point_graphemes(
+        test_cases: Vec<&str>,
+        features: Vec<Feature>,
+        expected_output: &str,
+    ) {
+        let regexp = RegExpBuilder::from(&test_cases)
+            .with_conversion_of(&features)
+            .build();
+        test_if_regexp_is_correct(regexp, expected_output, &test_cases);
+        test_if_regexp_matches_test_cases(expected_output, test_cases);
+    }
+
+    #[rstest(test_cases, unescaped_chars, expected_output,
+        case(vec!["a/b"], vec!['/'], "^a/b$"),
+        case(vec!["a-b", "-"], vec!['-'], "^(?:a-b|-)$")
+    )]
+    fn succeeds_with_unescaped_chars(
+        test_cases: Vec<&str>,
+        unescaped_chars: Vec<char>,
+        expected_output: &str,
+    ) {
+        let regexp = RegExpBuilder::from(&test_cases)
+            .without_escaping_of(&unescaped_chars)
+            .build();
+        test_if_regexp_is_correct(regexp, expected_output, &test_cases);
+    }
+
+    #[test]
+    fn succeeds_with_posix_flavor() {
+        let test_cases = vec!["/", "a", "b", "a/b"];
+        let regexp = RegExpBuilder::from(&test_cases)
+            .with_escaping_of(&['/'])
+            .with_flavor(Flavor::PosixExtended)
+            .build();
+        test_if_regexp_is_correct(regexp, "^(a\\/b|a|[/b])$", &test_cases);
+    }
+
+    #[test]
+    fn warns_about_unescaped_metachars() {
+        let (regexp, warnings) = RegExpBuilder::from(&["a.b"])
+            .without_escaping_of(&['.', '/', '+'])
+            .build_with_warnings();
+        assert_eq!(regexp.to_string(), "^a.b$");
+        assert_eq!(
+            warnings,
+            vec![
+                GenerationWarning::MetacharacterUnescaped('+'),
+                GenerationWarning::MetacharacterUnescaped('.')
+            ]
+        );
+        assert_eq!(
+            warnings[1].to_string(),
+            "metacharacter . has not been escaped"
+        );
+    }
+
+    #[test]
+    fn succeeds_with_char_which_is_escaped_and_unescaped() {
+        let (regexp, warnings) = RegExpBuilder::from(&["a.b"])
+            .with_escaping_of(&['.'])
+            .without_escaping_of(&['.'])
+            .build_with_warnings();
+        assert_eq!(regexp.to_string(), "^a\\.b$");
+        assert!(warnings.is_empty());
+    }
+}
+
+#[cfg(feature = "unicode-names")]
+mod unicode_names {
+    use super::*;
+
+    #[rstest(test_cases, expected_output,
+        case(vec!["α"], "^\\N{GREEK SMALL LETTER ALPHA}$"),
+        case(vec!["café"], "^caf\\N{LATIN SMALL LETTER E WITH ACUTE}$"),
+        case(vec!["€", "→"], "^[\\N{EURO SIGN}\\N{RIGHTWARDS ARROW}]$"),
+        case(vec!["♥"], "^\\x{2665}$")
+    )]
+    fn succeeds_with_pcre_flavor(test_cases: Vec<&str>, expected_output: &str) {
+        let regexp = RegExpBuilder::from(&test_cases)
+            .with_escaping_of_non_ascii_chars(false)
+            .with_unicode_names()
+            .with_flavor(Flavor::Pcre)
+            .build();
+        test_if_regexp_is_correct(regexp, expected_output, &test_cases);
+    }
+
+    #[test]
+    fn warns_about_unsupported_flavor() {
+        let (regexp, warnings) = RegExpBuilder::from(&["α"])
+            .with_escaping_of_non_ascii_chars(false)
+            .with_unicode_names()
+            .build_with_warnings();
+        assert_eq!(regexp.to_string(), "^\\u{3b1}$");
+        assert_eq!(
+            warnings,
+            vec![GenerationWarning::SettingIgnored(
+                "unicode names are not supported by the chosen flavor".to_string()
+            )]
+        );
+    }
+
+    #[test]
+    fn warns_about_missing_escaping_of_non_ascii_chars() {
+        let (regexp, warnings) = RegExpBuilder::from(&["α"])
+            .with_unicode_names()
+            .with_flavor(Flavor::Pcre)
+            .build_with_warnings();
+        assert_eq!(regexp.to_string(), "^α$");
+        assert_eq!(
+            warnings,
+            vec![GenerationWarning::SettingIgnored(
+                "unicode names require the escaping of non-ascii characters".to_string()
+            )]
+        );
+    }
+}
+
+mod length_minimization {
+    use super::*;
+
+    #[rstest(test_cases, flavor, expected_output,
+        case(vec!["0", "1", "2", "3", "4", "5", "6", "7", "8", "9"], Flavor::Pcre, "^\\d$"),
+        case(vec!["0", "1", "2", "3", "4", "5", "6", "7", "8", "9"], Flavor::Rust, "^[0-9]$"),
+        case(vec!["0", "1", "2", "3", "4", "5", "6", "7", "8", "9"], Flavor::PosixExtended, "^[0-9]$"),
+        case(vec!["x0", "x2", "x4", "x6", "x8", "x1", "x3", "x5", "x7", "x9", "xa", "xf"], Flavor::Pcre, "^x[\\daf]$"),
+        case(vec!["x0", "x2", "x4", "x6", "x8", "x1", "x3", "x5", "x7", "x9", "xa", "xf"], Flavor::Rust, "^x[0-9af]$")
+    )]
+    fn succeeds_with_digit_ranges(test_cases: Vec<&str>, flavor: Flavor, expected_output: &str) {
+        let regexp = RegExpBuilder::from(&test_cases)
+            .with_minimized_length()
+            .with_flavor(flavor)
+            .build();
+        test_if_regexp_is_correct(regexp, expected_output, &test_cases);
+        if flavor != Flavor::PosixExtended {
+            test_if_regexp_matches_test_cases(expected_output, test_cases);
+            test_if_regexp_does_not_match_other_strings(expected_output, vec!["a", "xb", "x"]);
+        }
+    }
+
+    #[test]
+    fn succeeds_with_word_chars() {
+        let mut test_cases = ('0'..='9')
+            .chain('A'..='Z')
+            .chain('a'..='z')
+            .chain(vec!['_', '-'])
+            .map(|c| c.to_string())
+            .collect::<Vec<_>>();
+        let regexp = RegExpBuilder::from(&test_cases)
+            .with_minimized_length()
+            .with_flavor(Flavor::Pcre)
+            .build();
+        assert_eq!(regexp, "^[\\w\\-]$");
+
+        test_cases.pop();
+        let regexp = RegExpBuilder::from(&test_cases)
+            .with_minimized_length()
+            .with_flavor(Flavor::Pcre)
+            .build();
+        assert_eq!(regexp, "^\\w$");
+    }
+
+    #[rstest(
+        feature,
+        flavor,
+        expected_output,
+        case(Feature::Digit, Flavor::PosixExtended, "^([ab][0-9]|c)$"),
+        case(Feature::Digit, Flavor::PosixBasic, "^\\([ab][0-9]\\|c\\)$"),
+        case(Feature::NonDigit, Flavor::PosixExtended, "^[^0-9][12]?$"),
+        case(Feature::Digit, Flavor::Pcre, "^(?:[ab]\\d|c)$")
+    )]
+    fn succeeds_with_shorthands(feature: Feature, flavor: Flavor, expected_output: &str) {
+        let test_cases = vec!["a1", "b2", "c"];
+        let regexp = RegExpBuilder::from(&test_cases)
+            .with_conversion_of(&[feature])
+            .with_minimized_length()
+            .with_flavor(flavor)
+            .build();
+        test_if_regexp_is_correct(regexp, expected_output, &test_cases);
+    }
+}
+
+mod cluster_transformation {
+    use super::*;
+
+    #[test]
+    fn succeeds_with_uppercased_cluster() {
+        let test_cases = vec!["abc", "def", "ghi"];
+        let regexp = RegExpBuilder::from(&test_cases)
+            .with_cluster_transformation(|cluster| {
+                if cluster.values() == vec!["d", "e", "f"] {
+                    cluster.map_values(|value| value.to_uppercase())
+                } else {
+                    cluster.clone()
+                }
+            })
+            .build();
+        assert_eq!(regexp, "^(?:abc|DEF|ghi)$");
+    }
+
+    #[test]
+    fn succeeds_after_conversion_of_repetitions() {
+        let regexp = RegExpBuilder::from(&["aaa.b"])
+            .with_conversion_of(&[Feature::Repetition])
+            .with_cluster_transformation(|cluster| {
+                assert_eq!(cluster.values(), vec!["a", ".", "b"]);
+                cluster.map_values(|value| value.replace("a", "x"))
+            })
+            .build();
+        assert_eq!(regexp, "^x{3}\\.b$");
+    }
+
+    #[test]
+    fn succeeds_after_conversion_of_digits() {
+        let regexp = RegExpBuilder::from(&["item-1", "item-42"])
+            .with_conversion_of(&[Feature::Digit])
+            .with_cluster_transformation(|cluster| {
+                assert!(cluster.values().contains(&"\\d".to_string()));
+                cluster.map_values(|value| {
+                    if value.starts_with('\\') {
+                        value.to_string()
+                    } else {
+                        value.to_uppercase()
+                    }
+                })
+            })
+            .build();
+        assert_eq!(regexp, "^ITEM\\-\\d(?:\\d)?$");
+    }
+
+    #[test]
+    fn succeeds_deterministically() {
+        let mut builder = RegExpBuilder::from(&["b1", "a2", "c3"]);
+        builder.with_cluster_transformation(|cluster| {
+            cluster.map_values(|value| value.replace("2", "0"))
+        });
+        let regexps = (0..3).map(|_| builder.build()).collect::<Vec<_>>();
+        assert_eq!(regexps, vec!["^(?:a0|b1|c3)$"; 3]);
+    }
+}
+
+mod automaton_table {
+    use super::*;
+
+    #[test]
+    fn succeeds_with_transitions_of_minimal_automaton() {
+        let table = RegExpBuilder::from(&["abc", "abd", "a"])
+            .build_regexp()
+            .automaton_table();
+        assert_eq!(table.states(), vec![0, 1, 2, 3]);
+        assert_eq!(table.alphabet(), &["a", "b", "c", "d"]);
+        assert_eq!(
+            table.transitions(),
+            &[
+                (0, "a".to_string(), 1),
+                (1, "b".to_string(), 2),
+                (2, "c".to_string(), 3),
+                (2, "d".to_string(), 3)
+            ]
+        );
+        assert_eq!(table.start_state(), 0);
+        assert_eq!(table.accept_states(), &[1, 3]);
+    }
+
+    #[test]
+    fn succeeds_with_single_transition_lookup() {
+        let table = RegExpBuilder::from(&["abc", "abd"])
+            .build_regexp()
+            .automaton_table();
+        assert_eq!(table.transition(0, "a"), Some(1));
+        assert_eq!(table.transition(2, "d"), Some(3));
+        assert_eq!(table.transition(0, "b"), None);
+        assert_eq!(table.transition(42, "a"), None);
+    }
+
+    #[test]
+    fn succeeds_with_converted_symbols() {
+        let table = RegExpBuilder::from(&["a1", "a22"])
+            .with_conversion_of(&[Feature::Digit])
+            .build_regexp()
+            .automaton_table();
+        assert_eq!(table.alphabet(), &["\\d", "a"]);
+        assert_eq!(table.accept_states(), &[2, 3]);
+    }
+
+    #[test]
+    fn succeeds_with_printed_table() {
+        let table = RegExpBuilder::from(&["abc", "abd", "a"])
+            .build_regexp()
+            .automaton_table();
+        assert_eq!(
+            table.to_string(),
+            concat!(
+                "   | a | b | c | d\n",
+                ">0 | 1 | - | - | -\n",
+                "*1 | - | 2 | - | -\n",
+                "2  | - | - | 3 | 3\n",
+                "*3 | - | - | - | -"
+            )
+        );
+    }
+}
+
+mod backreference_freedom {
+    use super::*;
+
+    #[rstest(test_cases, features,
+        case(vec!["abc", "abd"], vec![]),
+        case(vec!["aaa", "abab", "\\1", "\\k<name>"], vec![Feature::Repetition]),
+        case(vec!["a1", "b22", "c 333"], vec![Feature::Digit, Feature::Space, Feature::Repetition]),
+        case(vec!["ab", "cd"], vec![Feature::Word, Feature::NonWord, Feature::CapturingGroup])
+    )]
+    fn succeeds_with_generated_regexp(test_cases: Vec<&str>, features: Vec<Feature>) {
+        let mut builder = RegExpBuilder::from(&test_cases);
+        if !features.is_empty() {
+            builder.with_conversion_of(&features);
+        }
+        let regexp = builder.build_regexp();
+        assert!(regexp.is_backreference_free(), "{}", regexp);
+    }
+
+    #[test]
+    fn fails_with_injected_backreference() {
+        let regexp = RegExpBuilder::from(&["abc", "abd"])
+            .with_cluster_transformation(|cluster| {
+                cluster.map_values(|value| value.replace("b", "(b)\\1"))
+            })
+            .build_regexp();
+        assert!(!regexp.is_backreference_free());
+    }
+
+    #[test]
+    fn fails_with_injected_named_backreference() {
+        let regexp = RegExpBuilder::from(&["xyz"])
+            .with_cluster_transformation(|cluster| {
+                cluster.map_values(|value| value.replace("z", "\\k<name>"))
+            })
+            .build_regexp();
+        assert!(!regexp.is_backreference_free());
+    }
+}
+
+mod line_ending_normalization {
+    use super::*;
+
+    #[test]
+    fn succeeds_without_normalization() {
+        let regexp = RegExpBuilder::from(&["a\r\nb", "a\nb", "c\r\nd"]).build();
+        assert_eq!(regexp, "^(?:a(?:\\n|\\r\\n)b|c\\r\\nd)$");
+    }
+
+    #[rstest(line_ending, expected_output, normalized_test_cases,
+        case(LineEnding::Lf, "^(?:a\\nb|c\\nd)$", vec!["a\nb", "c\nd"]),
+        case(LineEnding::CrLf, "^(?:a\\r\\nb|c\\r\\nd)$", vec!["a\r\nb", "c\r\nd"])
+    )]
+    fn succeeds_with_normalization(
+        line_ending: LineEnding,
+        expected_output: &str,
+        normalized_test_cases: Vec<&str>,
+    ) {
+        let regexp = RegExpBuilder::from(&["a\r\nb", "a\nb", "c\r\nd"])
+            .with_line_ending_normalization(line_ending)
+            .build();
+        assert_eq!(regexp, expected_output);
+        test_if_regexp_matches_test_cases(&regexp, normalized_test_cases);
+        test_if_regexp_does_not_match_other_strings(&regexp, vec!["a\rb", "ab\r"]);
+    }
+
+    #[test]
+    fn succeeds_with_removal_of_normalized_duplicates() {
+        let (regexp, warnings) = RegExpBuilder::from(&["a\r\nb", "a\nb"])
+            .with_line_ending_normalization(LineEnding::Lf)
+            .build_with_warnings();
+        assert_eq!(regexp.to_string(), "^a\\nb$");
+        assert_eq!(
+            warnings,
+            vec![GenerationWarning::DuplicateTestCasesRemoved(1)]
+        );
+    }
+}
+
+mod optional_pattern {
+    use super::*;
+
+    #[rstest(test_cases, expected_output,
+        case(vec!["abc"], "^(?:abc)?$"),
+        case(vec!["abc", "abd"], "^(?:ab[cd])?$"),
+        case(vec!["a", "b"], "^[ab]?$"),
+        case(vec!["a", "bc"], "^(?:bc|a)?$"),
+        case(vec!["", "a"], "^a?$")
+    )]
+    fn succeeds(test_cases: Vec<&str>, expected_output: &str) {
+        let regexp = RegExpBuilder::from(&test_cases)
+            .with_optional_pattern()
+            .build();
+        assert_eq!(regexp, expected_output);
+        test_if_regexp_matches_test_cases(&regexp, test_cases);
+        test_if_regexp_matches_test_cases(&regexp, vec![""]);
+    }
+
+    #[test]
+    fn succeeds_with_capturing_group() {
+        let regexp = RegExpBuilder::from(&["abc", "abd"])
+            .with_conversion_of(&[Feature::CapturingGroup])
+            .with_optional_pattern()
+            .build();
+        assert_eq!(regexp, "^(ab[cd])?$");
+    }
+
+    #[test]
+    fn succeeds_without_matching_other_strings() {
+        let regexp = RegExpBuilder::from(&["abc", "abd"])
+            .with_optional_pattern()
+            .build();
+        test_if_regexp_does_not_match_other_strings(&regexp, vec!["ab", "abe", "abcabc"]);
+    }
+}
+
+mod repeated_tokens {
+    use super::*;
+
+    #[rstest(test_cases, separator, min, max, expected_output,
+        case(vec!["a", "b"], ",", 2, 4, "^[ab](?:,[ab]){1,3}$"),
+        case(vec!["foo", "bar"], ", ", 1, 3, "^(?:bar|foo)(?:, (?:bar|foo)){0,2}$"),
+        case(vec!["foo", "bar"], ".", 1, 2, "^(?:bar|foo)(?:\\.(?:bar|foo))?$"),
+        case(vec!["foo", "bar"], "|", 3, 3, "^(?:bar|foo)(?:\\|(?:bar|foo)){2}$"),
+        case(vec!["foo", "bar"], "+", 2, 2, "^(?:bar|foo)\\+(?:bar|foo)$"),
+        case(vec!["abc"], ";", 1, 1, "^abc$")
+    )]
+    fn succeeds(test_cases: Vec<&str>, separator: &str, min: u32, max: u32, expected_output: &str) {
+        let regexp = RegExpBuilder::from(&test_cases)
+            .with_repeated_tokens(separator, min, max)
+            .build();
+        test_if_regexp_is_correct(regexp, expected_output, &test_cases);
+    }
+
+    #[test]
+    fn succeeds_with_lists_of_tokens() {
+        let regexp = RegExpBuilder::from(&["red", "green", "blue"])
+            .with_repeated_tokens(",", 2, 4)
+            .build();
+        test_if_regexp_matches_test_cases(
+            &regexp,
+            vec!["red,green", "blue,blue,red", "green,red,blue,red"],
+        );
+        test_if_regexp_does_not_match_other_strings(
+            &regexp,
+            vec![
+                "red",
+                "red,",
+                "red,yellow",
+                "red,green,blue,red,green",
+                "red;green",
+            ],
+        );
+    }
+
+    #[test]
+    fn succeeds_with_posix_basic_flavor() {
+        let regexp = RegExpBuilder::from(&["foo", "bar"])
+            .with_repeated_tokens("+", 2, 4)
+            .with_flavor(Flavor::PosixBasic)
+            .build();
+        assert_eq!(regexp, "^\\(bar\\|foo\\)\\(+\\(bar\\|foo\\)\\)\\{1,3\\}$");
+    }
+
+    #[test]
+    fn succeeds_with_warning_in_verbose_mode() {
+        let (regexp, warnings) = RegExpBuilder::from(&["a", "b"])
+            .with_repeated_tokens(",", 2, 4)
+            .with_verbose_mode()
+            .build_with_warnings();
+        assert!(!regexp.to_string().contains(','));
+        assert_eq!(
+            warnings,
+            vec![GenerationWarning::SettingIgnored(
+                "repeated tokens are not supported in verbose mode".to_string()
+            )]
+        );
+    }
+}
+
+mod scoped_ignore_case_flag {
+    use super::*;
+
+    #[rstest(test_cases, features, is_flag_scoped, expected_output,
+        case(vec!["abc", "ABD"], vec![Feature::CaseInsensitivity], false, "(?i)^ab[cd]$"),
+        case(vec!["abc", "ABD"], vec![Feature::CaseInsensitivity], true, "^(?i:ab[cd])$"),
+        case(vec!["abc", "xyz"], vec![Feature::CaseInsensitivity], false, "(?i)^(?:abc|xyz)$"),
+        case(vec!["abc", "xyz"], vec![Feature::CaseInsensitivity], true, "^(?i:abc|xyz)$"),
+        case(vec!["abc", "xyz"], vec![Feature::CaseInsensitivity, Feature::CapturingGroup], true, "^(?i:(abc|xyz))$"),
+        case(vec!["123", "45"], vec![Feature::CaseInsensitivity], true, "^(?:123|45)$"),
+        case(vec!["abc", "xyz"], vec![], true, "^(?:abc|xyz)$")
+    )]
+    fn succeeds(
+        test_cases: Vec<&str>,
+        features: Vec<Feature>,
+        is_flag_scoped: bool,
+        expected_output: &str,
+    ) {
+        let mut builder = RegExpBuilder::from(&test_cases);
+        if !features.is_empty() {
+            builder.with_conversion_of(&features);
+        }
+        if is_flag_scoped {
+            builder.with_scoped_ignore_case_flag();
+        }
+        let regexp = builder.build();
+        test_if_regexp_is_correct(regexp, expected_output, &test_cases);
+        test_if_regexp_matches_test_cases(expected_output, test_cases);
+    }
+
+    #[test]
+    fn succeeds_with_anchors_outside_of_scoped_group() {
+        let regexp = RegExpBuilder::from(&["abc", "xyz"])
+            .with_conversion_of(&[Feature::CaseInsensitivity])
+            .with_scoped_ignore_case_flag()
+            .build();
+        test_if_regexp_matches_test_cases(&regexp, vec!["ABC", "xYz"]);
+        test_if_regexp_does_not_match_other_strings(&regexp, vec!["abcx", "xxyz"]);
+    }
+
+    #[test]
+    fn succeeds_with_verbose_mode() {
+        let regexp = RegExpBuilder::from(&["abc", "xyz"])
+            .with_conversion_of(&[Feature::CaseInsensitivity])
+            .with_scoped_ignore_case_flag()
+            .with_verbose_mode()
+            .build();
+        assert_eq!(regexp, "(?x)\n^\n(?i:\n  abc\n  |\n  xyz\n)\n$");
+    }
+}
+
+mod optional_affixes {
+    use super::*;
+
+    #[rstest(test_cases, expected_output,
+        case(vec!["#tag", "tag"], "^#?tag$"),
+        case(vec!["#tag", "tag", "#tags", "tags"], "^#?tags?$"),
+        case(vec!["@user", "user", "@users", "users"], "^@?users?$"),
+        case(vec!["tag", "tags"], "^tags?$"),
+        case(vec!["v1.0", "1.0", "v1.0.0", "1.0.0"], "^v?1\\.0(?:\\.0)?$")
+    )]
+    fn succeeds(test_cases: Vec<&str>, expected_output: &str) {
+        let regexp = RegExpBuilder::from(&test_cases).build();
+        test_if_regexp_is_correct(regexp, expected_output, &test_cases);
+        test_if_regexp_matches_test_cases(expected_output, test_cases);
+    }
+
+    #[test]
+    fn succeeds_with_leading_optional_char_matching_test_cases_only() {
+        let test_cases = vec!["#tag", "tag", "#tags", "tags"];
+        let regexp = RegExpBuilder::from(&test_cases).build_regexp();
+        let other_strings = vec!["##tag", "tag#", "#ta", "tagss", "tas"];
+        let sample = test_cases
+            .iter()
+            .chain(other_strings.iter())
+            .map(|it| it.to_string())
+            .collect::<Vec<_>>();
+        let stats = regexp.match_stats(&sample);
+        assert_eq!(stats.matched_count(), test_cases.len());
+        test_if_regexp_does_not_match_other_strings(&regexp.to_string(), other_strings);
+    }
+
+    #[test]
+    fn succeeds_with_trailing_optional_char_matching_test_cases_only() {
+        let test_cases = vec!["#tag", "#tags", "#tag!", "#tags!"];
+        let regexp = RegExpBuilder::from(&test_cases).build_regexp();
+        assert_eq!(regexp.to_string(), "^#tags?!?$");
+        let other_strings = vec!["tag", "#tag!!", "#tag!s", "#ta!"];
+        let sample = test_cases
+            .iter()
+            .chain(other_strings.iter())
+            .map(|it| it.to_string())
+            .collect::<Vec<_>>();
+        let stats = regexp.match_stats(&sample);
+        assert_eq!(stats.matched_count(), test_cases.len());
+        test_if_regexp_does_not_match_other_strings(&regexp.to_string(), other_strings);
+    }
+}
+
+mod match_stats {
+    use super::*;
+
+    #[rstest(test_cases, features, sample, expected_matched_count,
+        case(vec!["abc", "abd"], vec![], vec!["abc", "abd", "abe", "ab", ""], 2),
+        case(vec!["a1", "a22"], vec![Feature::Digit], vec!["a1", "a22", "a333", "a", "b1"], 2),
+        case(vec!["a1", "a22"], vec![Feature::Digit, Feature::Repetition], vec!["a1", "a22", "a333", "a", "b1"], 2),
+        case(vec!["abc", "abd"], vec![Feature::CaseInsensitivity], vec!["ABC", "aBd", "abe"], 2)
+    )]
+    fn succeeds_with_sample(
+        test_cases: Vec<&str>,
+        features: Vec<Feature>,
+        sample: Vec<&str>,
+        expected_matched_count: usize,
+    ) {
+        let mut builder = RegExpBuilder::from(&test_cases);
+        if !features.is_empty() {
+            builder.with_conversion_of(&features);
+        }
+        let regexp = builder.build_regexp();
+        let sample = sample.iter().map(|it| it.to_string()).collect::<Vec<_>>();
+        let stats = regexp.match_stats(&sample);
+
+        let re = Regex::new(&regexp.to_string()).unwrap();
+        let regex_matched_count = sample.iter().filter(|it| re.is_match(it)).count();
+
+        assert_eq!(stats.sample_count(), sample.len());
+        assert_eq!(stats.matched_count(), expected_matched_count);
+        assert_eq!(stats.matched_count(), regex_matched_count);
+        assert_eq!(
+            stats.unmatched_count(),
+            sample.len() - expected_matched_count
+        );
+    }
+
+    #[test]
+    fn succeeds_with_matched_ratio() {
+        let regexp = RegExpBuilder::from(&["abc", "abd"]).build_regexp();
+        let sample = vec!["abc".to_string(), "abx".to_string()];
+        assert_eq!(regexp.match_stats(&sample).matched_ratio(), Some(0.5));
+        assert_eq!(regexp.match_stats(&[]).matched_ratio(), None);
+    }
+}
+
+mod structured_semver {
+    use super::*;
+
+    #[rstest(test_cases, expected_output,
+        case(vec!["1.2.3", "10.20.30"], "^\\d+\\.\\d+\\.\\d+$"),
+        case(vec!["1.2.3", "1.2.3-beta.1"], "^\\d+\\.\\d+\\.\\d+(?:\\-[\\w.]+)?$"),
+        case(vec!["1.0.0+build.5", "0.9.1"], "^\\d+\\.\\d+\\.\\d+(?:\\+[\\w.]+)?$"),
+        case(
+            vec!["1.0.0-alpha", "1.0.0-rc.1+exp.sha.5114f85", "2.0.0"],
+            "^\\d+\\.\\d+\\.\\d+(?:\\-[\\w.]+)?(?:\\+[\\w.]+)?$"
+        )
+    )]
+    fn succeeds(test_cases: Vec<&str>, expected_output: &str) {
+        let regexp = RegExpBuilder::from(&test_cases)
+            .with_structured_semver()
+            .build();
+        test_if_regexp_is_correct(regexp, expected_output, &test_cases);
+        test_if_regexp_matches_test_cases(expected_output, test_cases);
+    }
+
+    #[rstest(test_cases, expected_output,
+        case(vec!["1.2.3", "abc"], "^(?:1\\.2\\.3|abc)$"),
+        case(vec!["1.2"], "^1\\.2$"),
+        case(vec!["1.2.3.4"], "^1\\.2\\.3\\.4$"),
+        case(vec!["1.2.3-"], "^1\\.2\\.3\\-$"),
+        case(vec!["1.2.3-rc-1"], "^1\\.2\\.3\\-rc\\-1$"),
+        case(vec!["v1.2.3"], "^v1\\.2\\.3$")
+    )]
+    fn succeeds_with_fallback(test_cases: Vec<&str>, expected_output: &str) {
+        let regexp = RegExpBuilder::from(&test_cases)
+            .with_structured_semver()
+            .build();
+        test_if_regexp_is_correct(regexp, expected_output, &test_cases);
+        test_if_regexp_matches_test_cases(expected_output, test_cases);
+    }
+
+    #[test]
+    fn succeeds_with_warnings() {
+        let (regexp, warnings) = RegExpBuilder::from(&["1.2.3-beta"])
+            .with_structured_semver()
+            .build_with_warnings();
+        assert_eq!(regexp.to_string(), "^\\d+\\.\\d+\\.\\d+(?:\\-[\\w.]+)?$");
+        assert_eq!(
+            warnings,
+            vec![
+                GenerationWarning::CharacterClassGeneralized("\\d".to_string()),
+                GenerationWarning::CharacterClassGeneralized("\\w".to_string())
+            ]
+        );
+    }
+
+    #[test]
+    fn succeeds_with_posix_flavor() {
+        let (regexp, warnings) = RegExpBuilder::from(&["1.2.3"])
+            .with_structured_semver()
+            .with_flavor(Flavor::PosixExtended)
+            .build_with_warnings();
+        assert_eq!(regexp.to_string(), "^1\\.2\\.3$");
+        assert_eq!(
+            warnings,
+            vec![GenerationWarning::SettingIgnored(
+                "structured semantic versions are not supported by the chosen flavor".to_string()
+            )]
+        );
+    }
+}
+
+mod frequency_ordering {
+    use super::*;
+
+    #[rstest(test_cases, expected_output,
+        case(vec!["abc", "xyz", "xyz"], "^(?:xyz|abc)$"),
+        case(vec!["abc", "def", "def", "def", "xyz", "xyz"], "^(?:def|xyz|abc)$"),
+        case(vec!["abc", "xyz"], "^(?:abc|xyz)$"),
+        case(vec!["a1", "a22", "bc", "bc", "bc"], "^(?:bc|a(?:22|1))$")
+    )]
+    fn succeeds(test_cases: Vec<&str>, expected_output: &str) {
+        let regexp = RegExpBuilder::from(&test_cases)
+            .with_frequency_ordered_branches()
+            .build();
+        test_if_regexp_is_correct(regexp, expected_output, &test_cases);
+        test_if_regexp_matches_test_cases(expected_output, test_cases);
+    }
+
+    #[test]
+    fn succeeds_with_removal_of_duplicates() {
+        let (regexp, warnings) = RegExpBuilder::from(&["abc", "xyz", "xyz"])
+            .with_frequency_ordered_branches()
+            .build_with_warnings();
+        assert_eq!(regexp.to_string(), "^(?:xyz|abc)$");
+        assert_eq!(
+            warnings,
+            vec![GenerationWarning::DuplicateTestCasesRemoved(1)]
+        );
+    }
+
+    #[test]
+    fn succeeds_with_repeated_builds() {
+        let mut builder = RegExpBuilder::from(&["abc", "xyz", "xyz", "xyz"]);
+        builder.with_frequency_ordered_branches();
+        assert_eq!(builder.build(), "^(?:xyz|abc)$");
+        assert_eq!(builder.build(), "^(?:xyz|abc)$");
+    }
+}
+
+mod equivalence {
+    use super::*;
+
+    #[test]
+    fn succeeds_with_different_order_of_test_cases() {
+        let first_regexp = RegExpBuilder::from(&["abc", "xyz", "abd"]).build_regexp();
+        let second_regexp = RegExpBuilder::from(&["xyz", "abd", "abc", "abc"]).build_regexp();
+        assert!(first_regexp.is_equivalent(&second_regexp));
+    }
+
+    #[test]
+    fn succeeds_with_different_surface_forms() {
+        let test_cases = vec!["abc", "abd", "xyz"];
+        let first_regexp = RegExpBuilder::from(&test_cases).build_regexp();
+        let second_regexp = RegExpBuilder::from(&test_cases)
+            .with_conversion_of(&[Feature::CapturingGroup])
+            .with_verbose_mode()
+            .build_regexp();
+        let third_regexp = RegExpBuilder::from(&test_cases)
+            .with_frequency_ordered_branches()
+            .with_flavor(Flavor::PosixExtended)
+            .build_regexp();
+        assert_ne!(first_regexp.to_string(), second_regexp.to_string());
+        assert_ne!(first_regexp.to_string(), third_regexp.to_string());
+        assert!(first_regexp.is_equivalent(&second_regexp));
+        assert!(first_regexp.is_equivalent(&third_regexp));
+        assert!(second_regexp.is_equivalent(&third_regexp));
+    }
+
+    #[test]
+    fn succeeds_with_suffix_factoring() {
+        let test_cases = vec!["abxy", "cdxy", "ef"];
+        let first_regexp = RegExpBuilder::from(&test_cases).build_regexp();
+        let second_regexp = RegExpBuilder::from(&test_cases)
+            .with_suffix_factoring()
+            .build_regexp();
+        assert!(first_regexp.is_equivalent(&second_regexp));
+    }
+
+    #[rstest(first_test_cases, second_test_cases,
+        case(vec!["abc", "abd"], vec!["abc"]),
+        case(vec!["abc"], vec!["abc", ""]),
+        case(vec!["a"], vec!["b"])
+    )]
+    fn fails_with_different_test_cases(first_test_cases: Vec<&str>, second_test_cases: Vec<&str>) {
+        let first_regexp = RegExpBuilder::from(&first_test_cases).build_regexp();
+        let second_regexp = RegExpBuilder::from(&second_test_cases).build_regexp();
+        assert!(!first_regexp.is_equivalent(&second_regexp));
+        assert!(!second_regexp.is_equivalent(&first_regexp));
+    }
+}
+
+mod bounded_quantifiers {
+    use super::*;
+
+    #[rstest(test_cases, expected_output,
+        case(vec!["aa", "aaa", "aaaa"], "^a{2,4}$"),
+        case(vec!["xaa", "xaaa", "y"], "^(?:xa{2,3}|y)$"),
+        case(vec!["abc", "abd", ""], "^(?:ab[cd])?$")
+    )]
+    fn succeeds(test_cases: Vec<&str>, expected_output: &str) {
+        let regexp = RegExpBuilder::from(&test_cases)
+            .with_conversion_of(&[Feature::Repetition])
+            .with_open_ended_repetitions()
+            .with_bounded_quantifiers_only()
+            .build();
+        test_if_regexp_is_correct(regexp.clone(), expected_output, &test_cases);
+        test_if_regexp_matches_test_cases(expected_output, test_cases);
+        assert!(!regexp.contains('*') && !regexp.contains('+') && !regexp.contains(",}"));
+    }
+
+    #[test]
+    fn succeeds_with_warning() {
+        let (regexp, warnings) = RegExpBuilder::from(&["1.2.3", "1.2.3-beta"])
+            .with_structured_semver()
+            .with_bounded_quantifiers_only()
+            .build_with_warnings();
+        assert_eq!(regexp.to_string(), "^1\\.2\\.3(?:\\-beta)?$");
+        assert_eq!(
+            warnings,
+            vec![GenerationWarning::SettingIgnored(
+                "unbounded quantifiers are disabled by bounded-only generation".to_string()
+            )]
+        );
+    }
+
+    #[test]
+    fn fails_with_optional_whitespace() {
+        let result = RegExpBuilder::from(&["a,b", "a , b"])
+            .with_optional_whitespace_around(&[','])
+            .with_bounded_quantifiers_only()
+            .try_build_regexp();
+        assert_eq!(
+            result.err(),
+            Some(GenerationError::UnboundedQuantifierRequired)
+        );
+    }
+}
+
+mod common_affixes {
+    use super::*;
+
+    #[rstest(test_cases, expected_prefix, expected_suffix,
+        case(vec!["foo.txt", "foobar.txt", "food.txt"], "foo", ".txt"),
+        case(vec!["abc", "xyz"], "", ""),
+        case(vec!["abc", "abd"], "ab", ""),
+        case(vec!["xbc", "ybc"], "", "bc"),
+        case(vec!["abc"], "abc", "abc"),
+        case(vec!["ab", "abab"], "ab", "ab"),
+        case(vec!["", "abc"], "", ""),
+        case(vec!["y̆a", "y̆b"], "y̆", ""),
+        case(vec!["ay̆", "by\u{306}"], "", "y̆")
+    )]
+    fn succeeds(test_cases: Vec<&str>, expected_prefix: &str, expected_suffix: &str) {
+        let regexp = RegExpBuilder::from(&test_cases).build_regexp();
+        assert_eq!(
+            regexp.common_affixes(),
+            (expected_prefix.to_string(), expected_suffix.to_string())
+        );
+    }
+
+    #[test]
+    fn succeeds_without_splitting_graphemes() {
+        let regexp = RegExpBuilder::from(&["y\u{306}", "yz"]).build_regexp();
+        assert_eq!(regexp.common_affixes(), (String::new(), String::new()));
+    }
+}
+
+mod class_syntax_preference {
+    use super::*;
+
+    #[rstest(flavor, class_syntaxes, test_cases, expected_output,
+        case(Flavor::Rust, vec![ClassSyntax::Perl, ClassSyntax::Posix], vec!["1", "2"], "^\\d$"),
+        case(Flavor::Rust, vec![ClassSyntax::Posix, ClassSyntax::Perl], vec!["1", "2"], "^[[:digit:]]$"),
+        case(Flavor::Rust, vec![ClassSyntax::Literal, ClassSyntax::Perl], vec!["1", "2"], "^[0-9]$"),
+        case(Flavor::PosixExtended, vec![ClassSyntax::Perl, ClassSyntax::Posix], vec!["1", "2"], "^[[:digit:]]$"),
+        case(Flavor::PosixExtended, vec![ClassSyntax::Perl, ClassSyntax::Literal], vec!["1", "2"], "^[0-9]$"),
+        case(Flavor::PosixExtended, vec![ClassSyntax::Perl], vec!["1", "2"], "^[[:digit:]]$"),
+        case(Flavor::Pcre, vec![ClassSyntax::Literal], vec!["a1", "b2"], "^[0-9A-Z_a-z][0-9]$"),
+        case(Flavor::Pcre, vec![ClassSyntax::Literal], vec![" ", "\t"], "^[\\t\\n\\x0B\\f\\r\\x20]$"),
+        case(Flavor::PosixBasic, vec![ClassSyntax::Literal], vec![" ", "\t"], "^[[:space:]]$")
+    )]
+    fn succeeds_with_class_syntax_preference(
+        flavor: Flavor,
+        class_syntaxes: Vec<ClassSyntax>,
+        test_cases: Vec<&str>,
+        expected_output: &str,
+    ) {
+        let regexp = RegExpBuilder::from(&test_cases)
+            .with_conversion_of(&[Feature::Digit, Feature::Space, Feature::Word])
+            .with_flavor(flavor)
+            .with_class_syntax_preference(&class_syntaxes)
+            .build();
+        assert_eq!(regexp.to_string(), expected_output);
+    }
+
+    #[test]
+    fn succeeds_with_literal_classes_matching_test_cases() {
+        let test_cases = vec!["a1 ", "b2\t"];
+        let regexp = RegExpBuilder::from(&test_cases)
+            .with_conversion_of(&[Feature::Digit, Feature::Space, Feature::Word])
+            .with_class_syntax_preference(&[ClassSyntax::Literal])
+            .build();
+        test_if_regexp_matches_test_cases(&regexp.to_string(), test_cases);
+    }
+
+    #[rstest(class_syntax, test_cases, features, expected_output,
+        case(ClassSyntax::Posix, vec!["٣"], vec![Feature::Digit], "^٣$"),
+        case(ClassSyntax::Literal, vec!["٣"], vec![Feature::Digit], "^٣$"),
+        case(ClassSyntax::Posix, vec!["é1", "b2"], vec![Feature::Word], "^(?:[[:alnum:]_]|é)[[:alnum:]_]$"),
+        case(ClassSyntax::Literal, vec!["é1", "b2"], vec![Feature::Word], "^(?:[0-9A-Z_a-z]|é)[0-9A-Z_a-z]$"),
+        case(ClassSyntax::Posix, vec!["a\u{3000}"], vec![Feature::Space, Feature::Word], "^[[:alnum:]_]\u{3000}$")
+    )]
+    fn succeeds_with_conversion_of_ascii_chars_only(
+        class_syntax: ClassSyntax,
+        test_cases: Vec<&str>,
+        features: Vec<Feature>,
+        expected_output: &str,
+    ) {
+        let regexp = RegExpBuilder::from(&test_cases)
+            .with_conversion_of(&features)
+            .with_class_syntax_preference(&[class_syntax])
+            .build();
+        test_if_regexp_is_correct(regexp, expected_output, &test_cases);
+        test_if_regexp_matches_test_cases(expected_output, test_cases);
+    }
+}
+
+mod mutations {
+    use super::*;
+
+    #[rstest(test_cases, mutations, expected_output,
+        case(vec!["a1"], vec![Mutation::DigitForDigit], "^a\\d$"),
+        case(vec!["a12", "b3"], vec![Mutation::DigitForDigit], "^(?:b\\d|a\\d{2})$"),
+        case(vec!["Ab1"], vec![Mutation::CaseFlip], "(?i)^ab1$"),
+        case(vec!["Ab1"], vec![Mutation::DigitForDigit, Mutation::CaseFlip], "(?i)^ab\\d$")
+    )]
+    fn succeeds_with_mutations(
+        test_cases: Vec<&str>,
+        mutations: Vec<Mutation>,
+        expected_output: &str,
+    ) {
+        let regexp = RegExpBuilder::from(&test_cases)
+            .with_conversion_of(&[Feature::Repetition])
+            .with_mutations(&mutations)
+            .build();
+        test_if_regexp_is_correct(regexp, expected_output, &test_cases);
+        test_if_regexp_matches_test_cases(expected_output, test_cases);
+    }
+
+    #[test]
+    fn succeeds_with_mutated_test_cases() {
+        let regexp = RegExpBuilder::from(&["a1", "B2"])
+            .with_mutations(&[Mutation::DigitForDigit, Mutation::CaseFlip])
+            .build();
+        let expected_output = "(?i)^[ab]\\d$";
+        assert_eq!(regexp.to_string(), expected_output);
+        test_if_regexp_matches_test_cases(expected_output, vec!["a2", "A9", "b0", "B1"]);
+        test_if_regexp_does_not_match_other_strings(expected_output, vec!["aa", "c1", "a12"]);
+    }
+
+    #[rstest(test_cases, quantity, expected_output,
+        case(vec!["form"], 1, "^(?:fomr|f(?:or|ro)m|ofrm)$"),
+        case(vec!["form"], 2, "^(?:f(?:rom|o(?:rm|mr))|of(?:rm|mr))$"),
+        case(vec!["aab"], 1, "^a(?:ab|ba)$"),
+        case(vec!["a"], 1, "^a$")
+    )]
+    fn succeeds_with_transpositions(test_cases: Vec<&str>, quantity: u32, expected_output: &str) {
+        let regexp = RegExpBuilder::from(&test_cases)
+            .with_mutations(&[Mutation::Transposition(quantity)])
+            .build();
+        test_if_regexp_is_correct(regexp, expected_output, &test_cases);
+        test_if_regexp_matches_test_cases(expected_output, test_cases);
+    }
+
+    #[test]
+    fn succeeds_with_transposed_test_cases() {
+        let regexp = RegExpBuilder::from(&["form", "receive"])
+            .with_mutations(&[Mutation::Transposition(1)])
+            .build();
+        test_if_regexp_matches_test_cases(&regexp, vec!["form", "from", "receive", "recieve"]);
+        test_if_regexp_does_not_match_other_strings(&regexp, vec!["rfom", "morf", "ercieve"]);
+    }
+
+    #[test]
+    fn warns_about_ignored_mutations() {
+        let (regexp, warnings) = RegExpBuilder::from(&["a1"])
+            .with_mutations(&[Mutation::DigitForDigit, Mutation::CaseFlip])
+            .with_flavor(Flavor::PosixExtended)
+            .build_with_warnings();
+        assert_eq!(regexp.to_string(), "^a[[:digit:]]$");
+        assert_eq!(
+            warnings,
+            vec![GenerationWarning::SettingIgnored(
+                "case-insensitive matching is not supported by the chosen flavor".to_string()
+            )]
+        );
+    }
+}
+
+mod pattern_fragments {
+    use super::*;
+
+    #[rstest(test_cases, flavor, expected_output,
+        case(vec!["\\d+", "[a-z]{2}"], Flavor::Rust, "^(?:\\d+|[a-z]{2})$"),
+        case(vec!["a.b"], Flavor::Rust, "^a.b$"),
+        case(vec!["\\."], Flavor::Rust, "^\\.$"),
+        case(vec!["a", "b", "."], Flavor::Rust, "^(?:.|a|b)$"),
+        case(vec!["a|b", "c"], Flavor::Rust, "^(?:c|(?:a|b))$"),
+        case(vec!["a+?", "(?:ab)*", "[]a]"], Flavor::Pcre, "^(?:a+?|[]a]|(?:ab)*)$"),
+        case(vec!["a|b", "[[:alpha:]]"], Flavor::PosixExtended, "^((a|b)|[[:alpha:]])$"),
+        case(vec!["a\\|b", "x\\{2\\}"], Flavor::PosixBasic, "^\\(\\(a\\|b\\)\\|x\\{2\\}\\)$")
+    )]
+    fn succeeds_with_pattern_fragments(
+        test_cases: Vec<&str>,
+        flavor: Flavor,
+        expected_output: &str,
+    ) {
+        let regexp = RegExpBuilder::from(&test_cases)
+            .with_flavor(flavor)
+            .with_test_cases_as_patterns()
+            .build();
+        assert_eq!(regexp.to_string(), expected_output);
+    }
+
+    #[test]
+    fn succeeds_with_matching_of_pattern_fragments() {
+        let expected_output = RegExpBuilder::from(&["\\d{2}", "[a-c]+"])
+            .with_test_cases_as_patterns()
+            .build();
+        test_if_regexp_matches_test_cases(&expected_output, vec!["12", "abcab"]);
+        test_if_regexp_does_not_match_other_strings(
+            &expected_output,
+            vec!["\\d{2}", "[a-c]+", "1", "abd"],
+        );
+    }
+
+    #[rstest(
+        test_case,
+        flavor,
+        case("(ab", Flavor::Rust),
+        case("a)", Flavor::Rust),
+        case("[ab", Flavor::Rust),
+        case("a\\", Flavor::Rust),
+        case("*a", Flavor::Rust),
+        case("a**", Flavor::Rust),
+        case("a|+", Flavor::Pcre),
+        case("\\(ab", Flavor::PosixBasic),
+        case("a+?", Flavor::PosixExtended)
+    )]
+    fn fails_with_invalid_pattern_fragment(test_case: &str, flavor: Flavor) {
+        let result = RegExpBuilder::from(&["abc", test_case])
+            .with_flavor(flavor)
+            .with_test_cases_as_patterns()
+            .try_build_regexp();
+        assert_eq!(
+            result.map(|it| it.to_string()),
+            Err(GenerationError::InvalidPatternFragment(
+                test_case.to_string()
+            ))
+        );
+    }
+
+    #[test]
+    fn warns_about_ignored_settings_with_pattern_fragments() {
+        let (regexp, warnings) = RegExpBuilder::from(&["\\d", "a"])
+            .with_conversion_of(&[Feature::Digit])
+            .with_test_cases_as_patterns()
+            .build_with_warnings();
+        assert_eq!(regexp.to_string(), "^(?:a|\\d)$");
+        assert_eq!(
+            warnings,
+            vec![GenerationWarning::SettingIgnored(
+                "generalizing settings and verbose mode are disabled by pattern fragments"
+                    .to_string()
+            )]
+        );
+    }
+}
+
+mod lookaround_assertions {
+    use super::*;
+
+    #[rstest(
+        lookbehind,
+        lookahead,
+        expected_output,
+        case(Some("\\d"), None, "(?<=\\d)(?:abc|def)$"),
+        case(None, Some("x"), "^(?:abc|def)(?=x)"),
+        case(Some("a|b"), Some("[0-9]+"), "(?<=a|b)(?:abc|def)(?=[0-9]+)")
+    )]
+    fn succeeds_with_lookaround_assertions(
+        lookbehind: Option<&str>,
+        lookahead: Option<&str>,
+        expected_output: &str,
+    ) {
+        let mut builder = RegExpBuilder::from(&["abc", "def"]);
+        builder.with_flavor(Flavor::Pcre);
+        if let Some(assertion) = lookbehind {
+            builder.with_lookbehind(assertion);
+        }
+        if let Some(assertion) = lookahead {
+            builder.with_lookahead(assertion);
+        }
+        let (regexp, warnings) = builder.build_with_warnings();
+        assert_eq!(regexp.to_string(), expected_output);
+        assert!(warnings.is_empty());
+    }
+
+    #[test]
+    fn succeeds_with_lookaround_assertions_replacing_word_boundaries() {
+        let (regexp, warnings) = RegExpBuilder::from(&["abc"])
+            .with_flavor(Flavor::Pcre)
+            .with_word_boundary_at_start()
+            .with_word_boundary_at_end()
+            .with_lookbehind("\\d")
+            .build_with_warnings();
+        assert_eq!(regexp.to_string(), "(?<=\\d)abc\\b");
+        assert_eq!(
+            warnings,
+            vec![GenerationWarning::SettingIgnored(
+                "word boundaries are replaced by lookaround assertions".to_string()
+            )]
+        );
+    }
+
+    #[rstest(
+        flavor,
+        expected_output,
+        case(Flavor::Rust, "^abc$"),
+        case(Flavor::PosixExtended, "^abc$")
+    )]
+    fn succeeds_with_lookaround_assertions_ignored_by_unsupported_flavor(
+        flavor: Flavor,
+        expected_output: &str,
+    ) {
+        let (regexp, warnings) = RegExpBuilder::from(&["abc"])
+            .with_flavor(flavor)
+            .with_lookbehind("\\d")
+            .with_lookahead("x")
+            .build_with_warnings();
+        assert_eq!(regexp.to_string(), expected_output);
+        assert_eq!(
+            warnings,
+            vec![GenerationWarning::SettingIgnored(
+                "lookaround assertions are not supported by the chosen flavor".to_string()
+            )]
+        );
+    }
+}
+
+mod min_states {
+    use super::*;
+
+    #[rstest(test_cases, expected_state_count,
+        case(vec![""], 1),
+        case(vec!["a"], 2),
+        case(vec!["a", "aa"], 3),
+        case(vec!["ab", "cb"], 3),
+        case(vec!["abc", "def"], 6),
+        case(vec!["abc", "abd", "abe"], 4)
+    )]
+    fn succeeds_with_min_states(test_cases: Vec<&str>, expected_state_count: usize) {
+        let regexp = RegExpBuilder::from(&test_cases).build_regexp();
+        assert_eq!(regexp.min_states(), expected_state_count);
+    }
+
+    #[test]
+    fn succeeds_with_min_states_independent_of_flavor() {
+        let test_cases = vec!["abc", "abd", "xbc"];
+        let rust = RegExpBuilder::from(&test_cases).build_regexp();
+        let posix = RegExpBuilder::from(&test_cases)
+            .with_flavor(Flavor::PosixBasic)
+            .build_regexp();
+        assert_eq!(rust.min_states(), posix.min_states());
+    }
+}
+
+mod split_under_limit {
+    use super::*;
+
+    #[test]
+    fn succeeds_with_pattern_fitting_into_limit() {
+        let regexp = RegExpBuilder::from(&["abc", "def"]).build_regexp();
+        assert_eq!(regexp.split_under_limit(100), vec!["^(?:abc|def)$"]);
+    }
+
+    #[rstest(max_len, case(20), case(50), case(200))]
+    fn succeeds_with_large_alternation(max_len: usize) {
+        let test_cases = (0..100)
+            .map(|i| format!("item{}x{}", i * 7919 % 1000, i))
+            .collect::<Vec<_>>();
+        let regexp = RegExpBuilder::from(&test_cases).build_regexp();
+        assert!(regexp.to_string().len() > max_len);
+
+        let patterns = regexp.split_under_limit(max_len);
+        assert!(patterns.len() > 1);
+
+        let regexes = patterns
+            .iter()
+            .map(|pattern| {
+                assert!(pattern.len() <= max_len, "{} exceeds the limit", pattern);
+                assert!(pattern.starts_with('^') && pattern.ends_with('$'));
+                Regex::new(pattern).unwrap()
+            })
+            .collect::<Vec<_>>();
+        for test_case in test_cases.iter() {
+            assert!(
+                regexes.iter().any(|regex| regex.is_match(test_case)),
+                "{} is not matched by any pattern",
+                test_case
+            );
+        }
+    }
+
+    #[test]
+    fn succeeds_with_test_case_exceeding_limit() {
+        let regexp = RegExpBuilder::from(&["abcdefghij", "k"]).build_regexp();
+        assert_eq!(regexp.split_under_limit(5), vec!["^k$", "^abcdefghij$"]);
+    }
+}
+
+mod subpattern_definitions {
+    use super::*;
+
+    #[rstest(test_cases, expected_output,
+        case(
+            vec!["xfooy", "xbary", "zfoow", "zbarw"],
+            "(?(DEFINE)(?P<sub1>bar|foo))^(?:x(?&sub1)y|z(?&sub1)w)$"
+        ),
+        case(
+            vec!["xfooy", "xbary", "zfoow", "zbarw", "1cat2", "1dog2", "3cat4", "3dog4"],
+            "(?(DEFINE)(?P<sub1>cat|dog)(?P<sub2>bar|foo))^(?:1(?&sub1)2|3(?&sub1)4|x(?&sub2)y|z(?&sub2)w)$"
+        ),
+        case(
+            vec!["xfooy", "xbary", "xfoow", "xbarw"],
+            "^x(?:bar[wy]|foo[wy])$"
+        ),
+        case(
+            vec!["(cat", "(dog", "zcat", "zdog"],
+            "^[(z](?:cat|dog)$"
+        ),
+        case(
+            vec!["(foo)", "(bar)", "[foo]", "[bar]"],
+            "(?(DEFINE)(?P<sub1>bar|foo))^(?:\\((?&sub1)\\)|\\[(?&sub1)\\])$"
+        )
+    )]
+    fn succeeds_with_subpattern_definitions(test_cases: Vec<&str>, expected_output: &str) {
+        let regexp = RegExpBuilder::from(&test_cases)
+            .with_flavor(Flavor::Pcre)
+            .with_subpattern_definitions()
+            .build();
+        test_if_regexp_is_correct(regexp, expected_output, &test_cases);
+    }
+
+    #[test]
+    fn succeeds_with_subpattern_definitions_after_flags() {
+        let test_cases = vec!["xfooy", "xbary", "zfoow", "zbarw"];
+        let regexp = RegExpBuilder::from(&test_cases)
+            .with_conversion_of(&[Feature::CapturingGroup, Feature::CaseInsensitivity])
+            .with_flavor(Flavor::Pcre)
+            .with_subpattern_definitions()
+            .build();
+        let expected_output = "(?i)(?(DEFINE)(?P<sub1>bar|foo))^(x(?&sub1)y|z(?&sub1)w)$";
+        test_if_regexp_is_correct(regexp, expected_output, &test_cases);
+    }
+
+    #[test]
+    fn succeeds_with_expanded_subroutine_calls() {
+        let test_cases = vec!["red-apple", "red-pear", "green-apple", "green-pear", "ripe"];
+        let regexp = RegExpBuilder::from(&test_cases)
+            .with_flavor(Flavor::Pcre)
+            .with_subpattern_definitions()
+            .build();
+        let definitions = "(?(DEFINE)(?P<sub1>apple|pear))";
+        assert!(regexp.starts_with(definitions));
+
+        let expanded_regexp = regexp[definitions.len()..].replace("(?&sub1)", "(?:apple|pear)");
+        let plain_regexp = RegExpBuilder::from(&test_cases)
+            .with_flavor(Flavor::Pcre)
+            .build();
+        assert_eq!(expanded_regexp, plain_regexp);
+        test_if_regexp_matches_test_cases(&expanded_regexp, test_cases);
+    }
+
+    #[rstest(
+        flavor,
+        is_verbose_mode_enabled,
+        expected_warning,
+        case(
+            Flavor::Rust,
+            false,
+            "subpattern definitions are not supported by the chosen flavor"
+        ),
+        case(
+            Flavor::PosixExtended,
+            false,
+            "subpattern definitions are not supported by the chosen flavor"
+        ),
+        case(
+            Flavor::Pcre,
+            true,
+            "subpattern definitions are not supported in verbose mode"
+        )
+    )]
+    fn succeeds_with_subpattern_definitions_ignored(
+        flavor: Flavor,
+        is_verbose_mode_enabled: bool,
+        expected_warning: &str,
+    ) {
+        let mut builder = RegExpBuilder::from(&["xfooy", "xbary", "zfoow", "zbarw"]);
+        builder.with_flavor(flavor).with_subpattern_definitions();
+        if is_verbose_mode_enabled {
+            builder.with_verbose_mode();
+        }
+        let (regexp, warnings) = builder.build_with_warnings();
+        assert!(!regexp.to_string().contains("DEFINE"));
+        assert_eq!(
+            warnings,
+            vec![GenerationWarning::SettingIgnored(
+                expected_warning.to_string()
+            )]
+        );
+    }
+}
+
+mod streaming {
+    use super::*;
+
+    #[test]
+    fn succeeds_with_initial_test_cases_only() {
+        let streaming = RegExpBuilder::from(&["abc", "abd"]).to_streaming();
+        assert_eq!(streaming.current_pattern(), "^ab[cd]$");
+        assert_eq!(streaming.test_case_count(), 2);
+    }
+
+    #[test]
+    fn succeeds_with_test_cases_in_batches() {
+        let batches = vec![
+            vec!["abc", "abd"],
+            vec!["xyz", "abc"],
+            vec!["a1", "a22", "a333"],
+        ];
+        let mut streaming = RegExpBuilder::from(&batches[0]).to_streaming();
+        let mut seen = batches[0].clone();
+
+        for batch in batches.iter().skip(1) {
+            streaming.add_test_cases(batch);
+            seen.extend(batch);
+            let pattern = streaming.current_pattern();
+            test_if_regexp_matches_test_cases(&pattern, seen.clone());
+            test_if_regexp_does_not_match_other_strings(&pattern, vec!["ab", "abcd", "a4444"]);
+        }
+        assert_eq!(streaming.test_case_count(), 7);
+        assert_eq!(streaming.current_pattern(), "^(?:a(?:333|b[cd]|22|1)|xyz)$");
+    }
+
+    #[test]
+    fn succeeds_with_conversion_features() {
+        let mut streaming = RegExpBuilder::from(&["aaa"])
+            .with_conversion_of(&[Feature::Repetition, Feature::Digit])
+            .to_streaming();
+        streaming.add_test_cases(&["12", "bbb"]);
+        let pattern = streaming.current_pattern();
+        assert_eq!(pattern, "^(?:a{3}|\\d{2}|b{3})$");
+        test_if_regexp_matches_test_cases(&pattern, vec!["aaa", "12", "bbb", "98"]);
+    }
+
+    #[test]
+    fn succeeds_with_case_insensitive_matching() {
+        let mut streaming = RegExpBuilder::from(&["ABC"])
+            .with_conversion_of(&[Feature::CaseInsensitivity])
+            .to_streaming();
+        streaming.add_test_cases(&["abd"]);
+        assert_eq!(streaming.current_pattern(), "(?i)^ab[cd]$");
+    }
+
+    #[test]
+    fn ignores_settings_depending_on_all_test_cases() {
+        let mut streaming = RegExpBuilder::from(&["192.168.0.1"])
+            .with_structured_ipv4()
+            .to_streaming();
+        streaming.add_test_cases(&["10.0.0.1"]);
+        let pattern = streaming.current_pattern();
+        test_if_regexp_matches_test_cases(&pattern, vec!["192.168.0.1", "10.0.0.1"]);
+        test_if_regexp_does_not_match_other_strings(&pattern, vec!["10.0.0.2"]);
+    }
+}
+
+mod unicode_word_chars {
+    use super::*;
+
+    #[rstest(
+        flavor,
+        expected_output,
+        case(Flavor::Rust, "^\\w{3}$"),
+        case(Flavor::Pcre, "^[\\p{L}\\p{M}\\p{Nd}\\p{Pc}\\x{200C}\\x{200D}]{3}$")
+    )]
+    fn succeeds_with_unicode_word_chars(flavor: Flavor, expected_output: &str) {
+        let test_cases = vec!["äöü", "дом", "a_1"];
+        let regexp = RegExpBuilder::from(&test_cases)
+            .with_conversion_of(&[Feature::Word, Feature::Repetition])
+            .with_unicode_word_chars()
+            .with_flavor(flavor)
+            .build();
+        assert_eq!(regexp, expected_output);
+        test_if_regexp_matches_test_cases(expected_output, test_cases);
+        test_if_regexp_does_not_match_other_strings(expected_output, vec!["a-b", "ä b"]);
+    }
+
+    #[rstest(test_case, expected_output,
+        case("e\u{301}", "^[\\p{L}\\p{M}\\p{Nd}\\p{Pc}\\x{200C}\\x{200D}]{2}$"),
+        case("a\u{203F}b", "^[\\p{L}\\p{M}\\p{Nd}\\p{Pc}\\x{200C}\\x{200D}]{3}$"),
+        case(
+            "a\u{200D}b",
+            "^[\\p{L}\\p{M}\\p{Nd}\\p{Pc}\\x{200C}\\x{200D}][\\p{L}\\p{M}\\p{Nd}\\p{Pc}\\x{200C}\\x{200D}][\\p{L}\\p{M}\\p{Nd}\\p{Pc}\\x{200C}\\x{200D}]$"
+        )
+    )]
+    fn succeeds_with_unicode_word_chars_beyond_letters_and_numbers(
+        test_case: &str,
+        expected_output: &str,
+    ) {
+        let regexp = RegExpBuilder::from(&[test_case])
+            .with_conversion_of(&[Feature::Word, Feature::Repetition])
+            .with_unicode_word_chars()
+            .with_flavor(Flavor::Pcre)
+            .build();
+        assert_eq!(regexp, expected_output);
+        test_if_regexp_matches_test_cases(expected_output, vec![test_case]);
+    }
+
+    #[test]
+    fn succeeds_with_ascii_word_chars_by_default() {
+        let regexp = RegExpBuilder::from(&["äöü", "abc"])
+            .with_conversion_of(&[Feature::Word, Feature::Repetition])
+            .with_flavor(Flavor::Pcre)
+            .build();
+        assert_eq!(regexp, "^\\w{3}$");
+    }
+
+    #[test]
+    fn succeeds_with_unicode_non_word_chars() {
+        let regexp = RegExpBuilder::from(&["-", "+"])
+            .with_conversion_of(&[Feature::NonWord])
+            .with_unicode_word_chars()
+            .with_flavor(Flavor::Pcre)
+            .build();
+        assert_eq!(regexp, "^[^\\p{L}\\p{M}\\p{Nd}\\p{Pc}\\x{200C}\\x{200D}]$");
+    }
+
+    #[test]
+    fn succeeds_without_word_shorthand_for_minimized_length() {
+        let test_cases = ('0'..='9')
+            .chain('A'..='Z')
+            .chain('a'..='z')
+            .chain("_.".chars())
+            .map(|c| c.to_string())
+            .collect::<Vec<_>>();
+        let regexp = RegExpBuilder::from(&test_cases)
+            .with_minimized_length()
+            .with_unicode_word_chars()
+            .with_flavor(Flavor::Pcre)
+            .build();
+        assert_eq!(regexp, "^[\\d.A-Z_a-z]$");
+    }
+
+    #[rstest(
+        flavor,
+        class_syntaxes,
+        expected_output,
+        expected_warning,
+        case(
+            Flavor::PosixExtended,
+            vec![ClassSyntax::Posix],
+            "^ä$",
+            "unicode word characters are not supported by the chosen flavor"
+        ),
+        case(
+            Flavor::Pcre,
+            vec![ClassSyntax::Literal],
+            "^ä$",
+            "unicode word characters require the perl class syntax"
+        )
+    )]
+    fn fails_with_unsupported_settings(
+        flavor: Flavor,
+        class_syntaxes: Vec<ClassSyntax>,
+        expected_output: &str,
+        expected_warning: &str,
+    ) {
+        let (regexp, warnings) = RegExpBuilder::from(&["ä"])
+            .with_conversion_of(&[Feature::Word])
+            .with_unicode_word_chars()
+            .with_class_syntax_preference(&class_syntaxes)
+            .with_flavor(flavor)
+            .build_with_warnings();
+        assert_eq!(regexp.to_string(), expected_output);
+        assert!(warnings.contains(&GenerationWarning::SettingIgnored(
+            expected_warning.to_string()
+        )));
+    }
+}
+
+mod trailing_catch_all {
+    use super::*;
+
+    #[test]
+    fn succeeds_with_trailing_catch_all() {
+        let regexp = RegExpBuilder::from(&["ERROR", "WARN"])
+            .with_trailing_catch_all()
+            .build();
+        assert_eq!(regexp, "^(?:ERROR|WARN).*");
+        test_if_regexp_matches_test_cases(
+            &regexp,
+            vec![
+                "ERROR",
+                "WARN disk almost full",
+                "ERROR: connection refused",
+            ],
+        );
+        test_if_regexp_does_not_match_other_strings(
+            &regexp,
+            vec!["INFO started", "[ERROR] failed", "WAR"],
+        );
+    }
+
+    #[test]
+    fn succeeds_with_catch_all_not_matching_newlines() {
+        let regexp = RegExpBuilder::from(&["ERROR"])
+            .with_trailing_catch_all()
+            .build();
+        let re = Regex::new(&regexp).unwrap();
+        assert_eq!(
+            re.find("ERROR first\nsecond").map(|it| it.as_str()),
+            Some("ERROR first")
+        );
+    }
+
+    #[rstest(
+        flavor,
+        expected_output,
+        case(Flavor::Rust, "(?s)^ERROR.*"),
+        case(Flavor::PosixExtended, "^ERROR.*")
+    )]
+    fn succeeds_with_catch_all_matching_newlines(flavor: Flavor, expected_output: &str) {
+        let regexp = RegExpBuilder::from(&["ERROR"])
+            .with_trailing_catch_all()
+            .with_dot_matching_newlines()
+            .with_flavor(flavor)
+            .build();
+        assert_eq!(regexp, expected_output);
+    }
+
+    #[test]
+    fn succeeds_with_catch_all_matching_newlines_in_log() {
+        let regexp = RegExpBuilder::from(&["ERROR"])
+            .with_trailing_catch_all()
+            .with_dot_matching_newlines()
+            .build();
+        let re = Regex::new(&regexp).unwrap();
+        assert_eq!(
+            re.find("ERROR first\nsecond").map(|it| it.as_str()),
+            Some("ERROR first\nsecond")
+        );
+    }
+
+    #[test]
+    fn succeeds_with_catch_all_replacing_end_word_boundary() {
+        let (regexp, warnings) = RegExpBuilder::from(&["ERROR"])
+            .with_trailing_catch_all()
+            .with_word_boundary_at_end()
+            .build_with_warnings();
+        assert_eq!(regexp.to_string(), "^ERROR.*");
+        assert_eq!(
+            warnings,
+            vec![GenerationWarning::SettingIgnored(
+                "the end of the regular expression is replaced by a catch-all".to_string()
+            )]
+        );
+    }
+}
+
+mod ineffective_options {
+    use super::*;
+
+    #[test]
+    fn succeeds_with_repetition_conversion_without_repetitions() {
+        let regexp = RegExpBuilder::from(&["abc", "def"])
+            .with_conversion_of(&[Feature::Repetition])
+            .build_regexp();
+        assert_eq!(regexp.to_string(), "^(?:abc|def)$");
+        assert_eq!(
+            regexp.ineffective_options(),
+            vec!["with_conversion_of(Feature::Repetition)"]
+        );
+    }
+
+    #[test]
+    fn succeeds_with_effective_options_only() {
+        let regexp = RegExpBuilder::from(&["aaa", "1"])
+            .with_conversion_of(&[Feature::Repetition, Feature::Digit])
+            .build_regexp();
+        assert_eq!(regexp.to_string(), "^(?:\\d|a{3})$");
+        assert!(regexp.ineffective_options().is_empty());
+    }
+
+    #[test]
+    fn succeeds_with_several_ineffective_options() {
+        let regexp = RegExpBuilder::from(&["aaa", "bbb"])
+            .with_conversion_of(&[Feature::Repetition, Feature::Digit])
+            .with_suffix_factoring()
+            .with_minimized_length()
+            .build_regexp();
+        assert_eq!(regexp.to_string(), "^(?:a{3}|b{3})$");
+        assert_eq!(
+            regexp.ineffective_options(),
+            vec![
+                "with_conversion_of(Feature::Digit)",
+                "with_suffix_factoring",
+                "with_minimized_length"
+            ]
+        );
+    }
+
+    #[test]
+    fn succeeds_without_any_options() {
+        let regexp = RegExpBuilder::from(&["abc", "def"]).build_regexp();
+        assert!(regexp.ineffective_options().is_empty());
+    }
+}
+
+mod case_insensitive_classes {
+    use super::*;
+
+    #[rstest(
+        test_cases,
+        expected_output,
+        case(vec!["abc", "ABD"], "^[Aa][Bb][CDcd]$"),
+        case(vec!["ß", "a"], "^[Aaß]$"),
+        case(vec!["1", "x"], "^[1Xx]$")
+    )]
+    fn succeeds_with_case_insensitive_classes(test_cases: Vec<&str>, expected_output: &str) {
+        let regexp = RegExpBuilder::from(&test_cases)
+            .with_case_insensitive_classes()
+            .build();
+        assert_eq!(regexp, expected_output);
+    }
+
+    #[test]
+    fn succeeds_with_posix_flavor() {
+        let (regexp, warnings) = RegExpBuilder::from(&["abc", "ABD"])
+            .with_case_insensitive_classes()
+            .with_flavor(Flavor::PosixExtended)
+            .build_with_warnings();
+        assert_eq!(regexp.to_string(), "^[Aa][Bb][CDcd]$");
+        assert!(warnings.is_empty());
+    }
+
+    #[rstest(
+        test_cases,
+        expected_output,
+        case(vec!["k"], "^[Kk\\u{212a}]$"),
+        case(vec!["\u{212a}"], "^[Kk\\u{212a}]$"),
+        case(vec!["s"], "^[Ss\\u{17f}]$"),
+        case(vec!["\u{17f}"], "^[Ss\\u{17f}]$")
+    )]
+    fn succeeds_with_unicode_case_folding(test_cases: Vec<&str>, expected_output: &str) {
+        let regexp = RegExpBuilder::from(&test_cases)
+            .with_case_insensitive_classes()
+            .with_unicode_case_folding()
+            .with_escaping_of_non_ascii_chars(false)
+            .build();
+        assert_eq!(regexp, expected_output);
+    }
+
+    #[test]
+    fn succeeds_with_unicode_case_folding_of_all_variants() {
+        let regexp = RegExpBuilder::from(&["kiss"])
+            .with_case_insensitive_classes()
+            .with_unicode_case_folding()
+            .with_escaping_of_non_ascii_chars(false)
+            .build();
+        assert_eq!(regexp, "^[Kk\\u{212a}][Ii][Ss\\u{17f}][Ss\\u{17f}]$");
+        test_if_regexp_matches_test_cases(
+            &regexp,
+            vec!["kiss", "KISS", "\u{212a}iss", "ki\u{17f}\u{17f}"],
+        );
+    }
+
+    #[test]
+    fn fails_with_unicode_case_folding_only() {
+        let (regexp, warnings) = RegExpBuilder::from(&["k"])
+            .with_unicode_case_folding()
+            .build_with_warnings();
+        assert_eq!(regexp.to_string(), "^k$");
+        assert_eq!(
+            warnings,
+            vec![GenerationWarning::SettingIgnored(
+                "unicode case folding requires case-insensitive classes".to_string()
+            )]
+        );
+    }
+}
+
+mod outer_group {
+    use super::*;
+
+    #[rstest(
+        test_cases,
+        expected_output,
+        case(vec!["abc"], "^(?:abc)$"),
+        case(vec!["abc", "abd"], "^(?:ab[cd])$"),
+        case(vec!["abc", "xyz"], "^(?:abc|xyz)$")
+    )]
+    fn succeeds_with_outer_group(test_cases: Vec<&str>, expected_output: &str) {
+        let regexp = RegExpBuilder::from(&test_cases).with_outer_group().build();
+        assert_eq!(regexp, expected_output);
+        test_if_regexp_matches_test_cases(&regexp, test_cases);
+    }
+
+    #[test]
+    fn succeeds_with_capturing_outer_group() {
+        let regexp = RegExpBuilder::from(&["abc"])
+            .with_outer_group()
+            .with_conversion_of(&[Feature::CapturingGroup])
+            .build();
+        assert_eq!(regexp, "^(abc)$");
+        let captures = Regex::new(&regexp).unwrap().captures("abc").unwrap();
+        assert_eq!(captures.get(1).map(|it| it.as_str()), Some("abc"));
+    }
+
+    #[test]
+    fn succeeds_with_outer_group_in_verbose_mode() {
+        let regexp = RegExpBuilder::from(&["abc"])
+            .with_outer_group()
+            .with_verbose_mode()
+            .build();
+        assert_eq!(regexp, "(?x)\n^\n(?:\n  abc\n)\n$");
+    }
+
+    #[test]
+    fn succeeds_without_outer_group_by_default() {
+        let regexp = RegExpBuilder::from(&["abc"]).build();
+        assert_eq!(regexp, "^abc$");
+    }
+}
+
+mod permuted_tokens {
+    use super::*;
+
+    #[rstest(test_cases, separator, expected_output,
+        case(vec!["a", "b"], ",", "^(?:a,b|b,a)$"),
+        case(
+            vec!["a", "b", "c"],
+            ",",
+            "^(?:(?:a,b|b,a),c|a,c,b|b,c,a|c,(?:a,b|b,a))$"
+        ),
+        case(vec!["a", "b", "c"], "", "^(?:(?:ab|ba)c|acb|bca|c(?:ab|ba))$")
+    )]
+    fn succeeds(test_cases: Vec<&str>, separator: &str, expected_output: &str) {
+        let regexp = RegExpBuilder::from(&test_cases)
+            .with_permuted_tokens(separator)
+            .build();
+        test_if_regexp_is_correct(regexp, expected_output, &test_cases);
+    }
+
+    #[rstest(test_cases, separator, permutations, other_strings,
+        case(
+            vec!["red", "green"],
+            ",",
+            vec!["red,green", "green,red"],
+            vec!["red", "red,red", "green,green", "red,green,red"]
+        ),
+        case(
+            vec!["foo", "bar", "baz"],
+            " ",
+            vec![
+                "foo bar baz",
+                "foo baz bar",
+                "bar foo baz",
+                "bar baz foo",
+                "baz foo bar",
+                "baz bar foo"
+            ],
+            vec!["foo bar", "foo foo bar", "foo bar baz foo", "foobarbaz"]
+        )
+    )]
+    fn succeeds_with_all_orderings(
+        test_cases: Vec<&str>,
+        separator: &str,
+        permutations: Vec<&str>,
+        other_strings: Vec<&str>,
+    ) {
+        let regexp = RegExpBuilder::from(&test_cases)
+            .with_permuted_tokens(separator)
+            .build();
+        test_if_regexp_matches_test_cases(&regexp, permutations);
+        test_if_regexp_does_not_match_other_strings(&regexp, other_strings);
+    }
+
+    #[rstest(test_cases, separator, expected_output,
+        case(
+            vec!["a", "b"],
+            ",",
+            "^(?=(?:.*,)?a(?:,|$))(?=(?:.*,)?b(?:,|$))[ab],[ab]$"
+        ),
+        case(
+            vec!["foo", "bar", "baz"],
+            " ",
+            "^(?=(?:.* )?bar(?: |$))(?=(?:.* )?baz(?: |$))(?=(?:.* )?foo(?: |$))(?:ba[rz]|foo)(?: (?:ba[rz]|foo)){2}$"
+        ),
+        case(
+            vec!["a.b", "c"],
+            ".",
+            "^(?=(?:.*\\.)?c(?:\\.|$))(?=(?:.*\\.)?a\\.b(?:\\.|$))(?:a\\.b|c)\\.(?:a\\.b|c)$"
+        ),
+        case(vec!["a", "b"], "", "^(?:ab|ba)$")
+    )]
+    fn succeeds_with_lookaheads_in_pcre_flavor(
+        test_cases: Vec<&str>,
+        separator: &str,
+        expected_output: &str,
+    ) {
+        let regexp = RegExpBuilder::from(&test_cases)
+            .with_permuted_tokens(separator)
+            .with_flavor(Flavor::Pcre)
+            .build();
+        test_if_regexp_is_correct(regexp, expected_output, &test_cases);
+    }
+
+    #[test]
+    fn succeeds_with_warning_for_too_many_tokens() {
+        let test_cases = vec!["1", "2", "3", "4", "5", "6"];
+        let (regexp, warnings) = RegExpBuilder::from(&test_cases)
+            .with_permuted_tokens(",")
+            .build_with_warnings();
+        test_if_regexp_is_correct(regexp.to_string(), "^[1-6]$", &test_cases);
+        assert_eq!(
+            warnings,
+            vec![GenerationWarning::SettingIgnored(
+                "permuted tokens are limited to 5 test cases".to_string()
+            )]
+        );
+    }
+
+    #[test]
+    fn succeeds_with_repeated_builds() {
+        let mut builder = RegExpBuilder::from(&["a", "b"]);
+        builder
+            .with_permuted_tokens(",")
+            .with_flavor(Flavor::PosixExtended);
+        assert_eq!(builder.build(), "^(a,b|b,a)$");
+        assert_eq!(builder.build(), "^(a,b|b,a)$");
+    }
+}
+
+mod quantifier_threshold {
+    use super::*;
+
+    #[rstest(test_cases, threshold, expected_output,
+        case(vec!["aa"], 1, "^aa$"),
+        case(vec!["aaaaa"], 1, "^a{5}$"),
+        case(vec!["aaaaa"], 2, "^aaaaa$"),
+        case(vec!["aaaaaa"], 2, "^a{6}$"),
+        case(vec!["abababab"], 1, "^abababab$"),
+        case(vec!["ababababab"], 1, "^(?:ab){5}$"),
+        case(vec!["..."], 1, "^\\.{3}$"),
+        case(vec!["aa", "aaaaa"], 1, "^(?:aa|a{5})$")
+    )]
+    fn succeeds(test_cases: Vec<&str>, threshold: u32, expected_output: &str) {
+        let regexp = RegExpBuilder::from(&test_cases)
+            .with_conversion_of(&[Feature::Repetition])
+            .with_quantifier_threshold(threshold)
+            .build();
+        test_if_regexp_is_correct(regexp, expected_output, &test_cases);
+        test_if_regexp_matches_test_cases(expected_output, test_cases);
+    }
+
+    #[test]
+    fn warns_without_conversion_of_repetitions() {
+        let test_cases = vec!["aaaaa"];
+        let (regexp, warnings) = RegExpBuilder::from(&test_cases)
+            .with_quantifier_threshold(1)
+            .build_with_warnings();
+        test_if_regexp_is_correct(regexp.to_string(), "^aaaaa$", &test_cases);
+        assert_eq!(
+            warnings,
+            vec![GenerationWarning::SettingIgnored(
+                "the quantifier threshold requires the conversion of repetitions".to_string()
+            )]
+        );
+    }
+}
+
+mod skeleton_grouping {
+    use super::*;
+
+    #[rstest(test_cases, expected_output,
+        case(
+            vec!["ERROR 404", "WARN 500", "2024-01-15 started", "2024-02-03 stopped"],
+            "^(?:2024\\-0(?:1\\-15 start|2\\-03 stopp)ed|ERROR 404|WARN 500)$"
+        ),
+        case(
+            vec!["user=alice id=42", "user=bob id=7", "GET /index.html 200"],
+            "^(?:GET /index\\.html 200|user=(?:alice id=42|bob id=7))$"
+        )
+    )]
+    fn succeeds(test_cases: Vec<&str>, expected_output: &str) {
+        let regexp = RegExpBuilder::from(&test_cases)
+            .with_skeleton_grouping()
+            .build();
+        test_if_regexp_is_correct(regexp, expected_output, &test_cases);
+        test_if_regexp_matches_test_cases(expected_output, test_cases);
+    }
+
+    #[rstest(test_cases, expected_output,
+        case(vec!["abc", "def"], "^(?:abc|def)$"),
+        case(vec!["a1", "b22"], "^(?:b22|a1)$")
+    )]
+    fn succeeds_with_single_skeleton(test_cases: Vec<&str>, expected_output: &str) {
+        let regexp = RegExpBuilder::from(&test_cases)
+            .with_skeleton_grouping()
+            .build();
+        test_if_regexp_is_correct(regexp, expected_output, &test_cases);
+        test_if_regexp_matches_test_cases(expected_output, test_cases);
+    }
+
+    #[test]
+    fn succeeds_with_conversion_features() {
+        let test_cases = vec![
+            "ERROR 404",
+            "WARN 500",
+            "2024-01-15 started",
+            "2024-02-03 stopped",
+        ];
+        let expected_output =
+            "^(?:\\d\\d\\d\\d\\-\\d\\d\\-\\d\\d st(?:art|opp)ed|(?:ERROR|WARN) \\d\\d\\d)$";
+        let (regexp, warnings) = RegExpBuilder::from(&test_cases)
+            .with_conversion_of(&[Feature::Digit])
+            .with_skeleton_grouping()
+            .build_with_warnings();
+        test_if_regexp_is_correct(regexp.to_string(), expected_output, &test_cases);
+        test_if_regexp_matches_test_cases(expected_output, test_cases);
+        test_if_regexp_does_not_match_other_strings(
+            expected_output,
+            vec!["ERROR 2024-01-15", "2024-01-15 404", "WARN started"],
+        );
+        assert_eq!(
+            warnings,
+            vec![GenerationWarning::CharacterClassGeneralized(
+                "\\d".to_string()
+            )]
+        );
+    }
+}
+
+mod astral_code_points {
+    use super::*;
+
+    #[rstest(
+        flavor,
+        expected_output,
+        case(Flavor::Rust, "^[\\u{1f600}-\\u{1f603}\\u{1f64f}]$"),
+        case(Flavor::Pcre, "^[\\x{1f600}-\\x{1f603}\\x{1f64f}]$")
+    )]
+    fn succeeds_with_escaped_range(flavor: Flavor, expected_output: &str) {
+        let test_cases = vec!["😀", "😁", "😂", "😃", "🙏"];
+        let regexp = RegExpBuilder::from(&test_cases)
+            .with_flavor(flavor)
+            .with_escaping_of_non_ascii_chars(false)
+            .build();
+        test_if_regexp_is_correct(regexp, expected_output, &test_cases);
+        test_if_regexp_matches_test_cases(expected_output, test_cases);
+        test_if_regexp_does_not_match_other_strings(expected_output, vec!["😄", "🙎", "😀😀"]);
+    }
+
+    #[rstest(
+        flavor,
+        case(Flavor::Rust),
+        case(Flavor::Pcre),
+        case(Flavor::PosixBasic),
+        case(Flavor::PosixExtended)
+    )]
+    fn succeeds_with_unescaped_range(flavor: Flavor) {
+        let test_cases = vec!["😀", "😁", "😂", "😃", "🙏"];
+        let regexp = RegExpBuilder::from(&test_cases).with_flavor(flavor).build();
+        test_if_regexp_is_correct(regexp, "^[😀-😃🙏]$", &test_cases);
+        test_if_regexp_matches_test_cases("^[😀-😃🙏]$", test_cases);
+    }
+
+    #[test]
+    fn succeeds_with_surrogate_pairs_outside_of_classes() {
+        let test_cases = vec!["é", "\u{fffd}", "😀", "😁"];
+        let regexp = RegExpBuilder::from(&test_cases)
+            .with_escaping_of_non_ascii_chars(true)
+            .build();
+        test_if_regexp_is_correct(
+            regexp,
+            "^(?:[\\u{e9}\\u{fffd}]|\\u{d83d}\\u{de00}|\\u{d83d}\\u{de01})$",
+            &test_cases,
+        );
+    }
+
+    #[test]
+    fn succeeds_with_surrogate_pairs_ignored_by_pcre() {
+        let test_cases = vec!["😀😀", "😁😁"];
+        let (regexp, warnings) = RegExpBuilder::from(&test_cases)
+            .with_conversion_of(&[Feature::Repetition])
+            .with_flavor(Flavor::Pcre)
+            .with_escaping_of_non_ascii_chars(true)
+            .build_with_warnings();
+        let expected_output = "^(?:\\x{1f600}{2}|\\x{1f601}{2})$";
+        test_if_regexp_is_correct(regexp.to_string(), expected_output, &test_cases);
+        test_if_regexp_matches_test_cases(expected_output, test_cases);
+        assert_eq!(
+            warnings,
+            vec![GenerationWarning::SettingIgnored(
+                "surrogate pairs are not supported by the chosen flavor".to_string()
+            )]
+        );
+    }
+
+    #[test]
+    fn succeeds_with_escaped_whitespace_in_pcre_verbose_mode() {
+        let test_cases = vec!["a\u{a0}b"];
+        let regexp = RegExpBuilder::from(&test_cases)
+            .with_flavor(Flavor::Pcre)
+            .with_verbose_mode()
+            .build();
+        test_if_regexp_is_correct(regexp, "(?x)\n^\na\\x{a0}b\n$", &test_cases);
+    }
+}
+
+mod backslashes {
+    use super::*;
+
+    #[rstest(
+        flavor,
+        test_case,
+        expected_output,
+        case(Flavor::Rust, "\\", "^\\\\$"),
+        case(Flavor::Rust, "\\\\", "^\\\\\\\\$"),
+        case(Flavor::Rust, "\\\\\\", "^\\\\\\\\\\\\$"),
+        case(Flavor::Pcre, "\\", "^\\\\$"),
+        case(Flavor::Pcre, "\\\\", "^\\\\\\\\$"),
+        case(Flavor::Pcre, "\\\\\\", "^\\\\\\\\\\\\$"),
+        case(Flavor::PosixBasic, "\\", "^\\\\$"),
+        case(Flavor::PosixBasic, "\\\\", "^\\\\\\\\$"),
+        case(Flavor::PosixBasic, "\\\\\\", "^\\\\\\\\\\\\$"),
+        case(Flavor::PosixExtended, "\\", "^\\\\$"),
+        case(Flavor::PosixExtended, "\\\\", "^\\\\\\\\$"),
+        case(Flavor::PosixExtended, "\\\\\\", "^\\\\\\\\\\\\$")
+    )]
+    fn succeeds_with_doubled_backslashes(flavor: Flavor, test_case: &str, expected_output: &str) {
+        let regexp = RegExpBuilder::from(&[test_case])
+            .with_flavor(flavor)
+            .build();
+        test_if_regexp_is_correct(regexp, expected_output, &[test_case]);
+    }
+
+    #[rstest(
+        test_case,
+        expected_output,
+        case("\\", "(?x)\n^\n\\\\\n$"),
+        case("\\\\", "(?x)\n^\n\\\\\\\\\n$"),
+        case("a\\\\\\ #", "(?x)\n^\na\\\\\\\\\\\\\\ \\#\n$")
+    )]
+    fn succeeds_with_doubled_backslashes_in_verbose_mode(test_case: &str, expected_output: &str) {
+        let regexp = RegExpBuilder::from(&[test_case])
+            .with_verbose_mode()
+            .build();
+        test_if_regexp_is_correct(regexp, expected_output, &[test_case]);
+        test_if_regexp_matches_test_cases(expected_output, vec![test_case]);
+    }
+
+    #[rstest(
+        test_cases,
+        expected_output,
+        case(vec!["\\", "\\\\", "\\\\\\"], "^\\\\(?:\\\\\\\\?)?$"),
+        case(vec!["\\", "a"], "^[\\\\a]$"),
+        case(vec!["a\\b", "a\\c"], "^a\\\\[bc]$"),
+        case(vec!["\\n", "\\\\d"], "^\\\\(?:\\\\d|n)$")
+    )]
+    fn succeeds_with_backslashes_among_other_chars(test_cases: Vec<&str>, expected_output: &str) {
+        let regexp = RegExpBuilder::from(&test_cases).build();
+        test_if_regexp_is_correct(regexp, expected_output, &test_cases);
+        test_if_regexp_matches_test_cases(expected_output, test_cases);
+        test_if_regexp_does_not_match_other_strings(expected_output, vec!["", "\\\\\\\\"]);
+    }
+
+    #[test]
+    fn succeeds_with_backslashes_and_conversion_of_repetitions() {
+        let test_cases = vec!["\\", "\\\\", "\\\\\\"];
+        let regexp = RegExpBuilder::from(&test_cases)
+            .with_conversion_of(&[Feature::Repetition])
+            .build();
+        test_if_regexp_is_correct(regexp, "^(?:\\\\){1,3}$", &test_cases);
+        test_if_regexp_matches_test_cases("^(?:\\\\){1,3}$", test_cases);
+    }
+
+    #[test]
+    fn succeeds_with_backslash_escaped_despite_unescaping() {
+        let test_cases = vec!["\\", "a"];
+        let (regexp, warnings) = RegExpBuilder::from(&test_cases)
+            .without_escaping_of(&['\\'])
+            .build_with_warnings();
+        test_if_regexp_is_correct(regexp.to_string(), "^[\\\\a]$", &test_cases);
+        assert_eq!(
+            warnings,
+            vec![GenerationWarning::SettingIgnored(
+                "the backslash is always escaped".to_string()
+            )]
+        );
+    }
+
+    #[test]
+    fn succeeds_with_backslash_escaped_next_to_unescaped_metachar() {
+        let test_cases = vec!["a\\", "a+"];
+        let (regexp, warnings) = RegExpBuilder::from(&test_cases)
+            .without_escaping_of(&['\\', '+'])
+            .build_with_warnings();
+        test_if_regexp_is_correct(regexp.to_string(), "^a[+\\\\]$", &test_cases);
+        assert!(warnings.contains(&GenerationWarning::MetacharacterUnescaped('+')));
+        assert!(!warnings.contains(&GenerationWarning::MetacharacterUnescaped('\\')));
+    }
+}
+
+mod common_positions {
+    use super::*;
+
+    #[rstest(
+        test_cases,
+        percentage,
+        expected_output,
+        case(vec!["a1", "a12345"], 100, "^a\\d(?:2345)?$"),
+        case(vec!["a1", "a12345"], 50, "^a\\d(?:\\d\\d\\d\\d)?$"),
+        case(vec!["1", "23", "456"], 100, "^\\d(?:56|3)?$"),
+        case(vec!["1", "23", "456"], 60, "^\\d(?:\\d6?)?$"),
+        case(vec!["12", "34"], 100, "^\\d\\d$")
+    )]
+    fn succeeds_with_digit_conversion(
+        test_cases: Vec<&str>,
+        percentage: u32,
+        expected_output: &str,
+    ) {
+        let regexp = RegExpBuilder::from(&test_cases)
+            .with_conversion_of(&[Feature::Digit])
+            .with_class_conversion_at_common_positions(percentage)
+            .build();
+        test_if_regexp_is_correct(regexp, expected_output, &test_cases);
+        test_if_regexp_matches_test_cases(expected_output, test_cases);
+    }
+
+    #[test]
+    fn succeeds_with_classes_confined_to_common_prefix() {
+        let test_cases = vec!["ab1", "cd2x", "ef34yz", "gh"];
+        let expected_output = "^\\w\\w(?:34yz|2x|1)?$";
+        let regexp = RegExpBuilder::from(&test_cases)
+            .with_conversion_of(&[Feature::Word])
+            .with_class_conversion_at_common_positions(100)
+            .build();
+        test_if_regexp_is_correct(regexp, expected_output, &test_cases);
+        test_if_regexp_matches_test_cases(expected_output, test_cases);
+        test_if_regexp_does_not_match_other_strings(expected_output, vec!["ab2", "cd34yy", "e"]);
+    }
+
+    #[test]
+    fn succeeds_with_warning_without_char_class_feature() {
+        let (regexp, warnings) = RegExpBuilder::from(&["a1", "a12"])
+            .with_class_conversion_at_common_positions(100)
+            .build_with_warnings();
+        assert_eq!(regexp.to_string(), "^a12?$");
+        assert_eq!(
+            warnings,
+            vec![GenerationWarning::SettingIgnored(
+                "common positions require the conversion of character classes".to_string()
+            )]
+        );
+    }
+}
+
+mod profile {
+    use super::*;
+
+    #[test]
+    fn succeeds_with_toml_round_trip() {
+        assert_profile_round_trip(ProfileFormat::Toml);
+    }
+
+    #[test]
+    fn succeeds_with_yaml_round_trip() {
+        assert_profile_round_trip(ProfileFormat::Yaml);
+    }
+
+    #[test]
+    fn succeeds_with_pattern_and_metrics_in_toml() {
+        let regexp = RegExpBuilder::from(&["a", "b\\c"]).build_regexp();
+        let profile = regexp.to_profile(ProfileFormat::Toml);
+        assert!(profile.starts_with(
+            "pattern = \"^(?:b\\\\\\\\c|a)$\"\n\n[metrics]\npattern_length = 12\nbranch_count = 2\n"
+        ));
+        assert!(profile.contains("\n[config]\nflavor = \"Rust\"\nconversion_features = []\n"));
+        assert!(!profile.contains("memory_budget"));
+    }
+
+    #[test]
+    fn succeeds_with_pattern_and_metrics_in_yaml() {
+        let regexp = RegExpBuilder::from(&["a", "b"])
+            .with_flavor(Flavor::Pcre)
+            .build_regexp();
+        let profile = regexp.to_profile(ProfileFormat::Yaml);
+        assert!(profile.starts_with(
+            "pattern: \"^[ab]$\"\nmetrics:\n  pattern_length: 6\n  branch_count: 1\n"
+        ));
+        assert!(profile.contains("\nconfig:\n  flavor: \"Pcre\"\n"));
+    }
+
+    #[test]
+    fn succeeds_with_default_settings_for_missing_keys() {
+        let config = RegExpConfig::from_profile(
+            "[config]\nconversion_features = [\"Digit\"] # comment\nmemory_budget = 1000000\n",
+        )
+        .unwrap();
+        let regexp = RegExpBuilder::from(&["1", "2"])
+            .with_config(&config)
+            .build();
+        assert_eq!(regexp, "^\\d$");
+    }
+
+    #[test]
+    fn fails_with_invalid_syntax() {
+        assert_eq!(
+            RegExpConfig::from_profile("pattern = \"^a$\"\n\n[config]\nflavor \"Pcre\"\n"),
+            Err(ProfileError::InvalidSyntax(4))
+        );
+        assert_eq!(
+            RegExpConfig::from_profile("config:\n  escaped_chars: \"/\n"),
+            Err(ProfileError::InvalidSyntax(2))
+        );
+    }
+
+    #[test]
+    fn fails_with_unknown_key() {
+        assert_eq!(
+            RegExpConfig::from_profile("config:\n  is_magic_enabled: true\n"),
+            Err(ProfileError::UnknownKey("is_magic_enabled".to_string()))
+        );
+    }
+
+    #[test]
+    fn fails_with_invalid_value() {
+        for (profile, key) in &[
+            ("[config]\nflavor = \"Perl\"", "flavor"),
+            ("[config]\nmaximum_branches = 0", "maximum_branches"),
+            (
+                "[config]\nis_verbose_mode_enabled = 1",
+                "is_verbose_mode_enabled",
+            ),
+            ("[config]\nmutations = [\"Transposition(0)\"]", "mutations"),
+            (
+                "[config]\nplaceholder = [\"\\\\\", \"Digit\"]",
+                "placeholder",
+            ),
+        ] {
+            assert_eq!(
+                RegExpConfig::from_profile(profile),
+                Err(ProfileError::InvalidValue(key.to_string()))
+            );
+        }
+    }
+
+    fn assert_profile_round_trip(format: ProfileFormat) {
+        let test_cases = vec!["a\"b\tc", "1234", "12", "x/y"];
+        let regexp = RegExpBuilder::from(&test_cases)
+            .with_conversion_of(&[Feature::Digit, Feature::Repetition])
+            .with_escaping_of(&['/'])
+            .with_mutations(&[Mutation::Transposition(1)])
+            .with_repeated_tokens(",", 1, 3)
+            .with_lookbehind("(?:x)")
+            .with_line_ending_normalization(LineEnding::CrLf)
+            .with_placeholder('?', Wildcard::Digit)
+            .with_memory_budget(1_000_000)
+            .build_regexp();
+        let profile = regexp.to_profile(format);
+        let config = RegExpConfig::from_profile(&profile).unwrap();
+        let reloaded_regexp = RegExpBuilder::from(&test_cases)
+            .with_config(&config)
+            .build_regexp();
+
+        assert_eq!(reloaded_regexp.to_string(), regexp.to_string());
+        assert_eq!(reloaded_regexp.to_profile(format), profile);
+    }
+}
+
+mod generation_warnings {
+    use super::*;
+
+    #[test]
+    fn succeeds_without_warnings() {
+        let (regexp, warnings) = RegExpBuilder::from(&["abc", "def"]).build_with_warnings();
+        assert_eq!(regexp.to_string(), "^(?:abc|def)$");
+        assert!(warnings.is_empty());
+    }
+
+    #[rstest(test_cases, features, expected_warnings,
+        case(
+            vec!["a1", "b2"],
+            vec![Feature::Digit],
+            vec![GenerationWarning::CharacterClassGeneralized("\\d".to_string())]
+        ),
+        case(
+            vec!["a1", "b 2"],
+            vec![Feature::Digit, Feature::Space, Feature::Word],
+            vec![
+                GenerationWarning::CharacterClassGeneralized("\\d".to_string()),
+                GenerationWarning::CharacterClassGeneralized("\\s".to_string()),
+                GenerationWarning::CharacterClassGeneralized("\\w".to_string())
+            ]
+        ),
+        case(
+            vec!["abc"],
+            vec![Feature::Digit],
+            vec![]
+        ),
+        case(
+            vec!["abc", "ABC", "abc"],
+            vec![Feature::CaseInsensitivity],
+            vec![GenerationWarning::DuplicateTestCasesRemoved(2)]
+        )
+    )]
+    fn succeeds_with_conversion_features(
+        test_cases: Vec<&str>,
+        features: Vec<Feature>,
+        expected_warnings: Vec<GenerationWarning>,
+    ) {
+        let (_, warnings) = RegExpBuilder::from(&test_cases)
+            .with_conversion_of(&features)
+            .build_with_warnings();
+        assert_eq!(warnings, expected_warnings);
+    }
+
+    #[test]
+    fn succeeds_with_open_ended_repetitions() {
+        let (regexp, warnings) = RegExpBuilder::from(&["aa", "aaa", "aaaa"])
+            .with_conversion_of(&[Feature::Repetition])
+            .with_open_ended_repetitions()
+            .build_with_warnings();
+        assert_eq!(regexp.to_string(), "^a{2,}$");
+        assert_eq!(
+            warnings,
+            vec![GenerationWarning::RepetitionUpperBoundRelaxed]
+        );
+    }
+
+    #[test]
+    fn succeeds_with_open_ended_repetitions_without_repetition_feature() {
+        let (_, warnings) = RegExpBuilder::from(&["aa", "aaaa"])
+            .with_open_ended_repetitions()
+            .build_with_warnings();
+        assert_eq!(
+            warnings,
+            vec![GenerationWarning::SettingIgnored(
+                "open-ended repetitions require the conversion of repetitions".to_string()
+            )]
+        );
+    }
+
+    #[test]
+    fn succeeds_with_maximum_branches() {
+        let (_, warnings) = RegExpBuilder::from(&["foo", "bar", "baz"])
+            .with_maximum_branches(1)
+            .build_with_warnings();
+        assert_eq!(warnings, vec![GenerationWarning::AlternationBranchesMerged]);
+    }
+
+    #[test]
+    fn succeeds_with_word_class_threshold() {
+        let (regexp, warnings) = RegExpBuilder::from(&["a", "b", "c"])
+            .with_word_class_threshold(3)
+            .build_with_warnings();
+        assert_eq!(regexp.to_string(), "^\\w$");
+        assert_eq!(
+            warnings,
+            vec![GenerationWarning::CharacterClassGeneralized(
+                "\\w".to_string()
+            )]
+        );
+    }
+
+    #[rstest(flavor, expected_warnings,
+        case(
+            Flavor::Pcre,
+            vec![GenerationWarning::CharacterClassGeneralized("\\X".to_string())]
+        ),
+        case(
+            Flavor::Rust,
+            vec![GenerationWarning::SettingIgnored(
+                "conversion to \\X is not supported by the chosen flavor".to_string()
+            )]
+        )
+    )]
+    fn succeeds_with_any_grapheme_threshold(
+        flavor: Flavor,
+        expected_warnings: Vec<GenerationWarning>,
+    ) {
+        let (_, warnings) = RegExpBuilder::from(&["a", "b", "c"])
+            .with_flavor(flavor)
+            .with_any_grapheme_threshold(3)
+            .build_with_warnings();
+        assert_eq!(warnings, expected_warnings);
+    }
+}
+
+fn test_if_regexp_is_correct(regexp: String, expected_output: &str, test_cases: &[&str]) {
+    assert_eq!(
+        regexp, expected_output,
+        "\n\ninput: {:?}\nexpected: {}\nactual: {}\n\n",
+        test_cases, expected_output, regexp
+    );
+}
+
+fn test_if_regexp_matches_test_cases(expected_output: &str, test_cases: Vec<&str>) {
+    let re = Regex::new(expected_output).unwrap();
+    for test_case in test_cases {
+        assert!(
+            re.is_match(test_case),
+            "\n\n\"{}\" does not match regex {}\n\n",
+            test_case,
+            expected_output
+        );
+    }
+}
+
+fn test_if_regexp_does_not_match_other_strings(expected_output: &str, other_strings: Vec<&str>) {
+    let re = Regex::new(expected_output).unwrap();
+    for other_string in other_strings {
+        assert!(
+            !re.is_match(other_string),
+            "\n\n\"{}\" unexpectedly matches regex {}\n\n",
+            other_string,
             expected_output
         );
     }
@@ -47,7 +47,7 @@ proptest! {
     #[ignore]
     fn valid_regexes_with_conversion_features(
         test_cases in prop::collection::hash_set(".{1,20}", 1..=10),
-        conversion_features in prop::collection::hash_set(conversion_feature_strategy(), 1..=9),
+        conversion_features in prop::collection::hash_set(conversion_feature_strategy(), 1..=10),
         minimum_repetitions in 1..100u32,
         minimum_substring_length in 1..100u32
     ) {
@@ -64,7 +64,7 @@ proptest! {
     #[ignore]
     fn valid_regexes_with_conversion_features_and_escape_sequences(
         test_cases in prop::collection::hash_set(".{1,20}", 1..=10),
-        conversion_features in prop::collection::hash_set(conversion_feature_strategy(), 1..=9),
+        conversion_features in prop::collection::hash_set(conversion_feature_strategy(), 1..=10),
         minimum_repetitions in 1..100u32,
         minimum_substring_length in 1..100u32
     ) {
@@ -108,7 +108,7 @@ proptest! {
     #[ignore]
     fn matching_regexes_with_conversion_features(
         test_cases in prop::collection::hash_set(".{1,20}", 1..=10),
-        conversion_features in prop::collection::hash_set(conversion_feature_strategy(), 1..=9),
+        conversion_features in prop::collection::hash_set(conversion_feature_strategy(), 1..=10),
         minimum_repetitions in 1..100u32,
         minimum_substring_length in 1..100u32
     ) {
@@ -127,7 +127,7 @@ proptest! {
     #[ignore]
     fn matching_regexes_with_conversion_features_and_escape_sequences(
         test_cases in prop::collection::hash_set(".{1,20}", 1..=10),
-        conversion_features in prop::collection::hash_set(conversion_feature_strategy(), 1..=9),
+        conversion_features in prop::collection::hash_set(conversion_feature_strategy(), 1..=10),
         minimum_repetitions in 1..100u32,
         minimum_substring_length in 1..100u32
     ) {
@@ -143,6 +143,20 @@ proptest! {
         }
     }
 
+    #[test]
+    #[ignore]
+    fn matching_regexes_with_suffix_factoring(
+        test_cases in prop::collection::hash_set("[a-c]{1,8}", 1..=10)
+    ) {
+        let test_cases_vec = test_cases.iter().cloned().collect::<Vec<_>>();
+        let regexp = RegExpBuilder::from(&test_cases_vec)
+            .with_suffix_factoring()
+            .build();
+        if let Ok(compiled_regexp) = compile_regexp(&regexp) {
+            prop_assert!(test_cases.iter().all(|test_case| compiled_regexp.is_match(&test_case)));
+        }
+    }
+
     #[test]
     #[ignore]
     fn regexes_not_matching_other_strings_with_default_settings(
@@ -174,6 +188,23 @@ proptest! {
             }
         }
     }
+
+    #[test]
+    #[ignore]
+    fn regexes_not_matching_other_strings_with_suffix_factoring(
+        test_cases in prop::collection::hash_set("[a-c]{1,8}", 1..=10),
+        other_strings in prop::collection::hash_set("[a-c]{1,8}", 1..=10)
+    ) {
+        if test_cases.is_disjoint(&other_strings) {
+            let test_cases_vec = test_cases.iter().cloned().collect::<Vec<_>>();
+            let regexp = RegExpBuilder::from(&test_cases_vec)
+                .with_suffix_factoring()
+                .build();
+            if let Ok(compiled_regexp) = compile_regexp(&regexp) {
+                prop_assert!(other_strings.iter().all(|other_string| !compiled_regexp.is_match(&other_string)));
+            }
+        }
+    }
 }
 
 fn conversion_feature_strategy() -> impl Strategy<Value = Feature> {
@@ -184,6 +215,7 @@ fn conversion_feature_strategy() -> impl Strategy<Value = Feature> {
         Just(Feature::NonSpace),
         Just(Feature::Word),
         Just(Feature::NonWord),
+        Just(Feature::Script),
         Just(Feature::Repetition),
         Just(Feature::CaseInsensitivity),
         Just(Feature::CapturingGroup)